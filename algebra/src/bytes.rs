@@ -11,6 +11,24 @@ pub trait FromBytes: Sized {
     fn read<R: Read>(reader: R) -> IoResult<Self>;
 }
 
+pub trait FromBytesChecked: Sized {
+    /// Reads `Self` from `reader`, additionally checking that the result is
+    /// a valid element (e.g. a curve point on the curve and in the prime order
+    /// subgroup). A failed check is reported as an error of kind `InvalidData`
+    /// wrapping a `BitSerializationError`.
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self>;
+}
+
+pub trait ToCompressedBytes {
+    /// Serializes `self` into `writer` using its compressed representation.
+    fn write_compressed<W: Write>(&self, writer: W) -> IoResult<()>;
+}
+
+pub trait FromCompressedBytes: Sized {
+    /// Reads `Self` from its compressed representation in `reader`.
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self>;
+}
+
 macro_rules! array_bytes {
     ($N:expr) => {
         impl ToBytes for [u8; $N] {
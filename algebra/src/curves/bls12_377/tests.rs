@@ -13,6 +13,8 @@ use crate::{
         Field, FpParameters, PrimeField, SquareRootField,
     },
     groups::tests::group_test,
+    bytes::{FromBytesChecked, ToBytes},
+    BitSerializationError, UniformRand,
 };
 use std::ops::{AddAssign, MulAssign};
 
@@ -35,6 +37,42 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_checked_deserialization() {
+    let mut rng = rand::thread_rng();
+
+    // A point which is not on the curve
+    let g = G1Affine::prime_subgroup_generator();
+    let not_on_curve = G1Affine::new(g.x, g.y + &Fq::one(), false);
+    assert!(!not_on_curve.is_on_curve());
+
+    let mut serialized = vec![];
+    not_on_curve.write(&mut serialized).unwrap();
+    let e = G1Affine::read_checked(serialized.as_slice()).unwrap_err();
+    match e.get_ref().and_then(|e| e.downcast_ref::<BitSerializationError>()) {
+        Some(BitSerializationError::NotOnCurve) => {},
+        _ => panic!("unexpected error: {}", e),
+    }
+
+    // A point on the curve but outside the prime order subgroup
+    let not_in_subgroup = loop {
+        let x = Fq::rand(&mut rng);
+        if let Some(p) = G1Affine::get_point_from_x(x, false) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                break p;
+            }
+        }
+    };
+
+    let mut serialized = vec![];
+    not_in_subgroup.write(&mut serialized).unwrap();
+    let e = G1Affine::read_checked(serialized.as_slice()).unwrap_err();
+    match e.get_ref().and_then(|e| e.downcast_ref::<BitSerializationError>()) {
+        Some(BitSerializationError::NotPrimeOrder) => {},
+        _ => panic!("unexpected error: {}", e),
+    }
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
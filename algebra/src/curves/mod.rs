@@ -1,4 +1,4 @@
-use crate::{bytes::{FromBytes, ToBytes}, fields::{Field, PrimeField, SquareRootField}, groups::Group};
+use crate::{bytes::{FromBytes, FromBytesChecked, ToBytes, FromCompressedBytes, ToCompressedBytes}, fields::{Field, PrimeField, SquareRootField}, groups::Group};
 use crate::UniformRand;
 use std::{
     fmt::{Debug, Display},
@@ -115,6 +115,9 @@ pub trait ProjectiveCurve:
     + Sized
     + ToBytes
     + FromBytes
+    + FromBytesChecked
+    + ToCompressedBytes
+    + FromCompressedBytes
     + Copy
     + Clone
     + Default
@@ -200,6 +203,9 @@ pub trait AffineCurve:
     + Sized
     + ToBytes
     + FromBytes
+    + FromBytesChecked
+    + ToCompressedBytes
+    + FromCompressedBytes
     + Copy
     + Clone
     + Default
@@ -1,4 +1,10 @@
-use crate::{biginteger::BigInteger, fields::{Field, PrimeField, SquareRootField}};
+use crate::{
+    biginteger::BigInteger,
+    bits::BitSerializationError,
+    bytes::FromBytes,
+    fields::{Field, PrimeField, SquareRootField},
+};
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult};
 
 pub mod bls12;
 pub mod mnt4;
@@ -7,6 +13,38 @@ pub mod short_weierstrass_jacobian;
 pub mod short_weierstrass_projective;
 pub mod twisted_edwards_extended;
 
+/// Flags stored in the byte following the x-coordinate in the compressed
+/// byte representation of an affine point: whether the y-coordinate is the
+/// odd one of the two associated with x, and whether the point is the point
+/// at infinity.
+pub(crate) const COMPRESSED_PARITY_FLAG: u8 = 1;
+pub(crate) const COMPRESSED_INFINITY_FLAG: u8 = 1 << 1;
+
+/// Implements `FromBytesChecked::read_checked` for the points of the curve
+/// models: reads a point, then checks that its affine form `affine(&point)`
+/// is on the curve and in the prime order subgroup.
+pub(crate) fn read_checked_point<T, A, R>(
+    reader: R,
+    affine: impl FnOnce(&T) -> A,
+    is_on_curve: impl FnOnce(&A) -> bool,
+    is_in_correct_subgroup_assuming_on_curve: impl FnOnce(&A) -> bool,
+) -> IoResult<T>
+    where
+        T: FromBytes,
+        R: Read,
+{
+    let p = T::read(reader)?;
+    let p_affine = affine(&p);
+    let err = |e: BitSerializationError| IoError::new(ErrorKind::InvalidData, e);
+    if !is_on_curve(&p_affine) {
+        Err(err(BitSerializationError::NotOnCurve))
+    } else if !is_in_correct_subgroup_assuming_on_curve(&p_affine) {
+        Err(err(BitSerializationError::NotPrimeOrder))
+    } else {
+        Ok(p)
+    }
+}
+
 pub trait ModelParameters: Send + Sync + 'static {
     type BaseField: Field + SquareRootField;
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInt>;
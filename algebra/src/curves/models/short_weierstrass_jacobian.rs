@@ -1,14 +1,16 @@
 use rand::{Rng, distributions::{Standard, Distribution}};
-use crate::curves::models::SWModelParameters as Parameters;
-use crate::UniformRand;
+use crate::curves::models::{
+    SWModelParameters as Parameters, COMPRESSED_INFINITY_FLAG, COMPRESSED_PARITY_FLAG, read_checked_point,
+};
+use crate::{UniformRand, BitSerializationError};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
+    io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
     marker::PhantomData,
 };
 
 use crate::{
-    bytes::{FromBytes, ToBytes},
+    bytes::{FromBytes, FromBytesChecked, ToBytes, FromCompressedBytes, ToCompressedBytes},
     curves::{AffineCurve, ProjectiveCurve},
     fields::{BitIterator, Field, PrimeField, SquareRootField},
 };
@@ -199,6 +201,71 @@ impl<P: Parameters> FromBytes for GroupAffine<P> {
     }
 }
 
+impl<P: Parameters> FromBytesChecked for GroupAffine<P> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        read_checked_point(
+            reader,
+            |p: &Self| *p,
+            Self::is_on_curve,
+            Self::is_in_correct_subgroup_assuming_on_curve,
+        )
+    }
+}
+
+impl<P: Parameters> ToCompressedBytes for GroupAffine<P> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Strictly speaking, self.x is zero already when self.infinity is true, but
+        // to guard against implementation mistakes we do not assume this.
+        let x = if self.infinity {P::BaseField::zero()} else {self.x};
+        x.write(&mut writer)?;
+
+        let mut flags = 0u8;
+        if self.infinity {
+            flags |= COMPRESSED_INFINITY_FLAG;
+        } else if self.y.is_odd() {
+            flags |= COMPRESSED_PARITY_FLAG;
+        }
+        flags.write(writer)
+    }
+}
+
+impl<P: Parameters> FromCompressedBytes for GroupAffine<P> {
+    #[inline]
+    fn read_compressed<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let flags = u8::read(reader)?;
+        let parity_flag_set = flags & COMPRESSED_PARITY_FLAG != 0;
+        let infinity_flag_set = flags & COMPRESSED_INFINITY_FLAG != 0;
+        let err = |e: BitSerializationError| IoError::new(ErrorKind::InvalidData, e);
+
+        match (flags & !(COMPRESSED_PARITY_FLAG | COMPRESSED_INFINITY_FLAG), infinity_flag_set, parity_flag_set, x.is_zero()) {
+
+            //If the infinity flag is set, the x-coordinate must be zero
+            //and the parity flag must not be set.
+            (0, true, false, true) => Ok(Self::zero()),
+
+            //If infinity flag is not set, then we attempt to construct
+            //a point from the x coordinate and the parity.
+            (0, false, _, _) => {
+                let p = Self::get_point_from_x_and_parity(x, parity_flag_set)
+                    .ok_or(err(BitSerializationError::NotOnCurve))?;
+
+                //Check p belongs to the subgroup we expect
+                if p.is_in_correct_subgroup_assuming_on_curve() {
+                    Ok(p)
+                } else {
+                    Err(err(BitSerializationError::NotPrimeOrder))
+                }
+            },
+
+            //Other combinations are illegal
+            _ => Err(err(BitSerializationError::InvalidFlags)),
+        }
+    }
+}
+
 impl<P: Parameters> Default for GroupAffine<P> {
     #[inline]
     fn default() -> Self {
@@ -283,6 +350,32 @@ impl<P: Parameters> FromBytes for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> FromBytesChecked for GroupProjective<P> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        read_checked_point(
+            reader,
+            |p: &Self| p.into_affine(),
+            GroupAffine::is_on_curve,
+            GroupAffine::is_in_correct_subgroup_assuming_on_curve,
+        )
+    }
+}
+
+impl<P: Parameters> ToCompressedBytes for GroupProjective<P> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_affine().write_compressed(writer)
+    }
+}
+
+impl<P: Parameters> FromCompressedBytes for GroupProjective<P> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        GroupAffine::<P>::read_compressed(reader).map(|p| p.into_projective())
+    }
+}
+
 impl<P: Parameters> Default for GroupProjective<P> {
     #[inline]
     fn default() -> Self {
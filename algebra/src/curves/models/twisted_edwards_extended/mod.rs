@@ -1,15 +1,16 @@
 use rand::{Rng, distributions::{Standard, Distribution}};
-use crate::UniformRand;
+use crate::{UniformRand, BitSerializationError};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
+    io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use crate::{
-    bytes::{FromBytes, ToBytes},
-    curves::{models::TEModelParameters as Parameters, models::MontgomeryModelParameters as MontgomeryParameters, AffineCurve, ProjectiveCurve},
+    bytes::{FromBytes, FromBytesChecked, ToBytes, FromCompressedBytes, ToCompressedBytes},
+    curves::{models::TEModelParameters as Parameters, models::MontgomeryModelParameters as MontgomeryParameters,
+             models::COMPRESSED_PARITY_FLAG, models::read_checked_point, AffineCurve, ProjectiveCurve},
     fields::{BitIterator, Field, PrimeField, SquareRootField},
 };
 
@@ -241,6 +242,52 @@ impl<P: Parameters> FromBytes for GroupAffine<P> {
     }
 }
 
+impl<P: Parameters> FromBytesChecked for GroupAffine<P> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        read_checked_point(
+            reader,
+            |p: &Self| *p,
+            Self::is_on_curve,
+            Self::is_in_correct_subgroup_assuming_on_curve,
+        )
+    }
+}
+
+impl<P: Parameters> ToCompressedBytes for GroupAffine<P> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // The identity (0, 1) is an affine point like any other, hence
+        // the infinity flag is never set.
+        self.x.write(&mut writer)?;
+        let flags = if self.y.is_odd() {COMPRESSED_PARITY_FLAG} else {0u8};
+        flags.write(writer)
+    }
+}
+
+impl<P: Parameters> FromCompressedBytes for GroupAffine<P> {
+    #[inline]
+    fn read_compressed<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let flags = u8::read(reader)?;
+        let err = |e: BitSerializationError| IoError::new(ErrorKind::InvalidData, e);
+
+        if flags & !COMPRESSED_PARITY_FLAG != 0 {
+            return Err(err(BitSerializationError::InvalidFlags));
+        }
+
+        let p = Self::get_point_from_x_and_parity(x, flags == COMPRESSED_PARITY_FLAG)
+            .ok_or(err(BitSerializationError::NotOnCurve))?;
+
+        //Check p belongs to the subgroup we expect
+        if p.is_in_correct_subgroup_assuming_on_curve() {
+            Ok(p)
+        } else {
+            Err(err(BitSerializationError::NotPrimeOrder))
+        }
+    }
+}
+
 impl<P: Parameters> Default for GroupAffine<P> {
     #[inline]
     fn default() -> Self {
@@ -369,6 +416,32 @@ impl<P: Parameters> FromBytes for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> FromBytesChecked for GroupProjective<P> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        read_checked_point(
+            reader,
+            |p: &Self| p.into_affine(),
+            GroupAffine::is_on_curve,
+            GroupAffine::is_in_correct_subgroup_assuming_on_curve,
+        )
+    }
+}
+
+impl<P: Parameters> ToCompressedBytes for GroupProjective<P> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_affine().write_compressed(writer)
+    }
+}
+
+impl<P: Parameters> FromCompressedBytes for GroupProjective<P> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        GroupAffine::<P>::read_compressed(reader).map(|p| p.into_projective())
+    }
+}
+
 impl<P: Parameters> Default for GroupProjective<P> {
     #[inline]
     fn default() -> Self {
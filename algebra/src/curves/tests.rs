@@ -1,4 +1,5 @@
 use crate::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes, FromCompressedBytes, ToCompressedBytes},
     curves::{AffineCurve, ProjectiveCurve},
     fields::{Field, PrimeField},
 };
@@ -221,6 +222,62 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+fn random_compressed_serialization_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points = vec![G::zero()];
+    for _ in 0..ITERATIONS {
+        let g = G::rand(&mut rng);
+        points.push(g);
+        points.push(-g);
+    }
+
+    for g in points {
+        let g_affine = g.into_affine();
+
+        let mut compressed = vec![];
+        g_affine.write_compressed(&mut compressed).unwrap();
+        let mut uncompressed = vec![];
+        g_affine.write(&mut uncompressed).unwrap();
+        assert!(compressed.len() < uncompressed.len());
+
+        let g_affine_decompressed = G::Affine::read_compressed(compressed.as_slice()).unwrap();
+        assert_eq!(g_affine, g_affine_decompressed);
+        assert_eq!(g_affine, G::Affine::read(uncompressed.as_slice()).unwrap());
+
+        let mut compressed_projective = vec![];
+        g.write_compressed(&mut compressed_projective).unwrap();
+        assert_eq!(compressed, compressed_projective);
+        assert_eq!(g, G::read_compressed(compressed_projective.as_slice()).unwrap());
+
+        // An illegal flags byte must be rejected
+        let last = compressed.len() - 1;
+        compressed[last] = 0xFF;
+        assert!(G::Affine::read_compressed(compressed.as_slice()).is_err());
+    }
+}
+
+fn random_checked_serialization_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points = vec![G::zero()];
+    for _ in 0..ITERATIONS {
+        points.push(G::rand(&mut rng));
+    }
+
+    for g in points {
+        let g_affine = g.into_affine();
+
+        let mut serialized = vec![];
+        g_affine.write(&mut serialized).unwrap();
+        assert_eq!(g_affine, G::Affine::read_checked(serialized.as_slice()).unwrap());
+
+        let mut serialized_projective = vec![];
+        g.write(&mut serialized_projective).unwrap();
+        assert_eq!(g, G::read_checked(serialized_projective.as_slice()).unwrap());
+    }
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -279,4 +336,6 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_compressed_serialization_test::<G>();
+    random_checked_serialization_test::<G>();
 }
@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate criterion;
+
+use algebra::{fields::mnt4753::Fr as MNT4753Fr, UniformRand};
+use criterion::Criterion;
+use primitives::{
+    crh::{MNT4PoseidonHash, MNT4BatchPoseidonHash},
+    merkle_tree::field_based_mht::*,
+};
+
+struct MNT4753FieldBasedMerkleTreeParams;
+
+impl FieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+    const HEIGHT: usize = 13;
+    type H = MNT4PoseidonHash;
+}
+
+impl BatchFieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+    type BH = MNT4BatchPoseidonHash;
+}
+
+type MNT4753FieldBasedMerkleTree = FieldBasedMerkleHashTree<MNT4753FieldBasedMerkleTreeParams>;
+
+fn leaves() -> Vec<MNT4753Fr> {
+    let mut rng = &mut rand::thread_rng();
+    (0..1 << (MNT4753FieldBasedMerkleTreeParams::HEIGHT - 1)).map(|_| MNT4753Fr::rand(&mut rng)).collect()
+}
+
+fn field_based_mht_new(c: &mut Criterion) {
+    let leaves = leaves();
+    c.bench_function("Field Based Merkle Tree New 2^12 leaves", move |b| {
+        b.iter(|| {
+            MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+        })
+    });
+}
+
+fn field_based_mht_new_batch(c: &mut Criterion) {
+    let leaves = leaves();
+    c.bench_function("Field Based Merkle Tree New Batch 2^12 leaves", move |b| {
+        b.iter(|| {
+            MNT4753FieldBasedMerkleTree::new_batch(&leaves).unwrap();
+        })
+    });
+}
+
+criterion_group! {
+    name = field_based_mht;
+    config = Criterion::default().sample_size(10);
+    targets = field_based_mht_new, field_based_mht_new_batch
+}
+
+criterion_main!(field_based_mht);
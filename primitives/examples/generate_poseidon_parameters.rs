@@ -0,0 +1,49 @@
+//! Generates the Poseidon parameters of `primitives/src/crh/poseidon/parameters`
+//! for the fields other than the MNT4-753 and MNT6-753 scalar fields.
+//!
+//! Run with `cargo run --release --example generate_poseidon_parameters <out_dir>`.
+use algebra::{
+    fields::{
+        bls12_377::Fr as BLS12377Fr, bls12_381::Fr as BLS12381Fr, edwards_bls12::fr::Fr as EdwardsBls12Fr,
+        edwards_sw6::fr::Fr as EdwardsSW6Fr, jubjub::{fq::Fq as JubJubFq, fr::Fr as JubJubFr},
+        mnt6::Fr as MNT6298Fr, sw6::Fr as SW6Fr,
+    },
+    PrimeField,
+};
+use primitives::crh::poseidon::{
+    generator::{sbox_is_permutation, PoseidonInstance},
+    PoseidonSBox,
+};
+use std::{fs::File, path::Path};
+
+// Width of the state, for a 2:1 hash
+const T: usize = 3;
+const SECURITY_LEVEL: usize = 128;
+
+fn generate<F: PrimeField>(out_dir: &Path, file_name: &str, field_path: &str, struct_name: &str) {
+    // The cheapest S-Box which is a permutation of the field
+    let sbox = [PoseidonSBox::Alpha3, PoseidonSBox::Alpha5, PoseidonSBox::Alpha17]
+        .iter()
+        .cloned()
+        .find(|sbox| sbox_is_permutation::<F>(*sbox))
+        .unwrap_or(PoseidonSBox::Inverse);
+
+    let instance = PoseidonInstance::<F>::generate(T, sbox, SECURITY_LEVEL).unwrap();
+    let file = File::create(out_dir.join(file_name)).unwrap();
+    instance.write_rust_module(file, field_path, struct_name).unwrap();
+    println!("{}: {:?}, R_F = {}, R_P = {}", struct_name, sbox, instance.r_f, instance.r_p);
+}
+
+fn main() {
+    let out_dir = std::env::args().nth(1).expect("Usage: generate_poseidon_parameters <out_dir>");
+    let out_dir = Path::new(&out_dir);
+
+    generate::<BLS12381Fr>(out_dir, "bls12_381.rs", "algebra::fields::bls12_381::Fr", "BLS12381PoseidonParameters");
+    generate::<BLS12377Fr>(out_dir, "bls12_377.rs", "algebra::fields::bls12_377::Fr", "BLS12377PoseidonParameters");
+    generate::<JubJubFq>(out_dir, "jubjub_fq.rs", "algebra::fields::jubjub::fq::Fq", "JubJubFqPoseidonParameters");
+    generate::<JubJubFr>(out_dir, "jubjub_fr.rs", "algebra::fields::jubjub::fr::Fr", "JubJubFrPoseidonParameters");
+    generate::<EdwardsBls12Fr>(out_dir, "edwards_bls12.rs", "algebra::fields::edwards_bls12::fr::Fr", "EdwardsBls12FrPoseidonParameters");
+    generate::<SW6Fr>(out_dir, "sw6.rs", "algebra::fields::sw6::Fr", "SW6PoseidonParameters");
+    generate::<EdwardsSW6Fr>(out_dir, "edwards_sw6.rs", "algebra::fields::edwards_sw6::fr::Fr", "EdwardsSW6FrPoseidonParameters");
+    generate::<MNT6298Fr>(out_dir, "mnt6.rs", "algebra::fields::mnt6::Fr", "MNT6298PoseidonParameters");
+}
@@ -39,4 +39,19 @@ pub trait BatchFieldBasedHash {
     type Parameters: FieldBasedHashParameters<Fr = Self::Data>;
 
     fn batch_evaluate_2_1(input_array: &mut[Self::Data]);
-}
\ No newline at end of file
+}
+
+/// A duplex sponge over a field: a stateful object absorbing field elements
+/// and squeezing any number of field elements out of them.
+pub trait FieldBasedSponge: Clone {
+    type Data: Field;
+    type Parameters: FieldBasedHashParameters<Fr = Self::Data>;
+
+    /// Initializes the sponge, with `domain_tag` separating its outputs from
+    /// those of sponges with other tags.
+    fn new(domain_tag: Self::Data) -> Self;
+
+    fn absorb(&mut self, input: &[Self::Data]);
+
+    fn squeeze(&mut self, num: usize) -> Vec<Self::Data>;
+}
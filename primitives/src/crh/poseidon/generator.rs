@@ -0,0 +1,526 @@
+//! Generation of Poseidon instances, following the reference implementation
+//! of the Poseidon paper (https://eprint.iacr.org/2019/458): the number of
+//! rounds is derived from the security inequalities of the paper, while the
+//! round constants and the MDS matrix are sampled from the Grain LFSR seeded
+//! with the description of the instance, so that anyone can reproduce them.
+use algebra::{biginteger::BigInteger, Field, FpParameters, PrimeField};
+
+use std::io::{Result as IoResult, Write};
+
+use crate::crh::poseidon::PoseidonSBox;
+use crate::{CryptoError, Error};
+
+/// A Poseidon instance with capacity of one field element, as generated by
+/// `PoseidonInstance::generate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonInstance<F: PrimeField> {
+    /// Width of the state vector
+    pub t:               usize,
+    /// Number of full rounds, at the beginning and at the end of the permutation
+    pub r_f:             usize,
+    /// Number of partial rounds
+    pub r_p:             usize,
+    pub sbox:            PoseidonSBox,
+    /// Round constants, T for each round
+    pub round_cst:       Vec<F>,
+    /// The T x T MDS matrix, in row-major order
+    pub mds:             Vec<F>,
+    /// The state vector after a permutation of the zero state vector
+    pub after_zero_perm: Vec<F>,
+    /// The security level the number of rounds was chosen for
+    pub security_level:  usize,
+}
+
+impl<F: PrimeField> PoseidonInstance<F> {
+
+    /// Generates the instance of width `t` with the S-Box `sbox`, providing
+    /// `security_level` bits of security.
+    pub fn generate(t: usize, sbox: PoseidonSBox, security_level: usize) -> Result<Self, Error> {
+        if t < 2 {
+            return Err(Box::new(CryptoError::IncorrectInputLength("Poseidon width".to_owned(), t)));
+        }
+        if !sbox_is_permutation::<F>(sbox) {
+            return Err(Box::new(CryptoError::InvalidElement(format!("S-Box {:?}", sbox))));
+        }
+
+        let (full_rounds, r_p) = round_numbers::<F>(t, sbox, security_level);
+
+        let mut grain = GrainLfsr::new(sbox, F::Params::MODULUS_BITS as usize, t, full_rounds, r_p);
+        let round_cst = (0..(full_rounds + r_p) * t)
+            .map(|_| grain.next_field_element())
+            .collect::<Vec<F>>();
+        let mds = secure_cauchy_matrix(&mut grain, t);
+
+        let mut instance = Self {
+            t,
+            r_f: full_rounds / 2,
+            r_p,
+            sbox,
+            round_cst,
+            mds,
+            after_zero_perm: vec![],
+            security_level,
+        };
+        let mut state = vec![F::zero(); t];
+        instance.permute(&mut state);
+        instance.after_zero_perm = state;
+
+        Ok(instance)
+    }
+
+    // The permutation of PoseidonHash, from the constants of the instance
+    fn permute(&self, state: &mut [F]) {
+        let rounds = 2 * self.r_f + self.r_p;
+        for (round, rc) in self.round_cst.chunks(self.t).enumerate() {
+            state.iter_mut().zip(rc).for_each(|(d, c)| *d += c);
+            if round < self.r_f || round >= self.r_f + self.r_p {
+                state.iter_mut().for_each(|d| self.sbox.apply(d));
+            } else {
+                self.sbox.apply(&mut state[0]);
+            }
+            // The last round does not contain a matrix mix
+            if round < rounds - 1 {
+                let new_state = mat_vec_mul(&self.mds, state);
+                state.copy_from_slice(&new_state);
+            }
+        }
+    }
+
+    /// Writes the source of a module defining `PoseidonParameters` for the
+    /// instance, as a struct named `struct_name` over the field at `field_path`.
+    pub fn write_rust_module<W: Write>(
+        &self,
+        mut writer: W,
+        field_path: &str,
+        struct_name: &str,
+    ) -> IoResult<()>
+    {
+        let field = field_path.rsplit("::").next().unwrap();
+        let fe = |f: &F| {
+            let limbs = f.into_repr_raw();
+            let limbs = limbs.as_ref();
+            format!(
+                "field_new!({}, BigInteger{}([{}]))",
+                field,
+                64 * limbs.len(),
+                limbs.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(", ")
+            )
+        };
+        let table = |elems: &[F]| elems
+            .iter()
+            .map(|f| format!("        {},\n", fe(f)))
+            .collect::<String>();
+
+        writeln!(
+            writer,
+            "// Generated by PoseidonInstance::generate({}, PoseidonSBox::{:?}, {})",
+            self.t, self.sbox, self.security_level
+        )?;
+        writeln!(writer, "use crate::crh::{{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters}};")?;
+        writeln!(writer)?;
+        writeln!(writer, "use {};", field_path)?;
+        writeln!(writer)?;
+        writeln!(writer, "use algebra::biginteger::BigInteger{};", 64 * F::zero().into_repr_raw().as_ref().len())?;
+        writeln!(writer, "use algebra::field_new;")?;
+        writeln!(writer)?;
+        writeln!(writer, "pub struct {};", struct_name)?;
+        writeln!(writer)?;
+        writeln!(writer, "impl FieldBasedHashParameters for {} {{", struct_name)?;
+        writeln!(writer, "    type Fr = {};", field)?;
+        writeln!(writer, "}}")?;
+        writeln!(writer)?;
+        writeln!(writer, "impl PoseidonParameters for {} {{", struct_name)?;
+        writeln!(writer)?;
+        writeln!(writer, "    const T: usize = {};", self.t)?;
+        writeln!(writer, "    const R_F: i32 = {};", self.r_f)?;
+        writeln!(writer, "    const R_P: i32 = {};", self.r_p)?;
+        writeln!(writer, "    const R: usize = {};", self.t - 1)?;
+        writeln!(writer, "    const SBOX: PoseidonSBox = PoseidonSBox::{:?};", self.sbox)?;
+        writeln!(writer)?;
+        writeln!(writer, "    const ZERO: {} = {};", field, fe(&F::zero()))?;
+        writeln!(writer, "    const C2: {} = {};", field, fe(&F::from(3u64)))?;
+        writeln!(writer)?;
+        writeln!(writer, "    const AFTER_ZERO_PERM: &'static [{}] = &[", field)?;
+        write!(writer, "{}", table(&self.after_zero_perm))?;
+        writeln!(writer, "    ];")?;
+        writeln!(writer)?;
+        writeln!(writer, "    const ROUND_CST: &'static [{}] = &[", field)?;
+        write!(writer, "{}", table(&self.round_cst))?;
+        writeln!(writer, "    ];")?;
+        writeln!(writer)?;
+        writeln!(writer, "    const MDS_CST: &'static [{}] = &[", field)?;
+        write!(writer, "{}", table(&self.mds))?;
+        writeln!(writer, "    ];")?;
+        writeln!(writer, "}}")
+    }
+}
+
+/// Returns true if x -> x^alpha, or the inversion, is a permutation of `F`.
+pub fn sbox_is_permutation<F: PrimeField>(sbox: PoseidonSBox) -> bool {
+    match sbox.alpha() {
+        None => true,
+        Some(alpha) => {
+            // alpha is prime, hence coprime with p - 1 unless it divides it
+            let p_mod = F::characteristic()
+                .iter()
+                .rev()
+                .fold(0u128, |acc, limb| ((acc << 64) + *limb as u128) % alpha as u128);
+            p_mod != 1
+        }
+    }
+}
+
+/// Returns the number of full rounds, in total, and of partial rounds of the
+/// cheapest instance of width `t` resisting the attacks considered in the
+/// Poseidon paper with `security_level` bits of security, including the
+/// security margin of two full rounds and 7.5% of the partial rounds.
+pub fn round_numbers<F: PrimeField>(t: usize, sbox: PoseidonSBox, security_level: usize) -> (usize, usize) {
+    // The logarithm of the modulus, from its 64 most significant bits
+    let modulus = F::Params::MODULUS;
+    let n = F::Params::MODULUS_BITS as usize;
+    let top_bits = modulus.to_bits().iter().skip_while(|b| !**b).take(64).fold(0u64, |acc, b| (acc << 1) + *b as u64);
+    let log_p = (n - 64.min(n)) as f64 + (top_bits as f64).log2();
+
+    let mut best = (0, 0);
+    let mut min_cost = usize::MAX;
+    for r_p in 1..500 {
+        for r_f in (4..100).step_by(2) {
+            if is_secure(log_p, n as f64, t as f64, r_f as f64, r_p as f64, sbox.alpha(), security_level as f64) {
+                let r_f = r_f + 2;
+                let r_p = (r_p as f64 * 1.075).ceil() as usize;
+                let cost = r_f * t + r_p;
+                if cost < min_cost || (cost == min_cost && r_f < best.0) {
+                    best = (r_f, r_p);
+                    min_cost = cost;
+                }
+            }
+        }
+    }
+    best
+}
+
+// The inequalities of the Poseidon paper, as in its reference implementation
+fn is_secure(log_p: f64, n: f64, t: f64, r_f: f64, r_p: f64, alpha: Option<u64>, m: f64) -> bool {
+    match alpha {
+        Some(alpha) => {
+            let alpha = alpha as f64;
+            let log_alpha = |x: f64| x.ln() / alpha.ln();
+
+            // Statistical attacks
+            let r_f_1 = if m <= (log_p - (alpha - 1.0) / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+            // Interpolation attack
+            let r_f_2 = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - r_p;
+            // Groebner basis attacks
+            let r_f_3 = 1.0 + log_alpha(2.0) * (m / 3.0).min(log_p / 2.0) - r_p;
+            let r_f_4 = t - 1.0 + (log_alpha(2.0) * m / (t + 1.0)).min(log_alpha(2.0) * log_p / 2.0) - r_p;
+            let r_f_5 = (t - 2.0 + m / (2.0 * alpha.log2()) - r_p) / (t - 1.0);
+
+            let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+                .iter()
+                .fold(0f64, |max, r| max.max(r.ceil()));
+            r_f >= r_f_max
+        },
+        None => {
+            // Statistical attacks
+            let r_f_1 = if m <= (log_p - 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+            // Interpolation attack
+            let r_p_1 = 1.0 + (0.5 * m.min(n)).ceil() + t.log2().ceil() - (r_f * t.log2()).floor();
+            // Groebner basis attack
+            let r_p_2 = t - 1.0 + t.log2().ceil() + (m / (t + 1.0)).ceil().min((0.5 * log_p).ceil())
+                - (r_f * t.log2()).floor();
+
+            r_f >= r_f_1 && r_p >= r_p_1.max(r_p_2)
+        }
+    }
+}
+
+// The Grain LFSR in self-shrinking mode, as specified in Appendix F of the
+// Poseidon paper
+struct GrainLfsr {
+    state:      [bool; 80],
+    head:       usize,
+    field_bits: usize,
+}
+
+impl GrainLfsr {
+    fn new(sbox: PoseidonSBox, field_bits: usize, t: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut init_bits = Vec::with_capacity(80);
+        let mut push_bits = |value: usize, num_bits: usize| {
+            init_bits.extend((0..num_bits).rev().map(|i| (value >> i) & 1 == 1))
+        };
+        // The field is a prime field
+        push_bits(1, 2);
+        push_bits(if sbox.alpha().is_some() { 0 } else { 1 }, 4);
+        push_bits(field_bits, 12);
+        push_bits(t, 12);
+        push_bits(full_rounds, 10);
+        push_bits(partial_rounds, 10);
+        push_bits((1 << 30) - 1, 30);
+
+        let mut state = [false; 80];
+        state.copy_from_slice(&init_bits);
+        let mut grain = Self { state, head: 0, field_bits };
+
+        // Discard the first 160 bits
+        for _ in 0..160 {
+            grain.update();
+        }
+        grain
+    }
+
+    fn update(&mut self) -> bool {
+        let bit = |i: usize| self.state[(self.head + i) % 80];
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state[self.head] = new_bit;
+        self.head = (self.head + 1) % 80;
+        new_bit
+    }
+
+    // Outputs the second bit of each pair of bits whose first bit is one
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let first = self.update();
+            let second = self.update();
+            if first {
+                return second;
+            }
+        }
+    }
+
+    fn next_bigint<F: PrimeField>(&mut self) -> F::BigInt {
+        let bits = (0..self.field_bits).map(|_| self.next_bit()).collect::<Vec<_>>();
+        F::BigInt::from_bits(&bits)
+    }
+
+    // Samples a field element, rejecting the integers not smaller than the modulus
+    fn next_field_element<F: PrimeField>(&mut self) -> F {
+        loop {
+            let n = self.next_bigint::<F>();
+            if n < F::Params::MODULUS {
+                return F::from_repr(n);
+            }
+        }
+    }
+
+    // Samples a field element, reducing the integer modulo the modulus
+    fn next_field_element_reduced<F: PrimeField>(&mut self) -> F {
+        let mut n = self.next_bigint::<F>();
+        if n >= F::Params::MODULUS {
+            n.sub_noborrow(&F::Params::MODULUS);
+        }
+        F::from_repr(n)
+    }
+}
+
+// Samples Cauchy matrices 1 / (x_i + y_j) until one is free of invariant
+// subspace trails
+fn secure_cauchy_matrix<F: PrimeField>(grain: &mut GrainLfsr, t: usize) -> Vec<F> {
+    loop {
+        let mut elems = (0..2 * t).map(|_| grain.next_field_element_reduced()).collect::<Vec<F>>();
+        while (0..2 * t).any(|i| elems[i + 1..].contains(&elems[i])) {
+            elems = (0..2 * t).map(|_| grain.next_field_element_reduced()).collect();
+        }
+        let (xs, ys) = elems.split_at(t);
+
+        let sums = xs
+            .iter()
+            .flat_map(|x| ys.iter().map(move |y| *x + y))
+            .collect::<Vec<_>>();
+        if sums.iter().any(|s| s.is_zero()) {
+            continue;
+        }
+        let mds = sums.iter().map(|s| s.inverse().unwrap()).collect::<Vec<_>>();
+
+        if has_no_invariant_subspace_trails(&mds, t) {
+            return mds;
+        }
+    }
+}
+
+// A sufficient condition for the absence of infinitely long invariant subspace
+// trails (https://eprint.iacr.org/2020/500): the minimal polynomials of the
+// powers M^1, ..., M^2t of the matrix are irreducible and of maximum degree.
+fn has_no_invariant_subspace_trails<F: PrimeField>(mds: &[F], t: usize) -> bool {
+    let mut power = mds.to_vec();
+    for _ in 0..2 * t {
+        if !is_irreducible(&characteristic_polynomial(&power, t)) {
+            return false;
+        }
+        power = mat_mul(&power, mds, t);
+    }
+    true
+}
+
+fn mat_vec_mul<F: Field>(m: &[F], v: &[F]) -> Vec<F> {
+    m.chunks(v.len())
+        .map(|row| row.iter().zip(v).fold(F::zero(), |acc, (a, b)| acc + &(*a * b)))
+        .collect()
+}
+
+fn mat_mul<F: Field>(a: &[F], b: &[F], t: usize) -> Vec<F> {
+    let mut c = vec![F::zero(); t * t];
+    for i in 0..t {
+        for k in 0..t {
+            for j in 0..t {
+                c[i * t + j] += &(a[i * t + k] * &b[k * t + j]);
+            }
+        }
+    }
+    c
+}
+
+// The characteristic polynomial of the t x t matrix `m`, by the Faddeev-LeVerrier
+// algorithm, with coefficients in increasing degree
+fn characteristic_polynomial<F: PrimeField>(m: &[F], t: usize) -> Vec<F> {
+    let mut coeffs = vec![F::zero(); t + 1];
+    coeffs[t] = F::one();
+    let mut aux = vec![F::zero(); t * t];
+    for k in 1..=t {
+        for i in 0..t {
+            aux[i * t + i] += &coeffs[t - k + 1];
+        }
+        aux = mat_mul(m, &aux, t);
+        let trace = (0..t).fold(F::zero(), |acc, i| acc + &aux[i * t + i]);
+        coeffs[t - k] = -(trace * &F::from(k as u64).inverse().unwrap());
+    }
+    coeffs
+}
+
+// Ben-Or's irreducibility test of the monic polynomial `f`: gcd(x^(p^i) - x, f) = 1
+// for all i up to half of its degree
+fn is_irreducible<F: PrimeField>(f: &[F]) -> bool {
+    let degree = f.len() - 1;
+    let x = vec![F::zero(), F::one()];
+    let mut x_p_i = x.clone();
+    for _ in 0..degree / 2 {
+        x_p_i = poly_pow_p_mod(&x_p_i, f);
+        let mut h = x_p_i.clone();
+        h.resize(h.len().max(2), F::zero());
+        h[1] -= &F::one();
+        if poly_gcd(h, f.to_vec()).len() > 1 {
+            return false;
+        }
+    }
+    true
+}
+
+fn poly_trim<F: Field>(mut a: Vec<F>) -> Vec<F> {
+    while a.last().map_or(false, |c| c.is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+// The remainder of the division of `a` by the non-zero polynomial `b`
+fn poly_rem<F: Field>(a: Vec<F>, b: &[F]) -> Vec<F> {
+    let b = poly_trim(b.to_vec());
+    let lead_inv = b.last().unwrap().inverse().unwrap();
+    let mut r = poly_trim(a);
+    while r.len() >= b.len() {
+        let q = *r.last().unwrap() * &lead_inv;
+        let shift = r.len() - b.len();
+        for (i, c) in b.iter().enumerate() {
+            r[shift + i] -= &(q * c);
+        }
+        r = poly_trim(r);
+    }
+    r
+}
+
+fn poly_mul_mod<F: Field>(a: &[F], b: &[F], f: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut c = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            c[i + j] += &(*x * y);
+        }
+    }
+    poly_rem(c, f)
+}
+
+// a^p mod f, with p the characteristic of the field
+fn poly_pow_p_mod<F: PrimeField>(a: &[F], f: &[F]) -> Vec<F> {
+    let mut res = vec![F::one()];
+    for limb in F::characteristic().iter().rev() {
+        for i in (0..64).rev() {
+            res = poly_mul_mod(&res, &res, f);
+            if (limb >> i) & 1 == 1 {
+                res = poly_mul_mod(&res, a, f);
+            }
+        }
+    }
+    res
+}
+
+fn poly_gcd<F: Field>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+    a = poly_trim(a);
+    b = poly_trim(b);
+    while !b.is_empty() {
+        let r = poly_rem(a, &b);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crh::poseidon::{PoseidonParameters, parameters::{
+        BLS12381PoseidonParameters, BLS12377PoseidonParameters,
+        JubJubFqPoseidonParameters, JubJubFrPoseidonParameters, EdwardsBls12FrPoseidonParameters,
+        SW6PoseidonParameters, EdwardsSW6FrPoseidonParameters, MNT6298PoseidonParameters,
+    }};
+    use algebra::fields::{
+        bls12_381::Fr as BLS12381Fr, mnt4753::Fr as MNT4753Fr,
+    };
+
+    #[test]
+    fn test_round_numbers() {
+        // The same as the 2:1 instance over the 255 bit Pallas field used in Zcash
+        assert_eq!(round_numbers::<BLS12381Fr>(3, PoseidonSBox::Alpha5, 128), (8, 56));
+    }
+
+    #[test]
+    fn test_sbox_is_permutation() {
+        assert!(!sbox_is_permutation::<BLS12381Fr>(PoseidonSBox::Alpha3));
+        assert!(sbox_is_permutation::<BLS12381Fr>(PoseidonSBox::Alpha5));
+        assert!(sbox_is_permutation::<BLS12381Fr>(PoseidonSBox::Inverse));
+        assert!(PoseidonInstance::<BLS12381Fr>::generate(3, PoseidonSBox::Alpha3, 128).is_err());
+        assert!(PoseidonInstance::<MNT4753Fr>::generate(1, PoseidonSBox::Inverse, 128).is_err());
+    }
+
+    // The hard-coded parameters must be those generated from their description
+    fn test_generated_parameters<F: PrimeField, P: PoseidonParameters<Fr = F>>() {
+        let instance = PoseidonInstance::<F>::generate(P::T, P::SBOX, 128).unwrap();
+        assert_eq!(instance.r_f, P::R_F as usize);
+        assert_eq!(instance.r_p, P::R_P as usize);
+        assert_eq!(P::R, P::T - 1);
+        assert_eq!(&instance.round_cst[..], P::ROUND_CST);
+        assert_eq!(&instance.mds[..], P::MDS_CST);
+        assert_eq!(&instance.after_zero_perm[..], P::AFTER_ZERO_PERM);
+        assert_eq!(P::C2, F::from(3u64));
+    }
+
+    #[test]
+    fn test_parameters_reproducibility() {
+        test_generated_parameters::<_, BLS12381PoseidonParameters>();
+        test_generated_parameters::<_, BLS12377PoseidonParameters>();
+        test_generated_parameters::<_, JubJubFqPoseidonParameters>();
+        test_generated_parameters::<_, JubJubFrPoseidonParameters>();
+        test_generated_parameters::<_, EdwardsBls12FrPoseidonParameters>();
+        test_generated_parameters::<_, SW6PoseidonParameters>();
+        test_generated_parameters::<_, EdwardsSW6FrPoseidonParameters>();
+        test_generated_parameters::<_, MNT6298PoseidonParameters>();
+    }
+
+    #[test]
+    fn test_instance_with_wider_state() {
+        let instance = PoseidonInstance::<BLS12381Fr>::generate(5, PoseidonSBox::Alpha5, 128).unwrap();
+        assert_eq!(instance.round_cst.len(), 5 * (2 * instance.r_f + instance.r_p));
+        assert_eq!(instance.mds.len(), 25);
+        assert!(has_no_invariant_subspace_trails(&instance.mds, 5));
+    }
+}
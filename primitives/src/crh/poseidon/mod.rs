@@ -4,13 +4,18 @@ extern crate rayon;
 
 use algebra::fields::mnt6753::Fr as MNT6753Fr;
 use algebra::fields::mnt4753::Fr as MNT4753Fr;
-use algebra::{PrimeField, MulShort};
+use algebra::fields::{
+    bls12_377::Fr as BLS12377Fr, bls12_381::Fr as BLS12381Fr, edwards_bls12::fr::Fr as EdwardsBls12Fr,
+    edwards_sw6::fr::Fr as EdwardsSW6Fr, jubjub::{fq::Fq as JubJubFq, fr::Fr as JubJubFr},
+    mnt6::Fr as MNT6298Fr, sw6::Fr as SW6Fr,
+};
+use algebra::{Field, PrimeField, fields::batch_inversion};
 
 use std::marker::PhantomData;
 
 use crate::crh::{
     FieldBasedHashParameters, poseidon::{
-        parameters::{MNT4753PoseidonParameters, MNT6753PoseidonParameters}
+        parameters::*
     }
 };
 
@@ -18,6 +23,10 @@ use crate::crh::{FieldBasedHash, BatchFieldBasedHash};
 use crate::Error;
 
 pub mod parameters;
+pub mod generator;
+
+pub mod sponge;
+pub use self::sponge::*;
 
 pub struct PoseidonHash<F: PrimeField, P: PoseidonParameters<Fr = F>>{
     _field:      PhantomData<F>,
@@ -29,404 +38,133 @@ pub struct PoseidonBatchHash<F: PrimeField, P: PoseidonParameters<Fr = F>>{
     _parameters: PhantomData<P>,
 }
 
-pub trait PoseidonParameters: 'static + FieldBasedHashParameters{
-
-    const T: usize;  // Number of S-Boxesb
-    const R_F:i32;   // Number of full rounds
-    const R_P:i32;   // Number of partial rounds
-    const R:usize;   // The rate of the hash function
-    const ZERO:Self::Fr;   // The zero element in the field
-    const C2:Self::Fr;     // The constant 3 to add in the position corresponding to the capacity
-    const AFTER_ZERO_PERM: &'static[Self::Fr]; // State vector after a zero permutation
-    const ROUND_CST: &'static[Self::Fr];  // Array of round constants
-    const MDS_CST: &'static[Self::Fr];  // The MDS matrix
-    const MDS_CST_SHORT: &'static[Self::Fr];  // The MDS matrix for fast matrix multiplication
-
+/// The S-Box of a Poseidon instance: either the inversion, or the power
+/// x^alpha for a small alpha coprime with p - 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoseidonSBox {
+    /// x^-1, mapping zero to itself
+    Inverse,
+    /// x^3
+    Alpha3,
+    /// x^5
+    Alpha5,
+    /// x^17
+    Alpha17,
 }
 
-
-impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr=F>> PoseidonBatchHash<F, P> {
-
-    // Function that does the mix matrix
-    // It uses Montgomery multiplication
-    // Constants are defined such that the result is x * t * 2^768,
-    // that is the Montgomery representation of the operand x * t, and t is the 64-bit constant
-    #[allow(dead_code)]
-    fn matrix_mix (state: &mut Vec<F>) {
-
-        // the new state where the result will be stored initialized to zero elements
-        let mut new_state = vec![F::zero(); P::T];
-
-        let m_11 = P::MDS_CST[0];
-        let m_12 = P::MDS_CST[1];
-        let m_13 = P::MDS_CST[2];
-
-        // scalar multiplication for position 0 of the state vector
-        let elem_0 = state[0].mul(&m_11);
-        let elem_1 = state[1].mul(&m_12);
-        let elem_2 = state[2].mul(&m_13);
-
-        new_state[0] = elem_0;
-        new_state[0] += &elem_1;
-        new_state[0] += &elem_2;
-
-        // scalar multiplication for position 1 of the state vector
-        let m_21 = P::MDS_CST[3];
-        let m_22 = P::MDS_CST[4];
-        let m_23 = P::MDS_CST[5];
-
-        let elem_3 = state[0].mul(&m_21);
-        let elem_4 = state[1].mul(&m_22);
-        let elem_5 = state[2].mul(&m_23);
-
-        new_state[1] = elem_3;
-        new_state[1] += &elem_4;
-        new_state[1] += &elem_5;
-
-        // scalar multiplication for the position 2 of the state vector
-        let m_31 = P::MDS_CST[6];
-        let m_32 = P::MDS_CST[7];
-        let m_33 = P::MDS_CST[8];
-
-        let elem_6 = state[0].mul(&m_31);
-        let elem_7 = state[1].mul(&m_32);
-        let elem_8 = state[2].mul(&m_33);
-
-        new_state[2] = elem_6;
-        new_state[2] += &elem_7;
-        new_state[2] += &elem_8;
-
-        // copy the result to the state vector
-        state[0] = new_state[0];
-        state[1] = new_state[1];
-        state[2] = new_state[2];
-
-    }
-
-    // Function that does the mix matrix with fast algorithm
-    // It uses a partial Montgomery multiplication defined as PM(x, t) = x * t * 2^-64 mod M
-    // t is a 64-bit matrix constant. In the algorithm, the constants are represented in
-    // partial Montgomery representation, i.e. t * 2^64 mod M
-    fn matrix_mix_short (state: &mut Vec<F>) {
-
-        //use algebra::MulShort;
-
-        // the new state where the result will be stored initialized to zero elements
-        let mut new_state = vec![F::zero(); P::T];
-
-        let m_11 = P::MDS_CST_SHORT[0];
-        let m_12 = P::MDS_CST_SHORT[1];
-        let m_13 = P::MDS_CST_SHORT[2];
-
-        let elem_0 = m_11.mul_short(&state[0]);
-        let elem_1 = m_12.mul_short(&state[1]);
-        let elem_2 = m_13.mul_short(&state[2]);
-
-        new_state[0] = elem_0;
-        new_state[0] += &elem_1;
-        new_state[0] += &elem_2;
-
-        // scalar multiplication for position 1 of the state vector
-        let m_21 = P::MDS_CST_SHORT[3];
-        let m_22 = P::MDS_CST_SHORT[4];
-        let m_23 = P::MDS_CST_SHORT[5];
-
-        let elem_3 = m_21.mul_short(&state[0]);
-        let elem_4 = m_22.mul_short(&state[1]);
-        let elem_5 = m_23.mul_short(&state[2]);
-
-        new_state[1] = elem_3;
-        new_state[1] += &elem_4;
-        new_state[1] += &elem_5;
-
-        // scalar multiplication for the position 2 of the state vector
-        let m_31 = P::MDS_CST_SHORT[6];
-        let m_32 = P::MDS_CST_SHORT[7];
-        let m_33 = P::MDS_CST_SHORT[8];
-
-        let elem_6 = m_31.mul_short(&state[0]);
-        let elem_7 = m_32.mul_short(&state[1]);
-        let elem_8 = m_33.mul_short(&state[2]);
-
-        new_state[2] = elem_6;
-        new_state[2] += &elem_7;
-        new_state[2] += &elem_8;
-
-        // copy the result to the state vector
-        state[0] = new_state[0];
-        state[1] = new_state[1];
-        state[2] = new_state[2];
-    }
-
-    fn poseidon_full_round(vec_state: &mut Vec<Vec<P::Fr>>, round_cst_idx: &mut usize) {
-
-        // For each of the element position of the state vector
-        for j in 0..P::T {
-
-            // get the constant associated to element position of the state vector
-            let rc = P::ROUND_CST[*round_cst_idx];
-
-            // go over each of the state vectors and add the constant
-            for k in 0..vec_state.len() {
-                vec_state[k][j] += &rc;
-            }
-            *round_cst_idx += 1;
+impl PoseidonSBox {
+    /// The exponent alpha of the S-Box, or `None` for the inversion.
+    pub fn alpha(&self) -> Option<u64> {
+        match self {
+            PoseidonSBox::Inverse => None,
+            PoseidonSBox::Alpha3 => Some(3),
+            PoseidonSBox::Alpha5 => Some(5),
+            PoseidonSBox::Alpha17 => Some(17),
         }
+    }
 
-        // Apply the S-BOX to each of the elements of the state vector
-        // Use Montgomery simultaneous inversion
-        let mut w: Vec<P::Fr> = Vec::new();
-        let mut accum_prod = P::Fr::one();
-
-        w.push(accum_prod);
-        // Calculate the intermediate partial products
-        for i in 0..vec_state.len() {
-            for j in 0..P::T {
-                accum_prod = accum_prod * &vec_state[i][j];
-                w.push(accum_prod);
-            }
-        }
-
-        // if the accum_prod is zero, it means that one of the S-Boxes is zero
-        // in that case compute the inverses individually
-        if accum_prod == P::Fr::zero() {
-            for i in 0..vec_state.len() {
-                for j in 0..P::T {
-                    if vec_state[i][j] != P::Fr::zero() {
-                        vec_state[i][j] = vec_state[i][j].inverse().unwrap();
-                    }
-                }
-            }
-        } else {
-
-            // Calculate the inversion of the products
-            // The inverse always exists in this case
-            let mut w_bar = accum_prod.inverse().unwrap();
-
-            // Extract the individual inversions
-            let mut idx: i64 = w.len() as i64 - 2;
-            for i in (0..vec_state.len()).rev() {
-                for j in (0..P::T).rev() {
-                    let vec_1 = vec_state[i][j].clone();
-                    vec_state[i][j] = w_bar * &w[idx as usize];
-                    w_bar = w_bar * &vec_1;
-                    idx -= 1;
-                }
-            }
-
+    /// Applies the S-Box to `x`.
+    pub fn apply<F: Field>(&self, x: &mut F) {
+        match self.alpha() {
+            None => if let Some(inv) = x.inverse() { *x = inv },
+            Some(alpha) => *x = x.pow([alpha]),
         }
     }
 
-    fn poseidon_partial_round(vec_state: &mut Vec<Vec<P::Fr>>, round_cst_idx: &mut usize) {
-
-        // For each of the state vector element position
-        for j in 0..P::T {
-
-            // get the constant associated to state vector element position
-            let rc = P::ROUND_CST[*round_cst_idx];
-
-            // go over each state vector
-            for k in 0..vec_state.len() {
-                vec_state[k][j] += &rc;
-            }
-            *round_cst_idx += 1;
+    /// Applies the S-Box to all the elements of `v`, sharing a single inversion
+    /// among them in case of the inversion S-Box.
+    pub fn apply_batch<F: Field>(&self, v: &mut [F]) {
+        match self {
+            PoseidonSBox::Inverse => batch_inversion(v),
+            _ => v.iter_mut().for_each(|x| self.apply(x)),
         }
+    }
+}
 
-        // Apply the S-BOX to the first elements of each of the state vector
-        let mut w: Vec<P::Fr> = Vec::new();
-        let mut accum_prod = P::Fr::one();
+pub trait PoseidonParameters: 'static + FieldBasedHashParameters{
 
-        w.push(accum_prod);
-        // Calculate the intermediate partial products
-        for i in 0..vec_state.len() {
-            accum_prod = accum_prod * &vec_state[i][0];
-            w.push(accum_prod);
-        }
+    const T: usize;  // Width of the state vector
+    const R_F:i32;   // Number of full rounds
+    const R_P:i32;   // Number of partial rounds
+    const R:usize;   // The rate of the hash function
+    const SBOX: PoseidonSBox;  // The S-Box applied in the rounds
+    const ZERO:Self::Fr;   // The zero element in the field
+    const C2:Self::Fr;     // The constant 3 to add in the position corresponding to the capacity
+    const AFTER_ZERO_PERM: &'static[Self::Fr]; // State vector after a zero permutation
+    const ROUND_CST: &'static[Self::Fr];  // Array of round constants, T for each round
+    const MDS_CST: &'static[Self::Fr];  // The T x T MDS matrix, in row-major order
+
+    /// Multiplies the state vector by the MDS matrix. Parameters can override
+    /// it with a faster implementation suited to their constants.
+    fn matrix_mix(state: &mut [Self::Fr]) {
+        let new_state = Self::MDS_CST
+            .chunks(Self::T)
+            .map(|row| {
+                row.iter().zip(state.iter()).fold(Self::ZERO, |acc, (m, s)| acc + &(*m * s))
+            })
+            .collect::<Vec<_>>();
+        state.copy_from_slice(&new_state);
+    }
+}
 
-        // if the accum_prod is zero, it means that one of the S-Boxes is zero
-        // in that case compute the inverses individually
-        if accum_prod == P::Fr::zero() {
-            for i in 0..(vec_state.len() - 1) {
-                if vec_state[i][0] != P::Fr::zero() {
-                    vec_state[i][0] = vec_state[i][0].inverse().unwrap();
-                }
-            }
-        } else {
-
-            // Calculate the inversion of the products
-            // Use Montgomery simultaneous inversion
-            let mut w_bar = accum_prod.inverse().unwrap();
-
-            // Extract the individual inversions
-            let mut idx: i64 = w.len() as i64 - 2;
-            for i in (0..vec_state.len()).rev() {
-                let vec_1 = vec_state[i][0].clone();
-                vec_state[i][0] = w_bar * &w[idx as usize];
-                w_bar = w_bar * &vec_1;
-                idx -= 1;
-            }
+// Adds the round constants, T at a time, to each of the state vectors in `states`
+fn add_round_constants<P: PoseidonParameters>(states: &mut [P::Fr], round_cst_idx: &mut usize) {
+    let rc = &P::ROUND_CST[*round_cst_idx..*round_cst_idx + P::T];
+    for state in states.chunks_mut(P::T) {
+        for (d, c) in state.iter_mut().zip(rc) {
+            *d += c;
         }
     }
+    *round_cst_idx += P::T;
+}
+
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> PoseidonBatchHash<F, P> {
 
-    fn poseidon_perm_gen(vec_state: &mut Vec<Vec<P::Fr>>) {
+    // Applies the permutation to each of the state vectors of T elements
+    // concatenated in `states`. In case of the inversion S-Box, the inversions
+    // of each round are batched among all the state vectors.
+    fn poseidon_perm_gen(states: &mut [F]) {
 
         // index that goes over the round constants
         let mut round_cst_idx: usize = 0;
 
-        // Full rounds
+        // First full rounds
         for _i in 0..P::R_F {
-            Self::poseidon_full_round(vec_state, &mut round_cst_idx);
-
-            // Perform the matrix mix
-            for i in 0..vec_state.len() {
-                //Self::matrix_mix_short(&mut vec_state[i]);
-                Self::matrix_mix_short(&mut vec_state[i]);
-            }
-
+            add_round_constants::<P>(states, &mut round_cst_idx);
+            P::SBOX.apply_batch(states);
+            states.chunks_mut(P::T).for_each(P::matrix_mix);
         }
 
         // Partial rounds
         for _i in 0..P::R_P {
-            Self::poseidon_partial_round(vec_state, &mut round_cst_idx);
+            add_round_constants::<P>(states, &mut round_cst_idx);
 
-            // Perform the matrix mix
-            for i in 0..vec_state.len() {
-                //Self::matrix_mix_short(&mut vec_state[i]);
-                Self::matrix_mix_short(&mut vec_state[i]);
+            // Apply the S-BOX to the first elements of each of the state vector
+            let mut first_elems = states.iter().step_by(P::T).cloned().collect::<Vec<_>>();
+            P::SBOX.apply_batch(&mut first_elems);
+            for (state, elem) in states.chunks_mut(P::T).zip(first_elems) {
+                state[0] = elem;
             }
+            states.chunks_mut(P::T).for_each(P::matrix_mix);
         }
 
-        // Full rounds
+        // Second full rounds
+        // Process only to R_F -1 iterations. The last iteration does not contain a matrix mix
         for _i in 0..(P::R_F - 1) {
-            Self::poseidon_full_round(vec_state, &mut round_cst_idx);
-
-            // Perform the matrix mix
-            for i in 0..vec_state.len() {
-                //Self::matrix_mix_short(&mut vec_state[i]);
-                Self::matrix_mix_short(&mut vec_state[i]);
-            }
+            add_round_constants::<P>(states, &mut round_cst_idx);
+            P::SBOX.apply_batch(states);
+            states.chunks_mut(P::T).for_each(P::matrix_mix);
         }
 
-        Self::poseidon_full_round(vec_state, &mut round_cst_idx);
+        add_round_constants::<P>(states, &mut round_cst_idx);
+        P::SBOX.apply_batch(states);
     }
 }
 
 
-impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr=F>> PoseidonHash<F, P> {
-
-    // Function that does the mix matrix
-    // It uses Montgomery multiplication
-    // Constants are defined such that the result is x * t * 2^768,
-    // that is the Montgomery representation of the operand x * t, and t is the 64-bit constant
-    fn matrix_mix (state: &mut Vec<F>) {
-
-        // the new state where the result will be stored initialized to zero elements
-        let mut new_state = vec![F::zero(); P::T];
-
-        let m_11 = P::MDS_CST[0];
-        let m_12 = P::MDS_CST[1];
-        let m_13 = P::MDS_CST[2];
-
-        // scalar multiplication for position 0 of the state vector
-        let elem_0 = state[0].mul(&m_11);
-        let elem_1 = state[1].mul(&m_12);
-        let elem_2 = state[2].mul(&m_13);
-
-        new_state[0] = elem_0;
-        new_state[0] += &elem_1;
-        new_state[0] += &elem_2;
-
-        // scalar multiplication for position 1 of the state vector
-        let m_21 = P::MDS_CST[3];
-        let m_22 = P::MDS_CST[4];
-        let m_23 = P::MDS_CST[5];
-
-        let elem_3 = state[0].mul(&m_21);
-        let elem_4 = state[1].mul(&m_22);
-        let elem_5 = state[2].mul(&m_23);
-
-        new_state[1] = elem_3;
-        new_state[1] += &elem_4;
-        new_state[1] += &elem_5;
-
-        // scalar multiplication for the position 2 of the state vector
-        let m_31 = P::MDS_CST[6];
-        let m_32 = P::MDS_CST[7];
-        let m_33 = P::MDS_CST[8];
-
-        let elem_6 = state[0].mul(&m_31);
-        let elem_7 = state[1].mul(&m_32);
-        let elem_8 = state[2].mul(&m_33);
-
-        new_state[2] = elem_6;
-        new_state[2] += &elem_7;
-        new_state[2] += &elem_8;
-
-        // copy the result to the state vector
-        state[0] = new_state[0];
-        state[1] = new_state[1];
-        state[2] = new_state[2];
-
-    }
-
-    // Function that does the mix matrix with fast algorithm
-    // It uses a partial Montgomery multiplication defined as PM(x, t) = x * t * 2^-64 mod M
-    // t is a 64-bit matrix constant. In the algorithm, the constants are represented in
-    // partial Montgomery representation, i.e. t * 2^64 mod M
-    fn matrix_mix_short (state: &mut Vec<F>) {
-
-        //use algebra::MulShort;
-
-        // the new state where the result will be stored initialized to zero elements
-        let mut new_state = vec![F::zero(); P::T];
-
-        let m_11 = P::MDS_CST_SHORT[0];
-        let m_12 = P::MDS_CST_SHORT[1];
-        let m_13 = P::MDS_CST_SHORT[2];
-
-        let elem_0 = m_11.mul_short(&state[0]);
-        let elem_1 = m_12.mul_short(&state[1]);
-        let elem_2 = m_13.mul_short(&state[2]);
-
-        new_state[0] = elem_0;
-        new_state[0] += &elem_1;
-        new_state[0] += &elem_2;
-
-        // scalar multiplication for position 1 of the state vector
-        let m_21 = P::MDS_CST_SHORT[3];
-        let m_22 = P::MDS_CST_SHORT[4];
-        let m_23 = P::MDS_CST_SHORT[5];
-
-        let elem_3 = m_21.mul_short(&state[0]);
-        let elem_4 = m_22.mul_short(&state[1]);
-        let elem_5 = m_23.mul_short(&state[2]);
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> PoseidonHash<F, P> {
 
-        new_state[1] = elem_3;
-        new_state[1] += &elem_4;
-        new_state[1] += &elem_5;
-
-        // scalar multiplication for the position 2 of the state vector
-        let m_31 = P::MDS_CST_SHORT[6];
-        let m_32 = P::MDS_CST_SHORT[7];
-        let m_33 = P::MDS_CST_SHORT[8];
-
-        let elem_6 = m_31.mul_short(&state[0]);
-        let elem_7 = m_32.mul_short(&state[1]);
-        let elem_8 = m_33.mul_short(&state[2]);
-
-        new_state[2] = elem_6;
-        new_state[2] += &elem_7;
-        new_state[2] += &elem_8;
-
-        // copy the result to the state vector
-        state[0] = new_state[0];
-        state[1] = new_state[1];
-        state[2] = new_state[2];
-    }
-
-    fn poseidon_perm (state: &mut Vec<F>) {
-
-        let use_fast = true;
+    pub(crate) fn poseidon_perm (state: &mut [F]) {
 
         // index that goes over the round constants
         let mut round_cst_idx = 0;
@@ -435,66 +173,26 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr=F>> PoseidonHash<F, P> {
         for _i in 0..P::R_F {
 
             // Add the round constants to the state vector
-            for d in state.iter_mut() {
-                let rc = P::ROUND_CST[round_cst_idx];
-                *d += &rc;
-                round_cst_idx += 1;
-            }
-
+            add_round_constants::<P>(state, &mut round_cst_idx);
 
             // Apply the S-BOX to each of the elements of the state vector
-            // Optimization for the inversion S-Box
-            let w2 = state[0] * &state[1];
-            let w = state[2] * &w2;
-            if w == P::Fr::zero() {
-                // At least one of the S-Boxes is zero
-                for d in state.iter_mut() {
-                    // The S-BOX is an inversion function
-                    if *d != P::Fr::zero() {
-                        *d = (*d).inverse().unwrap();
-                    }
-                }
-            } else {
-                let mut w_bar = w.inverse().unwrap();
-
-                let z_2 = w_bar * &w2;
-                w_bar = w_bar * &state[2];
-                state[2] = z_2;
-                let z_1 = w_bar * &state[0];
-                state[0] = w_bar * &state[1];
-                state[1] = z_1;
-            }
+            P::SBOX.apply_batch(state);
 
             // Perform the matrix mix
-            if use_fast {
-                Self::matrix_mix_short(state);
-            } else {
-                Self::matrix_mix(state);
-            }
-
+            P::matrix_mix(state);
         }
 
         // Partial rounds
         for _i in 0..P::R_P {
 
             // Add the round constants to the state vector
-            for d in state.iter_mut() {
-                let rc = P::ROUND_CST[round_cst_idx];
-                *d += &rc;
-                round_cst_idx += 1;
-            }
+            add_round_constants::<P>(state, &mut round_cst_idx);
 
             // Apply S-BOX only to the first element of the state vector
-            if state[0]!=P::Fr::zero() {
-                state[0] = state[0].inverse().unwrap();
-            }
+            P::SBOX.apply(&mut state[0]);
 
             // Apply the matrix mix
-            if use_fast {
-                Self::matrix_mix_short(state);
-            } else {
-                Self::matrix_mix(state);
-            }
+            P::matrix_mix(state);
         }
 
         // Second full rounds
@@ -502,85 +200,33 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr=F>> PoseidonHash<F, P> {
         for _i in 0..(P::R_F-1) {
 
             // Add the round constants
-            for d in state.iter_mut() {
-                //let rc = MNT4753Fr::from_str(ROUND_CST[round_cst_idx]).map_err(|_| ()).unwrap();
-                let rc = P::ROUND_CST[round_cst_idx];
-                *d += &rc;
-                round_cst_idx += 1;
-            }
+            add_round_constants::<P>(state, &mut round_cst_idx);
 
             // Apply the S-BOX to each of the elements of the state vector
-            // Optimization for the inversion S-Box
-            let w2 = state[0] * &state[1];
-            let w = state[2] * &w2;
-            if w == P::Fr::zero() {
-                // At least one of the S-Boxes is zero
-                for d in state.iter_mut() {
-                    // The S-BOX is an inversion function
-                    if *d != P::Fr::zero() {
-                        *d = (*d).inverse().unwrap();
-                    }
-                }
-            } else {
-                let mut w_bar = w.inverse().unwrap();
-
-                let z_2 = w_bar * &w2;
-                w_bar = w_bar * &state[2];
-                state[2] = z_2;
-                let z_1 = w_bar * &state[0];
-                state[0] = w_bar * &state[1];
-                state[1] = z_1;
-            }
+            P::SBOX.apply_batch(state);
 
             // Apply matrix mix
-            if use_fast {
-                Self::matrix_mix_short(state);
-            } else {
-                Self::matrix_mix(state);
-            }
+            P::matrix_mix(state);
         }
 
         // Last full round does not perform the matrix_mix
         // Add the round constants
-        for d in state.iter_mut() {
-            let rc = P::ROUND_CST[round_cst_idx];
-            *d += &rc;
-            round_cst_idx += 1;
-        }
+        add_round_constants::<P>(state, &mut round_cst_idx);
 
         // Apply the S-BOX to each of the elements of the state vector
-        // Optimization for the inversion S-Box
-        let w2 = state[0] * &state[1];
-        let w = state[2] * &w2;
-        if w == P::Fr::zero() {
-            for d in state.iter_mut() {
-                // The S-BOX is an inversion function
-                if *d != P::Fr::zero() {
-                    *d = (*d).inverse().unwrap();
-                }
-            }
-        } else {
-            let mut w_bar = w.inverse().unwrap();
-
-            let z_2 = w_bar * &w2;
-            w_bar = w_bar * &state[2];
-            state[2] = z_2;
-            let z_1 = w_bar * &state[0];
-            state[0] = w_bar * &state[1];
-            state[1] = z_1;
-        }
+        P::SBOX.apply_batch(state);
     }
 }
 
 
-impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> FieldBasedHash for PoseidonHash<F, P> {
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> FieldBasedHash for PoseidonHash<F, P> {
     type Data = F;
     type Parameters = P;
 
     fn evaluate(input: &[F]) -> Result<F, Error> {
 
-        // state is a vector of 3 elements. They are initialized to constants that are obtained after applying a permutation to a zero elements vector
-        let mut state = vec![P::AFTER_ZERO_PERM[0], P::AFTER_ZERO_PERM[1], P::AFTER_ZERO_PERM[2]];
+        // state is a vector of T elements. They are initialized to constants that are obtained after applying a permutation to a zero elements vector
+        let mut state = P::AFTER_ZERO_PERM[..P::T].to_vec();
 
         // calculate the number of cycles to process the input dividing in portions of rate elements
         let num_cycles = input.len() / P::R;
@@ -592,20 +238,22 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> FieldBasedHash for
         // iterate of the portions of rate elements
         for _i in 0..num_cycles {
             // add the elements to the state vector. Add rate elements
-            for j in 0..P::R {
-                state[j] += &input[input_idx];
-                input_idx += 1;
+            for (d, x) in state.iter_mut().zip(&input[input_idx..input_idx + P::R]) {
+                *d += x;
             }
-            // for application to a 2-1 Merkle tree, add the constant 3 to the third state vector
+            input_idx += P::R;
+            // for application to a Merkle tree, add the constant 3 to the first capacity element of the state vector
             state[P::R] += &P::C2;
 
             // apply permutation after adding the input vector
             Self::poseidon_perm(&mut state);
         }
 
-        // in case the input is not a multiple of the rate process the remainder part padding a zero
+        // in case the input is not a multiple of the rate process the remainder part padding zeros
         if rem != 0 {
-            state[0] += &input[input_idx];
+            for (d, x) in state.iter_mut().zip(&input[input_idx..]) {
+                *d += x;
+            }
             state[P::R] += &P::C2;
             // apply permutation after adding the input vector
             Self::poseidon_perm(&mut state);
@@ -616,7 +264,7 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> FieldBasedHash for
     }
 }
 
-impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> BatchFieldBasedHash for PoseidonBatchHash<F, P> {
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> BatchFieldBasedHash for PoseidonBatchHash<F, P> {
     type Data = F;
     type Parameters = P;
 
@@ -629,48 +277,52 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> BatchFieldBasedHas
         // (d_00, d01, d_10, d_11, d_20, d_21, ...
         // Output:
         // The output will be placed in the same array taking half of the positions
-        // as the rate of the hash function is 2 field elements
+        // as the rate of the hash function is at least 2 field elements
 
         // Checks that input contains data
         assert_ne!(input_array.len(), 0, "Input to the hash has length 0.");
         assert_eq!(input_array.len() % 2, 0, "The length of the input to the hash is not even.");
+        assert!(P::R >= 2, "The rate of the hash is smaller than 2.");
 
         let input_length = input_array.len() / 2;
 
         // Assign pre-computed values of the state vector equivalent to a permutation with zero element state vector
-        let state_z = vec![P::AFTER_ZERO_PERM[0], P::AFTER_ZERO_PERM[1], P::AFTER_ZERO_PERM[2]];
-
-        // Copy the result of the permutation to a vector of state vectors of the length equal to the length of the input
-        // state is a vector of 3-element state vector.
-        let mut state = Vec::new();
+        // and concatenate one copy of it for each pair of inputs
+        let mut state = Vec::with_capacity(input_length * P::T);
         for _i in 0..input_length {
-            state.push(state_z.clone());
+            state.extend_from_slice(&P::AFTER_ZERO_PERM[..P::T]);
         }
 
-        // input_idx is to scan the input_array
-        let mut input_idx = 0;
-
-        for k in 0..input_length {
-            state[k][0] += &input_array[input_idx];
-            input_idx += 1;
-            state[k][1] += &input_array[input_idx];
-            input_idx += 1;
+        for (k, pair) in input_array.chunks(2).enumerate() {
+            state[k * P::T] += &pair[0];
+            state[k * P::T + 1] += &pair[1];
             // constant to add for a 2-1 Merkle tree
-            state[k][2] += &P::C2;
+            state[k * P::T + P::R] += &P::C2;
         }
 
         // apply permutation after adding the input vector
         Self::poseidon_perm_gen(&mut state);
 
         // overwrite the input with the result of the hash
-        for k in 0..input_array.len()/2 {
-            input_array[k] = state[k][0];
+        for k in 0..input_length {
+            input_array[k] = state[k * P::T];
         }
     }
 }
 
 pub type MNT4PoseidonHash = PoseidonHash<MNT4753Fr, MNT4753PoseidonParameters>;
 pub type MNT6PoseidonHash = PoseidonHash<MNT6753Fr, MNT6753PoseidonParameters>;
+pub type BLS12381PoseidonHash = PoseidonHash<BLS12381Fr, BLS12381PoseidonParameters>;
+pub type BLS12377PoseidonHash = PoseidonHash<BLS12377Fr, BLS12377PoseidonParameters>;
+pub type JubJubFqPoseidonHash = PoseidonHash<JubJubFq, JubJubFqPoseidonParameters>;
+pub type JubJubFrPoseidonHash = PoseidonHash<JubJubFr, JubJubFrPoseidonParameters>;
+pub type EdwardsBls12FrPoseidonHash = PoseidonHash<EdwardsBls12Fr, EdwardsBls12FrPoseidonParameters>;
+pub type SW6PoseidonHash = PoseidonHash<SW6Fr, SW6PoseidonParameters>;
+pub type EdwardsSW6FrPoseidonHash = PoseidonHash<EdwardsSW6Fr, EdwardsSW6FrPoseidonParameters>;
+pub type MNT6298PoseidonHash = PoseidonHash<MNT6298Fr, MNT6298PoseidonParameters>;
+
+pub type MNT4BatchPoseidonHash = PoseidonBatchHash<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6BatchPoseidonHash = PoseidonBatchHash<MNT6753Fr, MNT6753PoseidonParameters>;
 
 #[cfg(test)]
 mod test {
@@ -680,7 +332,7 @@ mod test {
     use std::str::FromStr;
     use crate::{FieldBasedHash, BatchFieldBasedHash, PoseidonBatchHash};
     use super::rand::SeedableRng;
-    use algebra::UniformRand;
+    use algebra::{UniformRand, biginteger::BigInteger768};
     use std::time::Instant;
 
     #[test]
@@ -688,9 +340,10 @@ mod test {
         let mut input = Vec::new();
         input.push(MNT4753Fr::from_str("1").unwrap());
         input.push(MNT4753Fr::from_str("2").unwrap());
-        let output = MNT4PoseidonHash::evaluate(&input);
+        let output = MNT4PoseidonHash::evaluate(&input).unwrap();
 
-        println!("{:?}", output);
+        let expected_output = MNT4753Fr::new(BigInteger768([120759599714708995, 15132412086599307425, 1270378153255747692, 3280164418217209635, 5680179791594071572, 2475152338055275001, 9455820118751334058, 6363436228419696186, 3538976751580678769, 14987158621073838958, 10703097083485496843, 48481977539350]));
+        assert_eq!(output, expected_output, "Outputs do not match for MNT4753.");
     }
 
 
@@ -699,9 +352,42 @@ mod test {
         let mut input = Vec::new();
         input.push(MNT6753Fr::from_str("1").unwrap());
         input.push(MNT6753Fr::from_str("2").unwrap());
-        let output = MNT6PoseidonHash::evaluate(&mut input);
+        let output = MNT6PoseidonHash::evaluate(&mut input).unwrap();
+
+        let expected_output = MNT6753Fr::new(BigInteger768([8195238283171732026, 13694263410588344527, 1885103367289967816, 17142467091011072910, 13844754763865913168, 14332001103319040991, 8911700442280604823, 6452872831806760781, 17467681867740706391, 5384727593134901588, 2343350281633109128, 244405261698305]));
+        assert_eq!(output, expected_output, "Outputs do not match for MNT6753.");
+    }
+
+    // The hard-coded initial state must be the permutation of the zero state, and
+    // the matrix mix specialized to the short MDS constants must agree with the
+    // product by the full MDS matrix.
+    fn test_parameters_consistency<F: PrimeField, P: PoseidonParameters<Fr = F>>() {
+        let mut state = vec![P::ZERO; P::T];
+        PoseidonHash::<F, P>::poseidon_perm(&mut state);
+        assert_eq!(&state[..], &P::AFTER_ZERO_PERM[..P::T]);
 
-        println!("{:?}", output);
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut state = (0..P::T).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let expected_state = P::MDS_CST
+            .chunks(P::T)
+            .map(|row| row.iter().zip(state.iter()).fold(P::ZERO, |acc, (m, s)| acc + &(*m * s)))
+            .collect::<Vec<_>>();
+        P::matrix_mix(&mut state);
+        assert_eq!(state, expected_state);
+    }
+
+    #[test]
+    fn test_poseidon_parameters_consistency() {
+        test_parameters_consistency::<_, MNT4753PoseidonParameters>();
+        test_parameters_consistency::<_, MNT6753PoseidonParameters>();
+        test_parameters_consistency::<_, BLS12381PoseidonParameters>();
+        test_parameters_consistency::<_, BLS12377PoseidonParameters>();
+        test_parameters_consistency::<_, JubJubFqPoseidonParameters>();
+        test_parameters_consistency::<_, JubJubFrPoseidonParameters>();
+        test_parameters_consistency::<_, EdwardsBls12FrPoseidonParameters>();
+        test_parameters_consistency::<_, SW6PoseidonParameters>();
+        test_parameters_consistency::<_, EdwardsSW6FrPoseidonParameters>();
+        test_parameters_consistency::<_, MNT6298PoseidonParameters>();
     }
 
     #[test]
@@ -1,10 +1,50 @@
-use crate::crh::{PoseidonParameters, FieldBasedHashParameters};
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
 
 use algebra::fields::mnt6753::Fr as MNT6753Fr;
 use algebra::fields::mnt4753::Fr as MNT4753Fr;
 
 use algebra::biginteger::BigInteger768;
-use algebra::field_new;
+use algebra::{field_new, PrimeField, MulShort};
+
+// Parameters generated by PoseidonInstance::generate, for the other fields
+pub mod bls12_381;
+pub use self::bls12_381::*;
+
+pub mod bls12_377;
+pub use self::bls12_377::*;
+
+pub mod jubjub_fq;
+pub use self::jubjub_fq::*;
+
+pub mod jubjub_fr;
+pub use self::jubjub_fr::*;
+
+pub mod edwards_bls12;
+pub use self::edwards_bls12::*;
+
+pub mod sw6;
+pub use self::sw6::*;
+
+pub mod edwards_sw6;
+pub use self::edwards_sw6::*;
+
+pub mod mnt6;
+pub use self::mnt6::*;
+
+// Multiplies the state vector by the MDS matrix, using a partial Montgomery
+// multiplication defined as PM(x, t) = x * t * 2^-64 mod M. The matrix
+// constants t are 64-bit and represented in partial Montgomery representation,
+// i.e. t * 2^64 mod M.
+fn matrix_mix_short<F: PrimeField + MulShort>(state: &mut [F], mds_short: &[F]) {
+    let t = state.len();
+    let new_state = mds_short
+        .chunks(t)
+        .map(|row| {
+            row.iter().zip(state.iter()).fold(F::zero(), |acc, (m, s)| acc + &m.mul_short(s))
+        })
+        .collect::<Vec<_>>();
+    state.copy_from_slice(&new_state);
+}
 
 pub struct MNT4753PoseidonParameters;
 
@@ -18,6 +58,7 @@ impl PoseidonParameters for MNT4753PoseidonParameters {
     const R_F:i32 = 4;  // Number of full rounds (for each of the two full rounds)
     const R_P:i32 = 57; // Number of partial rounds
     const R:usize = 2;  // The rate of the hash function
+    const SBOX: PoseidonSBox = PoseidonSBox::Inverse;
 
     // The zero element of the field
     const ZERO:MNT4753Fr = field_new!(MNT4753Fr, BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
@@ -247,8 +288,15 @@ impl PoseidonParameters for MNT4753PoseidonParameters {
         field_new!(MNT4753Fr,BigInteger768([2645972488122500518, 15174087784261972608, 6012496592884668757, 16888489121435014067, 9408068342580935725, 11672250714878796655, 4677362699477437319, 5084801265611243754, 1031670177470027200, 6927805189305206633, 15792063270661056320, 339600880556469]))
         ];
 
+    // Uses the fast multiplication by the 64-bit MDS constants in MDS_CST_SHORT
+    fn matrix_mix(state: &mut [MNT4753Fr]) {
+        matrix_mix_short(state, Self::MDS_CST_SHORT);
+    }
+}
+
+impl MNT4753PoseidonParameters {
     // The MDS matrix for fast matrix multiplication
-    const MDS_CST_SHORT: &'static[MNT4753Fr]  = &[
+    const MDS_CST_SHORT: &'static[MNT4753Fr] = &[
         // These constants are in Partial Montgomery representation with R = 2^64
         // In Zexe, they are already in Montgomery representation with R = 2^768
         field_new!(MNT4753Fr,BigInteger768([15593384137415911782, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
@@ -279,6 +327,7 @@ impl PoseidonParameters for MNT6753PoseidonParameters {
     const R_P: i32 = 60;
     // Number of partial rounds
     const R: usize = 2;  // The rate of the hash function
+    const SBOX: PoseidonSBox = PoseidonSBox::Inverse;
 
     // The zero element of the field
     const ZERO: MNT6753Fr = field_new!(MNT6753Fr, BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
@@ -512,8 +561,15 @@ impl PoseidonParameters for MNT6753PoseidonParameters {
         field_new!(MNT6753Fr,BigInteger768([4700957091855560976, 6321749939000513571, 3986127287804284905, 14787117947656435373, 1752249663818719547, 13200901751925719051, 14518686523481547758, 14226701265191878637, 3484444629948253625, 1446660298549585645, 12944892059483427095, 492396350662227]))
         ];
 
+    // Uses the fast multiplication by the 64-bit MDS constants in MDS_CST_SHORT
+    fn matrix_mix(state: &mut [MNT6753Fr]) {
+        matrix_mix_short(state, Self::MDS_CST_SHORT);
+    }
+}
+
+impl MNT6753PoseidonParameters {
     // The MDS matrix for fast matrix multiplication
-    const MDS_CST_SHORT: &'static[MNT6753Fr]  = &[
+    const MDS_CST_SHORT: &'static[MNT6753Fr] = &[
         // These constants are in Partial Montgomery representation with R = 2^64
         // In Zexe, they are already in Montgomery representation with R = 2^768
         field_new!(MNT6753Fr,BigInteger768([18217103266694245702, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
@@ -0,0 +1,163 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha17, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::bls12_377::Fr;
+
+use algebra::biginteger::BigInteger256;
+use algebra::field_new;
+
+pub struct BLS12377PoseidonParameters;
+
+impl FieldBasedHashParameters for BLS12377PoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for BLS12377PoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 31;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha17;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger256([0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger256([7147916296078753751, 11795755565450264533, 9448453213491875784, 183737022913545514]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([11372735713521470899, 7433096406827635480, 16380483861147562599, 1280118326945605298])),
+        field_new!(Fr, BigInteger256([16838875755200273152, 9391252554705479465, 3688121715936497202, 387117196871304395])),
+        field_new!(Fr, BigInteger256([13067828633066361163, 7935256808583373020, 11689321995453731103, 10235908382799028])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([11440379012468138605, 16406583739907189822, 6127585977537248138, 1103903786115451507])),
+        field_new!(Fr, BigInteger256([6706081136984544697, 11600996234986871163, 9867923509445685785, 517558277019168613])),
+        field_new!(Fr, BigInteger256([5317333396149352953, 18321458238548159861, 16421192329214117295, 1109844757066877043])),
+        field_new!(Fr, BigInteger256([6048080904628458442, 11841937849931618753, 13441817455528127478, 406218120741640621])),
+        field_new!(Fr, BigInteger256([7230432327772898359, 13437168470864525930, 17618452721757526615, 746971187782858912])),
+        field_new!(Fr, BigInteger256([2547642200462994869, 14900642663789386428, 3212583829661092048, 745289437761185920])),
+        field_new!(Fr, BigInteger256([1164700615188840190, 12651852595463018276, 4305983795683832175, 287992293393487215])),
+        field_new!(Fr, BigInteger256([9814068232078730626, 12175536996090352035, 5823386592360465637, 249233926464562606])),
+        field_new!(Fr, BigInteger256([12924232534711169041, 13922986449003598177, 1460702318514394343, 341842387660758415])),
+        field_new!(Fr, BigInteger256([15173916919500519233, 4265837692224486883, 18399581490301344002, 187222123378450737])),
+        field_new!(Fr, BigInteger256([12944345428873805781, 6079005169867773007, 9893906207151768287, 615028409076653388])),
+        field_new!(Fr, BigInteger256([2998204753645881900, 8150240240278257675, 12305971786498314351, 1120644430784245407])),
+        field_new!(Fr, BigInteger256([690043827829672323, 8074623795400862331, 5310403620240173074, 969091752336645211])),
+        field_new!(Fr, BigInteger256([682075399626367698, 15048700877090291535, 2275129808121666139, 1199885292270962289])),
+        field_new!(Fr, BigInteger256([1939705886941602932, 5768637064729216664, 9731083519970263315, 1252977425629152169])),
+        field_new!(Fr, BigInteger256([4569921201000159262, 766279659522121802, 17892834132139318408, 822352338114704771])),
+        field_new!(Fr, BigInteger256([16927715406589360781, 7369845898955465965, 7721370251432665340, 666814431729277342])),
+        field_new!(Fr, BigInteger256([11030837504891159313, 15164592157415500042, 15835348682847628861, 932966524906481772])),
+        field_new!(Fr, BigInteger256([4760277053820987915, 628213970203799710, 3647223205290180845, 1306856554932953840])),
+        field_new!(Fr, BigInteger256([13137815497952069805, 3048027864353992136, 14153871686331126941, 813154483753519613])),
+        field_new!(Fr, BigInteger256([5745083976597285389, 5054515737421407521, 15065347708483215408, 1195674830693785880])),
+        field_new!(Fr, BigInteger256([573867247416314950, 7398623471993662932, 10243990849961200615, 343432150033752330])),
+        field_new!(Fr, BigInteger256([3316554543118739131, 14685131580351688392, 13384614985661334205, 285699506214799011])),
+        field_new!(Fr, BigInteger256([11215033014448727056, 2902170006014471974, 5428077640189766690, 1147872893596882884])),
+        field_new!(Fr, BigInteger256([6443951668650945846, 7573226075073153668, 2022036115287246467, 1131337434593608356])),
+        field_new!(Fr, BigInteger256([3859502345081624230, 13073189111633415838, 7114443776202871097, 476878967747399007])),
+        field_new!(Fr, BigInteger256([9697473686295986619, 3470111255536290876, 14694986728964125386, 240150051833342872])),
+        field_new!(Fr, BigInteger256([13334716157963957989, 12728449443540592996, 5482860828046657314, 875997489330628718])),
+        field_new!(Fr, BigInteger256([14215086074059369267, 1650585253784100416, 14676848522638677992, 685274593412857014])),
+        field_new!(Fr, BigInteger256([1403751267452373529, 5470898031999857286, 4861712356473871375, 997683113602999338])),
+        field_new!(Fr, BigInteger256([17260856253422739140, 18374879379134622888, 3790454800561357416, 649743471922517981])),
+        field_new!(Fr, BigInteger256([393437580452176127, 9332965207021074676, 5627594178304323667, 219706129353699769])),
+        field_new!(Fr, BigInteger256([17085854265779056805, 12106649297949645630, 3247586426310970422, 453936859331089047])),
+        field_new!(Fr, BigInteger256([2270406714334459954, 1683948711814530104, 16323052474929115048, 1255595677332292770])),
+        field_new!(Fr, BigInteger256([17668859415021258648, 3132796189951095429, 10932721849364486910, 668704134920826979])),
+        field_new!(Fr, BigInteger256([9273420451696666058, 17990307504443646580, 7289446741523283066, 14729779693736857])),
+        field_new!(Fr, BigInteger256([2546900226046475643, 12518860698652861686, 401847055319539889, 1015386667296043001])),
+        field_new!(Fr, BigInteger256([16454442650824794571, 15013276235257407666, 16687989737544366674, 592666558989630692])),
+        field_new!(Fr, BigInteger256([7973430517240849113, 8777977053197990568, 438896525883341213, 1099053037824229451])),
+        field_new!(Fr, BigInteger256([16334948312239367883, 16626072818835100075, 14309623220515986426, 263882224207668564])),
+        field_new!(Fr, BigInteger256([17039920765026433817, 656530006659260123, 11561985437654706815, 246288445528164759])),
+        field_new!(Fr, BigInteger256([9598772168984450768, 8206806658863520380, 3136002129444678086, 1251422229138680715])),
+        field_new!(Fr, BigInteger256([9357830647225993494, 15437467557490355090, 7599979773883365478, 410565015674551326])),
+        field_new!(Fr, BigInteger256([17972605609827288597, 12212817093705063242, 9000533820221903960, 730032762439781435])),
+        field_new!(Fr, BigInteger256([6787993503869991084, 1487271503548919886, 12815488913911823560, 704935205887647881])),
+        field_new!(Fr, BigInteger256([7602860239060348099, 10715909898737806228, 11496616898726725084, 722150561467119376])),
+        field_new!(Fr, BigInteger256([2401539670849201612, 1069294814278726642, 15020987785250918987, 269781072409333011])),
+        field_new!(Fr, BigInteger256([11121259179557212382, 2912538041267186981, 12977691973516449306, 253424320302921468])),
+        field_new!(Fr, BigInteger256([12967041787129043499, 11347297067890678826, 1435109848648895186, 369136060264530646])),
+        field_new!(Fr, BigInteger256([1159628778073877300, 4655036905723471196, 7907397130966257704, 489338758875440217])),
+        field_new!(Fr, BigInteger256([15632371280493298615, 12979799038278697700, 3284238685185426089, 376429255880000508])),
+        field_new!(Fr, BigInteger256([4948276157515009014, 1861143579895560114, 10503150516658030726, 1028383498871765718])),
+        field_new!(Fr, BigInteger256([892886858764070338, 2402382828540162127, 14220710689365052750, 892082446882314996])),
+        field_new!(Fr, BigInteger256([17324580973944956624, 7621812382427136162, 13466866805971379826, 71833001324421007])),
+        field_new!(Fr, BigInteger256([9667798543655831489, 17122895196701927582, 1668961329611446311, 1055269153137243259])),
+        field_new!(Fr, BigInteger256([18395762347936784966, 14134966541722459309, 4060719879058155360, 893306642877729229])),
+        field_new!(Fr, BigInteger256([11314055307071276878, 3951246410796327026, 7286131019034095925, 49787091403160389])),
+        field_new!(Fr, BigInteger256([14621279375564607578, 2263954899571231747, 18151220088817262464, 1090559891987608517])),
+        field_new!(Fr, BigInteger256([13692878747615524986, 5643890970101868936, 16978509472835273874, 897291801920934653])),
+        field_new!(Fr, BigInteger256([17034467251502123283, 755220996229691163, 3674259428248335420, 225127709175032236])),
+        field_new!(Fr, BigInteger256([8369412540427645271, 9614459401066844004, 4116208599508688696, 398960327518526656])),
+        field_new!(Fr, BigInteger256([14553122020376749386, 16958173487507549492, 2771440797040003586, 480512256703896033])),
+        field_new!(Fr, BigInteger256([5768099255910954587, 5595434583080997272, 5533268291962050159, 710606121994582559])),
+        field_new!(Fr, BigInteger256([6600765302342291508, 4497714841233175085, 17970594222451047315, 1334777367657824279])),
+        field_new!(Fr, BigInteger256([13727174611551133251, 8580698895798444778, 9496831073483955860, 1277728587162591745])),
+        field_new!(Fr, BigInteger256([3241061249423536889, 15819371400364191107, 6542949373160197705, 965314659838275877])),
+        field_new!(Fr, BigInteger256([8869676188287998345, 7894495277101243315, 13921982041956644592, 30878607166154250])),
+        field_new!(Fr, BigInteger256([6858617730954686905, 11355180559294752659, 15031497013410315396, 943042393595255589])),
+        field_new!(Fr, BigInteger256([17085815627405967791, 14672262128733742055, 15989711479283256933, 818281475387526012])),
+        field_new!(Fr, BigInteger256([8583324784390755888, 8717492726559830114, 8687480251586988651, 1141120676609137556])),
+        field_new!(Fr, BigInteger256([17650494216831897103, 15909898579159129615, 1804100007878298629, 10171655040506939])),
+        field_new!(Fr, BigInteger256([13291528290798540724, 7923005585799888147, 952566833298831740, 1220178218591248888])),
+        field_new!(Fr, BigInteger256([2019256567722780841, 6391277448927070994, 16719616318821788751, 1161481396951416848])),
+        field_new!(Fr, BigInteger256([1879797325080938513, 10492215349493896834, 11888261789700459631, 895445644163527236])),
+        field_new!(Fr, BigInteger256([5589623532448093146, 5608885197012695426, 11171569943905491250, 468718510046614762])),
+        field_new!(Fr, BigInteger256([6774993906747260724, 2587817685920645623, 14750151693994033549, 442431084436722223])),
+        field_new!(Fr, BigInteger256([1254851149346349899, 15657906357623256246, 1378491760353654258, 1337599584304087396])),
+        field_new!(Fr, BigInteger256([6640307452415443384, 2750933880269119523, 5265706636791736096, 1079467593270869182])),
+        field_new!(Fr, BigInteger256([17775811454861627864, 13514371124640674100, 1789845351469020466, 1054042462454589205])),
+        field_new!(Fr, BigInteger256([1245905992449224142, 1129834822304219779, 12973936948822087860, 1086314949260642694])),
+        field_new!(Fr, BigInteger256([9814574259744336910, 17053594587603830564, 1632657785747203467, 428198637598989109])),
+        field_new!(Fr, BigInteger256([13414854723147192237, 5177335209903966772, 7635707182431741641, 801001243688731345])),
+        field_new!(Fr, BigInteger256([11750029257149094820, 4043012425151507785, 9807423859806354606, 293363129025693761])),
+        field_new!(Fr, BigInteger256([3269954019833793473, 10750950505200919289, 9676840434866936456, 603790177546751138])),
+        field_new!(Fr, BigInteger256([8545129697358344350, 5567025690048337476, 1094674172037064365, 1091419708419332187])),
+        field_new!(Fr, BigInteger256([11400754223315141959, 10830332407879581611, 4946425609545572781, 478058503905227691])),
+        field_new!(Fr, BigInteger256([8806765094488585371, 16096972062145097151, 17652047401847752179, 916771012847554923])),
+        field_new!(Fr, BigInteger256([3568690002845651923, 9217503673767256843, 12574879209750171270, 997184796861403758])),
+        field_new!(Fr, BigInteger256([7381466064792360206, 1625317556953814789, 2780450807215585770, 1001253763972183831])),
+        field_new!(Fr, BigInteger256([9467992757880901388, 6417112437021534412, 9963023626072103581, 1205276807461965927])),
+        field_new!(Fr, BigInteger256([5658983953886012887, 13490937438426292009, 17531923691763222860, 662984024138607834])),
+        field_new!(Fr, BigInteger256([16662169041225203378, 6252732619431296263, 1754516235418825062, 865954429485419936])),
+        field_new!(Fr, BigInteger256([10311471890809038568, 10564777501085035294, 4770708210987879768, 982216746662566733])),
+        field_new!(Fr, BigInteger256([18442302203140055299, 8903418473402177875, 9795831600666695521, 918964584575954705])),
+        field_new!(Fr, BigInteger256([14723975626839099234, 14968035699709754369, 3122506696026913624, 498742512686935688])),
+        field_new!(Fr, BigInteger256([3340929842161276511, 16502225711584604472, 18303068563430236989, 717729870798596435])),
+        field_new!(Fr, BigInteger256([4529132421846564568, 12693658339907323710, 3267692838650533018, 890005318408384818])),
+        field_new!(Fr, BigInteger256([7564000564240531362, 12050782987873628496, 16023986793604421965, 338451594764191166])),
+        field_new!(Fr, BigInteger256([2448524217378007705, 17504760116393159110, 6193619291054751045, 290793639004399224])),
+        field_new!(Fr, BigInteger256([2407426407626801116, 4459860915040329066, 2125836808878837755, 1245977546901622837])),
+        field_new!(Fr, BigInteger256([6806682938207337469, 8466480369610071998, 15270944016656704356, 1259306189235943981])),
+        field_new!(Fr, BigInteger256([16950427369128362296, 11818837160537714582, 4769202720210670751, 12710720511203187])),
+        field_new!(Fr, BigInteger256([13990953329300188185, 17322979135044628125, 5546984424533281268, 70399201663616050])),
+        field_new!(Fr, BigInteger256([16094094167780414243, 15801671956445436515, 8861842526567173627, 162123957293147840])),
+        field_new!(Fr, BigInteger256([12671355328450292422, 17105370010671313087, 2241467694138340323, 151841635532202587])),
+        field_new!(Fr, BigInteger256([10863771196016478465, 15101050075868875559, 14327094315992299652, 1177537039445205264])),
+        field_new!(Fr, BigInteger256([5434366904460863632, 3101895601648590360, 18067001271025966058, 1261431949209138039])),
+        field_new!(Fr, BigInteger256([12273510000302191650, 7236625788291459651, 14713410494497886576, 380574931366165964])),
+        field_new!(Fr, BigInteger256([8427951764690039393, 14211338225146434556, 3836125819842237733, 734507769447069393])),
+        field_new!(Fr, BigInteger256([16224463587460653560, 3811831925294307258, 2338336063983529700, 1297190171678255910])),
+        field_new!(Fr, BigInteger256([2488634757194439925, 1633769265896390924, 4014486944531318811, 421310506379083944])),
+        field_new!(Fr, BigInteger256([12789572734620553757, 4861568485431013740, 9808626763851679518, 737388430587973356])),
+        field_new!(Fr, BigInteger256([5651687303804613487, 4266579575401377701, 12670656487961722740, 425222026455721373])),
+        field_new!(Fr, BigInteger256([5024272470004793294, 5867267647168130087, 15968935357646610668, 605592224386872892])),
+        field_new!(Fr, BigInteger256([18070660655299390465, 13970957640653934965, 11868018139782279455, 1324814065779998425])),
+        field_new!(Fr, BigInteger256([9303256433569334270, 849736812202022733, 16489153871161024127, 365749507799173412])),
+        field_new!(Fr, BigInteger256([7581998207319730108, 17348716343193785088, 1167532670680207665, 137311557537734774])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([1296495286194776277, 17959319427469325793, 6619860840809020225, 627562799370392567])),
+        field_new!(Fr, BigInteger256([7921569706450359189, 2883542061105959820, 3301934883877228049, 416796504225348096])),
+        field_new!(Fr, BigInteger256([11142747555440274491, 5772032282937511961, 4858289401531862942, 1255622334591462115])),
+        field_new!(Fr, BigInteger256([10028194995449507172, 1187268079322054428, 1101727805092644770, 1151870533707881937])),
+        field_new!(Fr, BigInteger256([14215612472424804117, 17143662052166064101, 17844962150382471011, 790450786588539687])),
+        field_new!(Fr, BigInteger256([12332959667209734864, 12873007400684146498, 3102033517850267035, 296465663488685247])),
+        field_new!(Fr, BigInteger256([9344770838872356214, 14623389174266963205, 12833364040311273478, 753193101650234326])),
+        field_new!(Fr, BigInteger256([1002026429289412573, 1160820891353230249, 1355831687599734403, 640269503314752208])),
+        field_new!(Fr, BigInteger256([4386116067530885216, 12159345433663748099, 3899679431215031969, 702448769150933616])),
+    ];
+}
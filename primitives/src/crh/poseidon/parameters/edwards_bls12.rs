@@ -0,0 +1,319 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha3, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::edwards_bls12::fr::Fr;
+
+use algebra::biginteger::BigInteger256;
+use algebra::field_new;
+
+pub struct EdwardsBls12FrPoseidonParameters;
+
+impl FieldBasedHashParameters for EdwardsBls12FrPoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for EdwardsBls12FrPoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 83;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha3;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger256([0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger256([4737545855682762916, 1831596595678213329, 9448453213491875787, 183737022913545514]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([9958468573851418383, 15776802158720936610, 11991566415454738436, 132206193233347685])),
+        field_new!(Fr, BigInteger256([15311512054177076724, 8299667215042217034, 3455397153679973228, 305493548193352610])),
+        field_new!(Fr, BigInteger256([798691292661222680, 12598401403337641260, 9403059481864334190, 326349801920826221])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([17569338911868202065, 2733559169133926902, 9582744251462340450, 122801643779906257])),
+        field_new!(Fr, BigInteger256([10305400974043013193, 7135227036035688596, 13560563914318682401, 120448568853123186])),
+        field_new!(Fr, BigInteger256([4994358483171279734, 14027191541173356216, 11523736270327916159, 226844264280184610])),
+        field_new!(Fr, BigInteger256([8383668045529362479, 14623064604601859800, 2820731208085379609, 259979314664028635])),
+        field_new!(Fr, BigInteger256([12657759761662379950, 3860046962349634147, 7483939832379422998, 118123545433998980])),
+        field_new!(Fr, BigInteger256([16573224752876046517, 2923724200603109727, 6643263429684992540, 304090277548846801])),
+        field_new!(Fr, BigInteger256([12970901000914978461, 4917193855242754880, 1204774489699780917, 44047346273045763])),
+        field_new!(Fr, BigInteger256([12328928568577878779, 17672201017353646933, 18085996966288420553, 242902830253701670])),
+        field_new!(Fr, BigInteger256([5906047296157503882, 424589101219258681, 8663123893661481957, 73464437666028357])),
+        field_new!(Fr, BigInteger256([2688550065040894674, 7606140748249642246, 16597530738617400705, 256904828039140829])),
+        field_new!(Fr, BigInteger256([5262509738608949463, 1982402731905891297, 4604808087604586019, 30894415691429842])),
+        field_new!(Fr, BigInteger256([5075621784123505441, 15833017590201216792, 14444295781004562991, 293418205680865014])),
+        field_new!(Fr, BigInteger256([7454233502555608825, 13221970541536529373, 16587237008783200404, 113431339204577567])),
+        field_new!(Fr, BigInteger256([15074596099088704763, 66960277675271052, 2788079008812051162, 71385142869932995])),
+        field_new!(Fr, BigInteger256([6791867026685137771, 13258442188887940932, 6880042514059941480, 217731204109779618])),
+        field_new!(Fr, BigInteger256([3322133051540070259, 14114208030855434962, 1948388784582231339, 190736006099299260])),
+        field_new!(Fr, BigInteger256([6268545484432474750, 6908453906908492761, 5822046878956354308, 37724971517999832])),
+        field_new!(Fr, BigInteger256([10741313398488566217, 10176476087766865354, 13458435551478162357, 151747793674786306])),
+        field_new!(Fr, BigInteger256([17859540940282999981, 11294294157456287570, 539787294218051147, 225038093534898996])),
+        field_new!(Fr, BigInteger256([2694239261885693090, 9475575662988688051, 3910569594337651167, 136504241498725733])),
+        field_new!(Fr, BigInteger256([7433614597213400245, 11030042197409362250, 3263108070720198841, 73784840137623140])),
+        field_new!(Fr, BigInteger256([17323914452867096534, 15016463447830305147, 3117977601294485808, 318672690757181889])),
+        field_new!(Fr, BigInteger256([16669852051391699216, 11792149831664343651, 16869708785581328001, 244403132350619735])),
+        field_new!(Fr, BigInteger256([2486433723662474394, 14639716198437472119, 1330315865482454246, 98853739547475330])),
+        field_new!(Fr, BigInteger256([5674591499327805144, 12946181185456992505, 9451012422509368501, 288611189754793543])),
+        field_new!(Fr, BigInteger256([2999530280747314374, 3822442962377402404, 11792519934459034907, 271042774450164895])),
+        field_new!(Fr, BigInteger256([15483557544184384119, 1242774084646940468, 15897029849913320491, 107550113709238218])),
+        field_new!(Fr, BigInteger256([11804374774157456277, 2974400674500423599, 6549980347502787321, 163540284281670070])),
+        field_new!(Fr, BigInteger256([3574349211347898439, 12420515587831821094, 14847074678390805433, 137572586975070281])),
+        field_new!(Fr, BigInteger256([12637518445587723315, 10351302466855280945, 8304343702990819775, 247311250841060185])),
+        field_new!(Fr, BigInteger256([2336870053460691540, 4170556320340720912, 2470629770838271271, 200387177728327351])),
+        field_new!(Fr, BigInteger256([12050903591505321176, 18188237357801153212, 9253641582589486159, 183257292041797268])),
+        field_new!(Fr, BigInteger256([13030443920378421699, 564189321382392034, 13270256926840669877, 196142304377006370])),
+        field_new!(Fr, BigInteger256([4675990753198597573, 13063715870558298858, 2519484981909605132, 288967639219646750])),
+        field_new!(Fr, BigInteger256([17453225684617966893, 12077310121539314661, 1246178991413709116, 140453937191680185])),
+        field_new!(Fr, BigInteger256([1335560643648151267, 9955038012388926527, 5173853660414472339, 274263647456300548])),
+        field_new!(Fr, BigInteger256([4762274949226879292, 447919132534762249, 4104188340441962048, 99681842670850023])),
+        field_new!(Fr, BigInteger256([13872831649097197996, 382472816460861427, 9025280747802553140, 118924173950996308])),
+        field_new!(Fr, BigInteger256([3898113396064638477, 11877088567502660623, 8132406802247807382, 168896012066895980])),
+        field_new!(Fr, BigInteger256([6160472809661322026, 4585887032510041123, 3807655981457610285, 41988187278170188])),
+        field_new!(Fr, BigInteger256([11053348307290801260, 12723436997591758726, 9591669902964517840, 10320246797666411])),
+        field_new!(Fr, BigInteger256([18151428247466626786, 4965130747464489301, 974056397322427179, 31360116529248220])),
+        field_new!(Fr, BigInteger256([16658799027906011322, 2517423161863167277, 6138589895482662438, 146816384938323889])),
+        field_new!(Fr, BigInteger256([15344645356952070446, 10366089516500275241, 5230928439843344363, 253360158084822617])),
+        field_new!(Fr, BigInteger256([11592440531973490564, 14775308520708728218, 4911089547592962201, 148883421648576787])),
+        field_new!(Fr, BigInteger256([209977510590109586, 184905798144828793, 18396263454468977013, 171586809076649872])),
+        field_new!(Fr, BigInteger256([6295104077407217712, 8531614276127665801, 17416042902061225411, 291822572271180505])),
+        field_new!(Fr, BigInteger256([14202842709367398828, 2908163030730515094, 12018931587851386893, 297989618977608359])),
+        field_new!(Fr, BigInteger256([12088910149983739878, 14072181998185273113, 13605426331021260189, 272534934944011480])),
+        field_new!(Fr, BigInteger256([16789991646842142157, 11982345234684045672, 10811579543358033435, 158360629643155225])),
+        field_new!(Fr, BigInteger256([9155962817820950071, 5567330079340292905, 9144533869448037621, 260006171258518494])),
+        field_new!(Fr, BigInteger256([1280239170587447337, 3891725311517804511, 12997859146719321177, 147194282670626594])),
+        field_new!(Fr, BigInteger256([367949127996089468, 16834691240437776510, 4488888435176058428, 306490087864777118])),
+        field_new!(Fr, BigInteger256([3260482013784192713, 353813879197686580, 4980013870681824104, 267249762769760729])),
+        field_new!(Fr, BigInteger256([9667164161744749968, 3641952355669696158, 5621898019502443515, 267817576282436026])),
+        field_new!(Fr, BigInteger256([387091117096943335, 10202302548486061406, 16630529953863238309, 249337471834862654])),
+        field_new!(Fr, BigInteger256([14812722326878467861, 1539031171254127856, 13941593796964868058, 192180598076283455])),
+        field_new!(Fr, BigInteger256([1260944840140562053, 10318143329150708234, 12953421917705410562, 68967413958846647])),
+        field_new!(Fr, BigInteger256([11243898235157510519, 7183237864168136961, 6421545384964645939, 328525537301694717])),
+        field_new!(Fr, BigInteger256([14915177148944968038, 13678385901449973805, 7745768967182256057, 195125024201697136])),
+        field_new!(Fr, BigInteger256([14562978463765511754, 475090999379753814, 16943145332771807683, 187032708660850804])),
+        field_new!(Fr, BigInteger256([8496458852729106689, 11637189781367360561, 7130932204973487045, 242871813116981191])),
+        field_new!(Fr, BigInteger256([1975527022260452190, 15498947949719075385, 13674932403069888594, 127662965468307960])),
+        field_new!(Fr, BigInteger256([10877615329641319788, 2654115001187606342, 6196616311729201925, 78667761855707920])),
+        field_new!(Fr, BigInteger256([14131365711933800194, 12199096632833927580, 3490594289538323412, 200048114733421340])),
+        field_new!(Fr, BigInteger256([14158004268493581223, 6083325999158778038, 6657840523391988784, 147297771959055068])),
+        field_new!(Fr, BigInteger256([16174770261039322990, 16049649173178693395, 10282855546778320395, 225249585377860974])),
+        field_new!(Fr, BigInteger256([4277243171462379640, 15521121642643557616, 7449508922327551974, 233458479689187683])),
+        field_new!(Fr, BigInteger256([16160290447066920474, 9210767579978590078, 12830947928607515881, 277445535913085922])),
+        field_new!(Fr, BigInteger256([3642795606413493346, 12122309185186739753, 9272682877880603093, 286937511109976192])),
+        field_new!(Fr, BigInteger256([8411868897169792784, 914981034122219808, 11355168415998671558, 99059532515405016])),
+        field_new!(Fr, BigInteger256([14466478899097635061, 4035877631751899290, 8495611621895313772, 251506938134547994])),
+        field_new!(Fr, BigInteger256([7907295822821407291, 12338501447036517628, 2397376695281927714, 281807998043135573])),
+        field_new!(Fr, BigInteger256([12478528564356350646, 8155878132903626212, 17601997319260822280, 156043139935437947])),
+        field_new!(Fr, BigInteger256([13077164518042759840, 7050397400198528938, 14860134863856908770, 25053199704753908])),
+        field_new!(Fr, BigInteger256([4455849691233479688, 4839718826705481977, 3042809864677033880, 94548055513994824])),
+        field_new!(Fr, BigInteger256([886549122607011773, 6134504525863452895, 10040601229256703701, 234012116657435688])),
+        field_new!(Fr, BigInteger256([8112090718235748298, 9233298288403175331, 15943181898365876404, 306649187354724005])),
+        field_new!(Fr, BigInteger256([9478895655393977495, 17680569545514765761, 11622227236559242109, 297847815026399489])),
+        field_new!(Fr, BigInteger256([13355358905578321651, 9285347451204649769, 3121515403843254345, 36924663354655103])),
+        field_new!(Fr, BigInteger256([15731320510339887459, 10304832194020001452, 2260289300518204866, 132021629172089742])),
+        field_new!(Fr, BigInteger256([8899751353370858440, 1101912502260003215, 17570170894677062090, 235108057240199947])),
+        field_new!(Fr, BigInteger256([4224960259004284271, 4728872157393687756, 10330375181030167176, 208252105614290156])),
+        field_new!(Fr, BigInteger256([4171444552071195725, 7541308054480407275, 5780220002390815674, 186939083701524875])),
+        field_new!(Fr, BigInteger256([9511419084683511969, 14044754774417529987, 8933160544808447854, 91223394869534227])),
+        field_new!(Fr, BigInteger256([2854936840333380859, 7360105228232409870, 8744000501356481590, 171075571178402370])),
+        field_new!(Fr, BigInteger256([16326231860786607408, 8149453375970513863, 1747307966954448972, 261323960194927571])),
+        field_new!(Fr, BigInteger256([3592406017548770000, 5997300802639871152, 11045092093672140048, 154774681231108192])),
+        field_new!(Fr, BigInteger256([2455388593168294101, 4289829806160761108, 693923324462859827, 232036679411132068])),
+        field_new!(Fr, BigInteger256([1636158151639401454, 7440127656265715397, 3228910530754380996, 239027278153792035])),
+        field_new!(Fr, BigInteger256([12260017838447508191, 17418596942890587276, 14988303414456353663, 47385662310045352])),
+        field_new!(Fr, BigInteger256([11115976741932334163, 1470302822262368405, 3537143507453270391, 131306521869120302])),
+        field_new!(Fr, BigInteger256([6469396728640927295, 6544069434961905423, 4219515792168437373, 164312471578746653])),
+        field_new!(Fr, BigInteger256([12153945860998110868, 12764806456713267874, 11373654063264062225, 244346389281315278])),
+        field_new!(Fr, BigInteger256([12164545550611122852, 217475469617433108, 7184745737711748008, 139943903000108465])),
+        field_new!(Fr, BigInteger256([3515070226680383640, 8809410793280119428, 16510550732993663841, 143663145892777067])),
+        field_new!(Fr, BigInteger256([16926133706038125614, 4228712576790455784, 4975357539958221511, 66622658250820616])),
+        field_new!(Fr, BigInteger256([17189469569766596910, 1131772691470107277, 8528618197341745237, 250806560647235837])),
+        field_new!(Fr, BigInteger256([17846057160495482167, 4417549906166968150, 10417764229729562809, 101927743110657021])),
+        field_new!(Fr, BigInteger256([8922569112082664787, 11410401136513583608, 12629663012508928287, 83010169615037901])),
+        field_new!(Fr, BigInteger256([3841651841525356588, 14487290722570750420, 17044128578699111325, 328750743445000557])),
+        field_new!(Fr, BigInteger256([5942305958806065993, 4688371165525143446, 5501303418310222373, 74241000131647093])),
+        field_new!(Fr, BigInteger256([4772024437318911871, 17896030295839701818, 8087604200006887840, 261307914999414193])),
+        field_new!(Fr, BigInteger256([10199454106551701102, 1306099791299007015, 6369978175772406650, 286221390388125188])),
+        field_new!(Fr, BigInteger256([7715132730331819784, 1396018055450225431, 17747808082675868982, 108361686291471716])),
+        field_new!(Fr, BigInteger256([7248376809849881499, 11726154364941284056, 4802917051769879995, 258561701898097545])),
+        field_new!(Fr, BigInteger256([3069228931967039954, 981486049607585486, 14555691709866029417, 202594654764519226])),
+        field_new!(Fr, BigInteger256([11517103136744027541, 1221104169696625189, 277245503151696703, 104761685015367922])),
+        field_new!(Fr, BigInteger256([11952411316219311525, 1440508561330201861, 7823351600988669490, 67628447796636752])),
+        field_new!(Fr, BigInteger256([17606423197403775492, 14756323896502576980, 3595441440160014129, 114585369809803253])),
+        field_new!(Fr, BigInteger256([11410058985747476030, 3853405359200046295, 2992538075732554595, 335012090698091882])),
+        field_new!(Fr, BigInteger256([15035964829316951318, 1880618174519450459, 3953634472773680583, 325734411650371468])),
+        field_new!(Fr, BigInteger256([6993577717142613221, 1101997476192341429, 11971820011835752349, 70503317241009919])),
+        field_new!(Fr, BigInteger256([4416689897772990915, 3148847380694223307, 3229464098664070910, 60766654911153371])),
+        field_new!(Fr, BigInteger256([4363887760564872744, 2185422790781829765, 10901460035781523071, 109353474508332473])),
+        field_new!(Fr, BigInteger256([16036913777693429681, 13811750847948652705, 13495721280779524244, 311646150546879921])),
+        field_new!(Fr, BigInteger256([5853872024190773677, 3272396372070874234, 3096217728364571010, 72812108130359283])),
+        field_new!(Fr, BigInteger256([53542133570907321, 9520231169583122046, 1388574450005290064, 192000770722135317])),
+        field_new!(Fr, BigInteger256([1395956963198306563, 6440160984526707362, 17588074412761020300, 113829271040354257])),
+        field_new!(Fr, BigInteger256([5582922216517057315, 17805961247972609595, 16325976073321955956, 159002942668140462])),
+        field_new!(Fr, BigInteger256([8864276433856091412, 12447638296071303440, 953443926608743043, 56104925163127344])),
+        field_new!(Fr, BigInteger256([2741528114965137480, 11274917608207621207, 8838923240342517308, 96447818900041845])),
+        field_new!(Fr, BigInteger256([4816131563963920303, 18289871074071970138, 6352563157968216560, 238558290855048922])),
+        field_new!(Fr, BigInteger256([9958640105010822992, 12385036687123677678, 7140805813731391420, 210351993554654314])),
+        field_new!(Fr, BigInteger256([15121126750244927370, 15191352338047209141, 13470540175215023535, 72926606512264002])),
+        field_new!(Fr, BigInteger256([3476409340556519320, 5971105636996979987, 10830125168403099385, 188944395539447207])),
+        field_new!(Fr, BigInteger256([2242414717999613757, 207216433288486905, 11236097095658537857, 262444827138661606])),
+        field_new!(Fr, BigInteger256([4875914010751198832, 987214996817017070, 9236470197449177258, 189906707380701956])),
+        field_new!(Fr, BigInteger256([213540445965954961, 6205974425276263612, 13177838896973583749, 250710961853591387])),
+        field_new!(Fr, BigInteger256([7066173029546513743, 3598155113157684684, 6476925153881720964, 35421531949213226])),
+        field_new!(Fr, BigInteger256([9909660178551446856, 5804808651105000127, 16593415257113556061, 324706935498740020])),
+        field_new!(Fr, BigInteger256([13893645381413251369, 1073634896196878843, 9702104705044146318, 123106610369270363])),
+        field_new!(Fr, BigInteger256([2835937851159347906, 14859208328556913340, 16189368453184378787, 208301194885819416])),
+        field_new!(Fr, BigInteger256([2146546381929023546, 2757511989477952388, 2145161049830378946, 45587761017129212])),
+        field_new!(Fr, BigInteger256([2307128621189392526, 1533505581028931982, 2348783172110422674, 203800344467002049])),
+        field_new!(Fr, BigInteger256([8221262530187815226, 8403522533275443343, 4304472878765005479, 47873509843727610])),
+        field_new!(Fr, BigInteger256([252453750608610893, 1724148635232748916, 12062342908878650156, 279840653532763759])),
+        field_new!(Fr, BigInteger256([3537313161073893880, 951447400954686799, 10962559788491185614, 153543849783508570])),
+        field_new!(Fr, BigInteger256([11073383229837041795, 7443531422684573867, 12489630027435365950, 309819947910825569])),
+        field_new!(Fr, BigInteger256([7290751518360895798, 9741277504254441621, 12155313210307125976, 170382430401543950])),
+        field_new!(Fr, BigInteger256([13052981618805536884, 14134425116379237310, 13303945739613206323, 212371532588240663])),
+        field_new!(Fr, BigInteger256([13145150997738424823, 11434778124489121377, 4270609584156578094, 130158639444681712])),
+        field_new!(Fr, BigInteger256([3843795619615462353, 13714446867211576949, 778469151966699105, 241674795725181430])),
+        field_new!(Fr, BigInteger256([1267864914779662289, 7570705437920270151, 14591681386029501991, 138472168053663633])),
+        field_new!(Fr, BigInteger256([985659045624233116, 11741444730550442648, 3731560102599236962, 305893408841988230])),
+        field_new!(Fr, BigInteger256([16526373845857671695, 5727216384557531905, 9160502930439171277, 191935680160560022])),
+        field_new!(Fr, BigInteger256([18175625643361391721, 15627843471944704845, 6112555654182906180, 295356914588927365])),
+        field_new!(Fr, BigInteger256([1685997039840298369, 11881992675377447512, 12964119848266140479, 2507068799463278])),
+        field_new!(Fr, BigInteger256([14583666382602240491, 8913803523241918549, 3302308237945655061, 305992011779247244])),
+        field_new!(Fr, BigInteger256([10598865821226479770, 7421972158466894298, 10427007015317364616, 66679005835574911])),
+        field_new!(Fr, BigInteger256([13568997819729959855, 11619001759804747558, 429108131321832690, 319366852340734339])),
+        field_new!(Fr, BigInteger256([3504973941015594745, 3725502960177032089, 2902979064578199578, 155611306449978712])),
+        field_new!(Fr, BigInteger256([13236928167315853332, 16323892536877701553, 1237884570481400933, 154824852627956862])),
+        field_new!(Fr, BigInteger256([9873761301086916456, 12957522772335494020, 16933231705039284113, 153331271473880338])),
+        field_new!(Fr, BigInteger256([16808496899987376416, 11214687370214004662, 2561872584983128925, 314805777018929698])),
+        field_new!(Fr, BigInteger256([15418841423994710328, 4120299823251007698, 10001783455924435355, 49208264718082579])),
+        field_new!(Fr, BigInteger256([13937980689590606513, 13244046251790811606, 1685218883789460361, 32306411366903080])),
+        field_new!(Fr, BigInteger256([872041823094898626, 1137758903676731251, 14695331783428738415, 242199603597306529])),
+        field_new!(Fr, BigInteger256([12133105885938388099, 12167338931320691865, 1045173037850783469, 72004535782726033])),
+        field_new!(Fr, BigInteger256([6005436883416231113, 11463564282271732119, 5666585557395875963, 281452651421250293])),
+        field_new!(Fr, BigInteger256([2328513964476242152, 9307792867860574023, 1668933606450094514, 217367864152354965])),
+        field_new!(Fr, BigInteger256([14375834641250854756, 8037833020371857782, 7507926367993898872, 91725064031158071])),
+        field_new!(Fr, BigInteger256([13009060211958893578, 6163755172919707868, 784758506162725095, 153937337801708752])),
+        field_new!(Fr, BigInteger256([4684305037829790206, 6454501396161773160, 5934046370052320900, 210884776174558743])),
+        field_new!(Fr, BigInteger256([1608079840458163215, 17823357899560378596, 11769902659699748362, 150260114005299085])),
+        field_new!(Fr, BigInteger256([16826411087276507074, 665404931880087969, 8065067962199247133, 69900358550510696])),
+        field_new!(Fr, BigInteger256([9989330919549913223, 8582068754155003263, 9539768385028620116, 131483985999756518])),
+        field_new!(Fr, BigInteger256([7568488549466736953, 236653987145392587, 9213686015138376066, 180416563634666048])),
+        field_new!(Fr, BigInteger256([5138535662091873710, 3770225906715027562, 11834103818908786218, 294204057186825316])),
+        field_new!(Fr, BigInteger256([1337833621484732830, 10769120801861731511, 4467525599332091430, 67403557482617553])),
+        field_new!(Fr, BigInteger256([4558539347791538079, 7624793194361910695, 10773234901524805269, 38236775071507760])),
+        field_new!(Fr, BigInteger256([14740239929204988954, 9759207080553039830, 3255644902497013019, 231027371355296149])),
+        field_new!(Fr, BigInteger256([12604723696920767645, 10197845131397762363, 10825037666692728652, 190930936950613514])),
+        field_new!(Fr, BigInteger256([4699949231571850361, 86625491562686497, 14330163478922015711, 51221121338048715])),
+        field_new!(Fr, BigInteger256([12106855706502170141, 6019017219986815992, 4611477584348194617, 136604972116817891])),
+        field_new!(Fr, BigInteger256([7744406398402476922, 18407556286948084714, 12377134197493592799, 116428710588750378])),
+        field_new!(Fr, BigInteger256([7510641887761292625, 12549251730163208230, 16599186784910410424, 20352338987583543])),
+        field_new!(Fr, BigInteger256([11083872064525059063, 16846383770883202886, 17871919095781604312, 239276963053636322])),
+        field_new!(Fr, BigInteger256([9544970627814180040, 9110403862620013111, 16839720179077025035, 167095226281854239])),
+        field_new!(Fr, BigInteger256([14699424384036005740, 6337029840917466617, 8693921642432233578, 111765463125296408])),
+        field_new!(Fr, BigInteger256([8827278547191298894, 3691587203398172720, 6893051984226029871, 155682022380358306])),
+        field_new!(Fr, BigInteger256([14965314622623100461, 1935245072081849081, 7513383781881281741, 325464896740022695])),
+        field_new!(Fr, BigInteger256([10510833575112511883, 2241209063132692399, 1363499729211574338, 289017562565392114])),
+        field_new!(Fr, BigInteger256([3986853716217679274, 1202976004168523765, 5752135146264307617, 270015341479832272])),
+        field_new!(Fr, BigInteger256([12508130802124972551, 11396039549595270634, 6288481567289700377, 233141068439636463])),
+        field_new!(Fr, BigInteger256([12464321435755952152, 14297025190859939328, 7193266237523144003, 277583907152771449])),
+        field_new!(Fr, BigInteger256([1102296978978869145, 7140130632798624719, 14332374618896023159, 154802224732939627])),
+        field_new!(Fr, BigInteger256([179540119246936364, 4256146704852790746, 3820696281741869609, 278574382412572687])),
+        field_new!(Fr, BigInteger256([2099862668888422756, 10967129140845843011, 18291235608277934002, 108773053674461347])),
+        field_new!(Fr, BigInteger256([13130179426897873662, 12210858896075776065, 7387270123623032762, 3052507490620005])),
+        field_new!(Fr, BigInteger256([16241998596238403710, 14769120850518068964, 14735231534668650313, 110038759135964519])),
+        field_new!(Fr, BigInteger256([11568116703248959426, 6871408460411727219, 11215557350235838920, 78058980168069680])),
+        field_new!(Fr, BigInteger256([4145401478882519189, 4322531969143475817, 5143676021060827917, 71652638304060883])),
+        field_new!(Fr, BigInteger256([11199403072538355123, 1929710116818068738, 9987363188398265228, 126642008267134380])),
+        field_new!(Fr, BigInteger256([14319469960826740652, 3069862931217041079, 15640870544011327022, 155370719526826077])),
+        field_new!(Fr, BigInteger256([6513001879085840813, 8507954598496369216, 15680091602920810465, 205558608445914955])),
+        field_new!(Fr, BigInteger256([10838168247808857091, 9220318269004399205, 3304400572786025883, 221638932449641913])),
+        field_new!(Fr, BigInteger256([16370411842459933403, 3846433167416291931, 8662737458141734898, 25436164375189060])),
+        field_new!(Fr, BigInteger256([16358255791496578619, 4125756614555662622, 217716596605522495, 106328801762590545])),
+        field_new!(Fr, BigInteger256([660408998738463425, 11786378211397120780, 7778180963496485848, 31670480902373783])),
+        field_new!(Fr, BigInteger256([10403520403319759448, 8044483205267070814, 4355792283945502958, 45161297909329253])),
+        field_new!(Fr, BigInteger256([11804232709838325941, 6401295208912108626, 15437424992469795373, 285877703277278997])),
+        field_new!(Fr, BigInteger256([234439965367396069, 13913003331008227462, 6793892991107949716, 38951704306382047])),
+        field_new!(Fr, BigInteger256([8150484506457199734, 9073805527625916394, 4528929406920881251, 109131459637828180])),
+        field_new!(Fr, BigInteger256([15453459380407793740, 10678413081264937776, 8370960403769608278, 182920308098815002])),
+        field_new!(Fr, BigInteger256([15646702944637861443, 1102893071737523179, 6037769513695921374, 58932580439700825])),
+        field_new!(Fr, BigInteger256([14141707019748121248, 18300150620553529424, 15295824804856956441, 228414264782460990])),
+        field_new!(Fr, BigInteger256([5281839880667390029, 12148797444832308535, 16641557222377171439, 115351988749419020])),
+        field_new!(Fr, BigInteger256([17857447508939269513, 5254132175376843958, 7078675833142100103, 283216964764402051])),
+        field_new!(Fr, BigInteger256([12382971527194178703, 12119991578031692270, 12227456719088791308, 123954743134647104])),
+        field_new!(Fr, BigInteger256([11499641094730856352, 10203054336367834415, 3515290669884041914, 131055726993564074])),
+        field_new!(Fr, BigInteger256([1161563631703306224, 516033592360867727, 11501451555868120404, 69842170236924803])),
+        field_new!(Fr, BigInteger256([76617580044700515, 12779872195329658802, 11588627404257758325, 30262176026403192])),
+        field_new!(Fr, BigInteger256([15128550967421628436, 13978853698925635672, 10149713374214453271, 115984318240238932])),
+        field_new!(Fr, BigInteger256([8425500716171268313, 16244363270610450900, 15835589672852306532, 145156537785492980])),
+        field_new!(Fr, BigInteger256([6843597677192892194, 1693222627673818979, 369613903738773264, 122769845194872552])),
+        field_new!(Fr, BigInteger256([1863895144186508012, 12571714130946763351, 2631029256416541001, 247787846584687820])),
+        field_new!(Fr, BigInteger256([10859488352143048162, 18114922585545778198, 6568772846765018045, 13466371286351403])),
+        field_new!(Fr, BigInteger256([17588894500810997796, 144175710716821248, 3177174478925211836, 55919266800639854])),
+        field_new!(Fr, BigInteger256([12059301982429469851, 3225160089807026006, 9333837134884495437, 283153285474110872])),
+        field_new!(Fr, BigInteger256([267923387327962121, 9007594616939016523, 14602108175188410077, 284669876430171676])),
+        field_new!(Fr, BigInteger256([15209083156113736370, 17442915283233300180, 8456806454514703513, 266448161611840709])),
+        field_new!(Fr, BigInteger256([11972449166991674634, 1590026499536283641, 14525021818190342560, 22033379039732003])),
+        field_new!(Fr, BigInteger256([14880025888174426540, 7460718789287482363, 3714746623838485827, 176553904268572457])),
+        field_new!(Fr, BigInteger256([1121793154209880991, 15830911577383657643, 16927352530766894777, 257527547973270576])),
+        field_new!(Fr, BigInteger256([15095522763694524783, 10453084878988620871, 4252201737674785378, 90258836195656222])),
+        field_new!(Fr, BigInteger256([8657734368962021367, 3098130162303863099, 9902558079255900844, 269666057398375500])),
+        field_new!(Fr, BigInteger256([7950854777697193164, 1208726704679294459, 16632799303936927670, 243580656102720750])),
+        field_new!(Fr, BigInteger256([13042514994307883291, 8271286137500791158, 9337457735567624098, 335869528578395113])),
+        field_new!(Fr, BigInteger256([12507867570359942622, 518249432615619764, 16605720837045265867, 229982988838984366])),
+        field_new!(Fr, BigInteger256([13786213708293082876, 7721700885808535803, 15812074241798577615, 15682360862332328])),
+        field_new!(Fr, BigInteger256([16825466601678624268, 1080485924643498104, 1160653959664880120, 90299966361928529])),
+        field_new!(Fr, BigInteger256([9709324691725292074, 3274721641643299950, 8132159989091827151, 289884819191478419])),
+        field_new!(Fr, BigInteger256([11361066098310977325, 2741783924886315592, 9932632159464671003, 69956998762966125])),
+        field_new!(Fr, BigInteger256([8773456311131205723, 12857994248112040445, 8288287485819725537, 29661004087992074])),
+        field_new!(Fr, BigInteger256([8461136926704630582, 2863588860108946136, 17108177486879654782, 325324396819073091])),
+        field_new!(Fr, BigInteger256([12496141108107763333, 10143661491365163685, 8400358215333042233, 204744455004613570])),
+        field_new!(Fr, BigInteger256([5010005993466558111, 930380420194126042, 3030034309135268250, 99449362778761355])),
+        field_new!(Fr, BigInteger256([3940954965419650609, 5092769664382655837, 16801866857065383938, 86198606502393699])),
+        field_new!(Fr, BigInteger256([5696850396106674893, 1736680205039358125, 9577307631014998957, 192479083991146627])),
+        field_new!(Fr, BigInteger256([411783411120929150, 13538369289757234046, 16951588084672308046, 335042697124864919])),
+        field_new!(Fr, BigInteger256([16359374728510063503, 17072501162013976301, 14721725170108973526, 204006884751305758])),
+        field_new!(Fr, BigInteger256([12144190659608602688, 2950900206441378631, 11057866072886998197, 81408421699500241])),
+        field_new!(Fr, BigInteger256([4414027655725539875, 13369502236802863434, 106917987649892613, 107051233132898131])),
+        field_new!(Fr, BigInteger256([13548892456677086129, 8256267249816129996, 5648418250932642937, 86550547216512290])),
+        field_new!(Fr, BigInteger256([9414104957228871273, 12518880832603578824, 17501342650139224913, 117375527658252876])),
+        field_new!(Fr, BigInteger256([16655874406899759345, 300519355482138524, 12802694423870605062, 219010197723538636])),
+        field_new!(Fr, BigInteger256([17710498944246696829, 12953892739773224864, 13217137107045569487, 95240617226407772])),
+        field_new!(Fr, BigInteger256([7348360277504820464, 7868859296407346393, 5960250656329160391, 67175836683281930])),
+        field_new!(Fr, BigInteger256([4339232803243645254, 1185633514356134785, 17219867806577201631, 99677996421572806])),
+        field_new!(Fr, BigInteger256([140033669344568499, 17087332938836032032, 7098881765287880861, 192448415579972011])),
+        field_new!(Fr, BigInteger256([10984027673336156316, 12727604759889333652, 9477323073751243465, 233887398199465807])),
+        field_new!(Fr, BigInteger256([17867603072124979018, 10866581771554016926, 6364434523573268873, 296451469528522958])),
+        field_new!(Fr, BigInteger256([5109070985072787570, 36621334885315691, 8223261052645427217, 182166718286288977])),
+        field_new!(Fr, BigInteger256([12473694019816167849, 3175720815142661304, 10694523255506926042, 272419288244091026])),
+        field_new!(Fr, BigInteger256([18211836404097337408, 6576370958811500172, 2974957983300123268, 235765755650597379])),
+        field_new!(Fr, BigInteger256([14068689981672897280, 14325244045658687643, 14471797043641442823, 52582266476927077])),
+        field_new!(Fr, BigInteger256([10179384257840337877, 2416253845761856375, 16491976338442433856, 33788284016254191])),
+        field_new!(Fr, BigInteger256([7964156334304052077, 17785299718424334770, 5493570634222531304, 135522205025516243])),
+        field_new!(Fr, BigInteger256([3321584277263794044, 15375239693780061798, 9831862072999980482, 169309856481815439])),
+        field_new!(Fr, BigInteger256([16170488939729245505, 12488247954153787721, 7528041137260404879, 192100920018527480])),
+        field_new!(Fr, BigInteger256([5824359693808023301, 16834269110751655721, 15443967274955482900, 94010712538839103])),
+        field_new!(Fr, BigInteger256([6660271852423311349, 9356805599319100247, 10361859985659242489, 210687118042905039])),
+        field_new!(Fr, BigInteger256([18177271403429391349, 12500387405431786476, 11988058011340312148, 149629824344664951])),
+        field_new!(Fr, BigInteger256([13674022429372087063, 11866695888946018644, 4485581617019194263, 257098678118206698])),
+        field_new!(Fr, BigInteger256([15689389540415586298, 12379434678374223357, 9689698644383339226, 163358540352785376])),
+        field_new!(Fr, BigInteger256([11294959083637901842, 12975869914365341391, 2566460277767486110, 61177007538384837])),
+        field_new!(Fr, BigInteger256([9978747845422661750, 16576949114739243628, 12909289633061138604, 83370409143058521])),
+        field_new!(Fr, BigInteger256([11535593512317383486, 1135578691291306652, 7308250570622584835, 301894930506506588])),
+        field_new!(Fr, BigInteger256([10122302839958226258, 10816805470562018382, 10663331086027177791, 92581490249452743])),
+        field_new!(Fr, BigInteger256([1146686826551306256, 4557734594097766882, 13559871575135238287, 179701829261001589])),
+        field_new!(Fr, BigInteger256([11643733099326924368, 1829207368222174637, 11761142036055543339, 253684214851836392])),
+        field_new!(Fr, BigInteger256([1129686512962212193, 11796194366430745755, 4749881996754003727, 219296224015668783])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([15810846503924247756, 14851330512322778437, 4861305075021835187, 4730345393542125])),
+        field_new!(Fr, BigInteger256([16436111850571355337, 8713488300084941493, 10256852865957247734, 123545901760862849])),
+        field_new!(Fr, BigInteger256([14966250315061594630, 6332751220674598706, 5877622043359765504, 174834181899056649])),
+        field_new!(Fr, BigInteger256([16957780925537641820, 5712414202215310985, 776225800842259603, 196781663758952976])),
+        field_new!(Fr, BigInteger256([7584656884117547941, 10617332339531540315, 17761843866673466218, 89103715263130880])),
+        field_new!(Fr, BigInteger256([3850533871744319789, 17130676346257162080, 2275206935364767987, 131390854956239908])),
+        field_new!(Fr, BigInteger256([4143630509446094275, 6123075791975146355, 16898719724845329923, 240845414873953695])),
+        field_new!(Fr, BigInteger256([17337694418699837474, 18090491894498349675, 14231921870834896764, 42873639435494444])),
+        field_new!(Fr, BigInteger256([736975513657339054, 6734520298142692866, 677596755928401742, 328598194297282470])),
+    ];
+}
@@ -0,0 +1,238 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha5, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::edwards_sw6::fr::Fr;
+
+use algebra::biginteger::BigInteger384;
+use algebra::field_new;
+
+pub struct EdwardsSW6FrPoseidonParameters;
+
+impl FieldBasedHashParameters for EdwardsSW6FrPoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for EdwardsSW6FrPoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 56;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha5;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger384([0, 0, 0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger384([14565041193813930669, 4053471498258482584, 10810249831532414667, 13478508912820795408, 8748836244939108005, 13440603348889345]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([8608951861170603629, 10319721766658709947, 3590039655672854010, 16838469980422585814, 6779943267949428611, 9930519172268131])),
+        field_new!(Fr, BigInteger384([9803863744147016666, 9705734966327679322, 9259888340235278085, 12764599241265329485, 17706096288899592666, 13803021786546609])),
+        field_new!(Fr, BigInteger384([7401597715717156311, 10271832866820845111, 16933332104466475657, 12068929764026992925, 7674623316783868477, 8106311217631824])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([2997214638379410860, 3859761585679225016, 5580927065265709680, 2781212376569423494, 11078206062507655181, 3496815633105158])),
+        field_new!(Fr, BigInteger384([8000982370025492502, 5737937715535631392, 3520254075708268475, 9163201462189262915, 16989837999637034622, 12139035998490999])),
+        field_new!(Fr, BigInteger384([13588982676564406318, 14044122740216918609, 7231314598869123937, 13586373391688415845, 3221710423363607167, 6842811578402358])),
+        field_new!(Fr, BigInteger384([5168319591514045097, 15129341176463327752, 13895756759693400148, 2886271584178139139, 2855458730437711254, 8193096542203136])),
+        field_new!(Fr, BigInteger384([8353433600338608391, 15866623214186580041, 2888484174758569247, 18444286120403915218, 16078833589220409964, 9948407456333705])),
+        field_new!(Fr, BigInteger384([1976659847090597303, 9237196277802530760, 10920257570484758979, 3434714494516578759, 9966900365345716408, 14176019522888935])),
+        field_new!(Fr, BigInteger384([168271413605332910, 13892390782386368280, 10328915500511759642, 14705958298359359779, 985417988860093431, 3224728549753385])),
+        field_new!(Fr, BigInteger384([7922661355294941476, 7091065725568293225, 16505484878435109848, 11058594493510296078, 8854227169568869992, 14591514171309571])),
+        field_new!(Fr, BigInteger384([5913678467393525935, 1324878260617778228, 11069759229495756896, 6701297618508720927, 7173537661034223330, 1206652638472082])),
+        field_new!(Fr, BigInteger384([17229694840994007071, 6583696080144985664, 2713171102276923792, 7230182124011176528, 12026027030035465285, 3884724986453969])),
+        field_new!(Fr, BigInteger384([8431510134624326382, 18272889418161885920, 958643755522622521, 3607162740751804825, 9335805793701379525, 12889680433333067])),
+        field_new!(Fr, BigInteger384([13364132571048043503, 16000596771067445415, 3208438416019548354, 3854539059339811, 5828252402876289975, 14484232204305375])),
+        field_new!(Fr, BigInteger384([3606359185730707161, 16595250252577394727, 9750577015453739294, 11096918605484479465, 5709657018387057263, 4388687196594856])),
+        field_new!(Fr, BigInteger384([600453727311745620, 10193807298017972839, 11004366581541688577, 12523717797567030032, 10690924821724705397, 9228772042206524])),
+        field_new!(Fr, BigInteger384([8283619706211093474, 797133182030919741, 16587650153125418527, 7650365442349138231, 16920525739581367390, 6814525675520979])),
+        field_new!(Fr, BigInteger384([1690418877125554844, 5622668485541604559, 18227546360979147181, 9158014785429799350, 14648467338503845573, 13500753951415915])),
+        field_new!(Fr, BigInteger384([15404282940665058480, 8036214919512161680, 15975509687761057571, 18117593421430871659, 891041743956196583, 11158796446209752])),
+        field_new!(Fr, BigInteger384([9388742693903696947, 10084521251340289230, 14289626059486350250, 3983073467695012075, 16287081140230499844, 931204922300983])),
+        field_new!(Fr, BigInteger384([9124658943141949461, 1114740988336022049, 12299740906064518148, 5444503824586161550, 4704188102391216787, 573571471719371])),
+        field_new!(Fr, BigInteger384([14139722186127903243, 18160623197118858279, 14724184074932174934, 10174702971498842392, 4799511092161069206, 3162605234876642])),
+        field_new!(Fr, BigInteger384([13490625482394630652, 3298983543405957780, 5894202003392691814, 6391064486940370800, 10729549766621296355, 679036026607047])),
+        field_new!(Fr, BigInteger384([9970383134812953426, 7115677192504238355, 6781556989998280398, 9430098532085071615, 7506568051354669568, 6212088246352759])),
+        field_new!(Fr, BigInteger384([6631518264658216051, 4588085000930277371, 1118254553094418695, 10664307733970760753, 17561344891284650002, 8821136544390267])),
+        field_new!(Fr, BigInteger384([9614523913273124161, 5952077120324247911, 10188100560387744695, 3430380133008231810, 18305832518561164854, 11740851074584221])),
+        field_new!(Fr, BigInteger384([13833337557190873607, 14180944713348664728, 10271844394964722252, 2274099441973755610, 918660481092047904, 11144419133701636])),
+        field_new!(Fr, BigInteger384([18231458556227742470, 8596164368319888390, 10865841746325941261, 14928107556377733520, 3898350030384347241, 5038472189166283])),
+        field_new!(Fr, BigInteger384([2444172225061120836, 6328649592782255488, 5239646890508382962, 11687839564083156789, 15781505452992612273, 2143027272462904])),
+        field_new!(Fr, BigInteger384([8539465562322680597, 4917202788702016657, 14592235208972237309, 13254158299422399726, 15676052031859168080, 5543514624258943])),
+        field_new!(Fr, BigInteger384([11591071872209298442, 1859554837558982941, 16491212535935508098, 5017646771064947370, 14926460616225651134, 7776549749718169])),
+        field_new!(Fr, BigInteger384([13829770172495672570, 18316596756640347180, 17172418112065670941, 117929079068852427, 15514835894844613511, 2033599971474804])),
+        field_new!(Fr, BigInteger384([4063904039540057932, 3812139178906250456, 765761792658493210, 11886052885943872331, 6437567592183514135, 10335533243542028])),
+        field_new!(Fr, BigInteger384([16873624666955499516, 15467584238770147468, 16014068557931346859, 17102559349380304764, 17515966312959946130, 10703403859600986])),
+        field_new!(Fr, BigInteger384([2089474821451374249, 10777680948202003606, 5801955312150724231, 14126338018783837057, 2257026123635396920, 681526104284249])),
+        field_new!(Fr, BigInteger384([3854154720248447177, 3753337829856264195, 16068959073920117142, 7641073404840372087, 12715947628253705837, 9157522199773676])),
+        field_new!(Fr, BigInteger384([13103563963683815368, 14165838837924962431, 10095820851508316097, 12799844767475052912, 16573739944715194638, 1760430751791102])),
+        field_new!(Fr, BigInteger384([4128639781788363483, 3722583364011236891, 8241408538018753427, 8469411012731324315, 2908032423769772899, 14368545384738790])),
+        field_new!(Fr, BigInteger384([7183003324960211624, 15764656852333690763, 16875791105902108669, 8441778901630702009, 6817062805980139853, 678527185779544])),
+        field_new!(Fr, BigInteger384([3962916053140446538, 684948769719352438, 4734843999691667291, 6284750101395200443, 17782525196156632956, 12232119305099345])),
+        field_new!(Fr, BigInteger384([3067780893077162233, 1794752081565724709, 17282654448805550134, 11827762539071278377, 5762828440860373250, 4262200837430005])),
+        field_new!(Fr, BigInteger384([5513834035806258227, 1453018430412374243, 5225178300082279836, 13762362505018644521, 9360045005221127046, 10487341076593668])),
+        field_new!(Fr, BigInteger384([12001326888173623951, 3202380770898002566, 5514668695435468908, 15479401945994209565, 13006301701356400957, 7488543691337818])),
+        field_new!(Fr, BigInteger384([16315863702956060171, 15217779382276957333, 17241029188203463735, 2157393984202028884, 5163726478737145370, 7230979237225880])),
+        field_new!(Fr, BigInteger384([2039344443882022185, 16202241688840942279, 6631615341218025579, 6036686808823621472, 12166648967146814296, 12306019025882369])),
+        field_new!(Fr, BigInteger384([18058828741875133454, 12555751962426910773, 14993100025747187534, 15626386457036289660, 13007898543586053242, 12667161689176972])),
+        field_new!(Fr, BigInteger384([4905647943406424073, 13690864343997898980, 12293297223455749123, 3772631061730765965, 15094892449369812589, 10879734700860765])),
+        field_new!(Fr, BigInteger384([2545825807265193955, 3311546520357225504, 3546764977408118663, 636458415664931581, 16041000764632262846, 8334648439047942])),
+        field_new!(Fr, BigInteger384([9531890320988875012, 14133326313565887684, 16195130758107662068, 8653053420973892004, 256969303279695665, 13242237269694452])),
+        field_new!(Fr, BigInteger384([12795961440758093013, 12587797992437228111, 7998155159081348608, 3743382096924464748, 11690727626737771598, 6349034258145619])),
+        field_new!(Fr, BigInteger384([2966408746666155722, 17622787829558112600, 15154387587440420823, 17669107894403994396, 1110849205258551813, 9286084423692612])),
+        field_new!(Fr, BigInteger384([3406406273992590687, 8721926722140513596, 13327299563034159132, 6043396638811166366, 12102601597496706373, 12299446318197044])),
+        field_new!(Fr, BigInteger384([5900144720172436303, 5130320670793053261, 7547076051485592007, 11132602973180553665, 7634170310429990322, 12329633598335481])),
+        field_new!(Fr, BigInteger384([8053242872284793317, 4725222772345035516, 3809161082340853182, 13755357163736333470, 16859170318753660249, 14396558620885661])),
+        field_new!(Fr, BigInteger384([9428677840044392643, 9338724421611746860, 10968501970079170353, 10568971405817483389, 16745203630312362541, 10863732483227136])),
+        field_new!(Fr, BigInteger384([8186216187827680200, 6530977406692064692, 1000330313949644422, 11381395252176411474, 15960619419343545227, 2143503242837685])),
+        field_new!(Fr, BigInteger384([6655389626543581336, 6657434497934653769, 893850280121416918, 317344830140197668, 784419869154396248, 13714736542834494])),
+        field_new!(Fr, BigInteger384([8954273914737818678, 8181486734715359892, 5493730689534781496, 10131784741741026118, 573622460283105336, 13388464358873886])),
+        field_new!(Fr, BigInteger384([6535103735798202500, 12661258959534781530, 2167244213403182013, 17795304863301955154, 2668415042261837564, 9159747835770122])),
+        field_new!(Fr, BigInteger384([3262921450634334350, 4891165076184516578, 10552419297171840801, 16258774251138275526, 1139221169587803960, 5740554074693372])),
+        field_new!(Fr, BigInteger384([7569848549807659491, 9291599579555069464, 2117928639324550964, 15772405819830195885, 6269992603867491620, 13384706297771759])),
+        field_new!(Fr, BigInteger384([7236029653410634835, 6850239575809040060, 15563331839942490165, 18256487224477545186, 13741914009307109956, 12422871117900036])),
+        field_new!(Fr, BigInteger384([297774989833521174, 12755535727651481764, 15688987149744200198, 17401966336869992485, 6121830201394733267, 7795473500372901])),
+        field_new!(Fr, BigInteger384([15184753188637933664, 1278426673112415538, 1847112324247922803, 13881655286420302368, 14471613840712994709, 2123976047129208])),
+        field_new!(Fr, BigInteger384([15719318413575686006, 6920805312469301867, 14522076386288985591, 13708398266719760183, 7447854727455748918, 8093150159080392])),
+        field_new!(Fr, BigInteger384([16848526607083776686, 10996937618821277313, 6476999010204782751, 6883803545368917718, 13151072215336720041, 11197148366791704])),
+        field_new!(Fr, BigInteger384([9497971335493968043, 1091885877187195503, 1420781780911400956, 13738586422699997247, 10100180331351971824, 109095319443875])),
+        field_new!(Fr, BigInteger384([12634872081643395169, 13966825787767425507, 9380470013506439210, 15443808085843280005, 13081515629061795595, 3960821715547427])),
+        field_new!(Fr, BigInteger384([16141984869321082850, 1113666905258885211, 166142896213033913, 3260157682833757153, 950510310427323082, 10531544262568007])),
+        field_new!(Fr, BigInteger384([4955845426373991010, 10786140963287604496, 12521961907138774965, 9892251844228637259, 2094331957078327378, 7655234966220957])),
+        field_new!(Fr, BigInteger384([10202327680811557494, 10557354330037808032, 6144043898472823792, 3898618862934267403, 2268589576165999339, 13649246274362336])),
+        field_new!(Fr, BigInteger384([11680934333209154877, 3852695643234164603, 18425810381044321963, 15522279290660582919, 15727685707366232416, 11892894552491232])),
+        field_new!(Fr, BigInteger384([13803131044847490795, 10507932061801349837, 4464304038101856646, 11401449522018302517, 14293912640248367813, 13411440127709963])),
+        field_new!(Fr, BigInteger384([16080139185451013487, 15954218274537945517, 17169057092641178243, 2788595000351611855, 460572149941344070, 14365912592122056])),
+        field_new!(Fr, BigInteger384([7650634392744574932, 8647698594262439946, 15547374866157218050, 10496140538011177201, 3843283113273603098, 13871331833406102])),
+        field_new!(Fr, BigInteger384([673059160918020067, 8686900860639382719, 1650114358459919731, 3339006191167879118, 9300258729772043561, 4160266371624481])),
+        field_new!(Fr, BigInteger384([10006877432474927216, 12711084271130302165, 10168976351768319465, 8434863880500012554, 6125508405056919452, 11581153547159687])),
+        field_new!(Fr, BigInteger384([10616058815205826766, 1107720496192843637, 3416821951490378602, 13879535331207439674, 2452834876948725046, 6314479063695171])),
+        field_new!(Fr, BigInteger384([16215139657888884922, 9448404685039497557, 15506223058401435652, 9137800873050163315, 17116027710302763411, 44911954543185])),
+        field_new!(Fr, BigInteger384([1807602238725118942, 13341619659290477063, 16396422354741212621, 14761020384889695071, 7296606149132011227, 1889666369810123])),
+        field_new!(Fr, BigInteger384([1188205779877991607, 14287323464962688110, 1049044722490883180, 17218158696130932595, 1059847899164838432, 7436973965282826])),
+        field_new!(Fr, BigInteger384([6891465347362979424, 15757236041654871364, 15697920342496231127, 6943908405719334797, 17463309194920030563, 6411525308589857])),
+        field_new!(Fr, BigInteger384([13050177216569731817, 7637146871883844721, 500786245680397775, 12630868544755039192, 12945655048192708027, 1608338384571967])),
+        field_new!(Fr, BigInteger384([15541010832197605135, 4934892547855473157, 14938035573863787481, 569980901736060222, 3155299772878838696, 4332075523947023])),
+        field_new!(Fr, BigInteger384([8460227516986941179, 14095304368039175811, 4479074035947993084, 734524964282112171, 17633592465877159718, 8709233936164660])),
+        field_new!(Fr, BigInteger384([18169624002024842015, 3252959484350320911, 12242971451803031127, 17730246152638632994, 13557112202825791593, 12394440938107828])),
+        field_new!(Fr, BigInteger384([12233920697011982205, 10493348596370380761, 5987239283482356615, 10927031403034159945, 5366863693504997091, 673217066457954])),
+        field_new!(Fr, BigInteger384([18251771662367643630, 3492016983593279173, 13645443743831388274, 12080954831265319038, 10270085423856925636, 9264983874281951])),
+        field_new!(Fr, BigInteger384([11552993796544125504, 15285685557927892127, 2163703415706855494, 12285435525010394451, 14228723266198740664, 13289685427824498])),
+        field_new!(Fr, BigInteger384([15211507309850025700, 4304996363740550340, 5317907960245113949, 13521502185182147462, 4010528721284028351, 189770499766314])),
+        field_new!(Fr, BigInteger384([818917506890364678, 5779906956736112344, 12762568764240249228, 7978522781068207842, 6703647565419096914, 7839149649946310])),
+        field_new!(Fr, BigInteger384([15878673340640018574, 6001597956133913169, 9317303453301170792, 9816050665128368294, 4307411803165089709, 5077614162360265])),
+        field_new!(Fr, BigInteger384([7111146627520565615, 8155776861920041907, 3990536763173495193, 16382936660560229123, 3471923518950874789, 9663769417139030])),
+        field_new!(Fr, BigInteger384([10696534613626799890, 12775582609308734236, 11558745088270569585, 4177637083713246623, 1751128607207303750, 333645717409609])),
+        field_new!(Fr, BigInteger384([9323140222406113177, 8506289544481815262, 8604693201256206751, 2769402649912097079, 11741888941958563882, 12550246375383875])),
+        field_new!(Fr, BigInteger384([15734883253123954872, 17605871970278521188, 9274230939284062533, 18434560531377747364, 15632669866398735410, 10346642595822161])),
+        field_new!(Fr, BigInteger384([4753033245105539808, 9773882853443581756, 3170576029426079056, 7913902857820400925, 17545204794583609764, 11392665102083131])),
+        field_new!(Fr, BigInteger384([8404594690040688850, 7319641248295338, 17428022535553754974, 2443145262251604260, 2506620788721832021, 4574780469401127])),
+        field_new!(Fr, BigInteger384([11108350638363093800, 18008792665892999912, 11483294262134042048, 13871537680915221464, 10356644846448126465, 3497035709276175])),
+        field_new!(Fr, BigInteger384([3624638328644310591, 8425657711046635519, 3421948547783271380, 14065661237156420908, 13487440273790685280, 1077287152709753])),
+        field_new!(Fr, BigInteger384([18190191052907084879, 18010564836626942805, 3832873176830859181, 9119263156515697375, 13267865255724878025, 2911549374954573])),
+        field_new!(Fr, BigInteger384([17354004755094493476, 5128200370215148673, 12913591532556556559, 10072444131846119707, 16891057028354241843, 3361884704893591])),
+        field_new!(Fr, BigInteger384([17852964695090388047, 13347548715128740814, 5943342045747849011, 7567033511724789256, 10299277681472007336, 1404519874167958])),
+        field_new!(Fr, BigInteger384([9474446311860955845, 15683084502662185400, 5259051836617589994, 12271619124859683753, 15018384707370283866, 607476830670436])),
+        field_new!(Fr, BigInteger384([6721746901724821603, 16416897172038650795, 14800190738717275621, 17421425638965344982, 9596351165297423302, 3714963834200769])),
+        field_new!(Fr, BigInteger384([14822616006453499526, 12956267980098004025, 7229608383186768404, 1607108531770914890, 3459104277073310707, 14930786352320187])),
+        field_new!(Fr, BigInteger384([17403997991353811923, 6415857047529855779, 8516857029542877617, 11859163942652925369, 9910973949179437036, 8307253415844801])),
+        field_new!(Fr, BigInteger384([14699929399169519807, 14306685044528859730, 5207349305065624042, 17314866124198985906, 16933195139944369985, 15029457321206592])),
+        field_new!(Fr, BigInteger384([12822830915434744975, 8849743144613968832, 7098920441948368385, 14932256540503326588, 17116532708317314021, 2485096968365463])),
+        field_new!(Fr, BigInteger384([5121476226600704220, 10555817383703892799, 12433366817755259324, 3902129311163377104, 14581857181911009391, 12748948499471278])),
+        field_new!(Fr, BigInteger384([18399353722225943474, 15547455023199259188, 16306570017720417718, 302808598065473385, 1797740624349743638, 14694807441721483])),
+        field_new!(Fr, BigInteger384([7585350649166810680, 8893502417642081322, 8563340620880701101, 3980617564658356901, 7220537901899589403, 2494871356673636])),
+        field_new!(Fr, BigInteger384([12873695287737265185, 3472085477395194352, 1480578884251655887, 13716158763541875140, 7733586732408335064, 1052953093310955])),
+        field_new!(Fr, BigInteger384([16028990461779970844, 4077652256822189669, 14861689273436558720, 6809065484680722892, 3227262377225648624, 3974839856471127])),
+        field_new!(Fr, BigInteger384([5011623454251386926, 14955527664270995024, 17878734766179088884, 8172164185172414525, 2921293581137027126, 9919742323837766])),
+        field_new!(Fr, BigInteger384([15488575092554601665, 5905477447537006713, 16326399695236092278, 2770627632573973025, 15526897010789331671, 3313008364910121])),
+        field_new!(Fr, BigInteger384([2227296605551629520, 5758217753578570152, 10267059410523929930, 3869950873951159098, 7093046110754147607, 11369340453483741])),
+        field_new!(Fr, BigInteger384([17783463498692700644, 14046507584277832555, 16132192917524358398, 10514567825609270544, 1939311797783026786, 8474096520388212])),
+        field_new!(Fr, BigInteger384([227621042257778239, 4423732977982732506, 17600151044621805497, 10125524652581705831, 10300214468379094595, 12946621550517649])),
+        field_new!(Fr, BigInteger384([18048179666152265356, 1071766811611049282, 16698442392575380789, 2383286675878159194, 7900035180493420070, 8356449278779817])),
+        field_new!(Fr, BigInteger384([16194440928424327780, 9868891630155480444, 529716584204925536, 4723431667307010909, 12366394145757013532, 9137003926155898])),
+        field_new!(Fr, BigInteger384([13432123015743765671, 8672598897946241289, 16921159452211392331, 9018938607589456960, 6989033553442012636, 313814795535398])),
+        field_new!(Fr, BigInteger384([6695270389345007321, 1400192173021953244, 18354390891012660850, 17332549702930429649, 15625616204586751961, 9460310658342083])),
+        field_new!(Fr, BigInteger384([727561460236284813, 13984478087307724138, 15355457078018752224, 10448755345951576178, 4223748284651854036, 395310127025300])),
+        field_new!(Fr, BigInteger384([15627538898168428862, 2782677745000027720, 3505229018073566297, 3565032865836793815, 8416679219149439869, 2861618436991191])),
+        field_new!(Fr, BigInteger384([15441154722895529360, 18141839028623139741, 7259629487367105673, 11296211042250483910, 7997110740673248322, 14340284489671905])),
+        field_new!(Fr, BigInteger384([5852205212405683592, 16917158205893982384, 10507632453588613759, 5949145996693432834, 13784110251661454235, 6241690600133450])),
+        field_new!(Fr, BigInteger384([5032665273792534804, 317646184656576350, 10576492393242110475, 13037018040489416091, 17080499267417381456, 391020473523401])),
+        field_new!(Fr, BigInteger384([9630702607879535860, 12280341196099310464, 2205302859643316263, 1738084405320032946, 5761372232193215430, 3536486524046043])),
+        field_new!(Fr, BigInteger384([14136209803206553771, 13745521751813247259, 5478133014691588151, 14051224246010958436, 17764008191789086910, 754663850388108])),
+        field_new!(Fr, BigInteger384([3844414241779671734, 15718112826425804869, 9512433667389331342, 7901396384947273952, 11813850131386232234, 11016101567264427])),
+        field_new!(Fr, BigInteger384([17386583181830106372, 11156011416629309906, 10165208589988835308, 1082303761115947041, 15567534309091379722, 14587547187655344])),
+        field_new!(Fr, BigInteger384([3903344250797232241, 5808172097316570821, 3578965870970360286, 326121756304754464, 9142424302050644955, 7497205047035214])),
+        field_new!(Fr, BigInteger384([7545728599772258371, 2909684722625554328, 2974525782353088234, 12849780893043833547, 8901639629196262605, 12517878120211301])),
+        field_new!(Fr, BigInteger384([11414723647619789214, 6143933334841847748, 6579646031121862954, 6652723750237801407, 9448882523168064453, 2267658972115602])),
+        field_new!(Fr, BigInteger384([11894251907830763642, 10836796260274465969, 2056141881934443848, 515566733057394134, 10614383354113796962, 4799681963541915])),
+        field_new!(Fr, BigInteger384([8864437738799044168, 8317365300408713129, 12548822628136825732, 14673457091840155065, 15045472043934513501, 8163721445940853])),
+        field_new!(Fr, BigInteger384([12373041122439006688, 3735205633538959697, 16171657365588300793, 12702491401669902668, 8142973371343862128, 12983170969497351])),
+        field_new!(Fr, BigInteger384([10533880196599520243, 4023924570715331554, 12753785429993635424, 7625055207033223575, 12420777697241689039, 6574110141807582])),
+        field_new!(Fr, BigInteger384([6649949757168377684, 15428174804121601083, 3247116814867639699, 6752326061560268441, 17551160885194943339, 5553867223002518])),
+        field_new!(Fr, BigInteger384([11037067236557055381, 1358145810485185558, 120448048951091545, 4696064814631516551, 16178107853818112827, 8257582807181361])),
+        field_new!(Fr, BigInteger384([1832259022625806894, 10171626731520794980, 15873230837771102610, 1877343840825008164, 13769606980155923782, 8398353414005109])),
+        field_new!(Fr, BigInteger384([643828504996649660, 1951434488406869137, 13974342452662232352, 5939730916379971763, 13126474482830023024, 14894179619526313])),
+        field_new!(Fr, BigInteger384([2246639498556159809, 5626649133883384638, 11371771675314578765, 10720800531133562762, 7666641285780556276, 1132000330200618])),
+        field_new!(Fr, BigInteger384([7456742374024260108, 2649173120367311952, 351869974528867443, 16217133401769513073, 11886269916876378122, 3650100649766390])),
+        field_new!(Fr, BigInteger384([1954858616612727501, 16923536931247777028, 14669659453548207971, 15932441663454177242, 17935401632598864013, 8013920804465804])),
+        field_new!(Fr, BigInteger384([8852696204766460459, 14949259853428226988, 17685711357674110956, 5552588656633795798, 10255549471693673159, 6715330436707377])),
+        field_new!(Fr, BigInteger384([14964140782648534187, 5805975671948205706, 15014163939107789468, 10491962788927079170, 6343674596729077431, 7876999352683468])),
+        field_new!(Fr, BigInteger384([6030891581870683191, 1288189618612574132, 11125767190163663033, 6458094724539129573, 229654028903692651, 5461606622103374])),
+        field_new!(Fr, BigInteger384([4694225574001330783, 3049248495384550650, 1727777890417986545, 17257037407520730456, 2621970395283943358, 9633939334202768])),
+        field_new!(Fr, BigInteger384([10081129801064330397, 17992709713121756342, 4359890999855667448, 17413461585990432184, 2870407395835183525, 4141147032504313])),
+        field_new!(Fr, BigInteger384([11298707117553119333, 4088319606518273385, 13301398992861716536, 9886909038889653675, 17107342901721794430, 2230760447136720])),
+        field_new!(Fr, BigInteger384([17658971081862561906, 12727874564578554938, 14880534014249637280, 16155754381233824963, 14093535672433039538, 8547287715521686])),
+        field_new!(Fr, BigInteger384([9106280445658580460, 3821493954405391363, 3074293980580621422, 6373590894380980263, 1741752678648869715, 4762765734351275])),
+        field_new!(Fr, BigInteger384([3998325782644572522, 13657654131347967258, 3072472136047536798, 12056967908948537294, 1844515808922971495, 8580046740322373])),
+        field_new!(Fr, BigInteger384([15906051856512583873, 9443238934449568188, 11535402139077150692, 8416759537348309923, 11831700142483092598, 5059336833191695])),
+        field_new!(Fr, BigInteger384([13507681081832084572, 4939665965526539699, 12432611457227104296, 14784739583088197452, 346143251812683071, 6126030673628795])),
+        field_new!(Fr, BigInteger384([16008030618642170053, 11794616170099437450, 11110707475408330261, 14212140264134352621, 15444592610852684834, 14835384859827319])),
+        field_new!(Fr, BigInteger384([8313973878062217872, 5135235490513241924, 16811641740141123338, 5810046357623518446, 6858520770518609187, 7488112547802522])),
+        field_new!(Fr, BigInteger384([2185197951483995971, 630569328274026100, 375441135258574319, 4893561805636826374, 15089889905595686634, 4885285698570456])),
+        field_new!(Fr, BigInteger384([10772023599337762449, 15512387077278119674, 11275783140593405319, 6596651149840205544, 14493599924866294083, 5596523474133793])),
+        field_new!(Fr, BigInteger384([17785019977430698843, 6994912607218950711, 4712066954902753134, 3450926807820903223, 3880243824677097852, 12533589765990940])),
+        field_new!(Fr, BigInteger384([17082696111044110193, 4948501992898392038, 9179526177596829369, 1432666461616643499, 4635908534136321000, 13785182930340041])),
+        field_new!(Fr, BigInteger384([14932661517261844870, 15382635242915628445, 17258730705925772824, 13942420756892864918, 6076605790729438575, 216120276583054])),
+        field_new!(Fr, BigInteger384([5959763235995321711, 2586944370775618283, 14649552431496025915, 3900842395322615765, 13218101757173029352, 10126737215645485])),
+        field_new!(Fr, BigInteger384([18311303124895943882, 4774919002516341762, 15857751519909426692, 14380386213015362243, 3823039785742233405, 4012550549027461])),
+        field_new!(Fr, BigInteger384([9402778470395033435, 10933383141164306487, 12921210188204962288, 10731043138784116325, 11457027805414607014, 989068187888088])),
+        field_new!(Fr, BigInteger384([7862823497732342647, 7480714819173431821, 241041856101793432, 14856156486417517031, 11242142517717373999, 4702873264743660])),
+        field_new!(Fr, BigInteger384([6508945304500405909, 14013631172089489932, 14159344007799232774, 16544359200452320575, 5747647242809610798, 14773776137361983])),
+        field_new!(Fr, BigInteger384([16321319263402394640, 4526975634540270611, 9104588532014662647, 17046445180324277439, 5184494022608648904, 13084308384535089])),
+        field_new!(Fr, BigInteger384([16504261416960286732, 12449657952782439777, 15650580081982827363, 2200831725524781586, 10834756035608608193, 10049552821265995])),
+        field_new!(Fr, BigInteger384([6942042143193347411, 7836056089897878172, 9305832286030801976, 13973115964442775556, 4100193447490629132, 1504130724383231])),
+        field_new!(Fr, BigInteger384([7169881054943642396, 13124886183322041358, 3693115968343101588, 17436742062428584165, 7313887112595405861, 6831898190526341])),
+        field_new!(Fr, BigInteger384([14096180168800281317, 2380824221801811539, 11621139057500568067, 17787656214792590554, 7589911003523447981, 1843710262791238])),
+        field_new!(Fr, BigInteger384([2142733734820988167, 8038052515745048916, 16780023513296309556, 13850643323884777872, 358866571652415018, 7913016684276929])),
+        field_new!(Fr, BigInteger384([8863204511322903251, 3744550747724518565, 11697989163649250444, 3943475985446076165, 12510062816498973015, 9201637892315803])),
+        field_new!(Fr, BigInteger384([8636509755054064603, 9219765157693468621, 9053162534689972182, 422013184419350983, 1700451971683039968, 1529818602274000])),
+        field_new!(Fr, BigInteger384([1377568467134883626, 15985063904034023837, 16631338931861585840, 1794843926323557025, 8550425121113023881, 4285725646766322])),
+        field_new!(Fr, BigInteger384([8363857445688201212, 15222625355228683410, 4121721219615595506, 9404388296539936683, 285226957871734845, 5133572790595712])),
+        field_new!(Fr, BigInteger384([8803997376175664721, 9582189573875382570, 10564901156799057580, 3501732164323720106, 3463120789491431997, 284854583308880])),
+        field_new!(Fr, BigInteger384([13466662616838124509, 17685224886046957898, 1484736088332269164, 17043508653524296672, 732687271109265660, 7674612407849932])),
+        field_new!(Fr, BigInteger384([7356957599148902462, 11564071037926226870, 7439986846354978432, 14668929088891747638, 11621276578369272475, 14677529527874624])),
+        field_new!(Fr, BigInteger384([14620788918148133852, 17709467618799683303, 17479270842996308638, 8600516049248709320, 1325860404321168362, 1473304573799058])),
+        field_new!(Fr, BigInteger384([18382494299860374134, 10630786572937897804, 11480421576014811203, 1842407552161998526, 9273041489582424192, 343778943366288])),
+        field_new!(Fr, BigInteger384([331081017205361372, 5096993392008349994, 15070671709035633306, 16151483395309925287, 14904317609852000219, 6359967054305935])),
+        field_new!(Fr, BigInteger384([10585551566764632443, 4464761578226027888, 3201148498365520423, 363990496447617637, 13711459748644216502, 10374520947621435])),
+        field_new!(Fr, BigInteger384([989604355143842155, 16739899635788491254, 10480209938322501929, 16221884700488701942, 11156175747512264951, 5646950960013649])),
+        field_new!(Fr, BigInteger384([9707342636204101541, 8920514279450582839, 8529024162454975892, 10555170296379261896, 130020272732438296, 2922210349702187])),
+        field_new!(Fr, BigInteger384([8833142468920058609, 68640964362741914, 4433119863596745494, 16912555245451927628, 11888674677062367728, 3977362181342704])),
+        field_new!(Fr, BigInteger384([15738871327985995877, 3552674881568877387, 7602426221809517211, 10401728008207329085, 15610972387972094149, 7845961170499006])),
+        field_new!(Fr, BigInteger384([5204613018284760982, 10112069246518389512, 14420542535547303098, 15058064710050001255, 545041194740225767, 14414854937733454])),
+        field_new!(Fr, BigInteger384([3534422913134974582, 17630459453627152979, 1158347795359305550, 16895408224860642259, 10129497709870358748, 5151701187100324])),
+        field_new!(Fr, BigInteger384([5219888823188400563, 9898727736316346503, 2424691966782816283, 1655208620945386228, 6675233792830965573, 12425940727310767])),
+        field_new!(Fr, BigInteger384([9682855928153141791, 1397757300397941645, 161429456791303645, 5045930376960660017, 16621417623220185981, 13443219906320467])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([5601192030443391847, 7833049682241675856, 13475283102298564959, 15391129979552754648, 13607104318705125604, 8236276796196200])),
+        field_new!(Fr, BigInteger384([16028048683987266129, 597186252289865535, 6756543644574287293, 17460815821841416674, 14911779087288286528, 2057881348790149])),
+        field_new!(Fr, BigInteger384([6425887019113206648, 5667270726181628159, 11314455534871311259, 2138509637237839675, 10460751655774940711, 1232101600769910])),
+        field_new!(Fr, BigInteger384([4612322469196767667, 823555480391235095, 12553351246952200536, 15790551376528071155, 7213962650273000014, 3303128018164290])),
+        field_new!(Fr, BigInteger384([10755354228941633926, 6264647195596945331, 4024643778162035181, 16571810727012466050, 7658525752636205755, 10466001484869081])),
+        field_new!(Fr, BigInteger384([12026452525785002326, 7229919501922205388, 11980620373452499860, 11934136891306287064, 12987943889665593291, 10985515360796344])),
+        field_new!(Fr, BigInteger384([7156429245392440271, 8746905792786802689, 18202469273558084936, 15951750632918762067, 4081135945039367555, 5764306124637522])),
+        field_new!(Fr, BigInteger384([15294247043929631614, 11326485010942358258, 13642804664583261941, 8278718570524472592, 9979820389454744349, 109251915775864])),
+        field_new!(Fr, BigInteger384([5483292697851284783, 10539874632100321392, 17236569725676789735, 13771850744700782204, 3537447171996049436, 2391796198209602])),
+    ];
+}
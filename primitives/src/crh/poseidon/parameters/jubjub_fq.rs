@@ -0,0 +1,238 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha5, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::jubjub::fq::Fq;
+
+use algebra::biginteger::BigInteger256;
+use algebra::field_new;
+
+pub struct JubJubFqPoseidonParameters;
+
+impl FieldBasedHashParameters for JubJubFqPoseidonParameters {
+    type Fr = Fq;
+}
+
+impl PoseidonParameters for JubJubFqPoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 56;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha5;
+
+    const ZERO: Fq = field_new!(Fq, BigInteger256([0, 0, 0, 0]));
+    const C2: Fq = field_new!(Fq, BigInteger256([25769803770, 688531696190609414, 14746174755580473312, 5219131064341958734]));
+
+    const AFTER_ZERO_PERM: &'static [Fq] = &[
+        field_new!(Fq, BigInteger256([2615066458187548763, 4127572739488759809, 1374430455148306550, 7536713048762882673])),
+        field_new!(Fq, BigInteger256([6093260513826392362, 3851981546652737526, 11624464436131719359, 6297943049463766985])),
+        field_new!(Fq, BigInteger256([16946320502013933831, 7560041613792934712, 469949271154807731, 4401257263510128714])),
+    ];
+
+    const ROUND_CST: &'static [Fq] = &[
+        field_new!(Fq, BigInteger256([2626890596746753433, 17073891948834934268, 17467571149734860723, 347925791047808019])),
+        field_new!(Fq, BigInteger256([4254895676235942439, 9218312822298716451, 2894685382011435313, 4708438676700219137])),
+        field_new!(Fq, BigInteger256([1808757881690163307, 15514333737253871085, 11558846460882055293, 7144437826577305125])),
+        field_new!(Fq, BigInteger256([15038709631990254773, 18091052544754696108, 10133779795356668663, 2010934176143795406])),
+        field_new!(Fq, BigInteger256([6821619752346561612, 14298944958143994656, 9909017524795761951, 6186270510874837357])),
+        field_new!(Fq, BigInteger256([551276884769320499, 10760344682956680531, 4786445162543845230, 7591905872947513972])),
+        field_new!(Fq, BigInteger256([2943101889527065623, 17248479651161376081, 9931316918019107728, 6361263614482597534])),
+        field_new!(Fq, BigInteger256([9313714719556878060, 856493377801274860, 1825239297187108979, 4973636901541459458])),
+        field_new!(Fq, BigInteger256([10433976911346447844, 17597603251198368870, 4987895206863908824, 4349073410583558940])),
+        field_new!(Fq, BigInteger256([13588807651475955981, 9767530354239317629, 7271696691832643150, 8273227898667079122])),
+        field_new!(Fq, BigInteger256([3914808294220165351, 11685964424208944956, 6550798535996108928, 2916970538724501362])),
+        field_new!(Fq, BigInteger256([18426418166447964805, 8935402063048147458, 11990738018389876034, 7049809131707130676])),
+        field_new!(Fq, BigInteger256([2787747073049911089, 1347365499631482328, 18372007554069263114, 3331112388555082562])),
+        field_new!(Fq, BigInteger256([7411763769337952056, 4709956655566542122, 10134661249941742023, 7901279028707246921])),
+        field_new!(Fq, BigInteger256([558466434963786037, 2972864321450762188, 10163626703742484757, 3247172100007875233])),
+        field_new!(Fq, BigInteger256([12761559768904201586, 10639387948803674103, 594778210540179049, 3703347298824584836])),
+        field_new!(Fq, BigInteger256([2448902746769103510, 5556550990925378924, 4934412731920461049, 1444807513257039995])),
+        field_new!(Fq, BigInteger256([13562073079657663691, 2678772816919247385, 8645948299801454271, 3287576162003492361])),
+        field_new!(Fq, BigInteger256([17471858898449759762, 7118049735540640310, 17430485472412306557, 1714977404640179711])),
+        field_new!(Fq, BigInteger256([9817081878292346163, 11990783117964910199, 12091088167512698015, 1271883676597130117])),
+        field_new!(Fq, BigInteger256([7921189294311455762, 1028598038514215882, 4209298922974415937, 379081559261098808])),
+        field_new!(Fq, BigInteger256([16210513245887021477, 7342929087017222544, 11331769616874624148, 5379496638533369763])),
+        field_new!(Fq, BigInteger256([18152724501540213340, 5065462857385676113, 13175419847232329629, 7627165478014803304])),
+        field_new!(Fq, BigInteger256([17782467431379352173, 15915188700671268340, 15546824875325949660, 3560744038601797498])),
+        field_new!(Fq, BigInteger256([3672619259561524882, 383137576102232340, 17256932398691922292, 676392657537245459])),
+        field_new!(Fq, BigInteger256([409014875976343629, 2428693163336556742, 3545149444885993913, 4654110529677246470])),
+        field_new!(Fq, BigInteger256([6811306207763857861, 8809400199240094935, 3382526808896545259, 984148561089292889])),
+        field_new!(Fq, BigInteger256([17650868049683530308, 18444797119192701515, 5247191074705680576, 2378029691434953882])),
+        field_new!(Fq, BigInteger256([8935086165312554873, 6695026283810309561, 12137810362817453053, 6924375645586639083])),
+        field_new!(Fq, BigInteger256([11275910971068006218, 7329817511774664456, 15977894850884313187, 4887319941327755820])),
+        field_new!(Fq, BigInteger256([9371014658933844011, 4619642738238066093, 16583620779953440146, 3020811305301486804])),
+        field_new!(Fq, BigInteger256([8383629055721684426, 5481407194137481887, 7888028625877836042, 7616153984748769924])),
+        field_new!(Fq, BigInteger256([16194270244674638132, 1068504809671045865, 5082674844695049892, 6635500362618496588])),
+        field_new!(Fq, BigInteger256([5500089868029434408, 8628131039370665238, 4694381865702979780, 902358549547805000])),
+        field_new!(Fq, BigInteger256([4389238723706051797, 13274744974136214194, 9612153024562025315, 6030836928212278436])),
+        field_new!(Fq, BigInteger256([18042806390394830835, 5173753727311904552, 13216845152929651382, 6609239601075272747])),
+        field_new!(Fq, BigInteger256([9893144697822442138, 974073862138577965, 9301579569415445637, 7020969855248291712])),
+        field_new!(Fq, BigInteger256([2553502627775126760, 18115860729796111038, 12755662479069079258, 8036187272052507088])),
+        field_new!(Fq, BigInteger256([9013676702340123730, 4393066520329304664, 4673905246672349100, 1037026677227721630])),
+        field_new!(Fq, BigInteger256([8617025229124117278, 11816595654861202455, 12658179893686234614, 2584146126251840706])),
+        field_new!(Fq, BigInteger256([11046458643930356938, 17221539814517466102, 14138440834900001489, 6175744863818223594])),
+        field_new!(Fq, BigInteger256([11755179198461408210, 134173679314611169, 349633610959089025, 2404738289874502643])),
+        field_new!(Fq, BigInteger256([14807396180099585847, 9001922249522372656, 14628444630829080541, 5310894847921436370])),
+        field_new!(Fq, BigInteger256([4445201501303107756, 1259135932114298793, 16223300931930104747, 6616399446178683829])),
+        field_new!(Fq, BigInteger256([15820176232465165366, 10094627852274137114, 16640608856646720301, 8263873878014901321])),
+        field_new!(Fq, BigInteger256([9969814120263593262, 14716310300196417958, 5203590366253753125, 8016881846717421179])),
+        field_new!(Fq, BigInteger256([15497016229784398100, 18076613714317400243, 11068003747438110501, 2957741066077080888])),
+        field_new!(Fq, BigInteger256([24519744032275209, 5411202579843632375, 7101867325651417117, 2276637982462368293])),
+        field_new!(Fq, BigInteger256([12405975932416504971, 13881426888286278929, 3678867457539748189, 4641000186914062668])),
+        field_new!(Fq, BigInteger256([2749228334637781632, 17964135110864992576, 4182109085832131123, 7886169315500626891])),
+        field_new!(Fq, BigInteger256([17038609197549177710, 6619753324658342407, 3795669213230406012, 3668748576128385192])),
+        field_new!(Fq, BigInteger256([12446701665859361896, 8455714915464508222, 16797706964701505787, 1593776556686486045])),
+        field_new!(Fq, BigInteger256([15719666790667984710, 2197319024440510252, 6070816458644540544, 2932162250509330359])),
+        field_new!(Fq, BigInteger256([3790702181592182828, 7683244525089287625, 14083396020183144319, 3264431251905788080])),
+        field_new!(Fq, BigInteger256([3752880482104767515, 6659399824301922886, 1576316227274619676, 5272072197279668808])),
+        field_new!(Fq, BigInteger256([14762969544782946076, 16885534118430277374, 11043683980747663214, 4949928796067257419])),
+        field_new!(Fq, BigInteger256([2243247348114673163, 5628263862071704697, 819351938717802571, 1320695320487841544])),
+        field_new!(Fq, BigInteger256([8344326702118456487, 1100929154392599052, 12101526210218978642, 3949438732679323514])),
+        field_new!(Fq, BigInteger256([6180468730875393410, 18027220585726195097, 16676142900596887018, 6996290155511068518])),
+        field_new!(Fq, BigInteger256([10875416701380806442, 17226152986076486107, 3899247559893283113, 5888906471918549445])),
+        field_new!(Fq, BigInteger256([1211619999697879344, 6316342724177005202, 12772890247887207785, 8020143380694757783])),
+        field_new!(Fq, BigInteger256([11960089896370435269, 11605431556040017792, 13763394703086804000, 4388090184151054632])),
+        field_new!(Fq, BigInteger256([5601524923671775115, 9560811024248072200, 7204043623396322618, 4661284520662684538])),
+        field_new!(Fq, BigInteger256([18220913159206794518, 7193316987965511837, 10032803681165653333, 6592580751709293314])),
+        field_new!(Fq, BigInteger256([8218933886060677792, 9080875450603691650, 17719418315639808222, 1782675748423141405])),
+        field_new!(Fq, BigInteger256([16394842733654941615, 3162650896480467039, 2382849154617216874, 7448186734420963795])),
+        field_new!(Fq, BigInteger256([14999073333863935273, 8409963859772916306, 4198467294180134947, 6901679216049726640])),
+        field_new!(Fq, BigInteger256([2225835234131580246, 12569517006411171085, 4626318127885848722, 487251304376906456])),
+        field_new!(Fq, BigInteger256([5910239275893247267, 2154271734293817693, 12566069240513140263, 7133433041102803944])),
+        field_new!(Fq, BigInteger256([6186000707593600813, 1028543055377600483, 1068146863585104866, 1049280633416824842])),
+        field_new!(Fq, BigInteger256([14822772404266510676, 17088834899689556462, 7426443390471859514, 8174616390220181596])),
+        field_new!(Fq, BigInteger256([9698474692209692850, 15833483484323714033, 11712822243430611238, 415145120257728835])),
+        field_new!(Fq, BigInteger256([1370871140392104193, 1390872772181277953, 4919317202127541568, 3845492385581267014])),
+        field_new!(Fq, BigInteger256([16452620356824863356, 6163803440102705557, 18200253897961406455, 799345493778626151])),
+        field_new!(Fq, BigInteger256([9949886353151675305, 7058981243269518992, 3913530641566068148, 557281351355211112])),
+        field_new!(Fq, BigInteger256([776095314184538990, 8526156556622816306, 3454951952445517855, 597402984935395591])),
+        field_new!(Fq, BigInteger256([12249275547836956398, 5653998988360953110, 17317201430393147097, 5368843204604352637])),
+        field_new!(Fq, BigInteger256([18298045417981577705, 13151855128923217214, 4521255548834426298, 3582019296349738097])),
+        field_new!(Fq, BigInteger256([11092780083352186969, 12240304020927050702, 13740723888366780132, 351970478289232968])),
+        field_new!(Fq, BigInteger256([5265431005295979039, 11053289779046004496, 17164364212482460457, 4306127982475877866])),
+        field_new!(Fq, BigInteger256([12831138143221618504, 8102997064479024558, 12461883878466722095, 3128526565808354176])),
+        field_new!(Fq, BigInteger256([2036668348951567210, 807372342608597836, 11121501764853547261, 275625749919905337])),
+        field_new!(Fq, BigInteger256([5071328162381891939, 1426673940573041473, 14513009352524017892, 5201253751700560791])),
+        field_new!(Fq, BigInteger256([11638194482600925842, 14347059061072152380, 3852541608741068072, 1767066948983917299])),
+        field_new!(Fq, BigInteger256([5888110103521326595, 15812500598624892614, 7158587940763149760, 4565591144698180049])),
+        field_new!(Fq, BigInteger256([1634061354221393312, 14074729539759296072, 2294241132912045787, 1614769893168972406])),
+        field_new!(Fq, BigInteger256([13729502300578436975, 10860179908140717994, 230518408377078479, 1307508562975419385])),
+        field_new!(Fq, BigInteger256([10478281965874126158, 12970916727222827888, 6535128407122746507, 6618489135279401895])),
+        field_new!(Fq, BigInteger256([4283632791982726597, 17104455613242769302, 18431442036725651344, 673927163054893030])),
+        field_new!(Fq, BigInteger256([13471980411503413388, 928267626212283452, 16862798532132033835, 6923735773230217609])),
+        field_new!(Fq, BigInteger256([12699183316958471083, 11298937540065163881, 5847089466477989274, 7323739836507539688])),
+        field_new!(Fq, BigInteger256([12507748033231498069, 10156691516661283989, 3823622734035124031, 810978231417460087])),
+        field_new!(Fq, BigInteger256([4886470574420621234, 10911618313254367404, 6324928110516130422, 4190836594281761284])),
+        field_new!(Fq, BigInteger256([5699145490241189963, 13547300695342659666, 8927895393956064387, 5309305356508370508])),
+        field_new!(Fq, BigInteger256([7579046117026810410, 12370948455618768388, 504340503002742137, 108148092707239680])),
+        field_new!(Fq, BigInteger256([1580552736365086762, 426091515193145326, 9821850986601278137, 2562848468842180403])),
+        field_new!(Fq, BigInteger256([14901521899020004325, 10872391771572833729, 11215184195324689993, 883992813859282820])),
+        field_new!(Fq, BigInteger256([5330235523609668497, 1438856529777111664, 14419040919648989361, 5854776131433154919])),
+        field_new!(Fq, BigInteger256([10632085060391344254, 1174912371867941999, 658091694454039848, 7255319374436310081])),
+        field_new!(Fq, BigInteger256([4165396841074069245, 12814049392177492269, 17349010527307272344, 5474976359151294163])),
+        field_new!(Fq, BigInteger256([599375420150447058, 8232838847510067772, 18079189767267689262, 2654913992977374941])),
+        field_new!(Fq, BigInteger256([10862243193433372842, 13600795415693176875, 768476959229389212, 1610950092128054181])),
+        field_new!(Fq, BigInteger256([3595146778918864390, 18432995244788177612, 16851405147296172350, 2036257340233847597])),
+        field_new!(Fq, BigInteger256([11110293084417373173, 3624163467950020034, 1519438868313857734, 2282571839408516894])),
+        field_new!(Fq, BigInteger256([13634104855812679289, 17282734375617685801, 15096668311064346232, 6926224394248997575])),
+        field_new!(Fq, BigInteger256([8202567954054159814, 18236397840073188812, 9817298091328825683, 1470920995664748366])),
+        field_new!(Fq, BigInteger256([13110375185342093008, 4064935347107179425, 11386733483077286031, 1497093116591707176])),
+        field_new!(Fq, BigInteger256([1099682723064111943, 8382092556157582902, 9824584628217929818, 4098458438096539863])),
+        field_new!(Fq, BigInteger256([1061911027063067338, 12878875318529986438, 6456269847335783473, 5246288546758843081])),
+        field_new!(Fq, BigInteger256([8437357662476088426, 12435914094518604278, 10910277662102846897, 1583083120699007055])),
+        field_new!(Fq, BigInteger256([1503938906718975223, 9641234432169432734, 17675988418771842427, 2244346580119362387])),
+        field_new!(Fq, BigInteger256([1138425174473316689, 12351203416631953503, 9976236625147692264, 638110489873037802])),
+        field_new!(Fq, BigInteger256([17509417342334233196, 12720559331855371248, 15139746027278426620, 5201604192240684097])),
+        field_new!(Fq, BigInteger256([13048524527978291640, 5320120030866268456, 6816405361405702266, 767760631203996906])),
+        field_new!(Fq, BigInteger256([5582649658622521533, 15316883128319264795, 12028158929189435811, 5494408795930324145])),
+        field_new!(Fq, BigInteger256([4479255196462347409, 17045633054048073494, 6776528334314108711, 276326882956941431])),
+        field_new!(Fq, BigInteger256([4740214194104162679, 17791253450396186200, 17088153167602233297, 7893586891036236447])),
+        field_new!(Fq, BigInteger256([3930817769873514961, 5942751096562684157, 7102275536416072035, 3596406204967910725])),
+        field_new!(Fq, BigInteger256([13441087246942467503, 13085503395847801606, 9762442272547652814, 1619726082222635979])),
+        field_new!(Fq, BigInteger256([9734382974838587523, 5127498215049084231, 10589935572146625081, 1474690226748376468])),
+        field_new!(Fq, BigInteger256([9272584640147723549, 15130797276762894144, 6179177471533724083, 2385599305046641419])),
+        field_new!(Fq, BigInteger256([14133079313346393421, 14435245623522161866, 2341204667884056944, 3803286584965272801])),
+        field_new!(Fq, BigInteger256([17031197153860851709, 9942947715127052531, 7849403052720137296, 4362759118776196650])),
+        field_new!(Fq, BigInteger256([9596857621922872576, 6281101775213375116, 4518238048728130706, 6186357544072348930])),
+        field_new!(Fq, BigInteger256([3430664937749211188, 11715051333063773881, 16980699197904381149, 6312664631166550990])),
+        field_new!(Fq, BigInteger256([5356041037508469138, 7043957804305129063, 13005062441256966113, 6872876585724009868])),
+        field_new!(Fq, BigInteger256([8710279405811667386, 6565752403196524904, 882830906085371326, 3474029189360829998])),
+        field_new!(Fq, BigInteger256([14267913015179424067, 17440334311201475554, 1299956875293220101, 2719556524176549160])),
+        field_new!(Fq, BigInteger256([9366185028599282790, 3925272674122849350, 3992781171310029449, 8180756639984325690])),
+        field_new!(Fq, BigInteger256([10778884178322417067, 16658101444514799733, 15830718129751476539, 7381741403976510118])),
+        field_new!(Fq, BigInteger256([13801801485732409239, 12211677613805635202, 16791494556455746714, 7823119534576527695])),
+        field_new!(Fq, BigInteger256([12399449748838632656, 3565116119789418917, 16818474995246322464, 5600633204077472599])),
+        field_new!(Fq, BigInteger256([3097630388227249711, 16236738732364978601, 5664370153562686127, 1829937212455913427])),
+        field_new!(Fq, BigInteger256([16257018355340804302, 1026011200210265623, 8500784531128241844, 266849870826789366])),
+        field_new!(Fq, BigInteger256([11204766446303262457, 7794813215258250194, 890298242275690558, 1439566193250384414])),
+        field_new!(Fq, BigInteger256([15115519568950555160, 2941507692073304294, 4224067810108575531, 4357333885720808115])),
+        field_new!(Fq, BigInteger256([10163952719782933566, 10292811999364725254, 13858328835313445836, 1766479323467182914])),
+        field_new!(Fq, BigInteger256([1117868649724842066, 10002599411715829559, 136839936141819069, 1901996930043252809])),
+        field_new!(Fq, BigInteger256([14336132081173682582, 4347343451514004073, 6008466500493766442, 1848764764680674298])),
+        field_new!(Fq, BigInteger256([7180361326457709000, 11851031544143034067, 3892213749797103602, 2061228233042930224])),
+        field_new!(Fq, BigInteger256([15633821664715071020, 15882444588656068837, 6822693650847059143, 6960404832769261010])),
+        field_new!(Fq, BigInteger256([7708492897123319798, 15227504505829063024, 7858671541382696647, 1806513573181179018])),
+        field_new!(Fq, BigInteger256([13603527444856888257, 5300897889256044923, 15607420025325127848, 3671558525181755097])),
+        field_new!(Fq, BigInteger256([12109182635542152728, 15425029732235099552, 2529483343976234956, 6333243742723960253])),
+        field_new!(Fq, BigInteger256([1714750294461858582, 18433371934064493877, 15065428809406905611, 8117088883275636636])),
+        field_new!(Fq, BigInteger256([10046135294684737426, 2066096270617841531, 13822614494196833945, 7432992733075542499])),
+        field_new!(Fq, BigInteger256([16637833498307804248, 13747799504073339528, 12958755961530709554, 541935348317734908])),
+        field_new!(Fq, BigInteger256([10750805783054176617, 753137992404689923, 17595915702243923887, 6643302078915411755])),
+        field_new!(Fq, BigInteger256([11723498772910735244, 3570658932262529742, 12862919219044783781, 2578190414124942322])),
+        field_new!(Fq, BigInteger256([14524578155147816098, 17143376089920664744, 13038012581401085862, 3344419950360833452])),
+        field_new!(Fq, BigInteger256([10582672305325514626, 17206426938362513441, 4551679906251823242, 7115393222282804283])),
+        field_new!(Fq, BigInteger256([6434753631990620208, 7262225603661719938, 16171431264961028433, 4265403363358796549])),
+        field_new!(Fq, BigInteger256([8497659036819705932, 8068415679675867142, 13090684982867198863, 1977949579906945331])),
+        field_new!(Fq, BigInteger256([6874113833439400682, 15202385997718735729, 6374437935307534052, 8159861471617842870])),
+        field_new!(Fq, BigInteger256([17955946285103093510, 488917199453291111, 10812681057466478016, 4317600434709552064])),
+        field_new!(Fq, BigInteger256([3622087008152410154, 9279566675809079039, 13828111906578605265, 1307077449097099108])),
+        field_new!(Fq, BigInteger256([12290500006589186359, 10013267555920242525, 9263446161339069750, 6813542337352804992])),
+        field_new!(Fq, BigInteger256([8970646689155724023, 1668832862571488840, 16846914652976424129, 6973515777629609274])),
+        field_new!(Fq, BigInteger256([6841408329982510733, 8504730011225194867, 891002416712082759, 4709985969692274630])),
+        field_new!(Fq, BigInteger256([10546125066239112805, 6035796051852167815, 9538759307446693503, 1613694451041804471])),
+        field_new!(Fq, BigInteger256([14933131432856211552, 17305190737839095670, 9797579443798345616, 4089013500188919317])),
+        field_new!(Fq, BigInteger256([2039043676449376802, 11464090708178052882, 3919770496157078302, 4413143747889119251])),
+        field_new!(Fq, BigInteger256([6804806927367246669, 497042197731582883, 15756543131834096496, 6717587785524013660])),
+        field_new!(Fq, BigInteger256([8093828175581744640, 571482853714982146, 5553766010598439305, 5227851111892304468])),
+        field_new!(Fq, BigInteger256([16346318087664878665, 3983058491121445329, 3256648356290449715, 2344810996972346474])),
+        field_new!(Fq, BigInteger256([5180953839693557228, 4832527210466242500, 335528983828717463, 5827119378516385113])),
+        field_new!(Fq, BigInteger256([9179126987995926950, 8741185898207809031, 11387910390848842225, 7990185158364011224])),
+        field_new!(Fq, BigInteger256([17493676414315790537, 3740119590490292802, 11473124781374923501, 4563985602082718116])),
+        field_new!(Fq, BigInteger256([8172652180702152747, 13366352156472594467, 15422617251544822906, 5065799525034552906])),
+        field_new!(Fq, BigInteger256([4874536929165649744, 8407899874002045750, 3081699913106556289, 2309200701387575043])),
+        field_new!(Fq, BigInteger256([12828558773575402905, 16316014915953227055, 17911723360133200383, 3401136499886570603])),
+        field_new!(Fq, BigInteger256([4858588106381399552, 10729085717954500773, 2276690382604416456, 7440239150251386134])),
+        field_new!(Fq, BigInteger256([6738331180346719206, 12550171929263962991, 15604049056516117476, 5076936500839817480])),
+        field_new!(Fq, BigInteger256([1024062704128063837, 11811032354805203300, 1578109122986656113, 2141283459811850836])),
+        field_new!(Fq, BigInteger256([6275913474660685580, 13732395433075801096, 11034546510316970929, 7788663763151410673])),
+        field_new!(Fq, BigInteger256([9165598680468624912, 3133459250615328202, 15649612741053836933, 868646763503569143])),
+        field_new!(Fq, BigInteger256([2951352717156775406, 11352483335368688645, 17644459009707956833, 5139557138584572758])),
+        field_new!(Fq, BigInteger256([14932080601181267894, 3376056642592803490, 5364578951696731237, 2242969914954372934])),
+        field_new!(Fq, BigInteger256([14700906479817124733, 11128958607363694104, 5531763581736927957, 2784642061202494287])),
+        field_new!(Fq, BigInteger256([17804308439390075520, 1681353018346966586, 10907139950490492453, 5406771347166896441])),
+        field_new!(Fq, BigInteger256([316039503293499255, 5170978828500829058, 8304519629948633578, 7277046903420692317])),
+        field_new!(Fq, BigInteger256([21303870441735555, 4481545996338670625, 7109959859204920426, 5828187664538904613])),
+        field_new!(Fq, BigInteger256([13201944704221452087, 6452030891405703582, 10502714008660351784, 1135155564377524046])),
+        field_new!(Fq, BigInteger256([17178071156855542643, 15333447947293710189, 7789566186488998464, 82051990589737206])),
+        field_new!(Fq, BigInteger256([14207224418298656567, 4201369744881469159, 1421132397318372544, 3077734358578735003])),
+        field_new!(Fq, BigInteger256([13754122233404228863, 4911702614588994108, 11428117160595630314, 7613187471150200798])),
+        field_new!(Fq, BigInteger256([13789734240711739202, 7609163828862957264, 11029059323926686070, 5653453072478134606])),
+        field_new!(Fq, BigInteger256([9004309676322149197, 10829256464689749242, 14566120607855469729, 787822593038962486])),
+        field_new!(Fq, BigInteger256([16910910183654673060, 9316873121363129722, 11537421916315110942, 6325063407690427840])),
+        field_new!(Fq, BigInteger256([11196464618174039741, 11602668629529029271, 9412335642917049881, 3681540935783117280])),
+        field_new!(Fq, BigInteger256([2884967727260665055, 5218425052719568313, 5144029208773339336, 3869752779613908715])),
+        field_new!(Fq, BigInteger256([11811188623927058504, 619155086350499103, 15199110903527192755, 5301319428927422401])),
+    ];
+
+    const MDS_CST: &'static [Fq] = &[
+        field_new!(Fq, BigInteger256([10546447847516792402, 12791416175339311677, 8057124232193492365, 1118020135140565740])),
+        field_new!(Fq, BigInteger256([16065735346475112606, 7899812357490434153, 15787621725792077345, 4067784365989671329])),
+        field_new!(Fq, BigInteger256([11605520414644681761, 6754370715175415079, 13969904690222209743, 6782907606885542693])),
+        field_new!(Fq, BigInteger256([760276583385105970, 10135515447172804620, 13176896759574120998, 3982824619354175863])),
+        field_new!(Fq, BigInteger256([10385838116578654807, 2435309924098017948, 14580417271092386782, 3644716628558298586])),
+        field_new!(Fq, BigInteger256([16549620783459724064, 15536126713023011808, 15869679785741114242, 8180770586911697356])),
+        field_new!(Fq, BigInteger256([11502294021244729833, 16064474702649013345, 7203678105484206438, 5174678720996618647])),
+        field_new!(Fq, BigInteger256([5958382653676380408, 8822155729644833363, 8424987050124456160, 1416632820884068252])),
+        field_new!(Fq, BigInteger256([8950425148911354138, 9315594995099036158, 16379288186780482438, 6005379955428723646])),
+    ];
+}
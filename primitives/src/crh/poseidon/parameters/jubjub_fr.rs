@@ -0,0 +1,238 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha5, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::jubjub::fr::Fr;
+
+use algebra::biginteger::BigInteger256;
+use algebra::field_new;
+
+pub struct JubJubFrPoseidonParameters;
+
+impl FieldBasedHashParameters for JubJubFrPoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for JubJubFrPoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 56;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha5;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger256([0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger256([11624094912345729748, 3664067499946452333, 12900565306260587486, 1042372634609734058]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([7351408656501175363, 1759036095888211719, 8517879029322748685, 146403177734445948])),
+        field_new!(Fr, BigInteger256([1754714583134473233, 3431027335564291394, 1942861921469421413, 495554872245342867])),
+        field_new!(Fr, BigInteger256([16726178645129275376, 5556534383829447908, 8617110789464105727, 121350005995896462])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([17703801370637286982, 15662393264589970844, 18062388651912614153, 982398687447445303])),
+        field_new!(Fr, BigInteger256([818429518119753318, 6505449560017241405, 17905074421269278727, 673367366112157534])),
+        field_new!(Fr, BigInteger256([10503956554639332083, 5446939720429399397, 12750648925895257841, 638852395966367492])),
+        field_new!(Fr, BigInteger256([7977783378601388204, 7872335012739609640, 13790001378250158241, 380645919025749936])),
+        field_new!(Fr, BigInteger256([3960843931889806449, 5703579438762844534, 10076390160085025021, 222169076995205786])),
+        field_new!(Fr, BigInteger256([5469157690057535918, 14911022630655578633, 7390614804034676397, 119048603132420964])),
+        field_new!(Fr, BigInteger256([13778940512525105751, 10983171618553733600, 7933097867090693815, 655072921425088703])),
+        field_new!(Fr, BigInteger256([14608819764026644207, 14973855491968194624, 6413412901656770501, 463042431754666373])),
+        field_new!(Fr, BigInteger256([17917958736665932119, 10758379629526344396, 256958199133071131, 39105604628529447])),
+        field_new!(Fr, BigInteger256([10943667792099500875, 5431244353247941768, 12164315692827323360, 773698706147006647])),
+        field_new!(Fr, BigInteger256([245954535368819629, 811010743897322233, 14598195070227807410, 453283234818450399])),
+        field_new!(Fr, BigInteger256([6644999139289707033, 14133103502265433719, 14360854042138771228, 506857925972326458])),
+        field_new!(Fr, BigInteger256([4259200772926966769, 322545413818988378, 1249658754562493993, 336385465680924416])),
+        field_new!(Fr, BigInteger256([1288799525985212202, 5838637702184274787, 17637430897002569266, 125410339971304834])),
+        field_new!(Fr, BigInteger256([2743604813220185290, 9582903930983046350, 7842644371039717709, 505725417765627724])),
+        field_new!(Fr, BigInteger256([5591472221209743466, 11800533218670747169, 8353791402660128249, 236819392799380745])),
+        field_new!(Fr, BigInteger256([5462761894420622514, 17873028736931136470, 547158916714300588, 418495696367210132])),
+        field_new!(Fr, BigInteger256([16645514614157762050, 10485560233475429507, 10532948430294096372, 336194616730741477])),
+        field_new!(Fr, BigInteger256([5395402929748671492, 8266390720790406417, 1394339473301279901, 863514563380556761])),
+        field_new!(Fr, BigInteger256([2141952071451703164, 13730283197898600848, 10797277825178089605, 234563988481951158])),
+        field_new!(Fr, BigInteger256([3537082327542306041, 9805004178425095475, 5235885040456602935, 135439333744381848])),
+        field_new!(Fr, BigInteger256([4286149026695109819, 13091530807588772440, 9698681663710003885, 294361227141166775])),
+        field_new!(Fr, BigInteger256([14867275659691859779, 11588321251434733450, 4622421848602973159, 911218144043325065])),
+        field_new!(Fr, BigInteger256([5548603972134857637, 7403993193094787334, 10667236596451813504, 638355253083599238])),
+        field_new!(Fr, BigInteger256([9083259058917457739, 16794317357604537565, 4485972814750954141, 304768647107845776])),
+        field_new!(Fr, BigInteger256([11179599194554675937, 15480472277076824564, 9640601655893071167, 274049915321581902])),
+        field_new!(Fr, BigInteger256([12370187337444213548, 11797952761226103650, 9303260544228275332, 753921406277676024])),
+        field_new!(Fr, BigInteger256([12082673997458933763, 1825526225098092573, 2788422685950937323, 125052511159514322])),
+        field_new!(Fr, BigInteger256([9181990663874136988, 9310274209390339268, 177217630274225800, 648117557441427694])),
+        field_new!(Fr, BigInteger256([11357262232182349415, 570254330244941605, 8202917254319631641, 786872008380777192])),
+        field_new!(Fr, BigInteger256([1424757427863427464, 16948327146708504420, 590591923230554752, 998988097621536767])),
+        field_new!(Fr, BigInteger256([15976853206148913950, 15640116709448972972, 15337214042852472166, 395618208271017972])),
+        field_new!(Fr, BigInteger256([4996041577917550057, 15937808772575108231, 10326624648026011782, 414076359255717506])),
+        field_new!(Fr, BigInteger256([13735526644805665929, 8309493261606847515, 10994976236469823475, 979487914019189425])),
+        field_new!(Fr, BigInteger256([2605188069597491469, 6371852247771462964, 3580591463237785991, 205639066628248480])),
+        field_new!(Fr, BigInteger256([16987790772412230577, 8067056215974314500, 13655610048566389195, 89677813340702766])),
+        field_new!(Fr, BigInteger256([18254022665489765909, 13450342217428432200, 4595703848622744019, 340767025972786769])),
+        field_new!(Fr, BigInteger256([5228475218877449892, 15062109751185472628, 16100060263441294388, 1033823086138991162])),
+        field_new!(Fr, BigInteger256([9248707889174458322, 5856153816345734076, 3709379689541165447, 695400596595712621])),
+        field_new!(Fr, BigInteger256([1800301026712417468, 11143414876527665187, 16662189982869103173, 331585276001517181])),
+        field_new!(Fr, BigInteger256([1668367844108717793, 2943267950523958156, 11449930797301586008, 338416444059001443])),
+        field_new!(Fr, BigInteger256([13608166859032116012, 8664020357749795634, 6630927446762121135, 544392970492335604])),
+        field_new!(Fr, BigInteger256([4862282538440703754, 9957190672471567631, 10868243220408607437, 791688782234407029])),
+        field_new!(Fr, BigInteger256([8550142068604742055, 434576317517911435, 12252322183553842726, 778800312957254506])),
+        field_new!(Fr, BigInteger256([18345742077680469147, 5085875138605652642, 12851329233780850140, 416205556182359703])),
+        field_new!(Fr, BigInteger256([18188655753973861965, 12052128692401876692, 3736139189430485039, 85898311367319936])),
+        field_new!(Fr, BigInteger256([8436824755170574215, 16845804310632995102, 9502016830132800694, 848167401089132404])),
+        field_new!(Fr, BigInteger256([17300654870431514162, 17066818899026570557, 7453275170526292235, 598873295824149738])),
+        field_new!(Fr, BigInteger256([5158484716126276328, 16392835005033989648, 1074281657448904263, 651853499478976281])),
+        field_new!(Fr, BigInteger256([1471926848501264067, 12958109653295544024, 2210888324979920412, 786127635689603227])),
+        field_new!(Fr, BigInteger256([6929245103647307156, 17452785737590491833, 11724650611447322108, 273085739681155909])),
+        field_new!(Fr, BigInteger256([11312940178797019364, 15620795241258192107, 13445275054233587390, 815988224054702062])),
+        field_new!(Fr, BigInteger256([5479843707177504978, 1543986151502477076, 9986778867394553737, 104920669832216002])),
+        field_new!(Fr, BigInteger256([9450803782856938541, 14220142335050404555, 13602521259840182299, 464737458035650199])),
+        field_new!(Fr, BigInteger256([11083952542939985559, 14351340953385292327, 737631168588901810, 412880940328538519])),
+        field_new!(Fr, BigInteger256([6636866329397125235, 7362568368040812585, 7671572225423652434, 60496839536009116])),
+        field_new!(Fr, BigInteger256([18271635361273022650, 1149876629482854026, 3753792384200218422, 572405321709116593])),
+        field_new!(Fr, BigInteger256([1285077127913475457, 3567946323135630577, 10264434408951888243, 738383378293780326])),
+        field_new!(Fr, BigInteger256([17098718056014993015, 389498730575357063, 15693238342271978760, 922480863821108391])),
+        field_new!(Fr, BigInteger256([7066551747004211375, 2230053633207965492, 1235089834452188450, 1007497204105176211])),
+        field_new!(Fr, BigInteger256([1899857848480458126, 7364052475675321251, 4705569720509557147, 810844972452278725])),
+        field_new!(Fr, BigInteger256([14547130943445570109, 5290084638513419132, 9858551079641779038, 298043163723188506])),
+        field_new!(Fr, BigInteger256([9925770175000922082, 18277517970691548554, 16044819441599672188, 425672905747570542])),
+        field_new!(Fr, BigInteger256([4252564752676755898, 12896968486041583105, 15549573346023639075, 84626207579209758])),
+        field_new!(Fr, BigInteger256([2036225347816758041, 17531178078892938533, 5314115028571770711, 29325170301176099])),
+        field_new!(Fr, BigInteger256([13774119632631230841, 5952151898878518879, 8751590236506064233, 698164342628153983])),
+        field_new!(Fr, BigInteger256([8857989321897409508, 12325019969838750157, 9471418357623653510, 803548141751590306])),
+        field_new!(Fr, BigInteger256([13544286284965058239, 12943233745065982243, 7918526017883415828, 480995107538501071])),
+        field_new!(Fr, BigInteger256([8142929830825950752, 6991503321516420211, 12496140892145897060, 573387807606422843])),
+        field_new!(Fr, BigInteger256([10026112629996341502, 11962385043859469887, 9753544274204265386, 519090299373967180])),
+        field_new!(Fr, BigInteger256([13747153925815884835, 2397160707404005183, 4090327212731290906, 819212768628100540])),
+        field_new!(Fr, BigInteger256([1878463915029396817, 12177319868465782822, 3164278578840556986, 979262407175656949])),
+        field_new!(Fr, BigInteger256([3650184941426584074, 13749274486133299197, 2597026540077180301, 117569558405189382])),
+        field_new!(Fr, BigInteger256([5858368795945977396, 6462506613094755047, 4576786079970706351, 466792895519430515])),
+        field_new!(Fr, BigInteger256([14911640105244621761, 1780828730290655091, 5928138146769799104, 515221826969485153])),
+        field_new!(Fr, BigInteger256([10210745624184123831, 8746843605009972108, 17770672581596947222, 658950656177023359])),
+        field_new!(Fr, BigInteger256([12882204556585401313, 9463694450168619603, 10598112545729792184, 340895046637053809])),
+        field_new!(Fr, BigInteger256([686620155072250734, 11291299494881332137, 9917251773635152325, 107869606540128101])),
+        field_new!(Fr, BigInteger256([1038742326353852443, 229980741310559600, 9025891138381874764, 391157668770290732])),
+        field_new!(Fr, BigInteger256([15596614890027826176, 328811974665166644, 11094706586064988232, 785766356563197788])),
+        field_new!(Fr, BigInteger256([16015178840206984408, 16057820471101852031, 15822517139746918354, 657794948581037724])),
+        field_new!(Fr, BigInteger256([6800063828402932119, 12897047794678634317, 1879240556302398311, 68104463524267087])),
+        field_new!(Fr, BigInteger256([13832684921013949120, 16274428989968990686, 18198190631239482107, 887168169669392140])),
+        field_new!(Fr, BigInteger256([3598348240904302263, 7781833915552216221, 6791001421007423204, 491087138128737537])),
+        field_new!(Fr, BigInteger256([1248317021075275461, 17452135017536164482, 8762739432719870362, 383622833834159534])),
+        field_new!(Fr, BigInteger256([4168380308068530761, 15746286745807577916, 2940711243166017369, 666205111190501910])),
+        field_new!(Fr, BigInteger256([5577701733069493693, 3282696056914818450, 4780082763579099862, 631783212334706146])),
+        field_new!(Fr, BigInteger256([2591402949641875186, 8741785047149974397, 5936166183445211482, 1044066764508540321])),
+        field_new!(Fr, BigInteger256([3643139753146480806, 6449100618068672625, 11980298004158451091, 789592079703100837])),
+        field_new!(Fr, BigInteger256([15497914737419022973, 12294225335447615136, 5341681989504204881, 445690169031108867])),
+        field_new!(Fr, BigInteger256([17950268983294469658, 14192130452038699893, 36062914951358846, 758609647333250719])),
+        field_new!(Fr, BigInteger256([14606063591745687784, 1478364618988750468, 15804685878350081399, 1000562956575736523])),
+        field_new!(Fr, BigInteger256([9861791498980823783, 6648923911899549703, 9799760610309860361, 149508644798486084])),
+        field_new!(Fr, BigInteger256([7694549938840614816, 7123918920431210196, 1426615875373780903, 323082726270289781])),
+        field_new!(Fr, BigInteger256([169739196915864692, 17710903062132632010, 3936492181136471479, 257060126821283108])),
+        field_new!(Fr, BigInteger256([842235329138768352, 7238119851122953239, 5528350416465738758, 563466074219384995])),
+        field_new!(Fr, BigInteger256([17598089550693473262, 4212415727723747667, 14052446044921245840, 1016526367205718869])),
+        field_new!(Fr, BigInteger256([5416954123791159600, 3307849944912470540, 2082011188599091610, 755905161692004949])),
+        field_new!(Fr, BigInteger256([3413403325757428437, 3762222918038052735, 10886298500801099778, 609572614883733716])),
+        field_new!(Fr, BigInteger256([12650294754498353236, 6675615804594459621, 5694845378417580647, 35316419727511692])),
+        field_new!(Fr, BigInteger256([63943771039213675, 2256878203159164781, 9144006237539397104, 597164352971196537])),
+        field_new!(Fr, BigInteger256([5009738401777416535, 8044969731020284725, 15617809108315236506, 579512189518243243])),
+        field_new!(Fr, BigInteger256([7206030538165225675, 15737954555609172248, 12805396966809615321, 62650172527568338])),
+        field_new!(Fr, BigInteger256([12375577962506116327, 3614542482003146304, 2419264812636287161, 31552572559435589])),
+        field_new!(Fr, BigInteger256([6351322375480449059, 200659735924690128, 5965104470737199616, 217179189287527061])),
+        field_new!(Fr, BigInteger256([16492951053457456239, 17615858716544793837, 3471832437136482757, 922943026679925037])),
+        field_new!(Fr, BigInteger256([4109770891369562651, 3734281851328229466, 4260603781897014265, 704280877215705934])),
+        field_new!(Fr, BigInteger256([7151891196142918928, 5479419128220461768, 11367336172269252049, 144166831413081919])),
+        field_new!(Fr, BigInteger256([4702711076079728585, 735126540095460392, 12204370325049093250, 403712864142231743])),
+        field_new!(Fr, BigInteger256([6799508387041343491, 3439833858512377208, 10460352207216195231, 264106702793210693])),
+        field_new!(Fr, BigInteger256([656519925870057796, 9519135001842132090, 1365293691641190998, 654857272492892432])),
+        field_new!(Fr, BigInteger256([3493534224121896296, 11900171101691412287, 16623721606221642590, 471726985667558256])),
+        field_new!(Fr, BigInteger256([11604140342621897352, 6944638443881990897, 17441307463212217421, 401281960628575200])),
+        field_new!(Fr, BigInteger256([4150788447042242354, 18436813318825770832, 10195071879379460038, 274988764118810261])),
+        field_new!(Fr, BigInteger256([16953037534400141364, 16664325243342312803, 14213661572340877676, 227977690879085750])),
+        field_new!(Fr, BigInteger256([13019612563881945092, 13003770657519903223, 13856172030584954891, 179828470805885864])),
+        field_new!(Fr, BigInteger256([6000117288071841021, 1547517953640257539, 9833917432684457266, 506276201107854230])),
+        field_new!(Fr, BigInteger256([5945792628490051348, 404130175158924516, 2846379994173341151, 769992158297369930])),
+        field_new!(Fr, BigInteger256([15164363951472621671, 8095766969405307328, 1997709051476872323, 143972526902873767])),
+        field_new!(Fr, BigInteger256([17880240771154242365, 16388854244500464800, 10472618621894341758, 901880526251030152])),
+        field_new!(Fr, BigInteger256([3222162532622939289, 9987943430333996680, 10398956035523250159, 398758192473630051])),
+        field_new!(Fr, BigInteger256([15218447702304474614, 11242829018371765400, 1474729636123865771, 626573107603231678])),
+        field_new!(Fr, BigInteger256([1036424953823386172, 2499650962613348852, 2838238664413921517, 91906159092880250])),
+        field_new!(Fr, BigInteger256([4602448728196476153, 1351524516399561425, 16654969406881594919, 270262082188736445])),
+        field_new!(Fr, BigInteger256([7813840233789656412, 5765336322648444980, 15700886833804015103, 191755165878710090])),
+        field_new!(Fr, BigInteger256([13128610655835994866, 15863272454070921233, 15804478764755416847, 963326306933193690])),
+        field_new!(Fr, BigInteger256([14174995593928030088, 16868267352803355678, 12096745757742566000, 326866669326219348])),
+        field_new!(Fr, BigInteger256([4433583824426522314, 4311731627863130882, 3325967873242036937, 53282734863583427])),
+        field_new!(Fr, BigInteger256([12453075653259601185, 5955861915032991556, 5844307328539754990, 525995763255390788])),
+        field_new!(Fr, BigInteger256([2394588145990588456, 16393845955812960427, 6276185420615645946, 628869060009836286])),
+        field_new!(Fr, BigInteger256([3464069547709409306, 10013305798386410030, 3439854665316168692, 756396964072599258])),
+        field_new!(Fr, BigInteger256([3479208818385757812, 3430942256978813623, 5788354389232516908, 395641937060954758])),
+        field_new!(Fr, BigInteger256([3772799873876880548, 2675430246214630138, 1309143198458506828, 811314235965680528])),
+        field_new!(Fr, BigInteger256([16022137514716400495, 12668170170955452628, 16568746606366781731, 886275984710419088])),
+        field_new!(Fr, BigInteger256([5640254029501185769, 6478884648922271836, 1545579581562501418, 5145360347118134])),
+        field_new!(Fr, BigInteger256([10549579555125934474, 4753722851980344700, 11513855708400472596, 1000555938955674328])),
+        field_new!(Fr, BigInteger256([13603929191830874509, 14092880209209189224, 11399964888394260258, 162978849685557208])),
+        field_new!(Fr, BigInteger256([2210599289026682013, 7266637159299885593, 6958918272255537712, 779780798978657553])),
+        field_new!(Fr, BigInteger256([12440347532206486667, 16769796367019981954, 13129668784083063951, 353011471328095226])),
+        field_new!(Fr, BigInteger256([16857115478388036954, 2093882530806544934, 4329394988249080104, 481038669617032374])),
+        field_new!(Fr, BigInteger256([7596261988438384236, 14830002447572764787, 3689643192614414836, 186371091938565897])),
+        field_new!(Fr, BigInteger256([3687516212041148862, 14159141814327952530, 10512337526614921240, 957192227378453223])),
+        field_new!(Fr, BigInteger256([4210045126185024947, 2998370825329769538, 1962043613089570586, 931359710948068589])),
+        field_new!(Fr, BigInteger256([8437555627086706303, 60741901017163852, 10396502951266854588, 828504428505476287])),
+        field_new!(Fr, BigInteger256([12554433641560469155, 12326992477690426166, 7298462878081862082, 518716998483322564])),
+        field_new!(Fr, BigInteger256([9277262337080274921, 15999475340702018438, 15632993648783288905, 811438205586364581])),
+        field_new!(Fr, BigInteger256([14889149349356454924, 922330452572450050, 15225999378131428523, 262715467094003920])),
+        field_new!(Fr, BigInteger256([9606086395265096355, 2404339337984861463, 8096435045274789702, 293522165742874228])),
+        field_new!(Fr, BigInteger256([6024184969099193920, 12173597765762387376, 6453909794061898144, 491594833043226256])),
+        field_new!(Fr, BigInteger256([2171493828044738864, 5207902925163548801, 8874223216495725215, 188923423884840315])),
+        field_new!(Fr, BigInteger256([781007010970666676, 13847735759895963755, 4816415820478993744, 578759485976292109])),
+        field_new!(Fr, BigInteger256([13482720891043170805, 7631037601719143320, 2718581409828100941, 264549336310506513])),
+        field_new!(Fr, BigInteger256([9885991102810777674, 17647482126664938713, 11053329927997328804, 455729729605451039])),
+        field_new!(Fr, BigInteger256([1625533731185581759, 7543252427061903417, 3299392335195903237, 77839979688086540])),
+        field_new!(Fr, BigInteger256([8017372036269778312, 13341050726465470581, 4507884409339310505, 20936192187277233])),
+        field_new!(Fr, BigInteger256([8929277329648649169, 11566864473640448551, 6018052257788826618, 456943916183251126])),
+        field_new!(Fr, BigInteger256([11988370447372687176, 11575772542370660262, 2114072178781939295, 877538905599568452])),
+        field_new!(Fr, BigInteger256([5845949309250608217, 8351684112043074148, 18430320742111616616, 187869883851266564])),
+        field_new!(Fr, BigInteger256([4075573101650570284, 14185956242099113448, 15639317347324467571, 730977230575303969])),
+        field_new!(Fr, BigInteger256([17466859805431726044, 14750144734030348343, 9969895222390850201, 935235462752182644])),
+        field_new!(Fr, BigInteger256([2174096700105732542, 11113646562016028293, 12027480870300417135, 279025161868424158])),
+        field_new!(Fr, BigInteger256([9863072028406973296, 2292210867303153247, 2790849967155357466, 44332510537385667])),
+        field_new!(Fr, BigInteger256([9583258403524117489, 7391685800569911459, 10927926902175867043, 894301285926352709])),
+        field_new!(Fr, BigInteger256([5166505881463040507, 9925550117734015648, 2582487749761158729, 755987376012906584])),
+        field_new!(Fr, BigInteger256([14100861050551191418, 1113351411461627219, 5596814922798266572, 996817237205109097])),
+        field_new!(Fr, BigInteger256([1350918048322872308, 13605561002472462459, 5357182451373950609, 43115755013900104])),
+        field_new!(Fr, BigInteger256([15201027664591528274, 2429183724777891866, 7302153186606024087, 462558782138347063])),
+        field_new!(Fr, BigInteger256([10956621612030583020, 5155062063681287182, 4794230169542007939, 92247493054280575])),
+        field_new!(Fr, BigInteger256([13766347437905469131, 17283415117517989649, 1348793782175052462, 756609706559187068])),
+        field_new!(Fr, BigInteger256([17082816628450023202, 3689331359649573750, 7230723874636131951, 227615543085633164])),
+        field_new!(Fr, BigInteger256([11658546961449667134, 15653562938246722815, 11557556717558523203, 863354753963575079])),
+        field_new!(Fr, BigInteger256([659144323456418544, 2560393561473075441, 14008627939833312183, 397077435245795789])),
+        field_new!(Fr, BigInteger256([8867013842069433009, 5163742616230740752, 13108886408612165083, 245411432984858231])),
+        field_new!(Fr, BigInteger256([15059787200605758862, 14005439958661598121, 6195655294573424464, 1001505305375220517])),
+        field_new!(Fr, BigInteger256([5218728922133588749, 1414672307730254477, 11484350734847098025, 945029217678943580])),
+        field_new!(Fr, BigInteger256([9608713085494273537, 6386094393594140168, 14402854171869391162, 456320651909400091])),
+        field_new!(Fr, BigInteger256([17309512516744838427, 13271827481911244689, 5974665256737113286, 386254903392430250])),
+        field_new!(Fr, BigInteger256([4536457331911546578, 3239865334850291555, 2839158154578035197, 539109294771568169])),
+        field_new!(Fr, BigInteger256([18394043265851389107, 394001047059145637, 8921337841095254641, 298810984520097030])),
+        field_new!(Fr, BigInteger256([15220456731211558785, 10835184116302669050, 18170034742187242588, 829920548077871029])),
+        field_new!(Fr, BigInteger256([12150952439268301300, 11154702118193604574, 11095243515972647933, 338563682421556905])),
+        field_new!(Fr, BigInteger256([1214638676607072131, 5416450964041319533, 13500870128826851731, 714929904116930251])),
+        field_new!(Fr, BigInteger256([6898861131474104690, 207687712280022940, 11910267875629586222, 653045237423842039])),
+        field_new!(Fr, BigInteger256([8162141379740056906, 14865907946259534977, 7422332992389206598, 366798949699361483])),
+        field_new!(Fr, BigInteger256([17008555514305107742, 1344483480880708823, 15770071608970986345, 364543186045679])),
+        field_new!(Fr, BigInteger256([8058495983646030343, 7522601182801779075, 7835392715364022065, 689471054082566780])),
+        field_new!(Fr, BigInteger256([14284639096552126587, 554751115516181484, 17351889738363250525, 841779714319000472])),
+        field_new!(Fr, BigInteger256([17872875519485775005, 3374653662720719176, 10473151856322178745, 877798278966362042])),
+        field_new!(Fr, BigInteger256([8541143341008566328, 15362902935767207373, 10579845121697100849, 480346684320449447])),
+        field_new!(Fr, BigInteger256([3346846793245282551, 11283443588722278281, 8100372788356636879, 1003349188281004059])),
+        field_new!(Fr, BigInteger256([6642618525850042218, 17076130516291511804, 14254394772813495643, 1336428634109140])),
+        field_new!(Fr, BigInteger256([17270949151132257680, 9012011299791474480, 2526252251363020620, 220466290172999881])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger256([7908323441030214289, 1424596052520049666, 4584031561245239835, 479284347157041433])),
+        field_new!(Fr, BigInteger256([9778790346555155518, 11972575964961533015, 10095778247366983929, 999235450697303621])),
+        field_new!(Fr, BigInteger256([3966980581887668187, 5146610811036514252, 8059525065246422043, 998754135959023524])),
+        field_new!(Fr, BigInteger256([6032578439479799258, 12940160920219421339, 4764483560767277169, 707691457609361984])),
+        field_new!(Fr, BigInteger256([2472381967366061026, 17713035994183969037, 13923916729774959838, 246936197781720950])),
+        field_new!(Fr, BigInteger256([10175276920019974611, 17405493962308481925, 15538570850352803884, 345379731825962001])),
+        field_new!(Fr, BigInteger256([1409005288690149296, 8830939593115946681, 15041422309060548796, 248011902779233851])),
+        field_new!(Fr, BigInteger256([11120256273170151795, 3794575015311149576, 8568393150485247814, 406509090830263851])),
+        field_new!(Fr, BigInteger256([10226981840803431008, 131868794503604671, 13298769038847622688, 852192073574692470])),
+    ];
+}
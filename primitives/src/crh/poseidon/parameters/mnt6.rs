@@ -0,0 +1,163 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha17, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::mnt6::Fr;
+
+use algebra::biginteger::BigInteger320;
+use algebra::field_new;
+
+pub struct MNT6298PoseidonParameters;
+
+impl FieldBasedHashParameters for MNT6298PoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for MNT6298PoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 31;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha17;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger320([0, 0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger320([9312451271732792595, 8931072245124799436, 9705194740070011096, 15628226467182256184, 1676899174101]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger320([17454792402282641802, 11790958276323350502, 7342106004857426995, 9918835354575679090, 1137313519226])),
+        field_new!(Fr, BigInteger320([12554861309807996698, 4425194297504238052, 15385085285551149646, 5528613812063233891, 1641346556511])),
+        field_new!(Fr, BigInteger320([12827914872757144457, 4167222341266797676, 16185193093595933512, 2738201312153654731, 2439144730419])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger320([4379615134375596144, 3402429411673915584, 12995220953759312194, 3713294003913201119, 2301568514997])),
+        field_new!(Fr, BigInteger320([3846900313117493048, 9286664035979669733, 464255542354851832, 5218910356593560028, 1581249879401])),
+        field_new!(Fr, BigInteger320([1727412536674604334, 16006694166713322320, 6579180921729791808, 5307860001590854808, 2174895876232])),
+        field_new!(Fr, BigInteger320([5507425210292694305, 4402120398841329286, 9693221737511976952, 2716920627763554738, 2339882520901])),
+        field_new!(Fr, BigInteger320([14480168183225704068, 2931327480294866265, 2916260303814241153, 763692493028102206, 1113714717802])),
+        field_new!(Fr, BigInteger320([11118183596330100125, 2320430254261888621, 5066451439644734735, 18009334343849356965, 926863024265])),
+        field_new!(Fr, BigInteger320([6632061370986904144, 7398937073438923139, 13139762070230453440, 10126856037862100808, 2121171630539])),
+        field_new!(Fr, BigInteger320([8646291955923451708, 16616910229714247218, 2227490036043767264, 12121164632960002396, 2173237353103])),
+        field_new!(Fr, BigInteger320([13499602909253304812, 4703326295682883741, 1844935158527879946, 8694916806724396636, 2605326415314])),
+        field_new!(Fr, BigInteger320([12266528917321399166, 4821466239145661206, 13104277371968992700, 13540979045734588072, 3316505170130])),
+        field_new!(Fr, BigInteger320([5553986937519390449, 15264193060373631143, 4665650293522603092, 8114460756718519582, 3512498598238])),
+        field_new!(Fr, BigInteger320([5805134267088307189, 1304019004893943564, 3430212508784216528, 7304535803767667910, 1092648372995])),
+        field_new!(Fr, BigInteger320([10023037522292130525, 6100822895270488700, 4434271284033078365, 16887800710921728370, 3722902462488])),
+        field_new!(Fr, BigInteger320([15760432332728582, 225189191328572104, 13766796460779860308, 13839542507288869863, 2381635204774])),
+        field_new!(Fr, BigInteger320([4484266592055264059, 987322744904431784, 9159932621936524945, 16775223156154070964, 819696018865])),
+        field_new!(Fr, BigInteger320([13418674245023765712, 8094624804759194401, 7084044458014811821, 2420107562969447042, 3613855296182])),
+        field_new!(Fr, BigInteger320([1714319379131807242, 1777503473489108921, 16341376557012617388, 10663554140962211968, 2724363210165])),
+        field_new!(Fr, BigInteger320([1597444733758304787, 9136133130041059880, 6074070617308398837, 10885703385385443909, 944902972039])),
+        field_new!(Fr, BigInteger320([13164930636785753140, 17397966116901828216, 7816195013363393945, 10403284161629527737, 2680193863595])),
+        field_new!(Fr, BigInteger320([16071727688811750889, 17628990541162944942, 2327167717286676386, 15061904424605803851, 3059980453953])),
+        field_new!(Fr, BigInteger320([17850551677333192107, 15786829728751322618, 15729047331218243071, 17738413979334705540, 3789546116087])),
+        field_new!(Fr, BigInteger320([14842626570510106867, 17701100957535056944, 1046415881849734409, 10469966154960066311, 2147293140823])),
+        field_new!(Fr, BigInteger320([7653299319324980276, 18067919121446081031, 16724077593207308145, 10584362381806794977, 4078250891504])),
+        field_new!(Fr, BigInteger320([8932141105095882078, 13617747603011043587, 8719005380705514323, 14620148853608438524, 3682631186023])),
+        field_new!(Fr, BigInteger320([1568617641835894260, 16869256469698706730, 2383740267157815446, 6753328944432682086, 1948836516474])),
+        field_new!(Fr, BigInteger320([9041978258020891683, 8861172179020676258, 17494671973084844433, 1717524009718585068, 4426931009])),
+        field_new!(Fr, BigInteger320([13945197185993578896, 15166131704757705545, 17475707486489238441, 3998519569344461595, 3991704858822])),
+        field_new!(Fr, BigInteger320([4893648569124535342, 8306762165965040274, 11619735311380063408, 7089393364880688585, 153610593726])),
+        field_new!(Fr, BigInteger320([16389587034742253411, 8585062750504945776, 7884005333020579142, 9640084353316711802, 1081832440547])),
+        field_new!(Fr, BigInteger320([7628298123021293429, 15565443585448438670, 13621278814475717947, 11970636196248057181, 2625779291625])),
+        field_new!(Fr, BigInteger320([5895884071039921414, 7451127411462943441, 17650679699155625002, 10690519760697840842, 1736651355909])),
+        field_new!(Fr, BigInteger320([18209404389714677913, 1901325308106851194, 16766682131767658228, 1795647771326113487, 3836954689350])),
+        field_new!(Fr, BigInteger320([6487694728707440773, 5495497779155655556, 6402621948533293664, 2176970700466541686, 3282916062714])),
+        field_new!(Fr, BigInteger320([7351888713729883984, 12011349515044259180, 15323491221842224168, 13540268278881449042, 2111347876410])),
+        field_new!(Fr, BigInteger320([1554670171065268432, 4256479871698984197, 17177528021529596849, 7947863154537556234, 2082598773630])),
+        field_new!(Fr, BigInteger320([674685811857651344, 6056330823303303995, 13919062013538984637, 9311324685397257121, 515898938954])),
+        field_new!(Fr, BigInteger320([2245399220785634636, 10059463055845272236, 12005556778893937264, 7830914221160248327, 2166519889561])),
+        field_new!(Fr, BigInteger320([8816463134008415747, 13289496157230178183, 1997480493179821004, 16731938754526426256, 1883606085947])),
+        field_new!(Fr, BigInteger320([18078955817890555497, 8720147745148459032, 11166289539032559745, 16713805275999719674, 3433338545266])),
+        field_new!(Fr, BigInteger320([11737771042885010170, 5878956009339694597, 3441061040940203566, 6346560453529013771, 3874935645759])),
+        field_new!(Fr, BigInteger320([17035404909045108634, 6004269851273189966, 12822585349532218233, 3296025924988986290, 3642192992033])),
+        field_new!(Fr, BigInteger320([5588987864237800051, 7034917417348887222, 4077382959186051537, 4451123564365304623, 419922430290])),
+        field_new!(Fr, BigInteger320([446687535540356021, 9835869341349714769, 11794070132826497204, 14478726314895467526, 598451285886])),
+        field_new!(Fr, BigInteger320([16785158257348823339, 3902841078888375877, 1913706554252533261, 14275162503953626961, 1605006151561])),
+        field_new!(Fr, BigInteger320([1138850789785590036, 8118092171824669101, 16636053809082384548, 126680119465968247, 181265801417])),
+        field_new!(Fr, BigInteger320([6554878755922577875, 9239387907400325974, 7820614588894520355, 226489617764772112, 1188491168063])),
+        field_new!(Fr, BigInteger320([16885574989592501665, 11275291615495675587, 2070432169856548193, 8897206132074382625, 569172054042])),
+        field_new!(Fr, BigInteger320([2037308371187151820, 10097208889214179953, 6304205728027968550, 4086435710779087077, 1762825285150])),
+        field_new!(Fr, BigInteger320([6728831415024783348, 3894698248610066939, 11758566743215021234, 1999028472423434868, 1918510258839])),
+        field_new!(Fr, BigInteger320([12095608105701858157, 2068775835136510835, 6069399340584630926, 712567652085490213, 1498188398071])),
+        field_new!(Fr, BigInteger320([8721189819465013968, 5505988124816191583, 9916298945165732342, 15514235273027091341, 3780099699245])),
+        field_new!(Fr, BigInteger320([4319641677008619534, 8846435159968770918, 4704502694572178486, 406778819823566340, 1800764792776])),
+        field_new!(Fr, BigInteger320([8003479855550143859, 12265103279345991740, 16226377846716660044, 893700501533660468, 2572306627669])),
+        field_new!(Fr, BigInteger320([16329365807202133250, 2132904667312051286, 875535540154222018, 3155165102484751630, 31172173748])),
+        field_new!(Fr, BigInteger320([7326931962405993203, 16216147408716821601, 16166403475740888489, 1280393575685909818, 332554011746])),
+        field_new!(Fr, BigInteger320([8193627114930569264, 13070706112689506417, 10845597153225948091, 3756420430144906791, 2793042126136])),
+        field_new!(Fr, BigInteger320([4193236171071454733, 8608660772333973186, 8224414499447939005, 15373762849663658407, 442405286844])),
+        field_new!(Fr, BigInteger320([12762456506122489683, 3117412175520720003, 16075702547179749676, 6959906316774541057, 2882634992590])),
+        field_new!(Fr, BigInteger320([6262289457047273216, 15349090001081948535, 4547661525026789879, 10365684373246424678, 3482700834602])),
+        field_new!(Fr, BigInteger320([13159870543775959780, 3790343089949967173, 8560795482891311868, 15876787491291297582, 1026089159840])),
+        field_new!(Fr, BigInteger320([16183362176151539152, 16910725766502837664, 10362023287698722765, 9996000554687119476, 2394589546104])),
+        field_new!(Fr, BigInteger320([2845093954307327475, 1782715863404625401, 6761216747529468662, 11091895393791285314, 773084835759])),
+        field_new!(Fr, BigInteger320([5298904837309631395, 15081892385348811406, 5360524904985480421, 9099821408566252386, 2621476188954])),
+        field_new!(Fr, BigInteger320([15423908978247736442, 7216103651131681183, 12814525742149571052, 10352651304414822879, 1423674219440])),
+        field_new!(Fr, BigInteger320([12600475212627318270, 16042319397043786999, 6498620354944675088, 16219720855842034775, 2399708231100])),
+        field_new!(Fr, BigInteger320([13843034861399406385, 10916205724499911778, 11644544721364195684, 2231206419721765726, 395361913631])),
+        field_new!(Fr, BigInteger320([1464798333423835183, 5724444991382601063, 14314033582448863234, 9263322432086679503, 6362710907])),
+        field_new!(Fr, BigInteger320([8116519998245443401, 8823337267247876647, 6174192475586175945, 12401666149858210926, 1320621003055])),
+        field_new!(Fr, BigInteger320([14268801823938455711, 3630001210702008427, 16957402679084478683, 5949957332588750422, 118105058716])),
+        field_new!(Fr, BigInteger320([5193301911604541282, 17933798978521615254, 3716092388937077209, 3751879589241569241, 553418678494])),
+        field_new!(Fr, BigInteger320([2949177271870439069, 1210994869511157688, 3923463051513606883, 4386081037410790076, 3396722209597])),
+        field_new!(Fr, BigInteger320([12794242680992954175, 4400836169091935113, 1715871147789270382, 14673533644100002349, 1071568997934])),
+        field_new!(Fr, BigInteger320([2815269798923951167, 15628616375700474034, 6432544646987709406, 5470344094680996850, 2012181329332])),
+        field_new!(Fr, BigInteger320([11342147533877349714, 13716729525149088017, 8267463886672970266, 17389450860170164625, 2894639148624])),
+        field_new!(Fr, BigInteger320([15520930362299271582, 10886003045128914710, 8927316190032712453, 4788560741798880370, 2215309458322])),
+        field_new!(Fr, BigInteger320([13035851064597765488, 7478812299043485070, 8366141436479757412, 10985341403754692477, 1029565519090])),
+        field_new!(Fr, BigInteger320([13817200600056781089, 12790172490390375511, 8385473231831034510, 16109220922114844238, 1250364107327])),
+        field_new!(Fr, BigInteger320([1184731401435681518, 1320538574656505246, 4082718659465492488, 3324869554670100803, 2308462029297])),
+        field_new!(Fr, BigInteger320([17619507919329828380, 8665510480074380827, 15254656981141596379, 15321945000026192540, 2903003196748])),
+        field_new!(Fr, BigInteger320([555851673551011406, 2691842546706031340, 4498506234877989659, 1710670843696688534, 287061844899])),
+        field_new!(Fr, BigInteger320([6414689036612438731, 10165582898923292277, 17522130599676588821, 4669444199547822422, 2065500663920])),
+        field_new!(Fr, BigInteger320([10560435646096807796, 15704944379246795639, 3034224961441707688, 10241091460765303728, 2067429448026])),
+        field_new!(Fr, BigInteger320([1199454517003730502, 13207134785456386731, 10647242226292946174, 12016876773188145745, 679172712982])),
+        field_new!(Fr, BigInteger320([3987322813580034804, 6269908966219285987, 13722873498541082524, 1371540755817691626, 123459307594])),
+        field_new!(Fr, BigInteger320([6185049417141051563, 7730980075689437006, 5986338508706086161, 7859553295623304398, 621454290221])),
+        field_new!(Fr, BigInteger320([11058632744914879768, 12495625324075953219, 11943808224403377215, 7190844778871691567, 2577075360609])),
+        field_new!(Fr, BigInteger320([3418103868375823068, 6790450723534516747, 407276054133659752, 3125762008600052519, 2171418446770])),
+        field_new!(Fr, BigInteger320([10189403969257473259, 16606388841802159302, 12227161059150439696, 3268199516371551205, 3064314020960])),
+        field_new!(Fr, BigInteger320([5721776984512773668, 12467149395263847032, 8967971091339243816, 7474410126576449997, 519437270072])),
+        field_new!(Fr, BigInteger320([12910735275010972876, 4598216831639876928, 5489753951639600081, 14393146518184273727, 1790754879723])),
+        field_new!(Fr, BigInteger320([16161791453033125884, 15686595755883029290, 7951492459180449331, 1842892431162888173, 1138891408237])),
+        field_new!(Fr, BigInteger320([4719815138528986974, 15137135596226618524, 10802609035921902773, 14368846548769485526, 495212417431])),
+        field_new!(Fr, BigInteger320([10943259904341036889, 17598009637994290425, 15979795977049763244, 4077928636417288258, 3494927854441])),
+        field_new!(Fr, BigInteger320([12159026147572046544, 404310973528585416, 16513272551641480294, 3205830169220219836, 701374902764])),
+        field_new!(Fr, BigInteger320([6467530337764306947, 8916473106463959131, 10476681932496620208, 1120162720665256021, 818525889185])),
+        field_new!(Fr, BigInteger320([16078907175376827486, 9889616120481307802, 2549483941105226717, 14457470244788575426, 3089192118084])),
+        field_new!(Fr, BigInteger320([12401120405082815808, 1762472635347931963, 5181180137837325261, 6889453020566928719, 2434109147051])),
+        field_new!(Fr, BigInteger320([9519946931658257997, 2661028824038744672, 6724794725594788238, 8534945800968625793, 3003994803448])),
+        field_new!(Fr, BigInteger320([7807100048460102891, 8909256960341114300, 1354119650850890439, 13103891608132621846, 1100173868969])),
+        field_new!(Fr, BigInteger320([14832554721439927207, 18185295904462826403, 6994368806442853576, 8226143968811702157, 193615668240])),
+        field_new!(Fr, BigInteger320([7399533289663855154, 10810943739487666134, 5757749252646445931, 11496715707791875108, 1189617747108])),
+        field_new!(Fr, BigInteger320([16984862694826966893, 17952360971369736656, 15308787506421685742, 9571135750616423536, 2812375565754])),
+        field_new!(Fr, BigInteger320([15200561262210507577, 2849798876413502488, 6333155764995529158, 9367180706926728613, 2409483370638])),
+        field_new!(Fr, BigInteger320([14634510173531243727, 6047293344577006726, 9822258966696552521, 16738618953231095023, 578096624469])),
+        field_new!(Fr, BigInteger320([1813926884163373316, 8636070722165893895, 16348459139497111448, 10250957367118115694, 693199861911])),
+        field_new!(Fr, BigInteger320([227645934879570601, 10762200260136071872, 1099425960839314021, 105005893830601077, 1098922305530])),
+        field_new!(Fr, BigInteger320([156509395586267002, 11562901689619551123, 16839415403918020011, 18340831300157403540, 2906182265496])),
+        field_new!(Fr, BigInteger320([3251664472797624803, 13637500569750681605, 3417446393028762418, 2887357092925587525, 3604186377645])),
+        field_new!(Fr, BigInteger320([13935462667947489619, 8357487492047215164, 11397711112587158782, 11180128030065544944, 3483539507526])),
+        field_new!(Fr, BigInteger320([11267979309238780792, 2924876136648011714, 9887343695713801285, 852909897036522047, 1866559163963])),
+        field_new!(Fr, BigInteger320([8322285382622398733, 14209897208633679222, 7157710492158775877, 508339685700118251, 2688049384211])),
+        field_new!(Fr, BigInteger320([3314144716760220039, 8769997754927504072, 15003638598064267176, 10374432157241816044, 3525895258320])),
+        field_new!(Fr, BigInteger320([3858568258513505464, 12093357342923879837, 2645006935553009637, 6933291681563347693, 1568778519043])),
+        field_new!(Fr, BigInteger320([6360457433190184997, 17903346020389618685, 8450668072797175449, 18336405975984041096, 3193752372781])),
+        field_new!(Fr, BigInteger320([15554124477326182427, 11561864838350899132, 4952216287576216862, 13794119842826985369, 3232193712246])),
+        field_new!(Fr, BigInteger320([11621581969461187024, 5665118177635503367, 2609444423831925455, 726258893783635999, 3096223710403])),
+        field_new!(Fr, BigInteger320([2789884556077784554, 3355315246478536880, 992189534537685714, 16147292649391077795, 2893039761789])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger320([14645327312495096628, 5326836679049718421, 12885803822937096670, 14340115738486397601, 5492928012])),
+        field_new!(Fr, BigInteger320([3540957833188892980, 10705032565260227143, 5232464266863641760, 7537586998973986928, 3900625410208])),
+        field_new!(Fr, BigInteger320([5838049244293885047, 5125700882204800523, 3259399697457836772, 13993638512104669097, 13704823569])),
+        field_new!(Fr, BigInteger320([17953502936545787910, 2506226578700298670, 13651940164615614297, 8232684247360005146, 2690702405324])),
+        field_new!(Fr, BigInteger320([6099175586447278278, 12543815553583982561, 10028841993164825485, 11226820552324404223, 1590378733680])),
+        field_new!(Fr, BigInteger320([16402003950006562214, 7234711921594756804, 13244136810424909767, 11884429360027427010, 1104834025950])),
+        field_new!(Fr, BigInteger320([9608641136401954794, 7695353627927225705, 13693855906098748694, 1941041665315237252, 1378944423533])),
+        field_new!(Fr, BigInteger320([18171577568366608771, 16438969817902831455, 11699753042046248253, 15312917612738779415, 430140357405])),
+        field_new!(Fr, BigInteger320([10197535183607831818, 174347798920305920, 1090348216200707405, 3581491339533709101, 790224342006])),
+    ];
+}
@@ -0,0 +1,238 @@
+// Generated by PoseidonInstance::generate(3, PoseidonSBox::Alpha5, 128)
+use crate::crh::{PoseidonParameters, PoseidonSBox, FieldBasedHashParameters};
+
+use algebra::fields::sw6::Fr;
+
+use algebra::biginteger::BigInteger384;
+use algebra::field_new;
+
+pub struct SW6PoseidonParameters;
+
+impl FieldBasedHashParameters for SW6PoseidonParameters {
+    type Fr = Fr;
+}
+
+impl PoseidonParameters for SW6PoseidonParameters {
+
+    const T: usize = 3;
+    const R_F: i32 = 4;
+    const R_P: i32 = 56;
+    const R: usize = 2;
+    const SBOX: PoseidonSBox = PoseidonSBox::Alpha5;
+
+    const ZERO: Fr = field_new!(Fr, BigInteger384([0, 0, 0, 0, 0, 0]));
+    const C2: Fr = field_new!(Fr, BigInteger384([606297099834752568, 17564564708155981587, 16030874020911497174, 8208873713101515024, 16635665072767995577, 119401626967072206]));
+
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([16829101725932988230, 1229422927581492794, 4715049772153010005, 9694357988819714361, 9748298066335383803, 6968237872278784])),
+        field_new!(Fr, BigInteger384([6472924463727659925, 15427512129153420304, 939509015526453421, 6176681582130008053, 12919344567733826088, 113151130146385045])),
+        field_new!(Fr, BigInteger384([165515542384316726, 11368128520130770179, 14049945956055789145, 4352308588376393204, 3551258457689299104, 1639086956662894])),
+    ];
+
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([14910279441105573343, 6726400975316493019, 9257106569797185818, 12811614788482048118, 15248874984972836113, 33646642381145369])),
+        field_new!(Fr, BigInteger384([9154790832345965730, 14414098183056618353, 10743762412689710800, 399743077210540892, 11243913414654046414, 75143038656368007])),
+        field_new!(Fr, BigInteger384([11154228833316328453, 15023071515488006546, 13336889140002240365, 16674729938300877875, 12465130104517486261, 36371923680900710])),
+        field_new!(Fr, BigInteger384([14542623400504890078, 16406238470964751512, 9224454720386771879, 2125428044477855114, 17298572183585677313, 42411100198418412])),
+        field_new!(Fr, BigInteger384([4731934368148050104, 16073431829159523610, 4085667833143327489, 11803957532809991729, 6761271798920319333, 26953179467779729])),
+        field_new!(Fr, BigInteger384([4351590205244647153, 8085192007312130252, 10728207210860326444, 644412421984822232, 13243668640030184713, 104133766030335716])),
+        field_new!(Fr, BigInteger384([13988021663123705156, 11926903170235362325, 15277747475232018585, 8200981765027304800, 634385610224427197, 35210986716876075])),
+        field_new!(Fr, BigInteger384([17537610893015763597, 18316548908851632831, 9526139984187121532, 16536126168089841641, 11650661182910793463, 49525949738460529])),
+        field_new!(Fr, BigInteger384([17768430389228268832, 10910741934112376823, 10361809619509182767, 18394016962934092881, 3176272297116655294, 84857320707489585])),
+        field_new!(Fr, BigInteger384([15568558618754018459, 12082505603460763126, 8400321138166884279, 6268305642913418277, 4492198562382398724, 109420570815962524])),
+        field_new!(Fr, BigInteger384([9517000229678575617, 13275384785508378511, 4130200656190408047, 14113201124392144642, 16592666779504498980, 67446601986891250])),
+        field_new!(Fr, BigInteger384([11741391530555055549, 2812907362432034500, 16160064939682675580, 13965137834828412009, 12707695062328227798, 77414889060633823])),
+        field_new!(Fr, BigInteger384([6664859501578429220, 8269476102680714832, 12919453683134743502, 4871716366231884235, 14683892041234916721, 95952838518063463])),
+        field_new!(Fr, BigInteger384([11615059940912930172, 4856159818646367141, 8162551530991332025, 9309145733449477118, 9482330331204160367, 408509796184531])),
+        field_new!(Fr, BigInteger384([1692720380039449360, 961326571488697239, 13981167405390442870, 10473855051789293426, 875683658214479331, 113845549697138881])),
+        field_new!(Fr, BigInteger384([763179274589104958, 9166916150470605764, 3417303052326853204, 814101248266583794, 12122318657220317433, 40001711243658937])),
+        field_new!(Fr, BigInteger384([13698030863030534899, 16576910378378253714, 10282665074442990342, 11104556400743623084, 672732174449600945, 54541289481711464])),
+        field_new!(Fr, BigInteger384([3419730666163756052, 7311520824979800936, 11018198928225582202, 9970222260971932229, 6133195730009030055, 72211127074006264])),
+        field_new!(Fr, BigInteger384([2683742864943683665, 14722314470120166021, 16381156238558106511, 654839027716845814, 14176500683006020880, 23807650538845279])),
+        field_new!(Fr, BigInteger384([12596471046717563850, 4365110583449378371, 14500400987936841701, 16237080416260901089, 14449570070734658074, 12515543529628445])),
+        field_new!(Fr, BigInteger384([3823445838675704402, 15867026421834277771, 18289139363168861673, 17367941581384439680, 13987243872113935429, 56046470538398676])),
+        field_new!(Fr, BigInteger384([3639801339994955667, 11886007136152231638, 10501367677668867177, 9667184281833964622, 5595717436090279539, 38766188779831016])),
+        field_new!(Fr, BigInteger384([4221001568268312648, 14725603715887315983, 13314832582272034979, 13103156604737118639, 12450623369828622125, 22999638343641250])),
+        field_new!(Fr, BigInteger384([17431413060157462444, 11244145311838702399, 9769172452302794148, 8323766323011684556, 10034414066753380498, 65085060419777584])),
+        field_new!(Fr, BigInteger384([5685376470673377694, 5707081366557756375, 10886111509354936922, 8193035396055864391, 1911290649577992329, 24727732804109822])),
+        field_new!(Fr, BigInteger384([10810951214392749010, 7387971965526253497, 10630780135299721860, 2757572758012505513, 7570431050862423630, 62950651668039065])),
+        field_new!(Fr, BigInteger384([572681189492724414, 2227820291056368661, 16734653976647841301, 8763864910830646037, 3956470003889481628, 49826493531976732])),
+        field_new!(Fr, BigInteger384([244474264337437089, 6029557421877992380, 16371059354427678608, 9996228405557139853, 10308574566298875161, 116796874971023702])),
+        field_new!(Fr, BigInteger384([2990034717303756184, 6703615035172269615, 9519081793131355613, 2460944784043467264, 14524061858592357261, 49942818932674342])),
+        field_new!(Fr, BigInteger384([6199898640104878721, 1505946368541681594, 1981972430085908445, 8597226014686100250, 3882482791717830600, 55734528726784701])),
+        field_new!(Fr, BigInteger384([10968523137486568172, 4609898194361533991, 16874893848302125691, 3043156551296601112, 14697378217366982364, 98667920308371976])),
+        field_new!(Fr, BigInteger384([3520260576632353361, 4907706173031541909, 6413394139949737913, 9142524464051889055, 13902541590221611186, 89668096000797638])),
+        field_new!(Fr, BigInteger384([12352724473377975591, 5908412481777557095, 15835002825432913397, 14874049915909330041, 2579911867527551425, 36364479719782247])),
+        field_new!(Fr, BigInteger384([5139014468625802831, 6975883148475270324, 13530822594545661597, 7552326922631203181, 11407473041054818947, 10949974350989431])),
+        field_new!(Fr, BigInteger384([11607189254143980616, 12720751261275847316, 5900901493712797216, 1349681058506550085, 14695735544543046040, 91311245056105896])),
+        field_new!(Fr, BigInteger384([11710436164528867635, 9161488959572260615, 6060716423957470009, 325365643144081252, 12956512558595625197, 52779439787583142])),
+        field_new!(Fr, BigInteger384([10955970058532834973, 17674830273912822015, 12225734141248351533, 6360224923982099385, 7285943421506792136, 118894186117381427])),
+        field_new!(Fr, BigInteger384([13789593451779040767, 8196844805820069402, 1944299112893775500, 17682226780795306447, 15307951641740284692, 112513916572911122])),
+        field_new!(Fr, BigInteger384([83506074124016697, 811938642570916212, 17622726429599371410, 16754835503757916934, 2070881583575202497, 16022387996561115])),
+        field_new!(Fr, BigInteger384([16533406755018684852, 15601702945300881044, 1031684733050713876, 8933431862821380005, 10421227664800366745, 78174011689670242])),
+        field_new!(Fr, BigInteger384([6248385909339281820, 15896711782379232656, 17021989290920124080, 6477791045806986988, 10842335594950626538, 59234612559436159])),
+        field_new!(Fr, BigInteger384([3809809503311496620, 10267242700372971708, 8384242793414663896, 10175603411642244144, 14674341737720921502, 73523759409179380])),
+        field_new!(Fr, BigInteger384([12420281996217881980, 9676365222682389564, 15095171096411458733, 14620886448300209801, 14776981743041523931, 84293309122753665])),
+        field_new!(Fr, BigInteger384([14705655013959102768, 15740269191155612550, 5755688385334566198, 14173120941971058421, 9491589974533706304, 8141704012245611])),
+        field_new!(Fr, BigInteger384([8967976870923077504, 16250574826287060064, 12220337150426312897, 25442834854416695, 1822204159448467124, 54515230033691333])),
+        field_new!(Fr, BigInteger384([9707340352347439976, 10703458930160527832, 17657920030310131576, 6319882219173735201, 13584735175687174661, 83685394610597751])),
+        field_new!(Fr, BigInteger384([17427299877671024850, 98658877239728509, 5354355942676091559, 6432456672945084293, 10737311975350571696, 56172308337970394])),
+        field_new!(Fr, BigInteger384([15692629600876529953, 11494265944437373002, 10856582548975652888, 16620470516588243220, 13871851257970896480, 47716776438401237])),
+        field_new!(Fr, BigInteger384([8645261206034463374, 14489801372486920925, 6845995695383583795, 5207735261711623514, 15963138394383774789, 100514049682188440])),
+        field_new!(Fr, BigInteger384([2717330237285216012, 3427982684259292988, 12904453645978202080, 8544641885047735324, 8184386769030954834, 46532762461399633])),
+        field_new!(Fr, BigInteger384([17836674484882601586, 11531249823478222646, 15337079758479576267, 10578386313167259630, 8547835042760050538, 36485877941760526])),
+        field_new!(Fr, BigInteger384([4137139450490308023, 5907914139938788950, 6441160890325991392, 14897426495708194873, 11907264953455391001, 18376554413415679])),
+        field_new!(Fr, BigInteger384([9516057389480809633, 16282046844635450174, 2492281345694013685, 16766185229768049803, 13158405827868675705, 29876581251047469])),
+        field_new!(Fr, BigInteger384([3444985494904237974, 51362081191980339, 9546947409790831543, 325486139411425824, 15625925137574032075, 72468643300128134])),
+        field_new!(Fr, BigInteger384([2460648603092653247, 2181195997360769038, 166123490825966426, 15917152618177000155, 11526143107892552692, 69839868652688629])),
+        field_new!(Fr, BigInteger384([11546220741996847461, 18009965994469274035, 7237445918306382584, 1917159762666665541, 7507135778790575098, 80325231243558465])),
+        field_new!(Fr, BigInteger384([7173419586846871507, 16448367792725692222, 9300026549226912315, 11893362017019624950, 17199076425729313800, 112893545908721588])),
+        field_new!(Fr, BigInteger384([4428085457699212591, 13854816785311819615, 13826315022394652490, 13892295989080800264, 9246453730814217169, 76858322797920825])),
+        field_new!(Fr, BigInteger384([14863092546746149427, 14979807025612805508, 9891319835883843875, 7509572691687959953, 9197094085046769024, 78932568516091667])),
+        field_new!(Fr, BigInteger384([12726012709711605950, 13617447685637926856, 17904914863604976187, 13766482973998163195, 10597417156070631732, 27422057530923933])),
+        field_new!(Fr, BigInteger384([7116991486126494115, 2511452911532951523, 11820427694946964043, 11836593021990031238, 4795794243723087004, 107548390065466784])),
+        field_new!(Fr, BigInteger384([6503842453487739515, 2757613109078042599, 11726564135114478920, 15573970654223507859, 7627734914112872630, 66772247263550679])),
+        field_new!(Fr, BigInteger384([4737893413725928477, 16020444781286638387, 6365955509935032584, 14610027056944318097, 16929460580990631000, 31501836350433230])),
+        field_new!(Fr, BigInteger384([7992353946471671132, 639363459852380296, 15096486472800837379, 12477154702659830510, 3336513667222435304, 101538883667052929])),
+        field_new!(Fr, BigInteger384([17750039362125439324, 15681545355678497832, 6014787187885957291, 14903571452782329330, 15914189207624029619, 35596203725877019])),
+        field_new!(Fr, BigInteger384([11882878511076510496, 9840608782321448293, 4429110102237788437, 1475933637829113899, 11284987575424689700, 37777083372027308])),
+        field_new!(Fr, BigInteger384([16335125731736478190, 10497939654094871585, 256787443802322574, 1891613796756891779, 2500566660467992406, 116315447154773613])),
+        field_new!(Fr, BigInteger384([16386334783597829367, 3349658550001977618, 15493410816566495167, 10201470675305123248, 18250675901726113256, 108083107050424729])),
+        field_new!(Fr, BigInteger384([11466964451150102304, 17536540334797142879, 15220307931085360391, 216976233309535661, 16028746054014095332, 88483207331482533])),
+        field_new!(Fr, BigInteger384([7212383346622740263, 10516349007535047595, 2723288283460502434, 13464882246766804944, 12147870608481616707, 72109554975782609])),
+        field_new!(Fr, BigInteger384([16510112809504269004, 11827566283391920925, 18134642515556343075, 6054113421139384332, 7809145190343448763, 61522438120506207])),
+        field_new!(Fr, BigInteger384([4680002999221155672, 9935342373729856678, 12772094150516266910, 17463972478756190025, 588226153000463604, 93072781696750937])),
+        field_new!(Fr, BigInteger384([17699101298805747246, 8845809905980514106, 3224253764743399182, 16814781589784823993, 10033114792945327983, 110334660458382132])),
+        field_new!(Fr, BigInteger384([2503650075033697692, 18023054947311112847, 6695395809069390906, 12932019541115966414, 16448282164901024063, 82059582154168629])),
+        field_new!(Fr, BigInteger384([2065069851828929208, 6897608067719536145, 12676585313794735613, 4166433603051396599, 16087350143235701841, 1307920252021333])),
+        field_new!(Fr, BigInteger384([4031259063098911819, 15834654091751427608, 16477764298866470399, 4422526751907980501, 11169352496474658046, 28508292835999859])),
+        field_new!(Fr, BigInteger384([5679714155698114765, 1801381989319607547, 13828707485916689848, 4157295960749036583, 13512321705264230629, 49187854371526714])),
+        field_new!(Fr, BigInteger384([691160489841765759, 17145869613183559131, 18016946400943389517, 13397618370017117661, 10402105401934049840, 2089273823304269])),
+        field_new!(Fr, BigInteger384([11376742938562804498, 16255101942509956746, 10824846540850624371, 1479522959773432283, 8923340046042999565, 84353761625282013])),
+        field_new!(Fr, BigInteger384([18309626891769327616, 1589697242377822078, 7804076248772586351, 6801159510306747274, 15780125243950615725, 23110834402364681])),
+        field_new!(Fr, BigInteger384([13441178816481852429, 3316488621215473510, 44763418241244679, 4696229148498798722, 16881452256436216547, 78404207805760615])),
+        field_new!(Fr, BigInteger384([2726427536145658109, 13938297019203443659, 11633147800773283160, 13511542485711914666, 18307361928693509976, 72820160849353863])),
+        field_new!(Fr, BigInteger384([2565982314720357970, 8047667095647569735, 17728437129293201230, 1866106671768487277, 13105271966306405925, 5248417612706108])),
+        field_new!(Fr, BigInteger384([7661707314190916508, 8145642940917367130, 2042967071678926185, 13485017250740922111, 13451473615096101919, 44070163595422839])),
+        field_new!(Fr, BigInteger384([14518536746078285393, 16603356547245628162, 3116029381054875476, 4105573350428770080, 13005787982671018507, 85501439207657727])),
+        field_new!(Fr, BigInteger384([200081216350314917, 3816709625742504315, 18252574707161317711, 1324564824912433902, 4850646034921252786, 52287420038208817])),
+        field_new!(Fr, BigInteger384([5227849625158225599, 6887730912610919607, 12959101713920923301, 13691402302284544910, 3220402086903276240, 72657454651428142])),
+        field_new!(Fr, BigInteger384([4427448846643122828, 2707742567211917788, 9534587151276417349, 12753852393184481281, 3780007613544403672, 41266486878922607])),
+        field_new!(Fr, BigInteger384([4491580215802038948, 7819610323196529919, 6322469188223482961, 9518828191785938710, 13654447105650581076, 20611332324464954])),
+        field_new!(Fr, BigInteger384([752437103870011978, 15980757811041007791, 11018316834144747317, 2035825998958322595, 5398427817375737721, 24339652148820124])),
+        field_new!(Fr, BigInteger384([14312590538254882773, 2711395079292944673, 18379706013626894307, 10953213057380634827, 14394903042686913106, 95744908404928064])),
+        field_new!(Fr, BigInteger384([1077097843839300519, 9984854963047267591, 15298705562794258757, 6578385484624193391, 14614172462016656439, 60571701130838413])),
+        field_new!(Fr, BigInteger384([14641789564204639359, 6470389106288316114, 12199308938752540988, 9251070375313829691, 7447609900154256518, 78683853764101558])),
+        field_new!(Fr, BigInteger384([16569621854652556400, 8703411759936300471, 15200713397843618639, 16693391192671354747, 6262768138488029806, 60659109576674498])),
+        field_new!(Fr, BigInteger384([2448113167336752501, 17347219220332994689, 2910854166397479913, 7226663450172923056, 3168941307530660355, 2105056160357479])),
+        field_new!(Fr, BigInteger384([10801869465830779282, 17929721857703816317, 8622126520530137253, 11177651039089211183, 15345868474123611729, 18438283958612804])),
+        field_new!(Fr, BigInteger384([3537461891676256972, 2582362501680566568, 16875111775341793565, 6717244433878720826, 16251211514976689241, 64398577886015425])),
+        field_new!(Fr, BigInteger384([3058874958874188578, 5954393629833281217, 7266993353766304665, 13863734549179987028, 11489522234059287743, 69085435422363524])),
+        field_new!(Fr, BigInteger384([8197082089653364633, 12208967310331722833, 15707931393937808514, 6605266370231700559, 1484823478079894570, 96924151290886642])),
+        field_new!(Fr, BigInteger384([7954751480718811369, 11216984707157461794, 17110325111133690714, 1082432639299542916, 10962290883493327031, 117687035167942773])),
+        field_new!(Fr, BigInteger384([17664829692961616991, 1514396135516875887, 15881744221969316515, 1540333977506065236, 17986928859633645893, 39003656401607403])),
+        field_new!(Fr, BigInteger384([10765184092491290631, 15233868965622011399, 5860874015314652249, 11596198624204404561, 8691191499670631747, 51562889680957894])),
+        field_new!(Fr, BigInteger384([12205952907870308357, 7903406582808401208, 4959595536040793896, 8107152005751789060, 6475803944257983962, 34193165478410093])),
+        field_new!(Fr, BigInteger384([13843288520157147366, 10530943258350772503, 13036416916304609840, 17106398614204472297, 6418160020115236877, 41150859803342459])),
+        field_new!(Fr, BigInteger384([16341572004857239596, 2752438356246496782, 10102586443317582561, 8451071692797764791, 4882873593770606429, 29448755856827917])),
+        field_new!(Fr, BigInteger384([7668891850241928303, 9257812943772028170, 9253859733698936874, 5414841909802243180, 8749520639023242388, 36640576153453451])),
+        field_new!(Fr, BigInteger384([12246634858882293756, 15687204365629075183, 10474962461110014028, 8405398711386999220, 15782666379018145193, 120349508873175215])),
+        field_new!(Fr, BigInteger384([17490426788201244661, 16268547184195139951, 3810609706932700857, 14031986432553794242, 8964798253762185637, 40392136714125242])),
+        field_new!(Fr, BigInteger384([5719885045976867520, 16194495598061564682, 9880667950640608688, 13633001943009528463, 13083689125822794944, 49435956617476218])),
+        field_new!(Fr, BigInteger384([880170585544514267, 16947547485145150807, 13286906229048839602, 9331247661834152017, 11445352325626152166, 5985214918098284])),
+        field_new!(Fr, BigInteger384([3282808060020218006, 1151049226846433245, 12942467031723695217, 16479315449617313525, 18100077637924285779, 9967227718790990])),
+        field_new!(Fr, BigInteger384([5348133089661564213, 743066377085493110, 4954847976497550707, 1530234635903463051, 12247300593699609164, 36989289332285821])),
+        field_new!(Fr, BigInteger384([1594464157522310534, 7957258382815124133, 15369299050211673228, 4707653722512040667, 13276868247655605313, 33033460690391876])),
+        field_new!(Fr, BigInteger384([2056943586603454873, 7786609768721592563, 751173122123266251, 188832412808206650, 1220565681650619997, 119476657890441734])),
+        field_new!(Fr, BigInteger384([128494260279903238, 1285200823939364191, 8044797211503402081, 18414506446406947144, 17849333063634432553, 52854459895385061])),
+        field_new!(Fr, BigInteger384([13633534642155234026, 2576733714420697110, 6525350938034960607, 17867421502410027246, 1205696734759191591, 115089164420253280])),
+        field_new!(Fr, BigInteger384([13856690861636966168, 12199916266232469080, 4500790678198030298, 10959623331870606916, 10984028691360908376, 27697102131759554])),
+        field_new!(Fr, BigInteger384([7302166736608988540, 7472158056055133625, 6239836848931299961, 2706864668508232685, 17978420657452350105, 116470462769224636])),
+        field_new!(Fr, BigInteger384([5789646064194827541, 3975078666014770052, 3870734088981781959, 9346511264127530146, 4868632817086048359, 35986618158267693])),
+        field_new!(Fr, BigInteger384([14429197508413565620, 10874883622036876863, 3787207273653146084, 17307335830677376272, 8614485763288819251, 39951623107970771])),
+        field_new!(Fr, BigInteger384([5434088637481660458, 11785637687959680530, 2319943491558240210, 12645353853605846825, 17957164092467323247, 35863357852092876])),
+        field_new!(Fr, BigInteger384([9904710507797824423, 5223977596882023608, 3189067023880159238, 18231980380889771718, 17772238934981843520, 16474264006875053])),
+        field_new!(Fr, BigInteger384([10866116774039450381, 9161858768998027175, 5644354550907210754, 9779243573253167070, 12358372749145178632, 45641985593422179])),
+        field_new!(Fr, BigInteger384([7881327614796175002, 11485942154347751146, 2245769574419891983, 9279876227591012955, 539413347354581858, 85657859844312267])),
+        field_new!(Fr, BigInteger384([4047162373055931411, 8899136811508622081, 10529132001272077583, 15303499974989839350, 15736881170647787359, 104544178122773437])),
+        field_new!(Fr, BigInteger384([3060849534653174923, 11602270415596997267, 17403420023699781880, 4302489535192655494, 9729647029879829164, 74622688273639026])),
+        field_new!(Fr, BigInteger384([2992692878002702246, 4423461609455754530, 224522839737194533, 18404701176392244229, 6641773704148362204, 99582555577481263])),
+        field_new!(Fr, BigInteger384([3072355936077806909, 4109599040401435936, 377384863601545459, 2050964745448477422, 16997277000350921804, 112831289815573209])),
+        field_new!(Fr, BigInteger384([17712898045869315784, 4258971413118677448, 7428863651976149367, 17856647126031150947, 17427237867903999855, 43013299288806470])),
+        field_new!(Fr, BigInteger384([7858674259040809115, 4460277079723253625, 10826802428164007587, 8759854385535789890, 13959707309205106635, 34941894019002317])),
+        field_new!(Fr, BigInteger384([1061290347756340451, 10770705227856674259, 716041553283895926, 13697257160880581237, 7287068792329967088, 65928570161975526])),
+        field_new!(Fr, BigInteger384([5565086536210406074, 5677115329449778317, 13668885990341840502, 13904558019906402883, 5036381351525624794, 3914636494136335])),
+        field_new!(Fr, BigInteger384([17423575488641304681, 12979738861103361819, 5441248719349237800, 14993652893191049427, 6260849550477162479, 110388640741974081])),
+        field_new!(Fr, BigInteger384([16471747635805108194, 14952373233199977307, 5880070468283118175, 6106739201328634593, 16169871737780183885, 71524472699954507])),
+        field_new!(Fr, BigInteger384([17784513927355333853, 3992984414861688007, 14671338508761541344, 5277315033646693709, 8106574912407306563, 13230265920485073])),
+        field_new!(Fr, BigInteger384([5212518126929852632, 13407689144040798238, 5192849979357812924, 3558483505085708354, 11235568706565990544, 110851709563775717])),
+        field_new!(Fr, BigInteger384([14971238680103659621, 16511087839354354190, 16549273178959894941, 7015659488239931612, 16598486544586065349, 114150717515706799])),
+        field_new!(Fr, BigInteger384([18376261721607493147, 3845558201612998009, 7401167187675023540, 68847418965170312, 4101859256044364833, 81478585282816152])),
+        field_new!(Fr, BigInteger384([10767444429734878013, 8260728249461912064, 2469741061020080557, 16428223940913556884, 11042785812762094834, 60681325331806900])),
+        field_new!(Fr, BigInteger384([3239814475096536844, 9922125444088705250, 16989565798385480436, 7738795385143099172, 11600890137420681652, 34756001086585839])),
+        field_new!(Fr, BigInteger384([3524825932427223708, 6528050413160393014, 13776838525047211294, 1850144313249723787, 5692595421285042714, 6392418641955501])),
+        field_new!(Fr, BigInteger384([4514762446826774776, 17829599461087221519, 1160858172890430488, 9730112737467258288, 4130058272652465663, 102894149421015228])),
+        field_new!(Fr, BigInteger384([15655227857256706646, 9871233962066999604, 17942148974102738792, 151857103586666571, 2929574400303339942, 51302755875939897])),
+        field_new!(Fr, BigInteger384([9379308228695063596, 4470784398330252540, 18137327111498687331, 5171909848998567560, 17790282331608564449, 118324053950296075])),
+        field_new!(Fr, BigInteger384([1305082759205352939, 13541382182217815680, 8901642803233098897, 569264537938907969, 16261801648401465309, 20404399998326707])),
+        field_new!(Fr, BigInteger384([10550662040755858632, 12279031652246129863, 3230391396875040802, 4154298492749196314, 14189894340807538865, 91351959393771827])),
+        field_new!(Fr, BigInteger384([12050612960164198042, 2545865644267904174, 9345025935756565296, 4915467171053139544, 3041756574290343545, 48732312287613027])),
+        field_new!(Fr, BigInteger384([6322113780084895858, 8015434463183762549, 11952621224212287402, 2729909356673577078, 17542445132772889714, 30060453033466558])),
+        field_new!(Fr, BigInteger384([12418029504689863358, 2742453901993539841, 2895103301194141438, 14197105095870533640, 5331129524845148201, 88382039558448678])),
+        field_new!(Fr, BigInteger384([1775139193120039320, 14738320746427773602, 16681902253173272580, 14990231871429524024, 6710276746314122557, 91586500001605202])),
+        field_new!(Fr, BigInteger384([8508509115366145906, 12783943028789956705, 18112163809571079805, 2023882092936895414, 15078292700557753289, 64923475624248173])),
+        field_new!(Fr, BigInteger384([12317533484964425123, 11805871512030799210, 2605116573680614442, 8403264925189773589, 3193547493626037105, 89181387679943345])),
+        field_new!(Fr, BigInteger384([641418295935955824, 10542986081290361687, 4322097213043286580, 14232555190582199488, 2078181275501330987, 109037735345145115])),
+        field_new!(Fr, BigInteger384([11714065128598129353, 4063681607428787135, 4874371636147972193, 16107864986519098525, 10132472793318743383, 15405692496599233])),
+        field_new!(Fr, BigInteger384([15209874313651506734, 7240834986297600501, 4938633925339796520, 18271178047222469615, 15952431761264160854, 109964274172193972])),
+        field_new!(Fr, BigInteger384([833933044264908487, 10826135648700443466, 5213696095152274889, 12139641133364473462, 8620244767716894586, 42935952326874285])),
+        field_new!(Fr, BigInteger384([5098284342815304297, 16643646236634834380, 15130457688783654802, 17906676246035220524, 10487837825154794844, 80859961398260367])),
+        field_new!(Fr, BigInteger384([3070179261545338401, 10529503660694896650, 10250613728953493500, 6140341471009696677, 3725537663246663496, 36123101346480953])),
+        field_new!(Fr, BigInteger384([11374498849028212670, 8416844324486311405, 1758670527025374333, 13760172730100922307, 793767169773843955, 40997666076593893])),
+        field_new!(Fr, BigInteger384([4067285536560646618, 2872256549891684394, 964264923361708469, 16804219551756482870, 10559405374132892849, 96656754455865805])),
+        field_new!(Fr, BigInteger384([10539038109077421176, 2285413874503527931, 10780824093780148218, 12078188526652476103, 8744548751169125385, 18580643737456677])),
+        field_new!(Fr, BigInteger384([12533593846907042084, 8394722626302302860, 6666061428947020096, 8434188800695020438, 15131466583309958753, 43958942625090646])),
+        field_new!(Fr, BigInteger384([13130961179864041225, 16655235772608329164, 5837889584320051113, 3192438117515341470, 17201540855986414777, 82228955810637421])),
+        field_new!(Fr, BigInteger384([16469743457941949764, 4772086333821326344, 4056728363083574422, 3522923717697946377, 661237120772628018, 77409802357430993])),
+        field_new!(Fr, BigInteger384([1954411077722002444, 11992611544918029303, 15793807534864680573, 1918690734959981937, 12404535437881520729, 22824752798842408])),
+        field_new!(Fr, BigInteger384([9185184744552544693, 3830375186519459022, 3122095919230033206, 8692237593078290623, 6598028268842212991, 54488333960762782])),
+        field_new!(Fr, BigInteger384([8775970189696397845, 294834788217058343, 11111218590039328131, 6398714966021200564, 2303531619076267822, 110402580337049136])),
+        field_new!(Fr, BigInteger384([7031640040095099961, 8508930773928722880, 18048875830759650803, 1294176530655305265, 7225343892093670199, 37180685582042881])),
+        field_new!(Fr, BigInteger384([17185907612405846886, 10057166036525965162, 12263017229795876114, 2945310951144397063, 17945276604935516950, 49815465062560025])),
+        field_new!(Fr, BigInteger384([11767704547644753581, 12616328150899853732, 10106665691174495341, 13787141941273493708, 13838251150235307472, 104638457674670632])),
+        field_new!(Fr, BigInteger384([7461400326020016605, 9126752623072236554, 5040413023215030484, 17488641352967680601, 18302693013107505415, 99597758487181195])),
+        field_new!(Fr, BigInteger384([18343675036305592283, 8949678740843892175, 7478860453228499411, 9120062740754946018, 625609801194286745, 66651045640556405])),
+        field_new!(Fr, BigInteger384([9737556958713014855, 10888613068298440018, 4808789748735022829, 11733976519152945088, 10431042815020798768, 106442173986915080])),
+        field_new!(Fr, BigInteger384([7741895453154090374, 99372643014488895, 16402348356549354338, 10191463881401167208, 4942799957217167099, 65566955887663442])),
+        field_new!(Fr, BigInteger384([13308318333550250839, 94194242338533998, 2980281037782172150, 10459025932932253772, 8924943282124387412, 8616648344020124])),
+        field_new!(Fr, BigInteger384([5722366237995918961, 18007713764185446208, 12796980260120954841, 4878159006488649587, 18162319575454895054, 29971377663668436])),
+        field_new!(Fr, BigInteger384([13293690456347179516, 14763354005312200300, 12437779649994398185, 13897397876977122429, 2475321218579111415, 61307880179452236])),
+        field_new!(Fr, BigInteger384([14545840817477296422, 13778300346075605361, 9008712898635644918, 253268028926381216, 18379931018056686212, 40725314866832678])),
+        field_new!(Fr, BigInteger384([6686141081518734773, 17473445389821774425, 8226788667159519441, 8213323447049253476, 18383511746149454477, 55585228209855516])),
+        field_new!(Fr, BigInteger384([8550295448468936036, 5606406282481237941, 1746725019877596383, 6996275923008321347, 10181227252502749948, 58609925596404891])),
+        field_new!(Fr, BigInteger384([13198425924564639657, 2221165806491242798, 4897160561650971139, 11330803763010066160, 12625992139781737801, 54342865399867495])),
+        field_new!(Fr, BigInteger384([16857308541999689688, 3963201824994133158, 12706901344574915033, 10270264222319073781, 17703080953034139361, 105392111881855349])),
+        field_new!(Fr, BigInteger384([588086104403617398, 6748912655505969783, 8511385346714220643, 12373069778586116912, 15860457766389020323, 55269897690380724])),
+        field_new!(Fr, BigInteger384([4567490448456755274, 15064294530852874574, 16454465919612654327, 12117172267430440108, 6026947909024658619, 22532928361366501])),
+        field_new!(Fr, BigInteger384([4010590158023486170, 6214053165927445631, 5580304550442200932, 1737323077688846023, 14460081152539581355, 56948560137065591])),
+        field_new!(Fr, BigInteger384([6785650568809577245, 1585759805750174866, 1045792242374240097, 11280750863693910217, 12184966041852840160, 55538319588418421])),
+        field_new!(Fr, BigInteger384([16752686120092409079, 8373462331085081143, 10206771643236029225, 2466693859863544023, 8376589268596353724, 90934174911638088])),
+        field_new!(Fr, BigInteger384([549722764350856213, 9916872151586831427, 13203881479579364453, 14807299403242458385, 11524738320072020201, 24419641043808253])),
+        field_new!(Fr, BigInteger384([12184379297784722808, 8928123304576560582, 1948854826543518526, 9503999812557784080, 742867222566366768, 56027417057274772])),
+        field_new!(Fr, BigInteger384([7841825331605191692, 16447435884218242622, 4625193974869241006, 93305262501132091, 17349869068593759697, 94701482580512400])),
+        field_new!(Fr, BigInteger384([14983008437399763431, 11420589960532901925, 13250531924959066127, 17149054977901915875, 17761051137980946319, 9032620648260289])),
+        field_new!(Fr, BigInteger384([4855328678297280695, 5421692258648145760, 1448944110003960901, 380250576878962961, 15990537613481445746, 101255063847510132])),
+    ];
+
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(Fr, BigInteger384([1968574817976670732, 5677887823625282314, 2694111327141694661, 7062990812930670928, 5301793997135762004, 46185220922513653])),
+        field_new!(Fr, BigInteger384([5756243949361718228, 4822500145329434975, 5747620519073565181, 544733023370189004, 18232141494906342664, 61828921376382035])),
+        field_new!(Fr, BigInteger384([9536541462075307280, 5150582439281875872, 9160691441690540499, 4736918718618710161, 8151361931673226942, 106662282493978979])),
+        field_new!(Fr, BigInteger384([10688030008047703971, 15484183491780892124, 4614880577028175330, 1243485416994669517, 14673379580796201884, 68158348842410373])),
+        field_new!(Fr, BigInteger384([2238936765671535685, 9810330565222900767, 4717496023498694685, 1579041031372209268, 1801241452952335619, 99539021125195279])),
+        field_new!(Fr, BigInteger384([11973551507105915468, 11471959755532168891, 18212185774670371270, 18221078239648445500, 2402344291573525704, 13663846699291834])),
+        field_new!(Fr, BigInteger384([9222955900919352897, 1135270942597868055, 15285560941788756103, 9451874939159147157, 4171585663410694954, 92586445205225670])),
+        field_new!(Fr, BigInteger384([2081439738846135515, 16485777015631004118, 2660532529488324463, 17051624527387884396, 13375727251981926558, 45038889564106632])),
+        field_new!(Fr, BigInteger384([7780409451796320959, 529916602978894580, 15855403206872165525, 4768658635070756408, 2688615055614059593, 87056312845233140])),
+    ];
+}
@@ -0,0 +1,171 @@
+use algebra::PrimeField;
+
+use std::marker::PhantomData;
+
+use crate::crh::{
+    FieldBasedSponge,
+    poseidon::{PoseidonHash, PoseidonParameters, MNT4753PoseidonParameters, MNT6753PoseidonParameters},
+};
+use algebra::fields::{mnt4753::Fr as MNT4753Fr, mnt6753::Fr as MNT6753Fr};
+
+/// The phase a duplex sponge is in, with the position in the rate part of
+/// the state of the next element to absorb or to squeeze.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeMode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+/// A duplex sponge built on the Poseidon permutation, with rate `P::R` and the
+/// domain separation tag as initial value of the first capacity element.
+///
+/// The elements absorbed between two squeezings are padded by appending a one,
+/// and as many zeros as needed to fill the rate: unlike `PoseidonHash`, the
+/// padding is injective, and the sponge tells `[x]` from `[x, 0]`.
+pub struct PoseidonSponge<F: PrimeField, P: PoseidonParameters<Fr = F>> {
+    state:       Vec<F>,
+    mode:        SpongeMode,
+    _parameters: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> Clone for PoseidonSponge<F, P> {
+    fn clone(&self) -> Self {
+        Self { state: self.state.clone(), mode: self.mode, _parameters: PhantomData }
+    }
+}
+
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> PoseidonSponge<F, P> {
+
+    // Pads the elements absorbed since the last permutation, and permutes
+    fn pad_and_permute(&mut self, pos: usize) {
+        self.state[pos] += &F::one();
+        PoseidonHash::<F, P>::poseidon_perm(&mut self.state);
+    }
+}
+
+impl<F: PrimeField, P: PoseidonParameters<Fr = F>> FieldBasedSponge for PoseidonSponge<F, P> {
+    type Data = F;
+    type Parameters = P;
+
+    fn new(domain_tag: F) -> Self {
+        let mut state = vec![P::ZERO; P::T];
+        state[P::R] = domain_tag;
+        Self { state, mode: SpongeMode::Absorbing(0), _parameters: PhantomData }
+    }
+
+    fn absorb(&mut self, input: &[F]) {
+        let mut pos = match self.mode {
+            SpongeMode::Absorbing(pos) => pos,
+            SpongeMode::Squeezing(_) => 0,
+        };
+        for x in input.iter() {
+            self.state[pos] += x;
+            pos += 1;
+            // Permute as soon as the rate is full, so that the padding of a
+            // full rate goes to a block of its own
+            if pos == P::R {
+                PoseidonHash::<F, P>::poseidon_perm(&mut self.state);
+                pos = 0;
+            }
+        }
+        self.mode = SpongeMode::Absorbing(pos);
+    }
+
+    fn squeeze(&mut self, num: usize) -> Vec<F> {
+        let mut pos = match self.mode {
+            SpongeMode::Absorbing(pos) => {
+                self.pad_and_permute(pos);
+                0
+            },
+            SpongeMode::Squeezing(pos) => pos,
+        };
+        let mut output = Vec::with_capacity(num);
+        for _ in 0..num {
+            if pos == P::R {
+                PoseidonHash::<F, P>::poseidon_perm(&mut self.state);
+                pos = 0;
+            }
+            output.push(self.state[pos]);
+            pos += 1;
+        }
+        self.mode = SpongeMode::Squeezing(pos);
+        output
+    }
+}
+
+pub type MNT4PoseidonSponge = PoseidonSponge<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6PoseidonSponge = PoseidonSponge<MNT6753Fr, MNT6753PoseidonParameters>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{Field, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_sponge_padding() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let x = MNT4753Fr::rand(&mut rng);
+
+        // The trailing zeros are not ignored, even filling the rate
+        let mut outputs = Vec::new();
+        for input in [vec![x], vec![x, MNT4753Fr::zero()], vec![x, MNT4753Fr::zero(), MNT4753Fr::zero()]].iter() {
+            let mut sponge = MNT4PoseidonSponge::new(MNT4753Fr::zero());
+            sponge.absorb(input);
+            outputs.push(sponge.squeeze(1)[0]);
+        }
+        assert_ne!(outputs[0], outputs[1]);
+        assert_ne!(outputs[1], outputs[2]);
+        assert_ne!(outputs[0], outputs[2]);
+
+        // Nor is the padding itself
+        let mut sponge = MNT4PoseidonSponge::new(MNT4753Fr::zero());
+        sponge.absorb(&[x, MNT4753Fr::one()]);
+        assert_ne!(sponge.squeeze(1)[0], outputs[0]);
+    }
+
+    #[test]
+    fn test_sponge_domain_separation() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let input = (0..5).map(|_| MNT6753Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+        let mut sponge_1 = MNT6PoseidonSponge::new(MNT6753Fr::one());
+        let mut sponge_2 = MNT6PoseidonSponge::new(MNT6753Fr::from(2u64));
+        sponge_1.absorb(&input);
+        sponge_2.absorb(&input);
+        assert_ne!(sponge_1.squeeze(3), sponge_2.squeeze(3));
+    }
+
+    #[test]
+    fn test_sponge_streaming() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let input = (0..7).map(|_| MNT4753Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Absorbing in several calls is the same as absorbing at once
+        let mut sponge = MNT4PoseidonSponge::new(MNT4753Fr::zero());
+        sponge.absorb(&input);
+        let mut sponge_split = MNT4PoseidonSponge::new(MNT4753Fr::zero());
+        sponge_split.absorb(&input[..3]);
+        sponge_split.absorb(&[]);
+        sponge_split.absorb(&input[3..]);
+        assert_eq!(sponge.state, sponge_split.state);
+
+        // And so is squeezing, across the boundaries of the rate
+        let output = sponge.squeeze(5);
+        let mut output_split = sponge_split.squeeze(1);
+        output_split.extend(sponge_split.squeeze(3));
+        output_split.extend(sponge_split.squeeze(1));
+        assert_eq!(output, output_split);
+
+        // The squeezed elements are all distinct
+        for i in 0..output.len() {
+            assert!(!output[i + 1..].contains(&output[i]));
+        }
+
+        // Absorbing after squeezing affects the next outputs
+        let mut sponge_duplex = sponge.clone();
+        sponge_duplex.absorb(&input[..1]);
+        assert_ne!(sponge.squeeze(2), sponge_duplex.squeeze(2));
+    }
+}
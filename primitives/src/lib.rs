@@ -4,6 +4,7 @@ extern crate bench_utils;
 #[cfg(any(
     feature = "commitment",
     feature = "merkle_tree",
+    feature = "poly_commit",
     feature = "prf",
     feature = "signature",
     feature = "vrf"
@@ -24,6 +25,11 @@ pub mod merkle_tree;
 #[cfg(feature = "merkle_tree")]
 pub use self::merkle_tree::*;
 
+#[cfg(feature = "poly_commit")]
+pub mod poly_commit;
+#[cfg(feature = "poly_commit")]
+pub use self::poly_commit::*;
+
 #[cfg(feature = "prf")]
 pub mod prf;
 #[cfg(feature = "prf")]
@@ -34,6 +40,11 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use self::signature::*;
 
+#[cfg(feature = "transcript")]
+pub mod transcript;
+#[cfg(feature = "transcript")]
+pub use self::transcript::*;
+
 #[cfg(feature = "vrf")]
 pub mod vrf;
 #[cfg(feature = "vrf")]
@@ -0,0 +1,330 @@
+use crate::{
+    crh::FieldBasedHash,
+    merkle_tree::{
+        MerkleTreeError,
+        field_based_mht::{
+            FieldBasedMerkleTreeConfig, FieldBasedMerkleTreePath,
+            MerkleTreeStorage, check_arity, hash_empty, hash_node_with_siblings,
+        },
+    },
+    Error,
+};
+use algebra::{to_bytes, FromBytes, ToBytes};
+use std::marker::PhantomData;
+
+// Keys of the storage
+const NODE_KEY_PREFIX: u8 = 0;
+const NUM_LEAVES_KEY: &[u8] = &[1];
+
+fn node_key(level: usize, index: usize) -> Vec<u8> {
+    let mut key = vec![NODE_KEY_PREFIX, level as u8];
+    key.extend_from_slice(&(index as u64).to_le_bytes());
+    key
+}
+
+/// An append-only Merkle Tree of `P::ARITY^(P::HEIGHT - 1)` leaves, which are field
+/// elements, updated one leaf at a time. The nodes are kept in a key-value
+/// store `S`: appending or updating a leaf reads and writes `P::HEIGHT` nodes
+/// of it.
+///
+/// The leaves not appended yet are `hash_empty`, and the roots of the empty
+/// subtrees are derived from it: unlike for `FieldBasedMerkleHashTree`, the
+/// root depends on the leaves only, and not on their number. The two trees
+/// have the same root when all of the leaves have been appended.
+pub struct FieldBasedIncrementalMerkleTree<P: FieldBasedMerkleTreeConfig, S: MerkleTreeStorage> {
+    storage:      S,
+    // The roots of the empty subtrees, by level, starting from the leaves
+    empty_hashes: Vec<<P::H as FieldBasedHash>::Data>,
+    num_leaves:   usize,
+    root:         <P::H as FieldBasedHash>::Data,
+    _config:      PhantomData<P>,
+}
+
+impl<P: FieldBasedMerkleTreeConfig, S: MerkleTreeStorage> FieldBasedIncrementalMerkleTree<P, S> {
+    pub const HEIGHT: u8 = P::HEIGHT as u8;
+
+    /// Opens the tree kept in `storage`, which is empty if `storage` is.
+    pub fn new(storage: S) -> Result<Self, Error> {
+        check_arity::<P>()?;
+        let mut empty_hashes = Vec::with_capacity(P::HEIGHT);
+        empty_hashes.push(hash_empty::<P::H>()?);
+        for level in 1..P::HEIGHT {
+            let empty_hash = empty_hashes[level - 1];
+            empty_hashes.push(P::H::evaluate(&vec![empty_hash; P::ARITY])?);
+        }
+
+        let num_leaves = match storage.get(NUM_LEAVES_KEY)? {
+            Some(bytes) => u64::read(bytes.as_slice())? as usize,
+            None => 0,
+        };
+
+        let mut tree = Self {
+            storage,
+            root: empty_hashes[P::HEIGHT - 1],
+            empty_hashes,
+            num_leaves,
+            _config: PhantomData,
+        };
+        tree.root = tree.get_node(P::HEIGHT - 1, 0)?;
+        Ok(tree)
+    }
+
+    /// The maximum number of leaves.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        P::ARITY.pow((P::HEIGHT - 1) as u32)
+    }
+
+    #[inline]
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    #[inline]
+    pub fn root(&self) -> <P::H as FieldBasedHash>::Data {
+        self.root
+    }
+
+    #[inline]
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Makes the changes to the tree persistent, if the storage is.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.storage.flush()
+    }
+
+    /// Appends `leaf` to the tree, returning its index.
+    pub fn append(&mut self, leaf: <P::H as FieldBasedHash>::Data) -> Result<usize, Error> {
+        if self.num_leaves == self.capacity() {
+            return Err(MerkleTreeError::TooManyLeaves(self.capacity()))?
+        }
+
+        let index = self.num_leaves;
+        self.update_path(index, leaf)?;
+        self.num_leaves += 1;
+        self.storage.put(NUM_LEAVES_KEY, to_bytes!(self.num_leaves as u64)?.as_slice())?;
+        Ok(index)
+    }
+
+    /// Replaces the leaf at `index`, which must have been appended already.
+    pub fn update(&mut self, index: usize, leaf: <P::H as FieldBasedHash>::Data) -> Result<(), Error> {
+        if index >= self.num_leaves {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+        self.update_path(index, leaf)
+    }
+
+    pub fn get_leaf(&self, index: usize) -> Result<<P::H as FieldBasedHash>::Data, Error> {
+        if index >= self.num_leaves {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+        self.get_node(0, index)
+    }
+
+    pub fn generate_proof(&self, index: usize) -> Result<FieldBasedMerkleTreePath<P>, Error> {
+        let prove_time = start_timer!(|| "IncrementalMerkleTree::GenProof");
+        if index >= self.num_leaves {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+
+        let mut path = Vec::with_capacity(P::HEIGHT - 1);
+        let mut current_index = index;
+        for level in 0..P::HEIGHT - 1 {
+            path.push((self.get_siblings(level, current_index)?, current_index % P::ARITY));
+            current_index /= P::ARITY;
+        }
+
+        end_timer!(prove_time);
+        Ok(FieldBasedMerkleTreePath { path })
+    }
+
+    // Sets the leaf at `index`, and recomputes the nodes from it to the root.
+    fn update_path(&mut self, index: usize, leaf: <P::H as FieldBasedHash>::Data) -> Result<(), Error> {
+        self.put_node(0, index, &leaf)?;
+
+        let mut current_hash = leaf;
+        let mut current_index = index;
+        for level in 0..P::HEIGHT - 1 {
+            let siblings = self.get_siblings(level, current_index)?;
+            current_hash = hash_node_with_siblings::<P>(current_hash, current_index % P::ARITY, &siblings)?;
+            current_index /= P::ARITY;
+            self.put_node(level + 1, current_index, &current_hash)?;
+        }
+
+        self.root = current_hash;
+        Ok(())
+    }
+
+    // Returns the other children of the parent of the node at `index`, in order.
+    fn get_siblings(&self, level: usize, index: usize) -> Result<Vec<<P::H as FieldBasedHash>::Data>, Error> {
+        let first_sibling = index - index % P::ARITY;
+        (first_sibling..first_sibling + P::ARITY)
+            .filter(|&i| i != index)
+            .map(|i| self.get_node(level, i))
+            .collect()
+    }
+
+    fn get_node(&self, level: usize, index: usize) -> Result<<P::H as FieldBasedHash>::Data, Error> {
+        match self.storage.get(node_key(level, index).as_slice())? {
+            Some(bytes) => Ok(<P::H as FieldBasedHash>::Data::read(bytes.as_slice())?),
+            None => Ok(self.empty_hashes[level]),
+        }
+    }
+
+    fn put_node(&mut self, level: usize, index: usize, node: &<P::H as FieldBasedHash>::Data) -> Result<(), Error> {
+        self.storage.put(node_key(level, index).as_slice(), to_bytes!(node)?.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::MNT4PoseidonHash,
+        merkle_tree::field_based_mht::*,
+    };
+    use algebra::{fields::mnt4753::Fr, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    struct MNT4753FieldBasedMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        type H = MNT4PoseidonHash;
+    }
+
+    type MNT4753FieldBasedMerkleTree = FieldBasedMerkleHashTree<MNT4753FieldBasedMerkleTreeParams>;
+    type MNT4753IncrementalMerkleTree<S> = FieldBasedIncrementalMerkleTree<MNT4753FieldBasedMerkleTreeParams, S>;
+
+    #[test]
+    fn test_append_and_update() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = MNT4753IncrementalMerkleTree::new(InMemoryMerkleTreeStorage::new()).unwrap();
+        let empty_root = tree.root();
+
+        let mut leaves = Vec::new();
+        for i in 0..tree.capacity() {
+            let leaf = Fr::rand(&mut rng);
+            assert_eq!(tree.append(leaf).unwrap(), i);
+            leaves.push(leaf);
+
+            // All the paths are valid after each append
+            let root = tree.root();
+            for (j, leaf) in leaves.iter().enumerate() {
+                assert!(tree.generate_proof(j).unwrap().verify(&root, leaf).unwrap());
+            }
+        }
+        assert_ne!(tree.root(), empty_root);
+        assert!(tree.append(Fr::rand(&mut rng)).is_err());
+
+        // Once full, the root is the one of the tree built at once
+        assert_eq!(tree.root(), MNT4753FieldBasedMerkleTree::new(&leaves).unwrap().root());
+
+        // And so it is after updating some leaves
+        for &i in [0, 7, 31, 7].iter() {
+            leaves[i] = Fr::rand(&mut rng);
+            tree.update(i, leaves[i]).unwrap();
+            assert_eq!(tree.get_leaf(i).unwrap(), leaves[i]);
+            assert_eq!(tree.root(), MNT4753FieldBasedMerkleTree::new(&leaves).unwrap().root());
+
+            let proof = tree.generate_proof(i).unwrap();
+            assert!(proof.verify(&tree.root(), &leaves[i]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_incorrect_index() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = MNT4753IncrementalMerkleTree::new(InMemoryMerkleTreeStorage::new()).unwrap();
+
+        for _ in 0..3 {
+            tree.append(Fr::rand(&mut rng)).unwrap();
+        }
+        assert!(tree.update(3, Fr::rand(&mut rng)).is_err());
+        assert!(tree.generate_proof(3).is_err());
+        assert!(tree.get_leaf(3).is_err());
+
+        // A path doesn't verify another leaf
+        let proof = tree.generate_proof(1).unwrap();
+        assert!(!proof.verify(&tree.root(), &tree.get_leaf(2).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_file_backed_tree() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let path = std::env::temp_dir().join(format!("incremental_mht_test_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let (root, leaves) = {
+            let storage = FileMerkleTreeStorage::open(&path).unwrap();
+            let mut tree = MNT4753IncrementalMerkleTree::new(storage).unwrap();
+            let leaves = (0..5).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            for leaf in leaves.iter() {
+                tree.append(*leaf).unwrap();
+            }
+            tree.update(2, leaves[4]).unwrap();
+            tree.flush().unwrap();
+            (tree.root(), leaves)
+        };
+
+        // The reopened tree is the one left
+        let storage = FileMerkleTreeStorage::open(&path).unwrap();
+        let mut tree = MNT4753IncrementalMerkleTree::new(storage).unwrap();
+        assert_eq!(tree.num_leaves(), 5);
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.get_leaf(2).unwrap(), leaves[4]);
+        assert!(tree.generate_proof(2).unwrap().verify(&root, &leaves[4]).unwrap());
+
+        // And the same as an in memory tree with the same history
+        let mut memory_tree = MNT4753IncrementalMerkleTree::new(InMemoryMerkleTreeStorage::new()).unwrap();
+        for leaf in leaves.iter() {
+            memory_tree.append(*leaf).unwrap();
+        }
+        memory_tree.update(2, leaves[4]).unwrap();
+        assert_eq!(memory_tree.root(), root);
+
+        let leaf = Fr::rand(&mut rng);
+        tree.append(leaf).unwrap();
+        memory_tree.append(leaf).unwrap();
+        assert_eq!(tree.root(), memory_tree.root());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    struct MNT4753QuaternaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternaryMerkleTreeParams {
+        const HEIGHT: usize = 3;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    #[test]
+    fn test_quaternary_tree() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = FieldBasedIncrementalMerkleTree::<MNT4753QuaternaryMerkleTreeParams, _>::new(
+            InMemoryMerkleTreeStorage::new()
+        ).unwrap();
+        assert_eq!(tree.capacity(), 16);
+
+        let mut leaves = Vec::new();
+        while leaves.len() < tree.capacity() {
+            let leaf = Fr::rand(&mut rng);
+            tree.append(leaf).unwrap();
+            leaves.push(leaf);
+            for (j, leaf) in leaves.iter().enumerate() {
+                assert!(tree.generate_proof(j).unwrap().verify(&tree.root(), leaf).unwrap());
+            }
+        }
+        assert_eq!(
+            tree.root(),
+            FieldBasedMerkleHashTree::<MNT4753QuaternaryMerkleTreeParams>::new(&leaves).unwrap().root()
+        );
+    }
+}
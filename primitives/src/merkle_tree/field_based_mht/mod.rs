@@ -1,13 +1,41 @@
 use crate::{
-    crh::FieldBasedHash, Error
+    crh::{FieldBasedHash, BatchFieldBasedHash}, Error
 };
 use super::*;
+use algebra::{FromBytes, FromBytesChecked, ToBytes};
+use std::io::{self, Read, Result as IoResult, Write};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod storage;
+pub use self::storage::*;
+
+pub mod incremental;
+pub use self::incremental::*;
+
+pub mod sparse;
+pub use self::sparse::*;
+
+pub mod multi_path;
+pub use self::multi_path::*;
 
 pub trait FieldBasedMerkleTreeConfig {
     const HEIGHT: usize;
+    /// The number of children of the inner nodes, either 2, 4 or 8. A node is
+    /// the hash of its children, so `H` should absorb `ARITY` elements at once.
+    const ARITY: usize = 2;
     type H: FieldBasedHash;
 }
 
+/// A `FieldBasedMerkleTreeConfig` whose hash has a batch version `BH`, used by
+/// `FieldBasedMerkleHashTree::new_batch` to hash the nodes of a level at once.
+/// `BH` must compute the same digests as `H` on pairs of nodes, so that the
+/// tree is the same whatever the constructor.
+pub trait BatchFieldBasedMerkleTreeConfig: FieldBasedMerkleTreeConfig {
+    type BH: BatchFieldBasedHash<Data = <Self::H as FieldBasedHash>::Data>;
+}
+
 use algebra::{
     biginteger::BigInteger768,
     fields::mnt4753::Fr as MNT4753Fr,
@@ -31,8 +59,10 @@ pub const MNT4753_PHANTOM_MERKLE_ROOT: MNT4753Fr =
         312822709740712
     ]));
 
-/// Stores the hashes of a particular path (in order) from leaf to root.
-/// Our path `is_left_child()` if the boolean in `path` is false.
+/// Stores the siblings of the nodes of a particular path (in order) from leaf
+/// to root, together with the position of the node among its siblings, from
+/// 0 to `P::ARITY - 1`: in a binary tree, our path `is_left_child()` if the
+/// position is 0.
 #[derive(Derivative)]
 #[derivative(
 Clone(bound = "P: FieldBasedMerkleTreeConfig"),
@@ -40,8 +70,8 @@ Debug(bound = "P: FieldBasedMerkleTreeConfig, <P::H as FieldBasedHash>::Data: fm
 )]
 pub struct FieldBasedMerkleTreePath<P: FieldBasedMerkleTreeConfig> {
     pub path: Vec<(
-        <P::H as FieldBasedHash>::Data,
-        bool,
+        Vec<<P::H as FieldBasedHash>::Data>,
+        usize,
     )>,
 }
 
@@ -52,8 +82,8 @@ impl<P: FieldBasedMerkleTreeConfig> Default for FieldBasedMerkleTreePath<P> {
         let mut path = Vec::with_capacity(P::HEIGHT as usize);
         for _i in 1..P::HEIGHT as usize {
             path.push((
-                <P::H as FieldBasedHash>::Data::default(),
-                false,
+                vec![<P::H as FieldBasedHash>::Data::default(); P::ARITY - 1],
+                0,
             ));
         }
         Self { path }
@@ -76,16 +106,10 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleTreePath<P> {
             let mut prev = *leaf;
 
             // Check levels between leaf level and root.
-            for &(sibling_hash, direction) in &self.path {
+            for (siblings, position) in &self.path {
 
                 // Check if the previous hash matches the correct current hash.
-                prev = {
-                    if direction {
-                        hash_inner_node::<P::H>(sibling_hash, prev)
-                    } else {
-                        hash_inner_node::<P::H>(prev, sibling_hash)
-                    }
-                }?;
+                prev = hash_node_with_siblings::<P>(prev, *position, siblings)?;
             }
 
             if root_hash != &prev {
@@ -98,12 +122,47 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleTreePath<P> {
     }
 }
 
+impl<P: FieldBasedMerkleTreeConfig> ToBytes for FieldBasedMerkleTreePath<P> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.path.len() as u32).write(&mut writer)?;
+        for (siblings, position) in self.path.iter() {
+            siblings.write(&mut writer)?;
+            (*position as u8).write(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FromBytes for FieldBasedMerkleTreePath<P> {
+    /// Fails if the length of the path is not `P::HEIGHT - 1`, or if a position
+    /// is not less than `P::ARITY`.
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let len = u32::read(&mut reader)? as usize;
+        check_path_length(len, P::HEIGHT)?;
+        let mut path = Vec::with_capacity(len);
+        for _ in 0..len {
+            let mut siblings = Vec::with_capacity(P::ARITY - 1);
+            for _ in 0..P::ARITY - 1 {
+                siblings.push(<P::H as FieldBasedHash>::Data::read(&mut reader)?);
+            }
+            let position = u8::read(&mut reader)? as usize;
+            if position >= P::ARITY {
+                return Err(invalid_data(MerkleTreeError::IncorrectLeafIndex(position)));
+            }
+            path.push((siblings, position));
+        }
+        Ok(Self { path })
+    }
+}
+
 /// Merkle Tree whose leaves are field elements, best with hash functions
 /// that works with field elements, such as Poseidon. This implementation
 /// works with leaves of size 1 field element.
 /// Leaves passed when creating a MerkleTree/MerklePath proof won't be
 /// hashed, it's responsibility of the caller to do it, if desired.
 pub struct FieldBasedMerkleHashTree<P: FieldBasedMerkleTreeConfig> {
+    // The nodes, level by level from the root, the children of the node at
+    // `i` being at `P::ARITY * i + 1..=P::ARITY * (i + 1)`
     tree:         Vec<<P::H as FieldBasedHash>::Data>,
     padding_tree: Vec<(
         <P::H as FieldBasedHash>::Data,
@@ -128,65 +187,107 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
     ) -> Result<Self, Error>
     {
         let new_time = start_timer!(|| "MerkleTree::New");
+        let tree = Self::build(leaves, |children, parents| {
+            for (parent, children) in parents.iter_mut().zip(children.chunks(P::ARITY)) {
+                *parent = P::H::evaluate(children)?;
+            }
+            Ok(())
+        })?;
+        end_timer!(new_time);
+        Ok(tree)
+    }
+
+    /// Same as `new`, but hashes the nodes of each level at once with the
+    /// batch hash of `P`, splitting the level among threads if the `parallel`
+    /// feature is enabled. The tree must be binary.
+    pub fn new_batch(
+        leaves: &[<P::H as FieldBasedHash>::Data],
+    ) -> Result<Self, Error>
+        where
+            P: BatchFieldBasedMerkleTreeConfig,
+    {
+        let new_time = start_timer!(|| "MerkleTree::NewBatch");
+        if P::ARITY != 2 {
+            Err(crate::CryptoError::InvalidElement(format!("Merkle tree arity {} for batch hashing", P::ARITY)))?
+        }
+
+        let tree = Self::build(leaves, |children, parents| {
+            // The batch hash overwrites its input with the digests
+            let mut buffer = children.to_vec();
+
+            #[cfg(feature = "parallel")]
+            {
+                let chunk_size = std::cmp::max(buffer.len() / rayon::current_num_threads(), 1);
+                let chunk_size = chunk_size + chunk_size % 2;
+                buffer.par_chunks_mut(chunk_size)
+                    .zip(parents.par_chunks_mut(chunk_size / 2))
+                    .for_each(|(buffer, parents)| {
+                        P::BH::batch_evaluate_2_1(buffer);
+                        parents.copy_from_slice(&buffer[..parents.len()]);
+                    });
+            }
+
+            #[cfg(not(feature = "parallel"))]
+            {
+                P::BH::batch_evaluate_2_1(buffer.as_mut_slice());
+                parents.copy_from_slice(&buffer[..parents.len()]);
+            }
+
+            Ok(())
+        })?;
+        end_timer!(new_time);
+        Ok(tree)
+    }
 
-        let last_level_size = leaves.len().next_power_of_two();
-        let tree_size = 2 * last_level_size - 1;
-        let tree_height = tree_height(tree_size);
+    // Builds the tree of `leaves`, calling `hash_level` with the nodes of each
+    // level, from the leaves, and the nodes of the level above to compute.
+    fn build<F>(
+        leaves: &[<P::H as FieldBasedHash>::Data],
+        mut hash_level: F,
+    ) -> Result<Self, Error>
+        where
+            F: FnMut(&[<P::H as FieldBasedHash>::Data], &mut [<P::H as FieldBasedHash>::Data]) -> Result<(), Error>,
+    {
+        check_arity::<P>()?;
+
+        let mut last_level_size = 1;
+        let mut tree_height = 1;
+        while last_level_size < leaves.len() {
+            last_level_size *= P::ARITY;
+            tree_height += 1;
+        }
+        let tree_size = level_start::<P>(tree_height);
         assert!(tree_height as u8 <= Self::HEIGHT);
 
         // Initialize the merkle tree.
-        let mut tree = Vec::with_capacity(tree_size);
         let empty_hash = hash_empty::<P::H>()?;
-        for _ in 0..tree_size {
-            tree.push(empty_hash.clone());
-        }
-
-        // Compute the starting indices for each level of the tree.
-        let mut index = 0;
-        let mut level_indices = Vec::with_capacity(tree_height);
-        for _ in 0..tree_height {
-            level_indices.push(index);
-            index = left_child(index);
-        }
+        let mut tree = vec![empty_hash; tree_size];
 
         // Compute and store the values for each leaf.
-        let last_level_index = level_indices.pop().unwrap();
-        for (i, leaf) in leaves.iter().enumerate() {
-            tree[last_level_index + i] = *leaf;
-        }
-
-        // Compute the hash values for every node in the tree.
-        let mut upper_bound = last_level_index;
-        level_indices.reverse();
-        for &start_index in &level_indices {
-            // Iterate over the current level.
-            for current_index in start_index..upper_bound {
-                let left_index = left_child(current_index);
-                let right_index = right_child(current_index);
-
-                // Compute Hash(left || right).
-                tree[current_index] = hash_inner_node::<P::H>(
-                    tree[left_index],
-                    tree[right_index],
-                )?;
-            }
-            upper_bound = start_index;
+        let last_level_index = level_start::<P>(tree_height - 1);
+        tree[last_level_index..last_level_index + leaves.len()].copy_from_slice(leaves);
+
+        // Compute the hash values for every node in the tree, from the level
+        // above the leaves up to the root.
+        for depth in (0..tree_height - 1).rev() {
+            let (upper_levels, lower_levels) = tree.split_at_mut(level_start::<P>(depth + 1));
+            let children = &lower_levels[..level_start::<P>(depth + 2) - level_start::<P>(depth + 1)];
+            hash_level(children, &mut upper_levels[level_start::<P>(depth)..])?;
         }
         // Finished computing actual tree.
         // Now, we compute the dummy nodes until we hit our HEIGHT goal.
         let mut cur_height = tree_height;
         let mut padding_tree = Vec::new();
-        let mut cur_hash = tree[0].clone();
+        let mut cur_hash = tree[0];
+        let padding = vec![empty_hash; P::ARITY - 1];
         while cur_height < Self::HEIGHT as usize {
-            cur_hash = hash_inner_node::<P::H>(cur_hash, empty_hash)?;
-            padding_tree.push((cur_hash.clone(), empty_hash.clone()));
+            cur_hash = hash_node_with_siblings::<P>(cur_hash, 0, &padding)?;
+            padding_tree.push((cur_hash, empty_hash));
             cur_height += 1;
         }
 
         let root_hash = cur_hash;
 
-        end_timer!(new_time);
-
         Ok(FieldBasedMerkleHashTree {
             tree,
             padding_tree,
@@ -201,8 +302,21 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
 
     #[inline]
     pub fn leaves(&self) -> &[<P::H as FieldBasedHash>::Data] {
-        let leaf_index = convert_index_to_last_level(0, tree_height(self.tree.len()));
-        &self.tree[leaf_index..]
+        let tree_height = self.tree_height();
+        if tree_height == 0 {
+            return &[];
+        }
+        &self.tree[level_start::<P>(tree_height - 1)..]
+    }
+
+    /// Returns the number of levels of `self.tree`, i.e. without the ones of
+    /// the padding tree.
+    pub(crate) fn tree_height(&self) -> usize {
+        let mut tree_height = 0;
+        while level_start::<P>(tree_height) < self.tree.len() {
+            tree_height += 1;
+        }
+        tree_height
     }
 
     pub fn generate_proof(
@@ -214,32 +328,35 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
         let prove_time = start_timer!(|| "MerkleTree::GenProof");
         let mut path = Vec::new();
 
-        let tree_height = tree_height(self.tree.len());
-        let tree_index = convert_index_to_last_level(index, tree_height);
+        let tree_height = self.tree_height();
+        let tree_index = level_start::<P>(tree_height - 1) + index;
 
         // Check that the given index corresponds to the correct leaf.
-        if *leaf != self.tree[tree_index] {
+        if tree_index >= self.tree.len() || *leaf != self.tree[tree_index] {
             Err(MerkleTreeError::IncorrectLeafIndex(tree_index))?
         }
 
         // Iterate from the leaf up to the root, storing all intermediate hash values.
         let mut current_node = tree_index;
-        while !is_root(current_node) {
-            let sibling_node = sibling(current_node).unwrap();
-            let sibling_hash = self.tree[sibling_node].clone();
-            if is_left_child(current_node) {
-                path.push((sibling_hash, false));
-            } else {
-                path.push((sibling_hash, true));
-            }
-            current_node = parent(current_node).unwrap();
+        while current_node != 0 {
+            let parent = (current_node - 1) / P::ARITY;
+            let first_sibling = P::ARITY * parent + 1;
+            let position = current_node - first_sibling;
+            let siblings = self.tree[first_sibling..first_sibling + P::ARITY]
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != position)
+                .map(|(_, sibling)| *sibling)
+                .collect();
+            path.push((siblings, position));
+            current_node = parent;
         }
 
         assert!(path.len() < Self::HEIGHT as usize);
 
         //Push the other elements of the padding tree
         for &(_, ref sibling_hash) in &self.padding_tree {
-            path.push((sibling_hash.clone(), false));
+            path.push((vec![*sibling_hash; P::ARITY - 1], 0));
         }
 
         end_timer!(prove_time);
@@ -251,6 +368,106 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
     }
 }
 
+/// A snapshot of the tree: its nodes, from the root to the leaves, then the
+/// nodes of the padding tree and the root. The blank tree has no nodes and no
+/// root.
+impl<P: FieldBasedMerkleTreeConfig> ToBytes for FieldBasedMerkleHashTree<P> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.tree.len() as u32).write(&mut writer)?;
+        for node in self.tree.iter() {
+            node.write(&mut writer)?;
+        }
+        (self.padding_tree.len() as u32).write(&mut writer)?;
+        for (node, sibling) in self.padding_tree.iter() {
+            node.write(&mut writer)?;
+            sibling.write(&mut writer)?;
+        }
+        match self.root {
+            Some(root) => {
+                true.write(&mut writer)?;
+                root.write(&mut writer)
+            },
+            None => false.write(&mut writer),
+        }
+    }
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FromBytes for FieldBasedMerkleHashTree<P> {
+    /// Fails if the snapshot is not the one of a tree of height `P::HEIGHT`.
+    /// The nodes are not rehashed, so the snapshot must come from a trusted
+    /// source, otherwise use `read_checked`.
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let tree_size = u32::read(&mut reader)? as usize;
+        let mut tree_height = 0;
+        while level_start::<P>(tree_height) < tree_size && tree_height < P::HEIGHT {
+            tree_height += 1;
+        }
+        if level_start::<P>(tree_height) != tree_size {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("incorrect Merkle tree size: {}", tree_size)));
+        }
+        let mut tree = Vec::new();
+        for _ in 0..tree_size {
+            tree.push(<P::H as FieldBasedHash>::Data::read(&mut reader)?);
+        }
+
+        let padding_tree_size = u32::read(&mut reader)? as usize;
+        if tree_size > 0 && padding_tree_size != P::HEIGHT - tree_height
+            || tree_size == 0 && padding_tree_size != 0 {
+            return Err(invalid_data(MerkleTreeError::IncorrectPathLength(padding_tree_size)));
+        }
+        let mut padding_tree = Vec::with_capacity(padding_tree_size);
+        for _ in 0..padding_tree_size {
+            let node = <P::H as FieldBasedHash>::Data::read(&mut reader)?;
+            let sibling = <P::H as FieldBasedHash>::Data::read(&mut reader)?;
+            padding_tree.push((node, sibling));
+        }
+
+        let root = if bool::read(&mut reader)? {
+            Some(<P::H as FieldBasedHash>::Data::read(&mut reader)?)
+        } else {
+            None
+        };
+        let expected_root = padding_tree.last().map(|(node, _)| node).or_else(|| tree.first());
+        if root.as_ref() != expected_root {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "inconsistent Merkle tree root"));
+        }
+
+        Ok(Self { tree, padding_tree, root })
+    }
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FromBytesChecked for FieldBasedMerkleHashTree<P> {
+    /// Same as `read`, but also rehashes the tree from its leaves and checks
+    /// that every node, including the ones of the padding tree, and the root
+    /// are the stored ones.
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        let tree = Self::read(reader)?;
+        if tree.root.is_none() {
+            return Ok(tree);
+        }
+        let rebuilt = Self::new(tree.leaves())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        if rebuilt.tree != tree.tree || rebuilt.padding_tree != tree.padding_tree || rebuilt.root != tree.root {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "inconsistent Merkle tree nodes"));
+        }
+        Ok(tree)
+    }
+}
+
+/// Returns the index in `FieldBasedMerkleHashTree::tree` of the first node at
+/// distance `depth` from the root, that is the number of nodes above it.
+#[inline]
+pub(crate) fn level_start<P: FieldBasedMerkleTreeConfig>(depth: usize) -> usize {
+    (P::ARITY.pow(depth as u32) - 1) / (P::ARITY - 1)
+}
+
+pub(crate) fn check_arity<P: FieldBasedMerkleTreeConfig>() -> Result<(), Error> {
+    match P::ARITY {
+        2 | 4 | 8 => Ok(()),
+        arity => Err(crate::CryptoError::InvalidElement(format!("Merkle tree arity {}", arity)))?,
+    }
+}
+
 /// Returns the output hash, given a left and right hash value.
 pub(crate) fn hash_inner_node<H: FieldBasedHash>(
     left: H::Data,
@@ -259,7 +476,27 @@ pub(crate) fn hash_inner_node<H: FieldBasedHash>(
     H::evaluate(&[left, right])
 }
 
-pub(crate) fn hash_empty<H: FieldBasedHash>() -> Result<H::Data, Error> {
+/// Returns the hash of the children of a node, given one of them, its position
+/// among them, and the other ones in order.
+pub(crate) fn hash_node_with_siblings<P: FieldBasedMerkleTreeConfig>(
+    node: <P::H as FieldBasedHash>::Data,
+    position: usize,
+    siblings: &[<P::H as FieldBasedHash>::Data],
+) -> Result<<P::H as FieldBasedHash>::Data, Error> {
+    if siblings.len() != P::ARITY - 1 {
+        Err(MerkleTreeError::IncorrectPathLength(siblings.len()))?
+    }
+    if position >= P::ARITY {
+        Err(MerkleTreeError::IncorrectLeafIndex(position))?
+    }
+    let mut children = Vec::with_capacity(P::ARITY);
+    children.extend_from_slice(&siblings[..position]);
+    children.push(node);
+    children.extend_from_slice(&siblings[position..]);
+    P::H::evaluate(children.as_slice())
+}
+
+pub fn hash_empty<H: FieldBasedHash>() -> Result<H::Data, Error> {
     use algebra::Field;
     let dummy = <H::Data as Field>::one();
     H::evaluate(&[dummy])
@@ -268,7 +505,7 @@ pub(crate) fn hash_empty<H: FieldBasedHash>() -> Result<H::Data, Error> {
 #[cfg(test)]
 mod test {
     use crate::{
-        crh::MNT4PoseidonHash,
+        crh::{MNT4PoseidonHash, MNT4BatchPoseidonHash},
         merkle_tree::field_based_mht::*,
     };
     use algebra::{
@@ -381,4 +618,144 @@ mod test {
         }
         bad_merkle_tree_verify(&leaves);
     }
-}
\ No newline at end of file
+
+    struct MNT4753QuaternaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternaryMerkleTreeParams {
+        const HEIGHT: usize = 4;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    struct MNT4753OctaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753OctaryMerkleTreeParams {
+        const HEIGHT: usize = 3;
+        const ARITY: usize = 8;
+        type H = MNT4PoseidonHash;
+    }
+
+    fn check_arity_merkle_tree<P: FieldBasedMerkleTreeConfig<H = MNT4PoseidonHash>>(leaves: &[Fr]) {
+        let tree = FieldBasedMerkleHashTree::<P>::new(leaves).unwrap();
+        let root = tree.root();
+        assert_eq!(&tree.leaves()[..leaves.len()], leaves);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(i, leaf).unwrap();
+            assert_eq!(proof.path.len(), P::HEIGHT - 1);
+            assert_eq!(proof.path[0].1, i % P::ARITY);
+            assert!(proof.path.iter().all(|(siblings, _)| siblings.len() == P::ARITY - 1));
+            assert!(proof.verify(&root, leaf).unwrap());
+            assert!(!proof.verify(&Fr::zero(), leaf).unwrap());
+
+            // A position out of the node is rejected
+            let mut bad_proof = proof.clone();
+            bad_proof.path[0].1 = P::ARITY;
+            assert!(bad_proof.verify(&root, leaf).is_err());
+        }
+    }
+
+    #[test]
+    fn arity_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [1, 5, 16, 64].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            check_arity_merkle_tree::<MNT4753QuaternaryMerkleTreeParams>(&leaves);
+            check_arity_merkle_tree::<MNT4753OctaryMerkleTreeParams>(&leaves);
+        }
+
+        // The 4 leaves of a 4-ary tree are hashed at once, then padded up to the height
+        let leaves = (0..4).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = FieldBasedMerkleHashTree::<MNT4753QuaternaryMerkleTreeParams>::new(&leaves).unwrap();
+        let empty = hash_empty::<MNT4PoseidonHash>().unwrap();
+        let mut expected_root = MNT4PoseidonHash::evaluate(&leaves).unwrap();
+        for _ in 0..2 {
+            expected_root = MNT4PoseidonHash::evaluate(&[expected_root, empty, empty, empty]).unwrap();
+        }
+        assert_eq!(tree.root(), expected_root);
+    }
+
+    impl BatchFieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+        type BH = MNT4BatchPoseidonHash;
+    }
+
+    #[test]
+    fn batch_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [1, 2, 7, 16, 32].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+            let batch_tree = MNT4753FieldBasedMerkleTree::new_batch(&leaves).unwrap();
+            assert_eq!(batch_tree.root(), tree.root());
+            assert_eq!(batch_tree.leaves(), tree.leaves());
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = batch_tree.generate_proof(i, leaf).unwrap();
+                assert!(proof.verify(&tree.root(), leaf).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn serialization_test() {
+        use algebra::{to_bytes, FromBytes, FromBytesChecked, ToBytes};
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [1, 13, 32].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+
+            let tree_bytes = to_bytes!(tree).unwrap();
+            let read_tree = MNT4753FieldBasedMerkleTree::read(tree_bytes.as_slice()).unwrap();
+            assert_eq!(read_tree.root(), tree.root());
+            assert_eq!(read_tree.leaves(), tree.leaves());
+            assert_eq!(to_bytes!(read_tree).unwrap(), tree_bytes);
+            assert!(MNT4753FieldBasedMerkleTree::read(&tree_bytes[..tree_bytes.len() - 1]).is_err());
+            assert_eq!(MNT4753FieldBasedMerkleTree::read_checked(tree_bytes.as_slice()).unwrap().root(), tree.root());
+
+            // A tampered node, or sibling in the padding tree, is only
+            // detected when checked
+            let mut tampered = MNT4753FieldBasedMerkleTree::read(tree_bytes.as_slice()).unwrap();
+            let last = tampered.tree.len() - 1;
+            tampered.tree[last] += &Fr::one();
+            let tampered_bytes = to_bytes!(tampered).unwrap();
+            assert!(MNT4753FieldBasedMerkleTree::read(tampered_bytes.as_slice()).is_ok());
+            assert!(MNT4753FieldBasedMerkleTree::read_checked(tampered_bytes.as_slice()).is_err());
+            let mut tampered = MNT4753FieldBasedMerkleTree::read(tree_bytes.as_slice()).unwrap();
+            if let Some((_, sibling)) = tampered.padding_tree.first_mut() {
+                *sibling += &Fr::one();
+                let tampered_bytes = to_bytes!(tampered).unwrap();
+                assert!(MNT4753FieldBasedMerkleTree::read(tampered_bytes.as_slice()).is_ok());
+                assert!(MNT4753FieldBasedMerkleTree::read_checked(tampered_bytes.as_slice()).is_err());
+            }
+
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = read_tree.generate_proof(i, leaf).unwrap();
+                let proof_bytes = to_bytes!(proof).unwrap();
+                let read_proof = FieldBasedMerkleTreePath::<MNT4753FieldBasedMerkleTreeParams>::read(proof_bytes.as_slice()).unwrap();
+                assert_eq!(read_proof.path, proof.path);
+                assert!(read_proof.verify(&tree.root(), leaf).unwrap());
+            }
+        }
+
+        // A blank tree is read back as blank
+        let blank_bytes = to_bytes!(MNT4753FieldBasedMerkleTree::blank()).unwrap();
+        assert!(MNT4753FieldBasedMerkleTree::read(blank_bytes.as_slice()).unwrap().leaves().is_empty());
+        assert!(MNT4753FieldBasedMerkleTree::read_checked(blank_bytes.as_slice()).unwrap().leaves().is_empty());
+
+        // Paths of the wrong length, or out of the tree, are rejected
+        let leaves = (0..4).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = FieldBasedMerkleHashTree::<MNT4753QuaternaryMerkleTreeParams>::new(&leaves).unwrap();
+        let proof = tree.generate_proof(2, &leaves[2]).unwrap();
+        let mut bad_proof = proof.clone();
+        bad_proof.path.push(bad_proof.path[0].clone());
+        assert!(FieldBasedMerkleTreePath::<MNT4753QuaternaryMerkleTreeParams>::read(to_bytes!(bad_proof).unwrap().as_slice()).is_err());
+        let mut bad_proof = proof.clone();
+        bad_proof.path[0].1 = 4;
+        assert!(FieldBasedMerkleTreePath::<MNT4753QuaternaryMerkleTreeParams>::read(to_bytes!(bad_proof).unwrap().as_slice()).is_err());
+        assert!(FieldBasedMerkleTreePath::<MNT4753FieldBasedMerkleTreeParams>::read(to_bytes!(proof).unwrap().as_slice()).is_err());
+
+        // And so are trees of another shape
+        assert!(MNT4753FieldBasedMerkleTree::read(to_bytes!(tree).unwrap().as_slice()).is_err());
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    crh::FieldBasedHash,
+    merkle_tree::{
+        MerkleTreeError,
+        field_based_mht::{FieldBasedMerkleTreeConfig, FieldBasedMerkleHashTree, level_start},
+    },
+    Error,
+};
+use std::fmt;
+
+/// Stores the nodes needed, besides the leaves, to compute the root from the
+/// leaves at `indices`, level by level from the leaves to the root, and by
+/// index within a level. A node is stored only if it can't be computed from
+/// the leaves, so the nodes shared by the paths of several leaves are hashed
+/// once when verifying.
+#[derive(Derivative)]
+#[derivative(
+Clone(bound = "P: FieldBasedMerkleTreeConfig"),
+Debug(bound = "P: FieldBasedMerkleTreeConfig, <P::H as FieldBasedHash>::Data: fmt::Debug")
+)]
+pub struct FieldBasedMerkleTreeMultiPath<P: FieldBasedMerkleTreeConfig> {
+    /// The indices of the leaves, sorted and without duplicates
+    pub indices:         Vec<usize>,
+    pub auxiliary_nodes: Vec<<P::H as FieldBasedHash>::Data>,
+}
+
+/// Walks the tree of arity `arity` from the leaves at `indices` up to the root,
+/// calling `node` for each node computed, with the index of the level below,
+/// the index of its first child, and whether each child is one of the computed
+/// nodes or must be read from the auxiliary nodes.
+pub fn walk_multi_path<E, F>(arity: usize, height: usize, indices: &[usize], mut node: F) -> Result<(), E>
+    where
+        F: FnMut(usize, usize, &[bool]) -> Result<(), E>,
+{
+    let mut level_indices = indices.to_vec();
+    let mut known = vec![false; arity];
+    for level in 0..height - 1 {
+        let mut next_level_indices = Vec::with_capacity(level_indices.len());
+        let mut i = 0;
+        while i < level_indices.len() {
+            let parent = level_indices[i] / arity;
+            known.iter_mut().for_each(|k| *k = false);
+            while i < level_indices.len() && level_indices[i] / arity == parent {
+                known[level_indices[i] % arity] = true;
+                i += 1;
+            }
+            node(level, parent * arity, &known)?;
+            next_level_indices.push(parent);
+        }
+        level_indices = next_level_indices;
+    }
+    Ok(())
+}
+
+fn check_indices<P: FieldBasedMerkleTreeConfig>(indices: &[usize]) -> Result<(), Error> {
+    let num_leaves = P::ARITY.checked_pow((P::HEIGHT - 1) as u32);
+    for (i, &index) in indices.iter().enumerate() {
+        if matches!(num_leaves, Some(n) if index >= n) || (i > 0 && index <= indices[i - 1]) {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `indices` are sorted, without duplicates and leaves of the
+/// tree, and returns the number of auxiliary nodes of their multi path.
+pub fn num_auxiliary_nodes<P: FieldBasedMerkleTreeConfig>(indices: &[usize]) -> Result<usize, Error> {
+    check_indices::<P>(indices)?;
+    let mut num_auxiliary_nodes = 0;
+    walk_multi_path::<(), _>(P::ARITY, P::HEIGHT, indices, |_, _, known| {
+        num_auxiliary_nodes += known.iter().filter(|&&k| !k).count();
+        Ok(())
+    }).unwrap();
+    Ok(num_auxiliary_nodes)
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleTreeMultiPath<P> {
+    /// A multi path of the right shape for `indices`, to be used when its
+    /// values are not known, e.g. when generating the parameters of a circuit.
+    pub fn blank(indices: &[usize]) -> Result<Self, Error> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        let num_auxiliary_nodes = num_auxiliary_nodes::<P>(&indices)?;
+
+        Ok(Self {
+            indices,
+            auxiliary_nodes: vec![<P::H as FieldBasedHash>::Data::default(); num_auxiliary_nodes],
+        })
+    }
+
+    /// Checks that `leaves` are the leaves at `self.indices` of the tree of
+    /// root `root`.
+    pub fn verify(
+        &self,
+        root_hash: &<P::H as FieldBasedHash>::Data,
+        leaves: &[<P::H as FieldBasedHash>::Data],
+    ) -> Result<bool, Error>
+    {
+        check_indices::<P>(&self.indices)?;
+        if leaves.len() != self.indices.len() || leaves.is_empty() {
+            return Err(MerkleTreeError::IncorrectLeafIndex(leaves.len()))?
+        }
+
+        let mut auxiliary_nodes = self.auxiliary_nodes.iter();
+        let mut level_nodes = leaves.to_vec();
+        let mut next_level_nodes = Vec::with_capacity(level_nodes.len());
+        let mut current_level = 0;
+        let mut pos = 0;
+
+        let mut children = Vec::with_capacity(P::ARITY);
+        walk_multi_path::<Error, _>(P::ARITY, P::HEIGHT, &self.indices, |level, _, known| {
+            if level != current_level {
+                level_nodes = std::mem::take(&mut next_level_nodes);
+                current_level = level;
+                pos = 0;
+            }
+            children.clear();
+            for &known in known {
+                if known {
+                    children.push(level_nodes[pos]);
+                    pos += 1;
+                } else {
+                    children.push(*auxiliary_nodes
+                        .next()
+                        .ok_or(MerkleTreeError::IncorrectPathLength(self.auxiliary_nodes.len()))?
+                    );
+                }
+            }
+            next_level_nodes.push(P::H::evaluate(&children)?);
+            Ok(())
+        })?;
+
+        if auxiliary_nodes.next().is_some() {
+            return Err(MerkleTreeError::IncorrectPathLength(self.auxiliary_nodes.len()))?
+        }
+        Ok(&next_level_nodes[0] == root_hash)
+    }
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
+    /// Returns a single proof of the leaves at `indices`, hashing each node
+    /// of their paths only once.
+    pub fn generate_multi_proof(&self, indices: &[usize]) -> Result<FieldBasedMerkleTreeMultiPath<P>, Error> {
+        let prove_time = start_timer!(|| "MerkleTree::GenMultiProof");
+        let mut multi_path = FieldBasedMerkleTreeMultiPath::<P>::blank(indices)?;
+        let num_leaves = self.leaves().len();
+        if let Some(&index) = multi_path.indices.iter().find(|&&index| index >= num_leaves) {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+
+        // The levels above the root of `self.tree` are the ones of the padding
+        // tree, in which the siblings are all the empty leaf
+        let tree_height = self.tree_height();
+        let get_node = |level: usize, index: usize| {
+            if level < tree_height - 1 {
+                self.tree[level_start::<P>(tree_height - 1 - level) + index]
+            } else {
+                self.padding_tree[level - (tree_height - 1)].1
+            }
+        };
+
+        let mut auxiliary_nodes = Vec::with_capacity(multi_path.auxiliary_nodes.len());
+        walk_multi_path::<(), _>(P::ARITY, P::HEIGHT, &multi_path.indices, |level, first_child, known| {
+            for (i, &known) in known.iter().enumerate() {
+                if !known {
+                    auxiliary_nodes.push(get_node(level, first_child + i));
+                }
+            }
+            Ok(())
+        }).unwrap();
+        multi_path.auxiliary_nodes = auxiliary_nodes;
+
+        end_timer!(prove_time);
+        Ok(multi_path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::MNT4PoseidonHash,
+        merkle_tree::field_based_mht::*,
+    };
+    use algebra::{fields::mnt4753::Fr, Field, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    struct MNT4753FieldBasedMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        type H = MNT4PoseidonHash;
+    }
+
+    type MNT4753FieldBasedMerkleTree = FieldBasedMerkleHashTree<MNT4753FieldBasedMerkleTreeParams>;
+
+    #[test]
+    fn test_multi_proof() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        // Trees with and without padding
+        for &num_leaves in [32, 13].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+            let root = tree.root();
+
+            for indices in [vec![0], vec![3, 2], vec![0, 1, 2, 3], vec![1, 6, 7, 12, 6], (0..num_leaves).collect()].iter() {
+                let proof = tree.generate_multi_proof(indices).unwrap();
+                let proven_leaves = proof.indices.iter().map(|&i| leaves[i]).collect::<Vec<_>>();
+                assert!(proof.verify(&root, &proven_leaves).unwrap());
+                assert!(!proof.verify(&Fr::zero(), &proven_leaves).unwrap());
+
+                // The shared nodes are not repeated
+                assert!(proof.auxiliary_nodes.len() <= proof.indices.len() * (MNT4753FieldBasedMerkleTreeParams::HEIGHT - 1));
+                assert_eq!(
+                    proof.auxiliary_nodes.len(),
+                    FieldBasedMerkleTreeMultiPath::<MNT4753FieldBasedMerkleTreeParams>::blank(indices).unwrap().auxiliary_nodes.len()
+                );
+
+                // Other leaves are rejected
+                let mut bad_leaves = proven_leaves.clone();
+                bad_leaves[0] += &Fr::one();
+                assert!(!proof.verify(&root, &bad_leaves).unwrap());
+                assert!(proof.verify(&root, &proven_leaves[1..]).is_err());
+
+                // And so are malformed proofs
+                let mut bad_proof = proof.clone();
+                bad_proof.auxiliary_nodes.push(Fr::zero());
+                assert!(bad_proof.verify(&root, &proven_leaves).is_err());
+                let mut bad_proof = proof.clone();
+                bad_proof.indices.reverse();
+                assert!(proof.indices.len() == 1 || bad_proof.verify(&root, &proven_leaves).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_proof_single_leaf() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let leaves = (0..5).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+
+        // A multi path for one leaf is its path
+        for (i, leaf) in leaves.iter().enumerate() {
+            let path = tree.generate_proof(i, leaf).unwrap();
+            let multi_path = tree.generate_multi_proof(&[i]).unwrap();
+            assert_eq!(multi_path.auxiliary_nodes, path.path.iter().flat_map(|(siblings, _)| siblings.clone()).collect::<Vec<_>>());
+        }
+        assert!(tree.generate_multi_proof(&[8]).is_err());
+        assert!(tree.generate_multi_proof(&[]).unwrap().verify(&tree.root(), &[]).is_err());
+    }
+
+    struct MNT4753QuaternaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternaryMerkleTreeParams {
+        const HEIGHT: usize = 4;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    #[test]
+    fn test_quaternary_multi_proof() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [64, 7].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let tree = FieldBasedMerkleHashTree::<MNT4753QuaternaryMerkleTreeParams>::new(&leaves).unwrap();
+            let root = tree.root();
+
+            for indices in [vec![0], vec![1, 3], vec![0, 5, 6], (0..num_leaves).collect()].iter() {
+                let proof = tree.generate_multi_proof(indices).unwrap();
+                let proven_leaves = proof.indices.iter().map(|&i| leaves[i]).collect::<Vec<_>>();
+                assert!(proof.verify(&root, &proven_leaves).unwrap());
+                assert!(!proof.verify(&Fr::zero(), &proven_leaves).unwrap());
+
+                if let [index] = indices.as_slice() {
+                    let path = tree.generate_proof(*index, &leaves[*index]).unwrap();
+                    assert_eq!(proof.auxiliary_nodes, path.path.into_iter().flat_map(|(siblings, _)| siblings).collect::<Vec<_>>());
+                }
+            }
+        }
+    }
+}
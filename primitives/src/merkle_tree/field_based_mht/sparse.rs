@@ -0,0 +1,369 @@
+use crate::{
+    crh::FieldBasedHash,
+    merkle_tree::{
+        MerkleTreeError,
+        field_based_mht::{FieldBasedMerkleTreeConfig, FieldBasedMerkleTreeDigest, hash_empty, hash_inner_node},
+    },
+    CryptoError, Error,
+};
+use algebra::{BigInteger, PrimeField};
+use std::{collections::BTreeMap, fmt};
+
+/// Returns the position of the leaf of `key` in a tree of height `height`, as
+/// the `height - 1` little endian bits of `key`, that is the directions from
+/// the leaf to the root.
+pub fn sparse_merkle_tree_position<F: PrimeField>(key: &F, height: usize) -> Result<Vec<bool>, Error> {
+    let repr = key.into_repr();
+    if repr.num_bits() as usize > height - 1 {
+        return Err(CryptoError::InvalidElement(format!("key {} out of the tree", key)))?
+    }
+    Ok((0..height - 1).map(|i| repr.get_bit(i)).collect())
+}
+
+/// Stores the siblings of the nodes (in order) from the leaf of a key to the root.
+/// The directions are given by the key, see `sparse_merkle_tree_position`.
+#[derive(Derivative)]
+#[derivative(
+Clone(bound = "P: FieldBasedMerkleTreeConfig"),
+Debug(bound = "P: FieldBasedMerkleTreeConfig, <P::H as FieldBasedHash>::Data: fmt::Debug")
+)]
+pub struct FieldBasedSparseMerkleTreePath<P: FieldBasedMerkleTreeConfig> {
+    pub siblings: Vec<<P::H as FieldBasedHash>::Data>,
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FieldBasedSparseMerkleTreePath<P>
+    where
+        <P::H as FieldBasedHash>::Data: PrimeField,
+{
+    /// Returns the root of a tree in which the leaf of `key` is `leaf`.
+    pub fn compute_root(
+        &self,
+        key: &<P::H as FieldBasedHash>::Data,
+        leaf: &<P::H as FieldBasedHash>::Data,
+    ) -> Result<<P::H as FieldBasedHash>::Data, Error>
+    {
+        if self.siblings.len() != P::HEIGHT - 1 {
+            return Err(MerkleTreeError::IncorrectPathLength(self.siblings.len()))?
+        }
+
+        let position = sparse_merkle_tree_position(key, P::HEIGHT)?;
+        let mut prev = *leaf;
+        for (&sibling_hash, &direction) in self.siblings.iter().zip(position.iter()) {
+            prev = if direction {
+                hash_inner_node::<P::H>(sibling_hash, prev)
+            } else {
+                hash_inner_node::<P::H>(prev, sibling_hash)
+            }?;
+        }
+        Ok(prev)
+    }
+
+    /// Checks that `key` is in the tree of root `root`, with value `leaf`.
+    pub fn verify_membership(
+        &self,
+        root: &<P::H as FieldBasedHash>::Data,
+        key: &<P::H as FieldBasedHash>::Data,
+        leaf: &<P::H as FieldBasedHash>::Data,
+    ) -> Result<bool, Error>
+    {
+        Ok(*leaf != hash_empty::<P::H>()? && &self.compute_root(key, leaf)? == root)
+    }
+
+    /// Checks that `key` is not in the tree of root `root`.
+    pub fn verify_non_membership(
+        &self,
+        root: &<P::H as FieldBasedHash>::Data,
+        key: &<P::H as FieldBasedHash>::Data,
+    ) -> Result<bool, Error>
+    {
+        Ok(&self.compute_root(key, &hash_empty::<P::H>()?)? == root)
+    }
+}
+
+// The index of a node in its level
+type NodeIndex<P> = <FieldBasedMerkleTreeDigest<P> as PrimeField>::BigInt;
+
+/// Sparse Merkle Tree with a leaf for each key less than `2^(P::HEIGHT - 1)`,
+/// the keys being field elements: a key is in the tree iff its leaf is not
+/// `hash_empty`. Only the nodes which are not the root of an empty subtree are
+/// stored, so that inserting, removing and proving a key cost `P::HEIGHT`
+/// hashes, whatever the height.
+///
+/// Unlike the dense trees, this tree proves that a key is not in it, by
+/// showing that its leaf is empty: for nullifier sets, a leaf can be any
+/// non-empty value, and for maps a commitment to the value of the key.
+/// The tree is binary: `P::ARITY` must be 2.
+pub struct FieldBasedSparseMerkleTree<P: FieldBasedMerkleTreeConfig>
+    where
+        <P::H as FieldBasedHash>::Data: PrimeField,
+{
+    // The non-empty nodes, by level, starting from the leaves, and by index in
+    // the level
+    nodes:        BTreeMap<(usize, NodeIndex<P>), <P::H as FieldBasedHash>::Data>,
+    // The roots of the empty subtrees, by level, starting from the leaves
+    empty_hashes: Vec<<P::H as FieldBasedHash>::Data>,
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FieldBasedSparseMerkleTree<P>
+    where
+        <P::H as FieldBasedHash>::Data: PrimeField,
+{
+    pub const HEIGHT: u8 = P::HEIGHT as u8;
+
+    pub fn new() -> Result<Self, Error> {
+        // The bits of a key are the directions of its path, so the tree is binary
+        if P::ARITY != 2 {
+            Err(CryptoError::InvalidElement(format!("sparse Merkle tree arity {}", P::ARITY)))?
+        }
+        if P::HEIGHT <= 1 || P::HEIGHT - 1 > <P::H as FieldBasedHash>::Data::size_in_bits() {
+            Err(CryptoError::InvalidElement(format!("sparse Merkle tree height {}", P::HEIGHT)))?
+        }
+
+        let mut empty_hashes = Vec::with_capacity(P::HEIGHT);
+        empty_hashes.push(hash_empty::<P::H>()?);
+        for level in 1..P::HEIGHT {
+            let empty_hash = empty_hashes[level - 1];
+            empty_hashes.push(hash_inner_node::<P::H>(empty_hash, empty_hash)?);
+        }
+        Ok(Self { nodes: BTreeMap::new(), empty_hashes })
+    }
+
+    /// The roots of the empty subtrees, by level, starting from the leaves.
+    #[inline]
+    pub fn empty_hashes(&self) -> &[<P::H as FieldBasedHash>::Data] {
+        self.empty_hashes.as_slice()
+    }
+
+    #[inline]
+    pub fn root(&self) -> <P::H as FieldBasedHash>::Data {
+        self.get_node(P::HEIGHT - 1, &Default::default())
+    }
+
+    /// Returns the leaf of `key`, if `key` is in the tree.
+    pub fn get(&self, key: &<P::H as FieldBasedHash>::Data) -> Result<Option<<P::H as FieldBasedHash>::Data>, Error> {
+        sparse_merkle_tree_position(key, P::HEIGHT)?;
+        Ok(self.nodes.get(&(0, key.into_repr())).cloned())
+    }
+
+    pub fn contains(&self, key: &<P::H as FieldBasedHash>::Data) -> Result<bool, Error> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Sets the leaf of `key` to `leaf`, which must not be `hash_empty`.
+    pub fn insert(
+        &mut self,
+        key: &<P::H as FieldBasedHash>::Data,
+        leaf: <P::H as FieldBasedHash>::Data,
+    ) -> Result<(), Error>
+    {
+        if leaf == self.empty_hashes[0] {
+            return Err(CryptoError::InvalidElement("the leaf is the empty leaf".to_owned()))?
+        }
+        self.update_path(key, leaf)
+    }
+
+    /// Removes `key` from the tree, returning its leaf if it was in.
+    pub fn remove(&mut self, key: &<P::H as FieldBasedHash>::Data) -> Result<Option<<P::H as FieldBasedHash>::Data>, Error> {
+        let leaf = self.get(key)?;
+        if leaf.is_some() {
+            self.update_path(key, self.empty_hashes[0])?;
+        }
+        Ok(leaf)
+    }
+
+    /// Returns the path of `key`, proving either that it is in the tree or that
+    /// it is not.
+    pub fn generate_proof(&self, key: &<P::H as FieldBasedHash>::Data) -> Result<FieldBasedSparseMerkleTreePath<P>, Error> {
+        let prove_time = start_timer!(|| "SparseMerkleTree::GenProof");
+        sparse_merkle_tree_position(key, P::HEIGHT)?;
+
+        let mut index = key.into_repr();
+        let mut siblings = Vec::with_capacity(P::HEIGHT - 1);
+        for level in 0..P::HEIGHT - 1 {
+            siblings.push(self.get_node(level, &sibling_index(&index)));
+            index.div2();
+        }
+
+        end_timer!(prove_time);
+        Ok(FieldBasedSparseMerkleTreePath { siblings })
+    }
+
+    // Sets the leaf of `key`, and recomputes the nodes from it to the root,
+    // dropping those which became empty.
+    fn update_path(
+        &mut self,
+        key: &<P::H as FieldBasedHash>::Data,
+        leaf: <P::H as FieldBasedHash>::Data,
+    ) -> Result<(), Error>
+    {
+        let position = sparse_merkle_tree_position(key, P::HEIGHT)?;
+
+        let mut index = key.into_repr();
+        let mut current_hash = leaf;
+        self.set_node(0, index, current_hash);
+        for (level, &direction) in position.iter().enumerate() {
+            let sibling_hash = self.get_node(level, &sibling_index(&index));
+            current_hash = if direction {
+                hash_inner_node::<P::H>(sibling_hash, current_hash)
+            } else {
+                hash_inner_node::<P::H>(current_hash, sibling_hash)
+            }?;
+            index.div2();
+            self.set_node(level + 1, index, current_hash);
+        }
+        Ok(())
+    }
+
+    fn get_node(
+        &self,
+        level: usize,
+        index: &NodeIndex<P>,
+    ) -> <P::H as FieldBasedHash>::Data
+    {
+        self.nodes.get(&(level, *index)).cloned().unwrap_or(self.empty_hashes[level])
+    }
+
+    fn set_node(
+        &mut self,
+        level: usize,
+        index: NodeIndex<P>,
+        node: <P::H as FieldBasedHash>::Data,
+    )
+    {
+        if node == self.empty_hashes[level] {
+            self.nodes.remove(&(level, index));
+        } else {
+            self.nodes.insert((level, index), node);
+        }
+    }
+}
+
+// Flips the least significant bit of `index`
+fn sibling_index<B: BigInteger>(index: &B) -> B {
+    let mut sibling = *index;
+    sibling.as_mut()[0] ^= 1;
+    sibling
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::MNT4PoseidonHash,
+        merkle_tree::field_based_mht::*,
+    };
+    use algebra::{fields::mnt4753::Fr, Field, PrimeField, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    struct MNT4753SparseMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753SparseMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        type H = MNT4PoseidonHash;
+    }
+
+    // A tree with a leaf for each field element
+    struct MNT4753FullSparseMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753FullSparseMerkleTreeParams {
+        const HEIGHT: usize = 754;
+        type H = MNT4PoseidonHash;
+    }
+
+    type MNT4753FieldBasedMerkleTree = FieldBasedMerkleHashTree<MNT4753SparseMerkleTreeParams>;
+    type MNT4753SparseMerkleTree = FieldBasedSparseMerkleTree<MNT4753SparseMerkleTreeParams>;
+    type MNT4753FullSparseMerkleTree = FieldBasedSparseMerkleTree<MNT4753FullSparseMerkleTreeParams>;
+
+    #[test]
+    fn test_membership_and_non_membership() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = MNT4753SparseMerkleTree::new().unwrap();
+        let empty_root = tree.root();
+        assert_eq!(empty_root, tree.empty_hashes()[5]);
+
+        let keys = [3u64, 4, 17, 31].iter().map(|&k| Fr::from(k)).collect::<Vec<_>>();
+        let leaves = (0..keys.len()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            tree.insert(key, *leaf).unwrap();
+        }
+
+        // The root is the one of the dense tree with the same leaves
+        let mut dense_leaves = vec![tree.empty_hashes()[0]; 32];
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            dense_leaves[key.into_repr().as_ref()[0] as usize] = *leaf;
+        }
+        let root = tree.root();
+        assert_eq!(root, MNT4753FieldBasedMerkleTree::new(&dense_leaves).unwrap().root());
+
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            assert_eq!(tree.get(key).unwrap(), Some(*leaf));
+            let proof = tree.generate_proof(key).unwrap();
+            assert!(proof.verify_membership(&root, key, leaf).unwrap());
+            assert!(!proof.verify_non_membership(&root, key).unwrap());
+            assert!(!proof.verify_membership(&root, key, &Fr::rand(&mut rng)).unwrap());
+        }
+        for key in [0u64, 5, 16, 30].iter().map(|&k| Fr::from(k)) {
+            assert!(!tree.contains(&key).unwrap());
+            let proof = tree.generate_proof(&key).unwrap();
+            assert!(proof.verify_non_membership(&root, &key).unwrap());
+            assert!(!proof.verify_membership(&root, &key, &tree.empty_hashes()[0]).unwrap());
+
+            // The path of a key doesn't prove the non-membership of another
+            assert!(!proof.verify_non_membership(&root, &keys[0]).unwrap());
+        }
+
+        // Keys out of the tree are rejected
+        assert!(tree.insert(&Fr::from(32u64), Fr::one()).is_err());
+        assert!(tree.generate_proof(&-Fr::one()).is_err());
+        assert!(tree.insert(&keys[0], tree.empty_hashes()[0]).is_err());
+
+        // Removing the keys restores the empty tree
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            assert_eq!(tree.remove(key).unwrap(), Some(*leaf));
+            assert_eq!(tree.remove(key).unwrap(), None);
+        }
+        assert_eq!(tree.root(), empty_root);
+        assert!(tree.nodes.is_empty());
+    }
+
+    #[test]
+    fn test_full_height() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = MNT4753FullSparseMerkleTree::new().unwrap();
+
+        let keys = (0..4).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        for key in keys.iter() {
+            tree.insert(key, Fr::one()).unwrap();
+        }
+        let root = tree.root();
+        for key in keys.iter() {
+            let proof = tree.generate_proof(key).unwrap();
+            assert!(proof.verify_membership(&root, key, &Fr::one()).unwrap());
+        }
+
+        let key = -Fr::one();
+        let proof = tree.generate_proof(&key).unwrap();
+        assert!(proof.verify_non_membership(&root, &key).unwrap());
+    }
+
+    struct MNT4753QuaternarySparseMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternarySparseMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    struct MNT4753TooHighSparseMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753TooHighSparseMerkleTreeParams {
+        const HEIGHT: usize = 755;
+        type H = MNT4PoseidonHash;
+    }
+
+    #[test]
+    fn test_unsupported_parameters() {
+        assert!(FieldBasedSparseMerkleTree::<MNT4753QuaternarySparseMerkleTreeParams>::new().is_err());
+        assert!(FieldBasedSparseMerkleTree::<MNT4753TooHighSparseMerkleTreeParams>::new().is_err());
+    }
+}
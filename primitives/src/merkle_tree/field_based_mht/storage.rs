@@ -0,0 +1,266 @@
+use crate::Error;
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// A key-value store holding the nodes of a Merkle tree, so that the tree can
+/// be kept in memory, on disk, or in an external database.
+pub trait MerkleTreeStorage {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error>;
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), Error>;
+
+    /// Makes the values put so far persistent, if the store is.
+    fn flush(&mut self) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryMerkleTreeStorage {
+    map: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl InMemoryMerkleTreeStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MerkleTreeStorage for InMemoryMerkleTreeStorage {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.map.get(key).cloned())
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), Error> {
+        self.map.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A store backed by an append-only log of batches, one per `flush`, each
+/// record being `batch_len (u32 LE) || crc32(batch) (u32 LE) || batch`, with
+/// `batch` the concatenation of `key_len (u32 LE) || key || value_len (u32 LE)
+/// || value` for each value put since the previous flush. The values put are
+/// kept in memory until flushed, so that a batch is either fully persisted or
+/// not at all.
+///
+/// The log is replayed when the store is opened, stopping at the first record
+/// cut by a crash or failing its checksum, so that the store reflects the
+/// batches flushed so far. The latest value of every key is kept in memory, so
+/// the whole tree must fit in RAM, and the log only shrinks with `compact`.
+pub struct FileMerkleTreeStorage {
+    path:    PathBuf,
+    map:     HashMap<Vec<u8>, Vec<u8>>,
+    // The entries put since the last flush
+    pending: Vec<u8>,
+    file:    File,
+}
+
+impl FileMerkleTreeStorage {
+    /// Opens the store at `path`, creating it if it doesn't exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(&path)?;
+
+        let mut log = Vec::new();
+        file.read_to_end(&mut log)?;
+
+        let mut map = HashMap::new();
+        let mut pos = 0;
+        while let Some((batch, next)) = read_record(&log, pos) {
+            let mut entry_pos = 0;
+            while entry_pos < batch.len() {
+                let (key, value, next_entry) = read_entry(batch, entry_pos)
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed Merkle tree log batch"))?;
+                map.insert(key, value);
+                entry_pos = next_entry;
+            }
+            pos = next;
+        }
+        // Drop the last record if incomplete or corrupted
+        if pos != log.len() {
+            file.set_len(pos as u64)?;
+            file.sync_data()?;
+        }
+
+        let file = OpenOptions::new().append(true).open(&path)?;
+        Ok(Self { path, map, pending: Vec::new(), file })
+    }
+
+    /// Flushes the pending values, then rewrites the log as a single batch
+    /// with the latest value of each key.
+    pub fn compact(&mut self) -> Result<(), Error> {
+        self.flush()?;
+
+        let tmp_path = self.path.with_extension("compact");
+        {
+            let mut batch = Vec::new();
+            for (key, value) in self.map.iter() {
+                write_entry(&mut batch, key, value);
+            }
+            let mut tmp = File::create(&tmp_path)?;
+            write_record(&mut tmp, &batch)?;
+            tmp.sync_data()?;
+        }
+        fs::rename(&tmp_path, &self.path)?;
+
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        Ok(())
+    }
+}
+
+impl MerkleTreeStorage for FileMerkleTreeStorage {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.map.get(key).cloned())
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), Error> {
+        write_entry(&mut self.pending, key, value);
+        self.map.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        write_record(&mut self.file, &self.pending)?;
+        self.file.sync_data()?;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+fn write_entry(batch: &mut Vec<u8>, key: &[u8], value: &[u8]) {
+    batch.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    batch.extend_from_slice(key);
+    batch.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    batch.extend_from_slice(value);
+}
+
+// Writes the batch with a single `write_all`, so that it is not split by
+// buffering.
+fn write_record<W: Write>(writer: &mut W, batch: &[u8]) -> Result<(), Error> {
+    let mut record = Vec::with_capacity(batch.len() + 8);
+    record.extend_from_slice(&(batch.len() as u32).to_le_bytes());
+    record.extend_from_slice(&crc32(batch).to_le_bytes());
+    record.extend_from_slice(batch);
+    writer.write_all(&record)?;
+    Ok(())
+}
+
+// Reads the length-prefixed chunk starting at `pos`, returning it together
+// with the position following it, or None if the chunk is incomplete.
+fn read_chunk(bytes: &[u8], pos: usize) -> Option<(&[u8], usize)> {
+    let mut len = [0u8; 4];
+    len.copy_from_slice(bytes.get(pos..pos + 4)?);
+    let end = pos.checked_add(4 + u32::from_le_bytes(len) as usize)?;
+    Some((bytes.get(pos + 4..end)?, end))
+}
+
+// Reads the batch of the record starting at `pos`, returning it together with
+// the position of the next record, or None if the record is incomplete or
+// fails its checksum.
+fn read_record(log: &[u8], pos: usize) -> Option<(&[u8], usize)> {
+    let mut len = [0u8; 4];
+    len.copy_from_slice(log.get(pos..pos + 4)?);
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(log.get(pos + 4..pos + 8)?);
+    let end = (pos + 8).checked_add(u32::from_le_bytes(len) as usize)?;
+    let batch = log.get(pos + 8..end)?;
+    if crc32(batch) != u32::from_le_bytes(checksum) {
+        return None;
+    }
+    Some((batch, end))
+}
+
+// Reads the entry of a batch starting at `pos`, returning it together with
+// the position of the next one, or None if the entry is incomplete.
+fn read_entry(batch: &[u8], pos: usize) -> Option<(Vec<u8>, Vec<u8>, usize)> {
+    let (key, pos) = read_chunk(batch, pos)?;
+    let (value, pos) = read_chunk(batch, pos)?;
+    Some((key.to_vec(), value.to_vec(), pos))
+}
+
+// The CRC-32 (IEEE 802.3) of `bytes`.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (!(crc & 1)).wrapping_add(1));
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_file_storage() {
+        let path = std::env::temp_dir().join(format!("file_storage_test_{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut storage = FileMerkleTreeStorage::open(&path).unwrap();
+            storage.put(b"a", b"1").unwrap();
+            storage.put(b"b", b"2").unwrap();
+            storage.put(b"a", b"3").unwrap();
+            storage.flush().unwrap();
+        }
+
+        // The latest values are found when reopening the store
+        let mut storage = FileMerkleTreeStorage::open(&path).unwrap();
+        assert_eq!(storage.get(b"a").unwrap(), Some(b"3".to_vec()));
+        assert_eq!(storage.get(b"b").unwrap(), Some(b"2".to_vec()));
+        assert_eq!(storage.get(b"c").unwrap(), None);
+
+        // Also after compacting the log
+        let len = fs::metadata(&path).unwrap().len();
+        storage.compact().unwrap();
+        assert!(fs::metadata(&path).unwrap().len() < len);
+        storage.put(b"c", b"4").unwrap();
+        storage.flush().unwrap();
+        drop(storage);
+
+        // An incomplete record is discarded
+        let len = fs::metadata(&path).unwrap().len();
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(&[5, 0, 0, 0, b'd']).unwrap();
+        let mut storage = FileMerkleTreeStorage::open(&path).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+        assert_eq!(storage.get(b"a").unwrap(), Some(b"3".to_vec()));
+        assert_eq!(storage.get(b"c").unwrap(), Some(b"4".to_vec()));
+        assert_eq!(storage.get(b"d").unwrap(), None);
+
+        // So is a batch failing its checksum, as a whole
+        storage.put(b"d", b"5").unwrap();
+        storage.put(b"a", b"6").unwrap();
+        storage.flush().unwrap();
+        drop(storage);
+        let mut log = fs::read(&path).unwrap();
+        let last = log.len() - 1;
+        log[last] ^= 1;
+        fs::write(&path, &log).unwrap();
+        let mut storage = FileMerkleTreeStorage::open(&path).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+        assert_eq!(storage.get(b"a").unwrap(), Some(b"3".to_vec()));
+        assert_eq!(storage.get(b"d").unwrap(), None);
+
+        // And the values not flushed are not persisted
+        storage.put(b"d", b"7").unwrap();
+        drop(storage);
+        let storage = FileMerkleTreeStorage::open(&path).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+        assert_eq!(storage.get(b"d").unwrap(), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
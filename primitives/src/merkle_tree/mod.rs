@@ -1,6 +1,9 @@
 use crate::{crh::FixedLengthCRH, Error};
-use algebra::bytes::ToBytes;
-use std::{fmt, rc::Rc};
+use algebra::bytes::{FromBytes, ToBytes};
+use std::{
+    fmt, rc::Rc,
+    io::{self, Read, Result as IoResult, Write},
+};
 
 pub mod field_based_mht;
 
@@ -79,6 +82,48 @@ impl<P: MerkleTreeConfig> MerkleTreePath<P> {
     }
 }
 
+impl<P: MerkleTreeConfig> ToBytes for MerkleTreePath<P> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.path.len() as u32).write(&mut writer)?;
+        for (sibling_hash, direction) in self.path.iter() {
+            sibling_hash.write(&mut writer)?;
+            direction.write(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: MerkleTreeConfig> FromBytes for MerkleTreePath<P>
+    where
+        <P::H as FixedLengthCRH>::Output: FromBytes,
+{
+    /// Fails if the length of the path is not `P::HEIGHT - 1`.
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let len = u32::read(&mut reader)? as usize;
+        check_path_length(len, P::HEIGHT)?;
+        let mut path = Vec::with_capacity(len);
+        for _ in 0..len {
+            let sibling_hash = <P::H as FixedLengthCRH>::Output::read(&mut reader)?;
+            let direction = bool::read(&mut reader)?;
+            path.push((sibling_hash, direction));
+        }
+        Ok(Self { path })
+    }
+}
+
+/// Returns an `InvalidData` error if `len` is not the length of the paths of
+/// a tree of height `height`.
+pub(crate) fn check_path_length(len: usize, height: usize) -> IoResult<()> {
+    if len != height - 1 {
+        return Err(invalid_data(MerkleTreeError::IncorrectPathLength(len)));
+    }
+    Ok(())
+}
+
+fn invalid_data(e: MerkleTreeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
 pub struct MerkleHashTree<P: MerkleTreeConfig> {
     tree:         Vec<<P::H as FixedLengthCRH>::Output>,
     padding_tree: Vec<(
@@ -233,6 +278,7 @@ impl<P: MerkleTreeConfig> MerkleHashTree<P> {
 pub enum MerkleTreeError {
     IncorrectLeafIndex(usize),
     IncorrectPathLength(usize),
+    TooManyLeaves(usize),
 }
 
 impl std::fmt::Display for MerkleTreeError {
@@ -242,6 +288,7 @@ impl std::fmt::Display for MerkleTreeError {
                 format!("incorrect leaf index: {}", index)
             },
             MerkleTreeError::IncorrectPathLength(len) => format!("incorrect path length: {}", len),
+            MerkleTreeError::TooManyLeaves(max) => format!("reached maximum number of leaves: {}", max),
         };
         write!(f, "{}", msg)
     }
@@ -457,4 +504,26 @@ mod test {
         }
         bad_merkle_tree_verify(&leaves);
     }
+
+    #[test]
+    fn serialization_test() {
+        use algebra::{to_bytes, FromBytes};
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        let leaves = (0..7u8).map(|i| [i; 8]).collect::<Vec<_>>();
+        let crh_parameters = Rc::new(H::setup(&mut rng).unwrap());
+        let tree = JubJubMerkleTree::new(crh_parameters.clone(), &leaves).unwrap();
+        let proof = tree.generate_proof(3, &leaves[3]).unwrap();
+
+        let proof_bytes = to_bytes!(proof).unwrap();
+        let read_proof = MerkleTreePath::<JubJubMerkleTreeParams>::read(proof_bytes.as_slice()).unwrap();
+        assert_eq!(read_proof.path, proof.path);
+        assert!(read_proof.verify(&crh_parameters, &tree.root(), &leaves[3]).unwrap());
+
+        // A path of the wrong length is rejected
+        let mut short_proof = proof.clone();
+        short_proof.path.pop();
+        assert!(MerkleTreePath::<JubJubMerkleTreeParams>::read(to_bytes!(short_proof).unwrap().as_slice()).is_err());
+        assert!(MerkleTreePath::<JubJubMerkleTreeParams>::read(&proof_bytes[..proof_bytes.len() - 1]).is_err());
+    }
 }
@@ -0,0 +1,677 @@
+//! A polynomial commitment scheme based on the [inner product argument][bulletproofs],
+//! in the variant "PC_DL" of [BCMS20][bcms20], over any prime order group in
+//! which the discrete logarithm is hard.
+//!
+//! The setup is transparent: the commitment key is derived by hashing to the
+//! curve, so that no one knows the discrete logarithms between its elements.
+//! Commitments are not hiding.
+//!
+//! Checking an opening proof splits into a succinct part, logarithmic in the
+//! degree, which reduces the claim to an `Accumulator`, and a linear time
+//! check of the accumulator. The latter can be deferred and amortized over
+//! many accumulators with `check_accumulators`.
+//!
+//! [bulletproofs]: https://eprint.iacr.org/2017/1066
+//! [bcms20]: https://eprint.iacr.org/2020/499
+use algebra::{
+    bytes::{FromBytes, ToBytes},
+    fft::DensePolynomial,
+    fields::batch_inversion,
+    msm::VariableBaseMSM,
+    to_bytes, AffineCurve, Field, FpParameters, FromBits, PrimeField, ProjectiveCurve,
+    ToConstraintField, UniformRand,
+};
+use blake2::Blake2b;
+use digest::Digest;
+use rand::Rng;
+use rayon::prelude::*;
+use std::{
+    io::{self, Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+use crate::{bytes_to_bits, crh::FieldBasedHash, poly_commit::PolynomialCommitment, CryptoError, Error};
+
+/// The domain separator used both to derive the commitment key and to
+/// initialize the Fiat-Shamir transcript.
+pub const PROTOCOL_NAME: &[u8] = b"PC-DL-2020";
+
+/// Maximum number of rounds accepted when deserializing a proof.
+const MAX_ROUNDS: usize = 64;
+
+/// The inner product argument over the curve `G`, made non-interactive with
+/// the hash `H` over the base field of `G` (typically Poseidon).
+pub struct InnerProductArgPC<G: AffineCurve, H: FieldBasedHash<Data = G::BaseField>> {
+    _group: PhantomData<G>,
+    _hash:  PhantomData<H>,
+}
+
+/// The generators `G_i` committing to the coefficients, and the generator
+/// `S` binding the evaluation in the opening proofs. The committer and the
+/// verifier use the same key.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: AffineCurve"),
+    Debug(bound = "G: AffineCurve"),
+    PartialEq(bound = "G: AffineCurve"),
+    Eq(bound = "G: AffineCurve")
+)]
+pub struct CommitterKey<G: AffineCurve> {
+    pub comm_key: Vec<G>,
+    pub s:        G,
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// The maximum degree of the polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+pub type UniversalParams<G> = CommitterKey<G>;
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// An opening proof: the cross terms `L_j, R_j` of every round, and the
+/// folded commitment key and coefficient of the last round.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: AffineCurve"),
+    Debug(bound = "G: AffineCurve"),
+    PartialEq(bound = "G: AffineCurve"),
+    Eq(bound = "G: AffineCurve")
+)]
+pub struct Proof<G: AffineCurve> {
+    pub l_vec:          Vec<G>,
+    pub r_vec:          Vec<G>,
+    pub final_comm_key: G,
+    pub c:              G::ScalarField,
+}
+
+impl<G: AffineCurve> ToBytes for Proof<G> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.l_vec.len() as u32).write(&mut writer)?;
+        for (l, r) in self.l_vec.iter().zip(self.r_vec.iter()) {
+            l.write(&mut writer)?;
+            r.write(&mut writer)?;
+        }
+        self.final_comm_key.write(&mut writer)?;
+        self.c.write(&mut writer)
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Proof<G> {
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let rounds = u32::read(&mut reader)? as usize;
+        if rounds > MAX_ROUNDS {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "too many rounds"));
+        }
+        let mut l_vec = Vec::with_capacity(rounds);
+        let mut r_vec = Vec::with_capacity(rounds);
+        for _ in 0..rounds {
+            l_vec.push(G::read(&mut reader)?);
+            r_vec.push(G::read(&mut reader)?);
+        }
+        let final_comm_key = G::read(&mut reader)?;
+        let c = G::ScalarField::read(&mut reader)?;
+        Ok(Self { l_vec, r_vec, final_comm_key, c })
+    }
+}
+
+/// The polynomial `h(X) = prod_j (u_j^-1 + u_j * X^(2^(k-1-j)))` defined by the
+/// round challenges `u_0, ..., u_{k-1}` of an opening proof. The final
+/// commitment key of a valid proof is the commitment to `h(X)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuccinctCheckPolynomial<F: Field>(pub Vec<F>);
+
+impl<F: Field> SuccinctCheckPolynomial<F> {
+    fn inverse_challenges(&self) -> Vec<F> {
+        let mut inverses = self.0.clone();
+        batch_inversion(&mut inverses);
+        inverses
+    }
+
+    /// Evaluates `h(point)` in time logarithmic in the degree.
+    pub fn evaluate(&self, point: F) -> F {
+        let mut power = point;
+        let mut result = F::one();
+        for (u, u_inv) in self.0.iter().zip(self.inverse_challenges()).rev() {
+            result *= &(u_inv + &(*u * &power));
+            power.square_in_place();
+        }
+        result
+    }
+
+    /// Returns the coefficients of `h(X)`.
+    pub fn compute_coeffs(&self) -> Vec<F> {
+        let mut coeffs = vec![F::one()];
+        for (u, u_inv) in self.0.iter().zip(self.inverse_challenges()).rev() {
+            let high = coeffs.iter().map(|c| *c * u).collect::<Vec<_>>();
+            coeffs.iter_mut().for_each(|c| *c *= &u_inv);
+            coeffs.extend(high);
+        }
+        coeffs
+    }
+}
+
+/// The claim, left by a succinct check, that `final_comm_key` commits to the
+/// polynomial `check_poly`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: AffineCurve"),
+    Debug(bound = "G: AffineCurve"),
+    PartialEq(bound = "G: AffineCurve"),
+    Eq(bound = "G: AffineCurve")
+)]
+pub struct Accumulator<G: AffineCurve> {
+    pub check_poly:     SuccinctCheckPolynomial<G::ScalarField>,
+    pub final_comm_key: G,
+}
+
+/// A Fiat-Shamir transcript chaining the hash `H` over its own output.
+struct Transcript<H: FieldBasedHash> {
+    state: H::Data,
+}
+
+impl<H: FieldBasedHash> Transcript<H>
+where
+    H::Data: PrimeField,
+{
+    fn new(key_size: usize) -> Result<Self, Error> {
+        let mut transcript = Self { state: H::Data::from(key_size as u64) };
+        transcript.absorb_bytes(PROTOCOL_NAME)?;
+        Ok(transcript)
+    }
+
+    fn absorb(&mut self, elems: &[H::Data]) -> Result<(), Error> {
+        let mut input = Vec::with_capacity(elems.len() + 1);
+        input.push(self.state);
+        input.extend_from_slice(elems);
+        self.state = H::evaluate(&input)?;
+        Ok(())
+    }
+
+    fn absorb_points<G: ToConstraintField<H::Data>>(&mut self, points: &[G]) -> Result<(), Error> {
+        let mut elems = vec![];
+        for p in points {
+            elems.extend(p.to_field_elements()?);
+        }
+        self.absorb(&elems)
+    }
+
+    /// Absorbs `bytes`, packed in field elements of `CAPACITY` bits.
+    fn absorb_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let capacity = <H::Data as PrimeField>::Params::CAPACITY as usize;
+        let elems = bytes_to_bits(bytes)
+            .chunks(capacity)
+            .map(|chunk| H::Data::read_bits(chunk.to_vec()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.absorb(&elems)
+    }
+
+    /// Absorbs elements of a field other than `H::Data`, as bytes.
+    fn absorb_scalars<F: PrimeField>(&mut self, scalars: &[F]) -> Result<(), Error> {
+        let mut bytes = vec![];
+        for s in scalars {
+            s.write(&mut bytes)?;
+        }
+        self.absorb_bytes(&bytes)
+    }
+
+    /// Derives a challenge from the lowest 128 bits of the next state.
+    fn squeeze_128_bits_challenge<F: PrimeField>(&mut self) -> Result<F, Error> {
+        self.state = H::evaluate(&[self.state])?;
+        let bytes = to_bytes!(self.state)?;
+        let mut lowest_bytes = [0u8; 16];
+        lowest_bytes.copy_from_slice(&bytes[..16]);
+        Ok(F::from(u128::from_le_bytes(lowest_bytes)))
+    }
+}
+
+/// Hashes `(PROTOCOL_NAME, i, counter)` to a candidate x-coordinate for
+/// increasing values of `counter`, until it is the one of a point of the
+/// prime order subgroup.
+fn sample_generators<G: AffineCurve>(num: usize) -> Vec<G>
+where
+    G::BaseField: PrimeField,
+{
+    (0..num)
+        .into_par_iter()
+        .map(|i| {
+            let mut counter = 0u64;
+            loop {
+                let mut bytes = Vec::with_capacity(128);
+                for block in 0u8..2 {
+                    let mut h = Blake2b::default();
+                    h.input(PROTOCOL_NAME);
+                    h.input(&(i as u64).to_le_bytes());
+                    h.input(&counter.to_le_bytes());
+                    h.input(&[block]);
+                    bytes.extend_from_slice(h.result().as_slice());
+                }
+                counter += 1;
+
+                if let Some(x) = G::BaseField::from_random_bytes(&bytes) {
+                    let mut compressed = to_bytes!(x).unwrap();
+                    compressed.push(0u8);
+                    if let Ok(g) = G::read_compressed(compressed.as_slice()) {
+                        let g = g.mul_by_cofactor();
+                        if !g.is_zero() {
+                            return g;
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).fold(F::zero(), |acc, (a, b)| acc + &(*a * b))
+}
+
+fn msm<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    let scalars = scalars.par_iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(&bases[..scalars.len()], &scalars)
+}
+
+fn normalize<P: ProjectiveCurve>(mut points: Vec<P>) -> Vec<P::Affine> {
+    P::batch_normalization(&mut points);
+    points.into_par_iter().map(|p| p.into_affine()).collect()
+}
+
+impl<G, H> InnerProductArgPC<G, H>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    H: FieldBasedHash<Data = G::BaseField>,
+{
+    /// Starts the transcript of an opening of `commitment` at `point` to
+    /// `value`, and derives from it the generator binding the evaluation.
+    fn init_transcript(
+        key: &CommitterKey<G>,
+        commitment: &G,
+        point: G::ScalarField,
+        value: G::ScalarField,
+    ) -> Result<(Transcript<H>, G), Error> {
+        let mut transcript = Transcript::<H>::new(key.comm_key.len())?;
+        transcript.absorb_points(&[*commitment])?;
+        transcript.absorb_scalars(&[point, value])?;
+        let xi = transcript.squeeze_128_bits_challenge::<G::ScalarField>()?;
+        Ok((transcript, key.s.mul(xi).into_affine()))
+    }
+
+    /// Reduces the claim that `values` are the evaluations at `point` of the
+    /// polynomials behind `commitments` to an accumulator, in time logarithmic
+    /// in the degree. Returns `None` if the proof is invalid; the claim holds
+    /// iff the accumulator is valid.
+    pub fn succinct_check(
+        vk: &VerifierKey<G>,
+        commitments: &[G],
+        point: G::ScalarField,
+        values: &[G::ScalarField],
+        proof: &Proof<G>,
+        opening_challenge: G::ScalarField,
+    ) -> Result<Option<Accumulator<G>>, Error> {
+        if commitments.len() != values.len() {
+            return Err(Box::new(CryptoError::IncorrectInputLength("values".to_owned(), values.len())));
+        }
+        let rounds = vk.comm_key.len().trailing_zeros() as usize;
+        if proof.l_vec.len() != rounds || proof.r_vec.len() != rounds {
+            return Ok(None);
+        }
+
+        let check_time = start_timer!(|| "IPA succinct check");
+
+        let mut combined_commitment = G::Projective::zero();
+        let mut combined_value = G::ScalarField::zero();
+        let mut challenge_power = G::ScalarField::one();
+        for (c, v) in commitments.iter().zip(values) {
+            combined_commitment += &c.mul(challenge_power);
+            combined_value += &(challenge_power * v);
+            challenge_power *= &opening_challenge;
+        }
+
+        let (mut transcript, h_prime) =
+            Self::init_transcript(vk, &combined_commitment.into_affine(), point, combined_value)?;
+
+        let mut round_commitment = combined_commitment + &h_prime.mul(combined_value);
+        let mut challenges = Vec::with_capacity(rounds);
+        for (l, r) in proof.l_vec.iter().zip(proof.r_vec.iter()) {
+            transcript.absorb_points(&[*l, *r])?;
+            let u = transcript.squeeze_128_bits_challenge::<G::ScalarField>()?;
+            let u_inv = match u.inverse() {
+                Some(u_inv) => u_inv,
+                None => {
+                    end_timer!(check_time);
+                    return Ok(None);
+                },
+            };
+            round_commitment += &l.mul(u.square());
+            round_commitment += &r.mul(u_inv.square());
+            challenges.push(u);
+        }
+
+        let check_poly = SuccinctCheckPolynomial(challenges);
+        let v_prime = check_poly.evaluate(point) * &proof.c;
+        let expected = proof.final_comm_key.mul(proof.c) + &h_prime.mul(v_prime);
+
+        end_timer!(check_time);
+        if round_commitment == expected {
+            Ok(Some(Accumulator { check_poly, final_comm_key: proof.final_comm_key }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Checks several accumulators at the cost of a single multi-scalar
+    /// multiplication of the size of the commitment key, by combining them
+    /// with the powers of a random scalar.
+    pub fn check_accumulators<R: Rng>(
+        vk: &VerifierKey<G>,
+        accumulators: &[Accumulator<G>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let rounds = vk.comm_key.len().trailing_zeros() as usize;
+        if accumulators.iter().any(|acc| acc.check_poly.0.len() != rounds) {
+            return Ok(false);
+        }
+
+        let check_time = start_timer!(|| format!("IPA check of {} accumulators", accumulators.len()));
+
+        let r = G::ScalarField::rand(rng);
+        let mut r_power = G::ScalarField::one();
+        let mut combined_coeffs = vec![G::ScalarField::zero(); vk.comm_key.len()];
+        let mut combined_comm_key = G::Projective::zero();
+        for acc in accumulators {
+            for (c, s) in combined_coeffs.iter_mut().zip(acc.check_poly.compute_coeffs()) {
+                *c += &(r_power * &s);
+            }
+            combined_comm_key += &acc.final_comm_key.mul(r_power);
+            r_power *= &r;
+        }
+        let result = msm(&vk.comm_key, &combined_coeffs) == combined_comm_key;
+
+        end_timer!(check_time);
+        Ok(result)
+    }
+}
+
+impl<G, H> PolynomialCommitment for InnerProductArgPC<G, H>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    H: FieldBasedHash<Data = G::BaseField>,
+{
+    type Field = G::ScalarField;
+    type Parameters = UniversalParams<G>;
+    type CommitterKey = CommitterKey<G>;
+    type VerifierKey = VerifierKey<G>;
+    type Commitment = G;
+    type Proof = Proof<G>;
+
+    /// Derives the commitment key deterministically, without using `rng`. The
+    /// key size is rounded up to a power of two.
+    fn setup<R: Rng>(max_degree: usize, _rng: &mut R) -> Result<Self::Parameters, Error> {
+        let setup_time = start_timer!(|| format!("IPA setup for degree {}", max_degree));
+        let key_size = (max_degree + 1).next_power_of_two();
+        let mut generators = sample_generators::<G>(key_size + 1);
+        let s = generators.remove(0);
+        end_timer!(setup_time);
+        Ok(CommitterKey { comm_key: generators, s })
+    }
+
+    fn trim(
+        params: &Self::Parameters,
+        supported_degree: usize,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Error> {
+        let key_size = (supported_degree + 1).next_power_of_two();
+        if key_size > params.comm_key.len() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "supported degree".to_owned(),
+                supported_degree,
+            )));
+        }
+
+        let ck = CommitterKey { comm_key: params.comm_key[..key_size].to_vec(), s: params.s };
+        Ok((ck.clone(), ck))
+    }
+
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<G::ScalarField>,
+    ) -> Result<Self::Commitment, Error> {
+        if polynomial.coeffs.len() > ck.comm_key.len() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "polynomial".to_owned(),
+                polynomial.coeffs.len(),
+            )));
+        }
+
+        let commit_time = start_timer!(|| format!("Committing to polynomial of degree {}", polynomial.degree()));
+        let commitment = msm(&ck.comm_key, &polynomial.coeffs).into_affine();
+        end_timer!(commit_time);
+
+        Ok(commitment)
+    }
+
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomials: &[&DensePolynomial<G::ScalarField>],
+        point: G::ScalarField,
+        opening_challenge: G::ScalarField,
+    ) -> Result<Self::Proof, Error> {
+        let open_time = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+
+        let mut combined = DensePolynomial::zero();
+        let mut challenge_power = G::ScalarField::one();
+        for p in polynomials {
+            combined += (challenge_power, *p);
+            challenge_power *= &opening_challenge;
+        }
+        let commitment = Self::commit(ck, &combined)?;
+        let value = combined.evaluate(point);
+
+        let (mut transcript, h_prime) = Self::init_transcript(ck, &commitment, point, value)?;
+
+        // Fold the coefficients a, the powers of the point b and the key G in
+        // halves, until a single element remains
+        let key_size = ck.comm_key.len();
+        let mut a = combined.coeffs;
+        a.resize(key_size, G::ScalarField::zero());
+        let mut b = Vec::with_capacity(key_size);
+        let mut cur = G::ScalarField::one();
+        for _ in 0..key_size {
+            b.push(cur);
+            cur *= &point;
+        }
+        let mut key = ck.comm_key.clone();
+
+        let mut l_vec = vec![];
+        let mut r_vec = vec![];
+        while a.len() > 1 {
+            let m = a.len() / 2;
+            let (a_l, a_r) = a.split_at(m);
+            let (b_l, b_r) = b.split_at(m);
+            let (key_l, key_r) = key.split_at(m);
+
+            let l = msm(key_r, a_l) + &h_prime.mul(inner_product(a_l, b_r));
+            let r = msm(key_l, a_r) + &h_prime.mul(inner_product(a_r, b_l));
+            let lr = normalize(vec![l, r]);
+            transcript.absorb_points(&lr)?;
+            l_vec.push(lr[0]);
+            r_vec.push(lr[1]);
+
+            let u = transcript.squeeze_128_bits_challenge::<G::ScalarField>()?;
+            let u_inv = u.inverse().ok_or_else(|| CryptoError::InvalidElement("round challenge".to_owned()))?;
+
+            let new_a = (0..m).map(|i| a_l[i] * &u + &(a_r[i] * &u_inv)).collect();
+            let new_b = (0..m).map(|i| b_l[i] * &u_inv + &(b_r[i] * &u)).collect();
+            let new_key = (0..m)
+                .into_par_iter()
+                .map(|i| key_l[i].mul(u_inv) + &key_r[i].mul(u))
+                .collect();
+            a = new_a;
+            b = new_b;
+            key = normalize(new_key);
+        }
+
+        end_timer!(open_time);
+        Ok(Proof { l_vec, r_vec, final_comm_key: key[0], c: a[0] })
+    }
+
+    fn check(
+        vk: &Self::VerifierKey,
+        commitments: &[Self::Commitment],
+        point: G::ScalarField,
+        values: &[G::ScalarField],
+        proof: &Self::Proof,
+        opening_challenge: G::ScalarField,
+    ) -> Result<bool, Error> {
+        match Self::succinct_check(vk, commitments, point, values, proof, opening_challenge)? {
+            Some(acc) => {
+                let coeffs = acc.check_poly.compute_coeffs();
+                Ok(msm(&vk.comm_key, &coeffs).into_affine() == acc.final_comm_key)
+            },
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::{mnt4753::G1Affine as MNT4G1Affine, mnt6753::G1Affine as MNT6G1Affine},
+        fft::DensePolynomial,
+        to_bytes, AffineCurve, Field, FromBytes, PrimeField, ToBytes, ToConstraintField, UniformRand,
+    };
+    use rand::thread_rng;
+
+    use crate::{
+        crh::{FieldBasedHash, MNT4PoseidonHash, MNT6PoseidonHash},
+        poly_commit::{
+            ipa::{InnerProductArgPC, Proof},
+            PolynomialCommitment,
+        },
+    };
+
+    type MNT4IPA = InnerProductArgPC<MNT4G1Affine, MNT6PoseidonHash>;
+    type MNT6IPA = InnerProductArgPC<MNT6G1Affine, MNT4PoseidonHash>;
+
+    fn single_opening<G, H>()
+    where
+        G: AffineCurve + ToConstraintField<G::BaseField>,
+        G::BaseField: PrimeField,
+        H: FieldBasedHash<Data = G::BaseField>,
+    {
+        let rng = &mut thread_rng();
+        let pp = InnerProductArgPC::<G, H>::setup(15, rng).unwrap();
+        let (ck, vk) = InnerProductArgPC::<G, H>::trim(&pp, 7).unwrap();
+
+        for &degree in [0, 1, 5, 7].iter() {
+            let p = DensePolynomial::<G::ScalarField>::rand(degree, rng);
+            let comm = InnerProductArgPC::<G, H>::commit(&ck, &p).unwrap();
+            let point = G::ScalarField::rand(rng);
+            let value = p.evaluate(point);
+            let challenge = G::ScalarField::rand(rng);
+
+            let proof = InnerProductArgPC::<G, H>::open(&ck, &[&p], point, challenge).unwrap();
+            assert!(InnerProductArgPC::<G, H>::check(&vk, &[comm], point, &[value], &proof, challenge).unwrap());
+            assert!(!InnerProductArgPC::<G, H>::check(
+                &vk, &[comm], point, &[value + &G::ScalarField::one()], &proof, challenge
+            ).unwrap());
+
+            // Proofs are tied to the size of the key
+            if degree == 7 {
+                let (bigger_ck, _) = InnerProductArgPC::<G, H>::trim(&pp, 15).unwrap();
+                assert_eq!(comm, InnerProductArgPC::<G, H>::commit(&bigger_ck, &p).unwrap());
+                let proof = InnerProductArgPC::<G, H>::open(&bigger_ck, &[&p], point, challenge).unwrap();
+                assert!(!InnerProductArgPC::<G, H>::check(&vk, &[comm], point, &[value], &proof, challenge).unwrap());
+            }
+        }
+
+        // Polynomials above the supported degree cannot be committed to
+        let p = DensePolynomial::<G::ScalarField>::rand(8, rng);
+        assert!(InnerProductArgPC::<G, H>::commit(&ck, &p).is_err());
+        assert!(InnerProductArgPC::<G, H>::trim(&pp, 16).is_err());
+    }
+
+    fn batched_opening_and_accumulation<G, H>()
+    where
+        G: AffineCurve + ToConstraintField<G::BaseField>,
+        G::BaseField: PrimeField,
+        H: FieldBasedHash<Data = G::BaseField>,
+    {
+        let rng = &mut thread_rng();
+        let pp = InnerProductArgPC::<G, H>::setup(7, rng).unwrap();
+        let (ck, vk) = InnerProductArgPC::<G, H>::trim(&pp, 7).unwrap();
+
+        let polys = (0..4).map(|i| DensePolynomial::<G::ScalarField>::rand(2 * i + 1, rng)).collect::<Vec<_>>();
+        let poly_refs = polys.iter().collect::<Vec<_>>();
+        let comms = polys.iter().map(|p| InnerProductArgPC::<G, H>::commit(&ck, p).unwrap()).collect::<Vec<_>>();
+
+        let mut accumulators = vec![];
+        for _ in 0..2 {
+            let point = G::ScalarField::rand(rng);
+            let challenge = G::ScalarField::rand(rng);
+            let values = polys.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+            let proof = InnerProductArgPC::<G, H>::open(&ck, &poly_refs, point, challenge).unwrap();
+            assert!(InnerProductArgPC::<G, H>::check(&vk, &comms, point, &values, &proof, challenge).unwrap());
+
+            // Swapping two values breaks the proof
+            let mut swapped = values.clone();
+            swapped.swap(0, 1);
+            assert!(InnerProductArgPC::<G, H>::succinct_check(&vk, &comms, point, &swapped, &proof, challenge)
+                .unwrap()
+                .is_none());
+
+            // So does swapping the cross terms of a round
+            let mut tampered = proof.clone();
+            tampered.l_vec.swap(0, 1);
+            assert!(!InnerProductArgPC::<G, H>::check(&vk, &comms, point, &values, &tampered, challenge).unwrap());
+
+            // Or dropping a round
+            let mut tampered = proof.clone();
+            tampered.l_vec.pop();
+            tampered.r_vec.pop();
+            assert!(!InnerProductArgPC::<G, H>::check(&vk, &comms, point, &values, &tampered, challenge).unwrap());
+
+            let proof_serialized = to_bytes!(proof).unwrap();
+            assert_eq!(proof, Proof::<G>::read(proof_serialized.as_slice()).unwrap());
+
+            accumulators.push(
+                InnerProductArgPC::<G, H>::succinct_check(&vk, &comms, point, &values, &proof, challenge)
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
+        assert!(InnerProductArgPC::<G, H>::check_accumulators(&vk, &accumulators, rng).unwrap());
+
+        // A single bad accumulator invalidates the batch
+        accumulators[1].final_comm_key = accumulators[0].final_comm_key;
+        assert!(!InnerProductArgPC::<G, H>::check_accumulators(&vk, &accumulators, rng).unwrap());
+    }
+
+    #[test]
+    fn mnt4753_ipa() {
+        single_opening::<MNT4G1Affine, MNT6PoseidonHash>();
+        batched_opening_and_accumulation::<MNT4G1Affine, MNT6PoseidonHash>();
+    }
+
+    #[test]
+    fn mnt6753_ipa() {
+        single_opening::<MNT6G1Affine, MNT4PoseidonHash>();
+        batched_opening_and_accumulation::<MNT6G1Affine, MNT4PoseidonHash>();
+    }
+
+    #[test]
+    fn transparent_setup() {
+        let rng = &mut thread_rng();
+        let small = MNT4IPA::setup(3, rng).unwrap();
+        let large = MNT4IPA::setup(15, rng).unwrap();
+        assert_eq!(small, MNT4IPA::trim(&large, 3).unwrap().0);
+        assert_ne!(small.comm_key[0], small.s);
+
+        let other = MNT6IPA::setup(3, rng).unwrap();
+        assert_eq!(other, MNT6IPA::setup(3, rng).unwrap());
+    }
+}
@@ -0,0 +1,644 @@
+//! The [KZG10][kzg] polynomial commitment scheme over a pairing-friendly curve.
+//!
+//! Commitments made with `commit` are not hiding. Those made with
+//! `commit_hiding` are blinded by a random polynomial committed to over the
+//! powers of `gamma * G`, whose degree `hiding_bound` is the number of
+//! evaluations that can be revealed without leaking anything else about the
+//! committed polynomial. Several polynomials opened at the same point share a
+//! single evaluation proof, and openings at different points can be checked
+//! together with a single pairing product.
+//!
+//! [kzg]: https://www.iacr.org/archive/asiacrypt2010/6477178/6477178.pdf
+use algebra::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes},
+    fft::DensePolynomial,
+    msm::{FixedBaseMSM, VariableBaseMSM},
+    AffineCurve, Field, PairingCurve, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
+};
+use rand::Rng;
+use rayon::prelude::*;
+use std::{
+    io::{self, Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+use crate::{poly_commit::PolynomialCommitment, CryptoError, Error};
+
+pub struct KZG10<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+/// The powers `tau^i * G` and `tau^i * gamma * G` for `i <= max_degree`,
+/// and `H`, `tau * H`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine")
+)]
+pub struct UniversalParams<E: PairingEngine> {
+    pub powers_of_g:       Vec<E::G1Affine>,
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    pub h:                 E::G2Affine,
+    pub beta_h:            E::G2Affine,
+}
+
+impl<E: PairingEngine> UniversalParams<E> {
+    /// The maximum degree of the polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let len = u32::read(&mut reader)? as usize;
+        if len == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "no powers of tau"));
+        }
+        let powers_of_g = (0..len)
+            .map(|_| read_affine::<E::G1Affine, _>(checked, &mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        let powers_of_gamma_g = (0..len)
+            .map(|_| read_affine::<E::G1Affine, _>(checked, &mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        let h = read_affine(checked, &mut reader)?;
+        let beta_h = read_affine(checked, &mut reader)?;
+        Ok(Self { powers_of_g, powers_of_gamma_g, h, beta_h })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for UniversalParams<E> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.powers_of_g.len() as u32).write(&mut writer)?;
+        for g in self.powers_of_g.iter().chain(self.powers_of_gamma_g.iter()) {
+            g.write(&mut writer)?;
+        }
+        self.h.write(&mut writer)?;
+        self.beta_h.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for UniversalParams<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for UniversalParams<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+/// The powers of tau in G1, with and without the `gamma` factor, needed to
+/// commit to polynomials of bounded degree.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine")
+)]
+pub struct CommitterKey<E: PairingEngine> {
+    pub powers_of_g:       Vec<E::G1Affine>,
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> CommitterKey<E> {
+    /// The maximum degree of the polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine")
+)]
+pub struct VerifierKey<E: PairingEngine> {
+    pub g:       E::G1Affine,
+    pub gamma_g: E::G1Affine,
+    pub h:       E::G2Affine,
+    pub beta_h:  E::G2Affine,
+}
+
+impl<E: PairingEngine> VerifierKey<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let g = read_affine(checked, &mut reader)?;
+        let gamma_g = read_affine(checked, &mut reader)?;
+        let h = read_affine(checked, &mut reader)?;
+        let beta_h = read_affine(checked, &mut reader)?;
+        Ok(Self { g, gamma_g, h, beta_h })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for VerifierKey<E> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.g.write(&mut writer)?;
+        self.gamma_g.write(&mut writer)?;
+        self.h.write(&mut writer)?;
+        self.beta_h.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifierKey<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for VerifierKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+/// The claim that `values` are the evaluations at `point` of the polynomials
+/// behind `commitments`, together with the proof produced by `open`, or by
+/// `open_hiding` along with the combined evaluation of the blinding
+/// polynomials `random_value`.
+pub struct OpeningClaim<'a, E: PairingEngine> {
+    pub commitments:       &'a [E::G1Affine],
+    pub point:             E::Fr,
+    pub values:            &'a [E::Fr],
+    pub proof:             E::G1Affine,
+    pub random_value:      Option<E::Fr>,
+    pub opening_challenge: E::Fr,
+}
+
+impl<E: PairingEngine> KZG10<E> {
+    /// Commits to `polynomial`, blinded by a random polynomial of degree
+    /// `hiding_bound` which is returned along with the commitment, as it is
+    /// needed to open it.
+    pub fn commit_hiding<R: Rng>(
+        ck: &CommitterKey<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        hiding_bound: usize,
+        rng: &mut R,
+    ) -> Result<(E::G1Affine, DensePolynomial<E::Fr>), Error> {
+        let blinding_polynomial = DensePolynomial::rand(hiding_bound, rng);
+        let commitment = msm::<E>(&ck.powers_of_g, polynomial)?
+            + &msm::<E>(&ck.powers_of_gamma_g, &blinding_polynomial)?;
+        Ok((commitment.into_affine(), blinding_polynomial))
+    }
+
+    /// Proves the evaluations at `point` of the `polynomials` committed to by
+    /// `commit_hiding` with the `blinding_polynomials`, returning the proof and
+    /// the combined evaluation of the blinding polynomials. Polynomials
+    /// committed to by `commit` can be opened together with the others, by
+    /// giving them a zero blinding polynomial.
+    pub fn open_hiding(
+        ck: &CommitterKey<E>,
+        polynomials: &[&DensePolynomial<E::Fr>],
+        blinding_polynomials: &[&DensePolynomial<E::Fr>],
+        point: E::Fr,
+        opening_challenge: E::Fr,
+    ) -> Result<(E::G1Affine, E::Fr), Error> {
+        if polynomials.len() != blinding_polynomials.len() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "blinding polynomials".to_owned(),
+                blinding_polynomials.len(),
+            )));
+        }
+
+        let open_time = start_timer!(|| format!("Opening {} hiding polynomials", polynomials.len()));
+
+        let combined = combine(polynomials, opening_challenge);
+        let combined_blinding = combine(blinding_polynomials, opening_challenge);
+
+        let witness = divide_by_linear_factor(&combined, point);
+        let blinding_witness = divide_by_linear_factor(&combined_blinding, point);
+        let proof = msm::<E>(&ck.powers_of_g, &witness)?
+            + &msm::<E>(&ck.powers_of_gamma_g, &blinding_witness)?;
+
+        end_timer!(open_time);
+        Ok((proof.into_affine(), combined_blinding.evaluate(point)))
+    }
+
+    /// Checks several openings, possibly at different points, with a single
+    /// pairing product. The claims are combined with the powers of a random
+    /// scalar, so that the check fails with overwhelming probability if any
+    /// of them is false; it does not tell which one.
+    pub fn batch_check<R: Rng>(
+        vk: &VerifierKey<E>,
+        claims: &[OpeningClaim<E>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let check_time = start_timer!(|| format!("KZG10 batch check of {} claims", claims.len()));
+
+        // Each claim holds iff e(sum_i xi^i (C_i - v_i * G) - r * gamma * G + z * W, H) == e(W, tau * H),
+        // where r is the random value of hiding openings
+        let mut bases = vec![];
+        let mut scalars = vec![];
+        let mut proofs = vec![];
+        let mut proof_scalars = vec![];
+
+        let r = E::Fr::rand(rng);
+        let mut r_power = E::Fr::one();
+        for claim in claims {
+            if claim.commitments.len() != claim.values.len() {
+                return Err(Box::new(CryptoError::IncorrectInputLength(
+                    "values".to_owned(),
+                    claim.values.len(),
+                )));
+            }
+
+            let mut challenge_power = r_power;
+            let mut combined_value = E::Fr::zero();
+            for (c, v) in claim.commitments.iter().zip(claim.values) {
+                bases.push(*c);
+                scalars.push(challenge_power.into_repr());
+                combined_value += &(challenge_power * v);
+                challenge_power *= &claim.opening_challenge;
+            }
+            bases.push(vk.g);
+            scalars.push((-combined_value).into_repr());
+            if let Some(random_value) = claim.random_value {
+                bases.push(vk.gamma_g);
+                scalars.push((-(r_power * &random_value)).into_repr());
+            }
+            bases.push(claim.proof);
+            scalars.push((r_power * &claim.point).into_repr());
+
+            proofs.push(claim.proof);
+            proof_scalars.push(r_power.into_repr());
+
+            r_power *= &r;
+        }
+
+        let lhs = VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine();
+        let rhs = VariableBaseMSM::multi_scalar_mul(&proofs, &proof_scalars).into_affine();
+
+        let result = pairing_check::<E>(vk, lhs, rhs);
+
+        end_timer!(check_time);
+        Ok(result)
+    }
+}
+
+impl<E: PairingEngine> PolynomialCommitment for KZG10<E> {
+    type Field = E::Fr;
+    type Parameters = UniversalParams<E>;
+    type CommitterKey = CommitterKey<E>;
+    type VerifierKey = VerifierKey<E>;
+    type Commitment = E::G1Affine;
+    type Proof = E::G1Affine;
+
+    fn setup<R: Rng>(max_degree: usize, rng: &mut R) -> Result<Self::Parameters, Error> {
+        let setup_time = start_timer!(|| format!("KZG10 setup for degree {}", max_degree));
+
+        let tau = E::Fr::rand(rng);
+        let g = E::G1Projective::rand(rng);
+        let gamma_g = E::G1Projective::rand(rng);
+        let h = E::G2Projective::rand(rng);
+
+        let mut powers_of_tau = Vec::with_capacity(max_degree + 1);
+        let mut cur = E::Fr::one();
+        for _ in 0..=max_degree {
+            powers_of_tau.push(cur);
+            cur *= &tau;
+        }
+
+        let scalar_bits = E::Fr::size_in_bits();
+        let window = FixedBaseMSM::get_mul_window_size(max_degree + 1);
+        let table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, window, g);
+        let mut powers_of_g =
+            FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, window, &table, &powers_of_tau);
+        E::G1Projective::batch_normalization(&mut powers_of_g);
+
+        let table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, window, gamma_g);
+        let mut powers_of_gamma_g =
+            FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, window, &table, &powers_of_tau);
+        E::G1Projective::batch_normalization(&mut powers_of_gamma_g);
+
+        let h = h.into_affine();
+        let beta_h = h.mul(tau.into_repr()).into_affine();
+
+        end_timer!(setup_time);
+        Ok(UniversalParams {
+            powers_of_g: powers_of_g.into_par_iter().map(|e| e.into_affine()).collect(),
+            powers_of_gamma_g: powers_of_gamma_g.into_par_iter().map(|e| e.into_affine()).collect(),
+            h,
+            beta_h,
+        })
+    }
+
+    fn trim(
+        params: &Self::Parameters,
+        supported_degree: usize,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Error> {
+        if supported_degree > params.max_degree() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "supported degree".to_owned(),
+                supported_degree,
+            )));
+        }
+
+        let ck = CommitterKey {
+            powers_of_g:       params.powers_of_g[..=supported_degree].to_vec(),
+            powers_of_gamma_g: params.powers_of_gamma_g[..=supported_degree].to_vec(),
+        };
+        let vk = VerifierKey {
+            g:       params.powers_of_g[0],
+            gamma_g: params.powers_of_gamma_g[0],
+            h:       params.h,
+            beta_h:  params.beta_h,
+        };
+        Ok((ck, vk))
+    }
+
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<E::Fr>,
+    ) -> Result<Self::Commitment, Error> {
+        let commit_time = start_timer!(|| format!("Committing to polynomial of degree {}", polynomial.degree()));
+        let commitment = msm::<E>(&ck.powers_of_g, polynomial)?;
+        end_timer!(commit_time);
+
+        Ok(commitment.into_affine())
+    }
+
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomials: &[&DensePolynomial<E::Fr>],
+        point: E::Fr,
+        opening_challenge: E::Fr,
+    ) -> Result<Self::Proof, Error> {
+        let open_time = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+
+        let combined = combine(polynomials, opening_challenge);
+
+        // The witness polynomial (p(X) - p(z)) / (X - z)
+        let witness = divide_by_linear_factor(&combined, point);
+        let proof = Self::commit(ck, &witness)?;
+
+        end_timer!(open_time);
+        Ok(proof)
+    }
+
+    fn check(
+        vk: &Self::VerifierKey,
+        commitments: &[Self::Commitment],
+        point: E::Fr,
+        values: &[E::Fr],
+        proof: &Self::Proof,
+        opening_challenge: E::Fr,
+    ) -> Result<bool, Error> {
+        if commitments.len() != values.len() {
+            return Err(Box::new(CryptoError::IncorrectInputLength("values".to_owned(), values.len())));
+        }
+
+        // e(C - v * G + z * W, H) == e(W, tau * H), with C and v combined
+        // with the powers of the opening challenge
+        let mut bases = commitments.to_vec();
+        let mut scalars = Vec::with_capacity(commitments.len() + 2);
+        let mut combined_value = E::Fr::zero();
+        let mut challenge_power = E::Fr::one();
+        for v in values {
+            scalars.push(challenge_power.into_repr());
+            combined_value += &(challenge_power * v);
+            challenge_power *= &opening_challenge;
+        }
+        bases.push(vk.g);
+        scalars.push((-combined_value).into_repr());
+        bases.push(*proof);
+        scalars.push(point.into_repr());
+
+        let lhs = VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine();
+        Ok(pairing_check::<E>(vk, lhs, *proof))
+    }
+}
+
+/// Checks that `e(lhs, H) == e(rhs, tau * H)`. Pairings with the identity
+/// are skipped, as not all the Miller loop implementations handle it.
+fn pairing_check<E: PairingEngine>(vk: &VerifierKey<E>, lhs: E::G1Affine, rhs: E::G1Affine) -> bool {
+    let lhs_prep = (lhs.prepare(), vk.h.prepare());
+    let rhs_prep = ((-rhs).prepare(), vk.beta_h.prepare());
+    let mut pairs = vec![];
+    if !lhs.is_zero() {
+        pairs.push((&lhs_prep.0, &lhs_prep.1));
+    }
+    if !rhs.is_zero() {
+        pairs.push((&rhs_prep.0, &rhs_prep.1));
+    }
+    E::final_exponentiation(&E::miller_loop(pairs.iter())) == Some(E::Fqk::one())
+}
+
+/// Returns the commitment to `polynomial` over `bases`, which must be at
+/// least as many as its coefficients.
+fn msm<E: PairingEngine>(
+    bases: &[E::G1Affine],
+    polynomial: &DensePolynomial<E::Fr>,
+) -> Result<E::G1Projective, Error> {
+    if polynomial.coeffs.len() > bases.len() {
+        return Err(Box::new(CryptoError::IncorrectInputLength(
+            "polynomial".to_owned(),
+            polynomial.coeffs.len(),
+        )));
+    }
+    let scalars = polynomial.coeffs.par_iter().map(|c| c.into_repr()).collect::<Vec<_>>();
+    Ok(VariableBaseMSM::multi_scalar_mul(&bases[..scalars.len()], &scalars))
+}
+
+/// Returns the linear combination of `polynomials` with the powers of `challenge`.
+fn combine<F: Field>(polynomials: &[&DensePolynomial<F>], challenge: F) -> DensePolynomial<F> {
+    let mut combined = DensePolynomial::zero();
+    let mut challenge_power = F::one();
+    for p in polynomials {
+        combined += (challenge_power, *p);
+        challenge_power *= &challenge;
+    }
+    combined
+}
+
+/// Returns the quotient of `p` by `X - point`, dropping the remainder `p(point)`.
+fn divide_by_linear_factor<F: Field>(p: &DensePolynomial<F>, point: F) -> DensePolynomial<F> {
+    if p.coeffs.len() < 2 {
+        return DensePolynomial::zero();
+    }
+    let mut quotient = vec![F::zero(); p.coeffs.len() - 1];
+    let mut acc = F::zero();
+    for (q, c) in quotient.iter_mut().zip(p.coeffs[1..].iter()).rev() {
+        acc = acc * &point + c;
+        *q = acc;
+    }
+    DensePolynomial::from_coefficients_vec(quotient)
+}
+
+fn read_affine<G: AffineCurve, R: Read>(checked: bool, reader: R) -> IoResult<G> {
+    if checked {
+        G::read_checked(reader)
+    } else {
+        G::read(reader)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::{bls12_377::Bls12_377, mnt4753::MNT4},
+        fft::DensePolynomial,
+        to_bytes, Field, FromBytes, FromBytesChecked, PairingEngine, ToBytes, UniformRand,
+    };
+    use rand::thread_rng;
+
+    use crate::poly_commit::{
+        kzg10::{OpeningClaim, UniversalParams, VerifierKey, KZG10},
+        PolynomialCommitment,
+    };
+
+    fn single_opening<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let pp = KZG10::<E>::setup(64, rng).unwrap();
+        let (ck, vk) = KZG10::<E>::trim(&pp, 32).unwrap();
+
+        for degree in 0..=32 {
+            let p = DensePolynomial::<E::Fr>::rand(degree, rng);
+            let comm = KZG10::<E>::commit(&ck, &p).unwrap();
+            let point = E::Fr::rand(rng);
+            let value = p.evaluate(point);
+            let challenge = E::Fr::rand(rng);
+
+            let proof = KZG10::<E>::open(&ck, &[&p], point, challenge).unwrap();
+            assert!(KZG10::<E>::check(&vk, &[comm], point, &[value], &proof, challenge).unwrap());
+            assert!(!KZG10::<E>::check(&vk, &[comm], point, &[value + &E::Fr::one()], &proof, challenge).unwrap());
+            if degree > 0 {
+                assert!(!KZG10::<E>::check(&vk, &[comm], point + &E::Fr::one(), &[value], &proof, challenge).unwrap());
+            }
+        }
+
+        // Polynomials above the supported degree cannot be committed to
+        let p = DensePolynomial::<E::Fr>::rand(33, rng);
+        assert!(KZG10::<E>::commit(&ck, &p).is_err());
+        assert!(KZG10::<E>::trim(&pp, 65).is_err());
+    }
+
+    fn batched_opening<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let pp = KZG10::<E>::setup(32, rng).unwrap();
+        let (ck, vk) = KZG10::<E>::trim(&pp, 32).unwrap();
+
+        let polys = (0..5).map(|i| DensePolynomial::<E::Fr>::rand(6 * i, rng)).collect::<Vec<_>>();
+        let poly_refs = polys.iter().collect::<Vec<_>>();
+        let comms = polys.iter().map(|p| KZG10::<E>::commit(&ck, p).unwrap()).collect::<Vec<_>>();
+
+        let mut claims_data = vec![];
+        for _ in 0..3 {
+            let point = E::Fr::rand(rng);
+            let challenge = E::Fr::rand(rng);
+            let values = polys.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+            let proof = KZG10::<E>::open(&ck, &poly_refs, point, challenge).unwrap();
+            assert!(KZG10::<E>::check(&vk, &comms, point, &values, &proof, challenge).unwrap());
+
+            // Swapping two values breaks the proof
+            let mut swapped = values.clone();
+            swapped.swap(1, 2);
+            assert!(!KZG10::<E>::check(&vk, &comms, point, &swapped, &proof, challenge).unwrap());
+
+            claims_data.push((point, values, proof, challenge));
+        }
+
+        let claims = claims_data
+            .iter()
+            .map(|(point, values, proof, challenge)| OpeningClaim {
+                commitments:       &comms,
+                point:             *point,
+                values:            values.as_slice(),
+                proof:             *proof,
+                random_value:      None,
+                opening_challenge: *challenge,
+            })
+            .collect::<Vec<_>>();
+        assert!(KZG10::<E>::batch_check(&vk, &claims, rng).unwrap());
+        assert!(KZG10::<E>::batch_check(&vk, &[], rng).unwrap());
+
+        // A single false claim invalidates the batch
+        let wrong_values = vec![E::Fr::one(); comms.len()];
+        let mut bad_claims = claims;
+        bad_claims[1].values = &wrong_values;
+        assert!(!KZG10::<E>::batch_check(&vk, &bad_claims, rng).unwrap());
+
+        // A mismatch between commitments and values is an error
+        bad_claims[1].values = &wrong_values[1..];
+        assert!(KZG10::<E>::batch_check(&vk, &bad_claims, rng).is_err());
+    }
+
+    fn hiding_opening<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let pp = KZG10::<E>::setup(32, rng).unwrap();
+        let (ck, vk) = KZG10::<E>::trim(&pp, 32).unwrap();
+
+        // Hiding commitments to the same polynomial differ
+        let p = DensePolynomial::<E::Fr>::rand(32, rng);
+        let (comm, blinding) = KZG10::<E>::commit_hiding(&ck, &p, 1, rng).unwrap();
+        let (other_comm, _) = KZG10::<E>::commit_hiding(&ck, &p, 1, rng).unwrap();
+        assert_ne!(comm, other_comm);
+        assert_ne!(comm, KZG10::<E>::commit(&ck, &p).unwrap());
+
+        // They can be opened together with non-hiding ones
+        let q = DensePolynomial::<E::Fr>::rand(20, rng);
+        let q_comm = KZG10::<E>::commit(&ck, &q).unwrap();
+        let zero = DensePolynomial::zero();
+
+        let point = E::Fr::rand(rng);
+        let challenge = E::Fr::rand(rng);
+        let comms = [comm, q_comm];
+        let values = [p.evaluate(point), q.evaluate(point)];
+        let (proof, random_value) =
+            KZG10::<E>::open_hiding(&ck, &[&p, &q], &[&blinding, &zero], point, challenge).unwrap();
+
+        let claim = |values, random_value| OpeningClaim {
+            commitments: &comms,
+            point,
+            values,
+            proof,
+            random_value,
+            opening_challenge: challenge,
+        };
+        assert!(KZG10::<E>::batch_check(&vk, &[claim(&values, Some(random_value))], rng).unwrap());
+        assert!(!KZG10::<E>::batch_check(&vk, &[claim(&values, None)], rng).unwrap());
+        assert!(!KZG10::<E>::batch_check(&vk, &[claim(&values, Some(random_value + &E::Fr::one()))], rng).unwrap());
+        let wrong_values = [values[0] + &E::Fr::one(), values[1]];
+        assert!(!KZG10::<E>::batch_check(&vk, &[claim(&wrong_values, Some(random_value))], rng).unwrap());
+
+        // The blinding polynomial must fit the committer key
+        assert!(KZG10::<E>::commit_hiding(&ck, &p, 33, rng).is_err());
+        assert!(KZG10::<E>::open_hiding(&ck, &[&p, &q], &[&blinding], point, challenge).is_err());
+    }
+
+    fn serialization<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let pp = KZG10::<E>::setup(16, rng).unwrap();
+        let pp_serialized = to_bytes!(pp).unwrap();
+        assert_eq!(pp, UniversalParams::<E>::read(pp_serialized.as_slice()).unwrap());
+        assert_eq!(pp, UniversalParams::<E>::read_checked(pp_serialized.as_slice()).unwrap());
+
+        let (_, vk) = KZG10::<E>::trim(&pp, 8).unwrap();
+        let vk_serialized = to_bytes!(vk).unwrap();
+        assert_eq!(vk, VerifierKey::<E>::read(vk_serialized.as_slice()).unwrap());
+        assert_eq!(vk, VerifierKey::<E>::read_checked(vk_serialized.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn bls12_377_kzg10() {
+        single_opening::<Bls12_377>();
+        batched_opening::<Bls12_377>();
+        hiding_opening::<Bls12_377>();
+        serialization::<Bls12_377>();
+    }
+
+    #[test]
+    fn mnt4753_kzg10() {
+        single_opening::<MNT4>();
+        batched_opening::<MNT4>();
+        hiding_opening::<MNT4>();
+        serialization::<MNT4>();
+    }
+}
@@ -0,0 +1,56 @@
+use algebra::{bytes::ToBytes, fft::DensePolynomial, PrimeField};
+use rand::Rng;
+use std::fmt::Debug;
+
+use crate::Error;
+
+pub mod ipa;
+pub use self::ipa::InnerProductArgPC;
+
+pub mod kzg10;
+pub use self::kzg10::*;
+
+/// A scheme committing to univariate polynomials over `Self::Field`, and
+/// proving their evaluations at arbitrary points.
+pub trait PolynomialCommitment {
+    type Field: PrimeField;
+    type Parameters: Clone;
+    type CommitterKey: Clone;
+    type VerifierKey: Clone;
+    type Commitment: ToBytes + Clone + Eq + Debug;
+    type Proof: ToBytes + Clone + Eq + Debug;
+
+    /// Generates the parameters supporting polynomials of degree up to `max_degree`.
+    fn setup<R: Rng>(max_degree: usize, rng: &mut R) -> Result<Self::Parameters, Error>;
+
+    /// Specializes the parameters to polynomials of degree up to `supported_degree`.
+    fn trim(
+        params: &Self::Parameters,
+        supported_degree: usize,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Error>;
+
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<Self::Field>,
+    ) -> Result<Self::Commitment, Error>;
+
+    /// Proves the evaluations at `point` of all the `polynomials` at once, by
+    /// opening their linear combination with the powers of `opening_challenge`.
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomials: &[&DensePolynomial<Self::Field>],
+        point: Self::Field,
+        opening_challenge: Self::Field,
+    ) -> Result<Self::Proof, Error>;
+
+    /// Checks that `values` are the evaluations at `point` of the polynomials
+    /// behind `commitments`, as proven by `open`.
+    fn check(
+        vk: &Self::VerifierKey,
+        commitments: &[Self::Commitment],
+        point: Self::Field,
+        values: &[Self::Field],
+        proof: &Self::Proof,
+        opening_challenge: Self::Field,
+    ) -> Result<bool, Error>;
+}
@@ -186,7 +186,7 @@ mod test {
         mnt4753::Fr as MNT4Fr,
         mnt6753::Fr as MNT6Fr,
     };
-    use algebra::{ToBytes, to_bytes, FromBytes};
+    use algebra::{ToBytes, to_bytes, FromBytes, ToCompressedBytes, FromCompressedBytes};
     use crate::crh::{MNT4PoseidonHash, MNT6PoseidonHash};
     use crate::signature::FieldBasedSignatureScheme;
     use crate::signature::schnorr::field_based_schnorr::FieldBasedSchnorrSignatureScheme;
@@ -207,6 +207,13 @@ mod test {
         let sig_deserialized = <S as FieldBasedSignatureScheme>::Signature::read(sig_serialized.as_slice()).unwrap();
         assert_eq!(sig, sig_deserialized);
         assert!(S::verify(&pk, &message, &sig_deserialized).unwrap());
+
+        //Compressed public key serialization/deserialization test
+        let mut pk_compressed = vec![];
+        pk.write_compressed(&mut pk_compressed).unwrap();
+        let pk_decompressed = <S as FieldBasedSignatureScheme>::PublicKey::read_compressed(pk_compressed.as_slice()).unwrap();
+        assert_eq!(pk, pk_decompressed);
+        assert!(S::verify(&pk_decompressed, &message, &sig).unwrap());
     }
 
     fn failed_verification<S: FieldBasedSignatureScheme, R: Rng>(rng: &mut R, message: &[S::Data], bad_message: &[S::Data]) {
@@ -0,0 +1,210 @@
+//! Fiat-Shamir transcripts: the prover and the verifier of a public coin
+//! protocol append the messages of the prover to a transcript, and derive the
+//! challenges of the verifier from it.
+//!
+//! The messages are elements of `Self::Field`, while the challenges may live in
+//! any prime field: they are obtained by dropping the leading bits of the
+//! output of the transcript until it is smaller than the modulus of the target
+//! field, as done in the field based signature schemes (see
+//! `compute_truncation_size`).
+use algebra::{Field, FpParameters, PrimeField, ToBits, ToConstraintField, to_bytes, ToBytes};
+use blake2::Blake2s;
+use digest::Digest;
+
+use crate::{bytes_to_bits, compute_truncation_size, Error};
+use crate::crh::{
+    FieldBasedSponge, MNT4PoseidonSponge, MNT6PoseidonSponge,
+};
+
+pub trait FiatShamirTranscript: Clone {
+    /// The field the messages are elements of
+    type Field: PrimeField;
+
+    /// Initializes a transcript for the protocol `protocol_name`, which
+    /// separates its challenges from those of other protocols.
+    fn new(protocol_name: &[u8]) -> Self;
+
+    fn append_field(&mut self, elems: &[Self::Field]);
+
+    /// Appends the coordinates of `point`.
+    fn append_point<G: ToConstraintField<Self::Field>>(&mut self, point: &G) -> Result<(), Error> {
+        self.append_field(point.to_field_elements()?.as_slice());
+        Ok(())
+    }
+
+    /// Derives a challenge in `S`, as its big endian bits.
+    fn challenge_bits<S: PrimeField>(&mut self) -> Vec<bool>;
+
+    /// Derives a challenge in `S`.
+    fn challenge_scalar<S: PrimeField>(&mut self) -> S {
+        S::read_bits(self.challenge_bits::<S>()).expect("the challenge bits are fewer than the capacity")
+    }
+}
+
+/// Encodes `bytes` into field elements, the first of them being the length of
+/// `bytes`, so that the encoding is injective when followed by other elements.
+pub fn bytes_to_field_elements<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    let mut elems = vec![F::from(bytes.len() as u64)];
+    for chunk in bytes_to_bits(bytes).chunks(F::Params::CAPACITY as usize) {
+        elems.push(F::read_bits(chunk.to_vec()).expect("the chunk is not longer than the capacity"));
+    }
+    elems
+}
+
+/// A transcript absorbing the messages in a sponge, and squeezing the challenges
+/// out of it.
+#[derive(Clone)]
+pub struct SpongeTranscript<S: FieldBasedSponge> {
+    sponge: S,
+}
+
+impl<S: FieldBasedSponge> FiatShamirTranscript for SpongeTranscript<S>
+    where
+        S::Data: PrimeField,
+{
+    type Field = S::Data;
+
+    fn new(protocol_name: &[u8]) -> Self {
+        let mut sponge = S::new(S::Data::zero());
+        sponge.absorb(bytes_to_field_elements::<S::Data>(protocol_name).as_slice());
+        Self { sponge }
+    }
+
+    fn append_field(&mut self, elems: &[Self::Field]) {
+        self.sponge.absorb(elems);
+    }
+
+    fn challenge_bits<F: PrimeField>(&mut self) -> Vec<bool> {
+        let to_skip = compute_truncation_size(
+            S::Data::size_in_bits() as i32,
+            F::size_in_bits() as i32,
+        );
+        self.sponge.squeeze(1)[0].write_bits().split_off(to_skip)
+    }
+}
+
+pub type MNT4PoseidonTranscript = SpongeTranscript<MNT4PoseidonSponge>;
+pub type MNT6PoseidonTranscript = SpongeTranscript<MNT6PoseidonSponge>;
+
+/// Size in bits of the output of Blake2s.
+pub const BLAKE2S_OUTPUT_BITS: usize = 256;
+
+/// A transcript hashing the serialization of the messages with Blake2s. Each
+/// challenge is the digest of the messages appended since the previous one,
+/// preceded by the digest it was derived from.
+#[derive(Clone)]
+pub struct Blake2sTranscript<F: PrimeField> {
+    // The serialization of the transcript since the previous challenge
+    state:  Vec<u8>,
+    _field: std::marker::PhantomData<F>,
+}
+
+impl<F: PrimeField> FiatShamirTranscript for Blake2sTranscript<F> {
+    type Field = F;
+
+    fn new(protocol_name: &[u8]) -> Self {
+        let mut state = (protocol_name.len() as u64).to_le_bytes().to_vec();
+        state.extend_from_slice(protocol_name);
+        Self { state, _field: std::marker::PhantomData }
+    }
+
+    fn append_field(&mut self, elems: &[Self::Field]) {
+        for elem in elems.iter() {
+            self.state.extend_from_slice(to_bytes!(elem).unwrap().as_slice());
+        }
+    }
+
+    fn challenge_bits<S: PrimeField>(&mut self) -> Vec<bool> {
+        let digest = Blake2s::digest(&self.state);
+        self.state = digest.to_vec();
+
+        // The bits of the digest, read as a little endian integer, in big endian
+        let mut bits = bytes_to_bits(digest.as_slice());
+        bits.reverse();
+
+        let to_skip = compute_truncation_size(BLAKE2S_OUTPUT_BITS as i32, S::size_in_bits() as i32);
+        bits.split_off(to_skip)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{
+        curves::mnt6753::G1Projective as MNT6G1Projective,
+        fields::{mnt4753::Fr as MNT4753Fr, mnt6753::Fr as MNT6753Fr, bls12_381::Fr as BLS12381Fr},
+        BigInteger, Group, UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn transcript_test<T: FiatShamirTranscript>() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let elems = (0..3).map(|_| T::Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        let mut transcript = T::new(b"TEST");
+        transcript.append_field(&elems);
+
+        // The same messages yield the same challenges
+        let mut transcript_clone = transcript.clone();
+        let c: MNT6753Fr = transcript.challenge_scalar();
+        assert_eq!(c, transcript_clone.challenge_scalar::<MNT6753Fr>());
+
+        // Successive challenges are different
+        assert_ne!(c, transcript.challenge_scalar::<MNT6753Fr>());
+
+        // And so are the challenges of other protocols, or of other messages
+        let mut other_protocol = T::new(b"TEST2");
+        other_protocol.append_field(&elems);
+        assert_ne!(c, other_protocol.challenge_scalar::<MNT6753Fr>());
+
+        let mut other_messages = T::new(b"TEST");
+        other_messages.append_field(&elems[..2]);
+        assert_ne!(c, other_messages.challenge_scalar::<MNT6753Fr>());
+
+        // The challenges fit their field, whatever its size
+        for _ in 0..10 {
+            let bits = transcript.challenge_bits::<BLS12381Fr>();
+            assert!(bits.len() < BLS12381Fr::size_in_bits());
+            let bits = transcript.challenge_bits::<MNT4753Fr>();
+            assert!(bits.len() < MNT4753Fr::size_in_bits());
+            let c = transcript.challenge_scalar::<MNT4753Fr>();
+            assert!(c.into_repr().num_bits() < MNT4753Fr::size_in_bits() as u32);
+        }
+    }
+
+    #[test]
+    fn test_poseidon_transcript() {
+        transcript_test::<MNT4PoseidonTranscript>();
+        transcript_test::<MNT6PoseidonTranscript>();
+    }
+
+    #[test]
+    fn test_blake2s_transcript() {
+        transcript_test::<Blake2sTranscript<MNT4753Fr>>();
+        transcript_test::<Blake2sTranscript<BLS12381Fr>>();
+    }
+
+    #[test]
+    fn test_append_point() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let point = MNT6G1Projective::rand(&mut rng);
+
+        // Appending a point is appending its coordinates
+        let mut transcript = MNT4PoseidonTranscript::new(b"TEST");
+        transcript.append_point(&point).unwrap();
+        let mut transcript_coords = MNT4PoseidonTranscript::new(b"TEST");
+        transcript_coords.append_field(&point.to_field_elements().unwrap());
+        assert_eq!(
+            transcript.challenge_scalar::<MNT6753Fr>(),
+            transcript_coords.challenge_scalar::<MNT6753Fr>()
+        );
+
+        let mut other_point = MNT4PoseidonTranscript::new(b"TEST");
+        other_point.append_point(&point.double()).unwrap();
+        assert_ne!(
+            transcript.challenge_scalar::<MNT6753Fr>(),
+            other_point.challenge_scalar::<MNT6753Fr>()
+        );
+    }
+}
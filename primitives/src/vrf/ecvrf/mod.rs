@@ -1,5 +1,6 @@
 use algebra::{Field, PrimeField, FpParameters, convert, leading_zeros, Group, AffineCurve, ProjectiveCurve,
-              ToBytes, to_bytes, ToBits, UniformRand, ToConstraintField, FromBytes};
+              ToBytes, to_bytes, ToBits, UniformRand, ToConstraintField, FromBytes,
+              FromBytesChecked, ToCompressedBytes, FromCompressedBytes};
 use crate::{crh::{
     FieldBasedHash, FixedLengthCRH,
 }, vrf::FieldBasedVrf, Error, CryptoError, compute_truncation_size};
@@ -53,6 +54,32 @@ impl<F: PrimeField, G: ProjectiveCurve> FromBytes for FieldBasedEcVrfProof<F, G>
     }
 }
 
+impl<F: PrimeField, G: ProjectiveCurve> FromBytesChecked for FieldBasedEcVrfProof<F, G> {
+    fn read_checked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = G::Affine::read_checked(&mut reader)?;
+        let c = F::read(&mut reader)?;
+        let s = F::read(&mut reader)?;
+        Ok(Self{ gamma: gamma.into_projective(), c, s })
+    }
+}
+
+impl<F: PrimeField, G: ProjectiveCurve> ToCompressedBytes for FieldBasedEcVrfProof<F, G> {
+    fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.gamma.into_affine().write_compressed(&mut writer)?;
+        self.c.write(&mut writer)?;
+        self.s.write(&mut writer)
+    }
+}
+
+impl<F: PrimeField, G: ProjectiveCurve> FromCompressedBytes for FieldBasedEcVrfProof<F, G> {
+    fn read_compressed<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = G::Affine::read_compressed(&mut reader)?;
+        let c = F::read(&mut reader)?;
+        let s = F::read(&mut reader)?;
+        Ok(Self{ gamma: gamma.into_projective(), c, s })
+    }
+}
+
 impl<F, G, FH, GH> FieldBasedVrf for FieldBasedEcVrf<F, G, FH, GH>
     where
         F: PrimeField,
@@ -253,7 +280,7 @@ mod test {
         mnt4753::Fr as MNT4Fr,
         mnt6753::Fr as MNT6Fr,
     };
-    use algebra::{ToBytes, FromBytes, to_bytes};
+    use algebra::{ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes};
     use crate::{
         crh::{
             MNT4PoseidonHash, MNT6PoseidonHash,
@@ -294,6 +321,20 @@ mod test {
         let proof_deserialized = <S as FieldBasedVrf>::Proof::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
         assert!(S::proof_to_hash(pp, &pk, &message, &proof_deserialized).is_ok());
+        let proof_deserialized = <S as FieldBasedVrf>::Proof::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        //Compressed serialization/deserialization test
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = <S as FieldBasedVrf>::Proof::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
+
+        let mut pk_compressed = vec![];
+        pk.write_compressed(&mut pk_compressed).unwrap();
+        let pk_decompressed = <S as FieldBasedVrf>::PublicKey::read_compressed(pk_compressed.as_slice()).unwrap();
+        assert_eq!(pk, pk_decompressed);
     }
 
     fn failed_verification<S: FieldBasedVrf, R: Rng>(rng: &mut R, message: &[S::Data], bad_message: &[S::Data], pp: &S::GHParams) {
@@ -1,8 +1,11 @@
 //! An implementation of the [Groth-Maller][GM17] simulation extractable zkSNARK.
 //! [GM17]: https://eprint.iacr.org/2017/540
-use algebra::{bytes::ToBytes, PairingCurve, PairingEngine};
+use algebra::{bytes::{
+    ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes,
+}, PairingCurve, PairingEngine};
 use r1cs_core::SynthesisError;
 use std::io::{self, Read, Result as IoResult, Write};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 /// Reduce an R1CS instance to a *Square Arithmetic Program* instance.
 pub mod r1cs_to_sap;
@@ -22,7 +25,7 @@ mod test;
 pub use self::{generator::*, prover::*, verifier::*};
 
 /// A proof in the GM17 SNARK.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Proof<E: PairingEngine> {
     pub a: E::G1Affine,
     pub b: E::G2Affine,
@@ -38,6 +41,45 @@ impl<E: PairingEngine> ToBytes for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Proof<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, false)
+    }
+}
+
+impl<E: PairingEngine> ToCompressedBytes for Proof<E> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.a.write_compressed(&mut writer)?;
+        self.b.write_compressed(&mut writer)?;
+        self.c.write_compressed(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromCompressedBytes for Proof<E> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, true)
+    }
+}
+
+impl<E: PairingEngine> Proof<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool, compressed: bool) -> IoResult<Self> {
+        let a = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
+        let b = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+        let c = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
+        Ok(Proof{a, b, c})
+    }
+}
+
 impl<E: PairingEngine> PartialEq for Proof<E> {
     fn eq(&self, other: &Self) -> bool {
         self.a == other.a && self.b == other.b && self.c == other.c
@@ -54,23 +96,45 @@ impl<E: PairingEngine> Default for Proof<E> {
     }
 }
 
-impl<E: PairingEngine> Proof<E> {
-    /// Serialize the proof into bytes, for storage on disk or transmission 
-    /// over the network.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
+use algebra::curves::AffineCurve;
 
-    /// Deserialize the proof from bytes.
-    pub fn read<R: Read>(mut _reader: R) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
+/// Reads a single affine point. Compressed points are always validated when
+/// decompressed, uncompressed ones only if `checked` is set.
+fn read_affine<G: AffineCurve, R: Read>(checked: bool, compressed: bool, reader: R) -> IoResult<G> {
+    match (compressed, checked) {
+        (true, _) => G::read_compressed(reader),
+        (false, true) => G::read_checked(reader),
+        (false, false) => G::read(reader),
     }
 }
 
+fn read_affine_vec<G: AffineCurve, R: Read>(
+    len: usize,
+    check_for_zero: bool,
+    checked: bool,
+    compressed: bool,
+    mut reader: R
+) -> IoResult<Vec<G>> {
+    let mut v = vec![];
+    for _ in 0..len {
+        let g = read_affine::<G, _>(checked, compressed, &mut reader)
+            .and_then(|e| {
+                if check_for_zero && e.is_zero() {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "point at infinity",
+                    ))
+                } else {
+                    Ok(e)
+                }
+            })?;
+        v.push(g);
+    }
+    Ok(v)
+}
+
 /// A verification key in the GM17 SNARK.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct VerifyingKey<E: PairingEngine> {
     pub h_g2:       E::G2Affine,
     pub g_alpha_g1: E::G1Affine,
@@ -87,6 +151,7 @@ impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
         self.h_beta_g2.write(&mut writer)?;
         self.g_gamma_g1.write(&mut writer)?;
         self.h_gamma_g2.write(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.query.len() as u32)?;
         for q in &self.query {
             q.write(&mut writer)?;
         }
@@ -94,6 +159,57 @@ impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
     }
 }
 
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for VerifyingKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, false)
+    }
+}
+
+impl<E: PairingEngine> ToCompressedBytes for VerifyingKey<E> {
+    fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.h_g2.write_compressed(&mut writer)?;
+        self.g_alpha_g1.write_compressed(&mut writer)?;
+        self.h_beta_g2.write_compressed(&mut writer)?;
+        self.g_gamma_g1.write_compressed(&mut writer)?;
+        self.h_gamma_g2.write_compressed(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.query.len() as u32)?;
+        for q in &self.query {
+            q.write_compressed(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromCompressedBytes for VerifyingKey<E> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, true)
+    }
+}
+
+impl<E: PairingEngine> VerifyingKey<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool, compressed: bool) -> IoResult<Self> {
+        let h_g2 = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+        let g_alpha_g1 = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
+        let h_beta_g2 = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+        let g_gamma_g1 = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
+        let h_gamma_g2 = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+
+        let query_len = reader.read_u32::<BigEndian>()? as usize;
+        let query = read_affine_vec::<E::G1Affine, _>(query_len, true, checked, compressed, &mut reader)?;
+
+        Ok(VerifyingKey{h_g2, g_alpha_g1, h_beta_g2, g_gamma_g1, h_gamma_g2, query})
+    }
+}
+
 impl<E: PairingEngine> Default for VerifyingKey<E> {
     fn default() -> Self {
         Self {
@@ -118,23 +234,8 @@ impl<E: PairingEngine> PartialEq for VerifyingKey<E> {
     }
 }
 
-impl<E: PairingEngine> VerifyingKey<E> {
-    /// Serialize the verification key into bytes, for storage on disk 
-    /// or transmission over the network.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
-
-    /// Deserialize the verification key from bytes.
-    pub fn read<R: Read>(mut _reader: R) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
-}
-
 /// Full public (prover and verifier) parameters for the GM17 zkSNARK.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Parameters<E: PairingEngine> {
     pub vk:           VerifyingKey<E>,
     pub a_query:      Vec<E::G1Affine>,
@@ -163,23 +264,73 @@ impl<E: PairingEngine> PartialEq for Parameters<E> {
     }
 }
 
-impl<E: PairingEngine> Parameters<E> {
-    /// Serialize the parameters to bytes.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
+impl<E: PairingEngine> ToBytes for Parameters<E>{
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.vk.write(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.a_query.len() as u32)?;
+        for a in &self.a_query {a.write(&mut writer)?;}
+        writer.write_u32::<BigEndian>(self.b_query.len() as u32)?;
+        for a in &self.b_query {a.write(&mut writer)?;}
+        writer.write_u32::<BigEndian>(self.c_query_1.len() as u32)?;
+        for a in &self.c_query_1 {a.write(&mut writer)?;}
+        writer.write_u32::<BigEndian>(self.c_query_2.len() as u32)?;
+        for a in &self.c_query_2 {a.write(&mut writer)?;}
+        self.g_gamma_z.write(&mut writer)?;
+        self.h_gamma_z.write(&mut writer)?;
+        self.g_ab_gamma_z.write(&mut writer)?;
+        self.g_gamma2_z2.write(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.g_gamma2_z_t.len() as u32)?;
+        for a in &self.g_gamma2_z_t {a.write(&mut writer)?;}
+        Ok(())
     }
+}
 
-    /// Deserialize the public parameters from bytes.
-    pub fn read<R: Read>(mut _reader: R, _checked: bool) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
+impl<E: PairingEngine> FromBytes for Parameters<E>{
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Parameters::<E>::read(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Parameters<E>{
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Parameters::<E>::read(reader, true)
+    }
+}
+
+impl<E: PairingEngine> Parameters<E> {
+    /// Deserialize the public parameters from bytes. If `checked` is set,
+    /// every point is required to be on the curve and in the prime order
+    /// subgroup; otherwise the points are read as they are, which is only
+    /// safe for parameters coming from a trusted source.
+    pub fn read<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read_maybe_checked(&mut reader, checked, false)?;
+        let a_len = reader.read_u32::<BigEndian>()? as usize;
+        let a_query = read_affine_vec::<E::G1Affine, _>(a_len, false, checked, false, &mut reader)?;
+        let b_len = reader.read_u32::<BigEndian>()? as usize;
+        let b_query = read_affine_vec::<E::G2Affine, _>(b_len, false, checked, false, &mut reader)?;
+        let c_1_len = reader.read_u32::<BigEndian>()? as usize;
+        let c_query_1 = read_affine_vec::<E::G1Affine, _>(c_1_len, false, checked, false, &mut reader)?;
+        let c_2_len = reader.read_u32::<BigEndian>()? as usize;
+        let c_query_2 = read_affine_vec::<E::G1Affine, _>(c_2_len, false, checked, false, &mut reader)?;
+        let g_gamma_z = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let h_gamma_z = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+        let g_ab_gamma_z = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let g_gamma2_z2 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let g_gamma2_z_t_len = reader.read_u32::<BigEndian>()? as usize;
+        let g_gamma2_z_t = read_affine_vec::<E::G1Affine, _>(g_gamma2_z_t_len, false, checked, false, &mut reader)?;
+        Ok(Parameters{
+            vk, a_query, b_query, c_query_1, c_query_2,
+            g_gamma_z, h_gamma_z, g_ab_gamma_z, g_gamma2_z2, g_gamma2_z_t
+        })
     }
 }
 
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct PreparedVerifyingKey<E: PairingEngine> {
     pub vk:                VerifyingKey<E>,
     pub g_alpha:           E::G1Affine,
@@ -227,6 +378,7 @@ impl<E: PairingEngine> ToBytes for PreparedVerifyingKey<E> {
         self.g_gamma_pc.write(&mut writer)?;
         self.h_gamma_pc.write(&mut writer)?;
         self.h_pc.write(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.query.len() as u32)?;
         for q in &self.query {
             q.write(&mut writer)?;
         }
@@ -234,6 +386,52 @@ impl<E: PairingEngine> ToBytes for PreparedVerifyingKey<E> {
     }
 }
 
+impl<E: PairingEngine> FromBytes for PreparedVerifyingKey<E> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read(&mut reader)?;
+        let g_alpha = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_beta = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_alpha_h_beta_ml = E::Fqk::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_gamma_pc = <E::G1Affine as PairingCurve>::Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_gamma_pc = <E::G2Affine as PairingCurve>::Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_pc = <E::G2Affine as PairingCurve>::Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let query_len = reader.read_u32::<BigEndian>()? as usize;
+        let query = read_affine_vec::<E::G1Affine, _>(query_len, true, false, false, &mut reader)?;
+
+        Ok(PreparedVerifyingKey {
+            vk,
+            g_alpha,
+            h_beta,
+            g_alpha_h_beta_ml,
+            g_gamma_pc,
+            h_gamma_pc,
+            h_pc,
+            query,
+        })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for PreparedVerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vk == other.vk
+            && self.g_alpha == other.g_alpha
+            && self.h_beta == other.h_beta
+            && self.g_alpha_h_beta_ml == other.g_alpha_h_beta_ml
+            && self.g_gamma_pc == other.g_gamma_pc
+            && self.h_gamma_pc == other.h_gamma_pc
+            && self.h_pc == other.h_pc
+            && self.query == other.query
+    }
+}
+
 impl<E: PairingEngine> Parameters<E> {
     pub fn get_vk(&self, _: usize) -> Result<VerifyingKey<E>, SynthesisError> {
         Ok(self.vk.clone())
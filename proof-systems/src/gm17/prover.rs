@@ -2,180 +2,22 @@ use rand::Rng;
 use rayon::prelude::*;
 
 use algebra::{
-    UniformRand, msm::VariableBaseMSM, AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve,
+    UniformRand, msm::VariableBaseMSM, AffineCurve, PairingEngine, PrimeField, ProjectiveCurve,
 };
 
 use crate::gm17::{Parameters, Proof};
 use crate::gm17::r1cs_to_sap::R1CStoSAP;
 
-use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
-
-use smallvec::SmallVec;
+use r1cs_core::{ConstraintSynthesizer, SynthesisError, WitnessConstraintSystem};
 
 use std::{
-    ops::{AddAssign, MulAssign},
+    ops::AddAssign,
     sync::Arc,
 };
 
-type CoeffVec<T> = SmallVec<[T; 2]>;
-
-#[inline]
-fn eval<E: PairingEngine>(
-    lc: &LinearCombination<E::Fr>,
-    constraints: &mut [CoeffVec<(E::Fr, Index)>],
-    input_assignment: &[E::Fr],
-    aux_assignment: &[E::Fr],
-    this_constraint: usize,
-) -> E::Fr {
-    let mut acc = E::Fr::zero();
-
-    for &(index, coeff) in lc.as_ref() {
-        let mut tmp;
-
-        match index.get_unchecked() {
-            Index::Input(i) => {
-                constraints[this_constraint].push((coeff, Index::Input(i)));
-                tmp = input_assignment[i];
-            },
-            Index::Aux(i) => {
-                constraints[this_constraint].push((coeff, Index::Aux(i)));
-                tmp = aux_assignment[i];
-            },
-        }
-
-        if coeff.is_one() {
-            acc.add_assign(&tmp);
-        } else {
-            tmp.mul_assign(&coeff);
-            acc.add_assign(&tmp);
-        }
-    }
-
-    acc
-}
-
-pub struct ProvingAssignment<E: PairingEngine> {
-    // Constraints
-    pub(crate) at: Vec<CoeffVec<(E::Fr, Index)>>,
-    pub(crate) bt: Vec<CoeffVec<(E::Fr, Index)>>,
-    pub(crate) ct: Vec<CoeffVec<(E::Fr, Index)>>,
-
-    // Evaluations of A and C polynomials
-    pub(crate) a: Vec<E::Fr>,
-    pub(crate) b: Vec<E::Fr>,
-    pub(crate) c: Vec<E::Fr>,
-
-    // Assignments of variables
-    pub(crate) input_assignment: Vec<E::Fr>,
-    pub(crate) aux_assignment:   Vec<E::Fr>,
-    pub(crate) num_inputs:       usize,
-    pub(crate) num_aux:          usize,
-    pub(crate) num_constraints:  usize,
-}
-
-impl<E: PairingEngine> ProvingAssignment<E> {
-    pub fn which_is_unsatisfied(&self) -> Option<usize> {
-        for (i, ((a_i, b_i), c_i)) in (self.a.iter().zip(self.b.iter()))
-            .zip(self.c.iter())
-            .enumerate()
-        {
-            if *a_i * b_i != *c_i {
-                return Some(i);
-            }
-        }
-        None
-    }
-}
-
-impl<E: PairingEngine> ConstraintSystem<E::Fr> for ProvingAssignment<E> {
-    type Root = Self;
-
-    #[inline]
-    fn alloc<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
-    where
-        F: FnOnce() -> Result<E::Fr, SynthesisError>,
-        A: FnOnce() -> AR,
-        AR: Into<String>,
-    {
-        let index = self.num_aux;
-        self.num_aux += 1;
-
-        self.aux_assignment.push(f()?);
-        Ok(Variable::new_unchecked(Index::Aux(index)))
-    }
-
-    #[inline]
-    fn alloc_input<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
-    where
-        F: FnOnce() -> Result<E::Fr, SynthesisError>,
-        A: FnOnce() -> AR,
-        AR: Into<String>,
-    {
-        let index = self.num_inputs;
-        self.num_inputs += 1;
-
-        self.input_assignment.push(f()?);
-        Ok(Variable::new_unchecked(Index::Input(index)))
-    }
-
-    #[inline]
-    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
-    where
-        A: FnOnce() -> AR,
-        AR: Into<String>,
-        LA: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
-        LB: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
-        LC: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
-    {
-        self.at.push(CoeffVec::new());
-        self.bt.push(CoeffVec::new());
-        self.ct.push(CoeffVec::new());
-
-        self.a.push(eval::<E>(
-            &a(LinearCombination::zero()),
-            &mut self.at,
-            &self.input_assignment,
-            &self.aux_assignment,
-            self.num_constraints,
-        ));
-        self.b.push(eval::<E>(
-            &b(LinearCombination::zero()),
-            &mut self.bt,
-            &self.input_assignment,
-            &self.aux_assignment,
-            self.num_constraints,
-        ));
-        self.c.push(eval::<E>(
-            &c(LinearCombination::zero()),
-            &mut self.ct,
-            &self.input_assignment,
-            &self.aux_assignment,
-            self.num_constraints,
-        ));
-
-        self.num_constraints += 1;
-    }
-
-    fn push_namespace<NR, N>(&mut self, _: N)
-    where
-        NR: Into<String>,
-        N: FnOnce() -> NR,
-    {
-        // Do nothing; we don't care about namespaces in this context.
-    }
-
-    fn pop_namespace(&mut self) {
-        // Do nothing; we don't care about namespaces in this context.
-    }
-
-    fn get_root(&mut self) -> &mut Self::Root {
-        self
-    }
-
-    fn num_constraints(&self) -> usize {
-        self.a.len()
-    }
-}
+/// The witness of a circuit, i.e. its assignment and the evaluations of its
+/// constraints, which is all the prover needs.
+pub type ProvingAssignment<E> = WitnessConstraintSystem<<E as PairingEngine>::Fr>;
 
 pub fn create_random_proof<E, C, R>(
     circuit: C,
@@ -194,6 +36,24 @@ where
     create_proof::<E, C>(circuit, params, d1, d2, r)
 }
 
+/// Same as `create_random_proof`, from the witness of the circuit, computed
+/// once with `WitnessConstraintSystem::from_synthesizer`.
+pub fn create_random_proof_from_witness<E, R>(
+    witness: &ProvingAssignment<E>,
+    params: &Parameters<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    R: Rng,
+{
+    let d1 = E::Fr::rand(rng);
+    let d2 = E::Fr::rand(rng);
+    let r = E::Fr::rand(rng);
+
+    create_proof_from_witness::<E>(witness, params, d1, d2, r)
+}
+
 pub fn create_proof<E, C>(
     circuit: C,
     params: &Parameters<E>,
@@ -205,42 +65,41 @@ where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
 {
-    let prover_time = start_timer!(|| "Prover");
-    let mut prover = ProvingAssignment {
-        at:               vec![],
-        bt:               vec![],
-        ct:               vec![],
-        a:                vec![],
-        b:                vec![],
-        c:                vec![],
-        input_assignment: vec![],
-        aux_assignment:   vec![],
-        num_inputs:       0,
-        num_aux:          0,
-        num_constraints:  0,
-    };
-
-    // Allocate the "one" input variable
-    prover.alloc_input(|| "", || Ok(E::Fr::one()))?;
-
     // Synthesize the circuit.
     let synthesis_time = start_timer!(|| "Constraint synthesis");
-    circuit.generate_constraints(&mut prover)?;
+    let prover = WitnessConstraintSystem::from_synthesizer(circuit)?;
     end_timer!(synthesis_time);
 
+    create_proof_from_witness::<E>(&prover, params, d1, d2, r)
+}
+
+/// Same as `create_proof`, from the witness of the circuit.
+pub fn create_proof_from_witness<E>(
+    prover: &ProvingAssignment<E>,
+    params: &Parameters<E>,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+{
+    let prover_time = start_timer!(|| "Prover");
+    let num_inputs = prover.num_inputs();
+
     let witness_map_time = start_timer!(|| "R1CS to SAP witness map");
-    let (full_input_assignment, h, _) = R1CStoSAP::witness_map::<E>(&prover, &d1, &d2)?;
+    let (full_input_assignment, h, _) = R1CStoSAP::witness_map::<E>(prover, &d1, &d2)?;
     end_timer!(witness_map_time);
 
     let input_assignment = Arc::new(
-        full_input_assignment[1..prover.num_inputs]
+        full_input_assignment[1..num_inputs]
             .into_iter()
             .map(|s| s.into_repr())
             .collect::<Vec<_>>(),
     );
 
     let aux_assignment = Arc::new(
-        full_input_assignment[prover.num_inputs..]
+        full_input_assignment[num_inputs..]
             .into_par_iter()
             .map(|s| s.into_repr())
             .collect::<Vec<_>>(),
@@ -248,13 +107,13 @@ where
     drop(full_input_assignment);
 
     let h_input = Arc::new(
-        h[0..prover.num_inputs]
+        h[0..num_inputs]
             .into_iter()
             .map(|s| s.into_repr())
             .collect::<Vec<_>>(),
     );
     let h_aux = Arc::new(
-        h[prover.num_inputs..]
+        h[num_inputs..]
             .into_par_iter()
             .map(|s| s.into_repr())
             .collect::<Vec<_>>(),
@@ -263,7 +122,7 @@ where
 
     // Compute A
     let a_acc_time = start_timer!(|| "Compute A");
-    let (a_inputs_source, a_aux_source) = params.get_a_query(prover.num_inputs)?;
+    let (a_inputs_source, a_aux_source) = params.get_a_query(num_inputs)?;
     let a_inputs_acc = VariableBaseMSM::multi_scalar_mul(a_inputs_source, &input_assignment);
     let a_aux_acc = VariableBaseMSM::multi_scalar_mul(a_aux_source, &aux_assignment);
 
@@ -280,7 +139,7 @@ where
     // Compute B
     let b_acc_time = start_timer!(|| "Compute B");
 
-    let (b_inputs_source, b_aux_source) = params.get_b_query(prover.num_inputs)?;
+    let (b_inputs_source, b_aux_source) = params.get_b_query(num_inputs)?;
     let b_inputs_acc = VariableBaseMSM::multi_scalar_mul(b_inputs_source, &input_assignment);
     let b_aux_acc = VariableBaseMSM::multi_scalar_mul(b_aux_source, &aux_assignment);
 
@@ -307,7 +166,7 @@ where
 
     let c2_acc_time = start_timer!(|| "Compute C2");
 
-    let (c2_inputs_source, c2_aux_source) = params.get_c_query_2(prover.num_inputs)?;
+    let (c2_inputs_source, c2_aux_source) = params.get_c_query_2(num_inputs)?;
     let c2_inputs_acc = VariableBaseMSM::multi_scalar_mul(c2_inputs_source, &input_assignment);
     let c2_aux_acc = VariableBaseMSM::multi_scalar_mul(c2_aux_source, &aux_assignment);
 
@@ -317,7 +176,7 @@ where
     // Compute G
     let g_acc_time = start_timer!(|| "Compute G");
 
-    let (g_inputs_source, g_aux_source) = params.get_g_gamma2_z_t(prover.num_inputs)?;
+    let (g_inputs_source, g_aux_source) = params.get_g_gamma2_z_t(num_inputs)?;
     let g_inputs_acc = VariableBaseMSM::multi_scalar_mul(g_inputs_source, &h_input);
     let g_aux_acc = VariableBaseMSM::multi_scalar_mul(g_aux_source, &h_aux);
 
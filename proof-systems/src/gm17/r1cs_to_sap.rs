@@ -102,48 +102,25 @@ impl R1CStoSAP {
         d1: &E::Fr,
         d2: &E::Fr,
     ) -> Result<(Vec<E::Fr>, Vec<E::Fr>, usize), SynthesisError> {
-        #[inline]
-        fn evaluate_constraint<E: PairingEngine>(
-            terms: &[(E::Fr, Index)],
-            assignment: &[E::Fr],
-            num_input: usize,
-        ) -> E::Fr {
-            let mut acc = E::Fr::zero();
-            for &(coeff, index) in terms {
-                let val = match index {
-                    Index::Input(i) => assignment[i],
-                    Index::Aux(i) => assignment[num_input + i],
-                };
-                acc += &(val * &coeff);
-            }
-            acc
-        }
-
         let zero = E::Fr::zero();
         let one = E::Fr::one();
 
         let mut full_input_assignment = prover.input_assignment.clone();
-        full_input_assignment.extend(prover.aux_assignment.clone());
+        full_input_assignment.extend_from_slice(&prover.aux_assignment);
+
+        let num_inputs = prover.num_inputs();
+        let num_aux = prover.num_aux();
+        let num_constraints = prover.a.len();
 
         let temp = prover
-            .at
+            .a
             .par_iter()
-            .zip(&prover.bt)
-            .map(|(a_i, b_i)| {
-                let mut extra_var: E::Fr =
-                    evaluate_constraint::<E>(&a_i, &full_input_assignment, prover.num_inputs);
-                extra_var.sub_assign(&evaluate_constraint::<E>(
-                    &b_i,
-                    &full_input_assignment,
-                    prover.num_inputs,
-                ));
-                extra_var.square_in_place();
-                extra_var
-            })
+            .zip(&prover.b)
+            .map(|(a_i, b_i)| (*a_i - b_i).square())
             .collect::<Vec<_>>();
         full_input_assignment.extend(temp);
 
-        for i in 1..prover.num_inputs {
+        for i in 1..num_inputs {
             let mut extra_var = full_input_assignment[i];
             extra_var.sub_assign(&one);
             extra_var.square_in_place();
@@ -151,39 +128,26 @@ impl R1CStoSAP {
         }
 
         let domain = EvaluationDomain::<E::Fr>::new(
-            2 * prover.num_constraints + 2 * (prover.num_inputs - 1) + 1,
+            2 * num_constraints + 2 * (num_inputs - 1) + 1,
         )
         .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let domain_size = domain.size();
 
-        let extra_constr_offset = 2 * prover.num_constraints;
-        let extra_var_offset = prover.num_inputs + prover.num_aux;
-        let extra_var_offset2 = prover.num_inputs + prover.num_aux + prover.num_constraints - 1;
+        let extra_constr_offset = 2 * num_constraints;
+        let extra_var_offset = num_inputs + num_aux;
+        let extra_var_offset2 = num_inputs + num_aux + num_constraints - 1;
 
         let mut a = vec![zero; domain_size];
-        a[..2 * prover.num_constraints]
+        a[..2 * num_constraints]
             .par_chunks_mut(2)
-            .zip(&prover.at)
-            .zip(&prover.bt)
-            .for_each(|((chunk, at_i), bt_i)| {
-                chunk[0] =
-                    evaluate_constraint::<E>(&at_i, &full_input_assignment, prover.num_inputs);
-                chunk[0].add_assign(&evaluate_constraint::<E>(
-                    &bt_i,
-                    &full_input_assignment,
-                    prover.num_inputs,
-                ));
-
-                chunk[1] =
-                    evaluate_constraint::<E>(&at_i, &full_input_assignment, prover.num_inputs);
-                chunk[1].sub_assign(&evaluate_constraint::<E>(
-                    &bt_i,
-                    &full_input_assignment,
-                    prover.num_inputs,
-                ));
+            .zip(&prover.a)
+            .zip(&prover.b)
+            .for_each(|((chunk, a_i), b_i)| {
+                chunk[0] = *a_i + b_i;
+                chunk[1] = *a_i - b_i;
             });
         a[extra_constr_offset] = one;
-        for i in 1..prover.num_inputs {
+        for i in 1..num_inputs {
             a[extra_constr_offset + 2 * i - 1] = full_input_assignment[i] + &one;
             a[extra_constr_offset + 2 * i] = full_input_assignment[i] - &one;
         }
@@ -204,15 +168,11 @@ impl R1CStoSAP {
         drop(a);
 
         let mut c = vec![zero; domain_size];
-        c[..2 * prover.num_constraints]
+        c[..2 * num_constraints]
             .par_chunks_mut(2)
             .enumerate()
             .for_each(|(i, chunk)| {
-                let mut tmp: E::Fr = evaluate_constraint::<E>(
-                    &prover.ct[i],
-                    &full_input_assignment,
-                    prover.num_inputs,
-                );
+                let mut tmp = prover.c[i];
                 tmp.double_in_place();
                 tmp.double_in_place();
 
@@ -221,7 +181,7 @@ impl R1CStoSAP {
                 chunk[1] = assignment;
             });
         c[extra_constr_offset] = one;
-        for i in 1..prover.num_inputs {
+        for i in 1..num_inputs {
             let mut tmp = full_input_assignment[i];
             tmp.double_in_place();
             tmp.double_in_place();
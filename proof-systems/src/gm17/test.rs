@@ -32,10 +32,14 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod bls12_377 {
     use super::*;
     use crate::gm17::{
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
+        create_random_proof, create_random_proof_from_witness, generate_random_parameters, prepare_verifying_key, verify_proof,
     };
 
-    use algebra::{UniformRand, curves::bls12_377::Bls12_377, fields::bls12_377::Fr};
+    use algebra::{UniformRand, curves::bls12_377::Bls12_377, fields::bls12_377::Fr,
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes,
+    };
+    use r1cs_core::WitnessConstraintSystem;
     use rand::thread_rng;
     use std::ops::MulAssign;
 
@@ -69,17 +73,108 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_from_witness() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * &b;
+
+        // The witness is computed once for several proofs
+        let witness = WitnessConstraintSystem::from_synthesizer(MySillyCircuit { a: Some(a), b: Some(b) }).unwrap();
+        assert_eq!((witness.num_inputs(), witness.num_aux(), witness.num_constraints()), (2, 2, 1));
+        assert!(witness.which_is_unsatisfied().is_none());
+
+        for _ in 0..2 {
+            let proof = create_random_proof_from_witness(&witness, &params, rng).unwrap();
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn serialize_deserialize() {
+
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let vk = params.vk.clone();
+
+        let params_serialized = to_bytes!(params).unwrap();
+        let params_deserialized = Parameters::<Bls12_377>::read(params_serialized.as_slice(), true).unwrap();
+        assert_eq!(params, params_deserialized);
+
+        let vk_serialized = to_bytes!(vk).unwrap();
+        let vk_deserialized = VerifyingKey::<Bls12_377>::read(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<Bls12_377>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<Bls12_377>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
+
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params_deserialized,
+            rng,
+        )
+            .unwrap();
+
+        let proof_serialized = to_bytes!(proof).unwrap();
+        let proof_deserialized = Proof::<Bls12_377>::read(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<Bls12_377>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<Bls12_377>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
+
+        let pvk = prepare_verifying_key(&vk_deserialized);
+        let pvk_serialized = to_bytes!(pvk).unwrap();
+        let pvk_deserialized = PreparedVerifyingKey::<Bls12_377>::read(pvk_serialized.as_slice()).unwrap();
+        assert_eq!(pvk, pvk_deserialized);
+
+        assert!(verify_proof(&pvk_deserialized, &proof_deserialized, &[c]).unwrap());
+    }
 }
 
 mod sw6 {
     use super::*;
     use crate::gm17::{
+        Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
     };
 
     use rand::thread_rng;
 
-    use algebra::{UniformRand, curves::sw6::SW6, fields::sw6::Fr as SW6Fr, Field};
+    use algebra::{UniformRand, curves::sw6::SW6, fields::sw6::Fr as SW6Fr, Field,
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes,
+    };
 
     #[test]
     fn prove_and_verify() {
@@ -108,17 +203,81 @@ mod sw6 {
         assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
         assert!(!verify_proof(&pvk, &proof, &[SW6Fr::zero()]).unwrap());
     }
+
+    #[test]
+    fn serialize_deserialize() {
+
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<SW6, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let vk = params.vk.clone();
+
+        let params_serialized = to_bytes!(params).unwrap();
+        let params_deserialized = Parameters::<SW6>::read(params_serialized.as_slice(), true).unwrap();
+        assert_eq!(params, params_deserialized);
+
+        let vk_serialized = to_bytes!(vk).unwrap();
+        let vk_deserialized = VerifyingKey::<SW6>::read(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<SW6>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<SW6>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
+
+
+        let a = SW6Fr::rand(rng);
+        let b = SW6Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params_deserialized,
+            rng,
+        )
+            .unwrap();
+
+        let proof_serialized = to_bytes!(proof).unwrap();
+        let proof_deserialized = Proof::<SW6>::read(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<SW6>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<SW6>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
+
+        let pvk = prepare_verifying_key(&vk_deserialized);
+        let pvk_serialized = to_bytes!(pvk).unwrap();
+        let pvk_deserialized = PreparedVerifyingKey::<SW6>::read(pvk_serialized.as_slice()).unwrap();
+        assert_eq!(pvk, pvk_deserialized);
+
+        assert!(verify_proof(&pvk_deserialized, &proof_deserialized, &[c]).unwrap());
+    }
 }
 
 mod mnt4753 {
     use super::*;
     use crate::gm17::{
+        Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
     };
 
     use rand::thread_rng;
 
-    use algebra::{curves::mnt4753::MNT4, fields::mnt4753::Fr as MNT4Fr, Field, UniformRand};
+    use algebra::{curves::mnt4753::MNT4, fields::mnt4753::Fr as MNT4Fr, Field, UniformRand,
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes};
 
     #[test]
     fn prove_and_verify() {
@@ -147,17 +306,81 @@ mod mnt4753 {
         assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
         assert!(!verify_proof(&pvk, &proof, &[MNT4Fr::zero()]).unwrap());
     }
+
+    #[test]
+    fn serialize_deserialize() {
+
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<MNT4, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let vk = params.vk.clone();
+
+        let params_serialized = to_bytes!(params).unwrap();
+        let params_deserialized = Parameters::<MNT4>::read(params_serialized.as_slice(), true).unwrap();
+        assert_eq!(params, params_deserialized);
+
+        let vk_serialized = to_bytes!(vk).unwrap();
+        let vk_deserialized = VerifyingKey::<MNT4>::read(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<MNT4>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<MNT4>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
+
+
+        let a = MNT4Fr::rand(rng);
+        let b = MNT4Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params_deserialized,
+            rng,
+        )
+            .unwrap();
+
+        let proof_serialized = to_bytes!(proof).unwrap();
+        let proof_deserialized = Proof::<MNT4>::read(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<MNT4>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<MNT4>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
+
+        let pvk = prepare_verifying_key(&vk_deserialized);
+        let pvk_serialized = to_bytes!(pvk).unwrap();
+        let pvk_deserialized = PreparedVerifyingKey::<MNT4>::read(pvk_serialized.as_slice()).unwrap();
+        assert_eq!(pvk, pvk_deserialized);
+
+        assert!(verify_proof(&pvk_deserialized, &proof_deserialized, &[c]).unwrap());
+    }
 }
 
 mod mnt6753 {
     use super::*;
     use crate::gm17::{
+        Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
     };
 
     use rand::thread_rng;
 
-    use algebra::{curves::mnt6753::MNT6, fields::mnt6753::Fr as MNT6Fr, Field, UniformRand};
+    use algebra::{curves::mnt6753::MNT6, fields::mnt6753::Fr as MNT6Fr, Field, UniformRand,
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes};
 
     #[test]
     fn prove_and_verify() {
@@ -186,4 +409,66 @@ mod mnt6753 {
         assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
         assert!(!verify_proof(&pvk, &proof, &[MNT6Fr::zero()]).unwrap());
     }
+
+    #[test]
+    fn serialize_deserialize() {
+
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<MNT6, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let vk = params.vk.clone();
+
+        let params_serialized = to_bytes!(params).unwrap();
+        let params_deserialized = Parameters::<MNT6>::read(params_serialized.as_slice(), true).unwrap();
+        assert_eq!(params, params_deserialized);
+
+        let vk_serialized = to_bytes!(vk).unwrap();
+        let vk_deserialized = VerifyingKey::<MNT6>::read(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<MNT6>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<MNT6>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
+
+
+        let a = MNT6Fr::rand(rng);
+        let b = MNT6Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params_deserialized,
+            rng,
+        )
+            .unwrap();
+
+        let proof_serialized = to_bytes!(proof).unwrap();
+        let proof_deserialized = Proof::<MNT6>::read(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<MNT6>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<MNT6>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
+
+        let pvk = prepare_verifying_key(&vk_deserialized);
+        let pvk_serialized = to_bytes!(pvk).unwrap();
+        let pvk_deserialized = PreparedVerifyingKey::<MNT6>::read(pvk_serialized.as_slice()).unwrap();
+        assert_eq!(pvk, pvk_deserialized);
+
+        assert!(verify_proof(&pvk_deserialized, &proof_deserialized, &[c]).unwrap());
+    }
 }
@@ -1,7 +1,7 @@
 //! An implementation of the [Groth][Groth16] zkSNARK.
 //! [Groth16]: https://eprint.iacr.org/2016/260.pdf
 use algebra::{bytes::{
-    ToBytes, FromBytes,
+    ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes,
 }, PairingCurve, PairingEngine};
 use r1cs_core::SynthesisError;
 use std::io::{self, Read, Result as IoResult, Write};
@@ -19,10 +19,17 @@ pub mod prover;
 /// Verify proofs for the Groth16 zkSNARK construction.
 pub mod verifier;
 
+/// Sources of the proving parameters, possibly loaded lazily from disk.
+pub mod source;
+
+/// Generate public parameters for the Groth16 zkSNARK construction via a
+/// multi-party computation.
+pub mod mpc;
+
 #[cfg(test)]
 mod test;
 
-pub use self::{generator::*, prover::*, verifier::*};
+pub use self::{generator::*, prover::*, source::*, verifier::*};
 
 /// A proof in the Groth16 SNARK.
 #[derive(Clone, Debug)]
@@ -43,13 +50,39 @@ impl<E: PairingEngine> ToBytes for Proof<E> {
 
 impl<E: PairingEngine> FromBytes for Proof<E> {
     #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let a = E::G1Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let b = E::G2Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let c = E::G1Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Proof<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, false)
+    }
+}
+
+impl<E: PairingEngine> ToCompressedBytes for Proof<E> {
+    #[inline]
+    fn write_compressed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.a.write_compressed(&mut writer)?;
+        self.b.write_compressed(&mut writer)?;
+        self.c.write_compressed(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromCompressedBytes for Proof<E> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, true)
+    }
+}
+
+impl<E: PairingEngine> Proof<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool, compressed: bool) -> IoResult<Self> {
+        let a = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
+        let b = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+        let c = read_affine::<E::G1Affine, _>(checked, compressed, &mut reader)?;
         Ok(Proof{a, b, c})
     }
 }
@@ -72,11 +105,26 @@ impl<E: PairingEngine> Default for Proof<E> {
 
 use algebra::curves::AffineCurve;
 
-fn read_affine_vec<G: AffineCurve, R: Read>(len: usize, check_for_zero: bool, mut reader: R) -> IoResult<Vec<G>> {
+/// Reads a single affine point. Compressed points are always validated when
+/// decompressed, uncompressed ones only if `checked` is set.
+fn read_affine<G: AffineCurve, R: Read>(checked: bool, compressed: bool, reader: R) -> IoResult<G> {
+    match (compressed, checked) {
+        (true, _) => G::read_compressed(reader),
+        (false, true) => G::read_checked(reader),
+        (false, false) => G::read(reader),
+    }
+}
+
+fn read_affine_vec<G: AffineCurve, R: Read>(
+    len: usize,
+    check_for_zero: bool,
+    checked: bool,
+    compressed: bool,
+    mut reader: R
+) -> IoResult<Vec<G>> {
     let mut v = vec![];
     for _ in 0..len {
-        let g = G::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        let g = read_affine::<G, _>(checked, compressed, &mut reader)
             .and_then(|e| {
                 if check_for_zero && e.is_zero() {
                     Err(io::Error::new(
@@ -116,22 +164,52 @@ impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
 
 impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
     #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for VerifyingKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, false)
+    }
+}
+
+impl<E: PairingEngine> ToCompressedBytes for VerifyingKey<E> {
+    fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.alpha_g1_beta_g2.write(&mut writer)?;
+        self.gamma_g2.write_compressed(&mut writer)?;
+        self.delta_g2.write_compressed(&mut writer)?;
+        writer.write_u32::<BigEndian>(self.gamma_abc_g1.len() as u32)?;
+        for q in &self.gamma_abc_g1 {
+            q.write_compressed(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromCompressedBytes for VerifyingKey<E> {
+    #[inline]
+    fn read_compressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true, true)
+    }
+}
+
+impl<E: PairingEngine> VerifyingKey<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool, compressed: bool) -> IoResult<Self> {
         let alpha_g1_beta_g2 = E::Fqk::read(&mut reader)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let gamma_g2 = E::G2Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let delta_g2 = E::G2Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let gamma_g2 = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
+        let delta_g2 = read_affine::<E::G2Affine, _>(checked, compressed, &mut reader)?;
 
         let ic_len = reader.read_u32::<BigEndian>()? as usize;
-        let gamma_abc_g1 = read_affine_vec::<E::G1Affine, _>(ic_len, true, &mut reader)?;
+        let gamma_abc_g1 = read_affine_vec::<E::G1Affine, _>(ic_len, true, checked, compressed, &mut reader)?;
 
         Ok(VerifyingKey{alpha_g1_beta_g2, gamma_g2, delta_g2, gamma_abc_g1})
     }
 }
 
-
 impl<E: PairingEngine> Default for VerifyingKey<E> {
     fn default() -> Self {
         Self {
@@ -210,31 +288,37 @@ impl<E: PairingEngine> ToBytes for Parameters<E>{
 
 impl<E: PairingEngine> FromBytes for Parameters<E>{
     #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let vk = VerifyingKey::<E>::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let alpha_g1 = E::G1Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let beta_g1 = E::G1Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let beta_g2 = E::G2Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let delta_g1 = E::G1Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        let delta_g2 = E::G2Affine::read(&mut reader)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Parameters<E>{
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+impl<E: PairingEngine> Parameters<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read_maybe_checked(&mut reader, checked, false)?;
+        let alpha_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let beta_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let beta_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+        let delta_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let delta_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
         let a_len = reader.read_u32::<BigEndian>()? as usize;
-        let a_query = read_affine_vec::<E::G1Affine, _>(a_len, false, &mut reader)?;
+        let a_query = read_affine_vec::<E::G1Affine, _>(a_len, false, checked, false, &mut reader)?;
         let b_g1_len = reader.read_u32::<BigEndian>()? as usize;
-        let b_g1_query = read_affine_vec::<E::G1Affine, _>(b_g1_len, false, &mut reader)?;
+        let b_g1_query = read_affine_vec::<E::G1Affine, _>(b_g1_len, false, checked, false, &mut reader)?;
         let b_g2_len = reader.read_u32::<BigEndian>()? as usize;
-        let b_g2_query = read_affine_vec::<E::G2Affine, _>(b_g2_len, false, &mut reader)?;
+        let b_g2_query = read_affine_vec::<E::G2Affine, _>(b_g2_len, false, checked, false, &mut reader)?;
         let h_len = reader.read_u32::<BigEndian>()? as usize;
-        let h_query = read_affine_vec::<E::G1Affine, _>(h_len, false, &mut reader)?;
+        let h_query = read_affine_vec::<E::G1Affine, _>(h_len, false, checked, false, &mut reader)?;
         let l_len = reader.read_u32::<BigEndian>()? as usize;
-        let l_query = read_affine_vec::<E::G1Affine, _>(l_len, false, &mut reader)?;
+        let l_query = read_affine_vec::<E::G1Affine, _>(l_len, false, checked, false, &mut reader)?;
         Ok(Parameters{vk, alpha_g1, beta_g1, beta_g2, delta_g1, delta_g2, a_query, b_g1_query, b_g2_query, h_query, l_query})
-
     }
 }
 
@@ -290,7 +374,7 @@ impl<E: PairingEngine> FromBytes for PreparedVerifyingKey<E> {
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
         let ic_len = reader.read_u32::<BigEndian>()? as usize;
-        let gamma_abc_g1 = read_affine_vec::<E::G1Affine, _>(ic_len, true, &mut reader)?;
+        let gamma_abc_g1 = read_affine_vec::<E::G1Affine, _>(ic_len, true, false, false, &mut reader)?;
 
         Ok(PreparedVerifyingKey {
             alpha_g1_beta_g2,
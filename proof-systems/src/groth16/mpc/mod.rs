@@ -0,0 +1,224 @@
+//! A multi-party computation ceremony for the Groth16 common reference string,
+//! following Bowe, Gabizon and Miers (https://eprint.iacr.org/2017/1050).
+//!
+//! The ceremony runs in two phases. Phase 1 ("powers of tau") is independent
+//! of the circuit and produces an `Accumulator` holding powers of the secret
+//! `tau`, multiplied by the secrets `alpha` and `beta`. Phase 2 specializes an
+//! accumulator to a circuit by means of `MPCParameters`, to which participants
+//! contribute the secret `delta`. Each contribution comes with a proof of
+//! knowledge of the secret it introduces, and the final `Parameters` are
+//! secure as long as at least one participant of each phase was honest.
+use algebra::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes},
+    msm::VariableBaseMSM,
+    to_bytes, AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
+};
+use blake2::Blake2b;
+use digest::Digest;
+use rand::Rng;
+
+use std::io::{self, Read, Result as IoResult, Write};
+
+use crate::groth16::read_affine;
+
+pub mod phase1;
+pub mod phase2;
+
+#[cfg(test)]
+mod test;
+
+pub use self::{phase1::*, phase2::*};
+
+/// Length in bytes of the transcript digests.
+pub const DIGEST_LENGTH: usize = 64;
+
+/// The public counterpart of a secret `x` introduced by a participant, together
+/// with a Schnorr proof of knowledge of `x` bound to the transcript so far.
+#[derive(Clone, Debug)]
+pub struct PublicKey<E: PairingEngine> {
+    pub x_g1: E::G1Affine,
+    pub x_g2: E::G2Affine,
+    pub r_g1: E::G1Affine,
+    pub z:    E::Fr,
+}
+
+impl<E: PairingEngine> PublicKey<E> {
+    /// Creates the public key of `x` and proves knowledge of it, binding the
+    /// proof to `digest`.
+    pub fn new<R: Rng>(x: &E::Fr, digest: &[u8], rng: &mut R) -> Self {
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+
+        let x_g1 = g1.mul(x.into_repr()).into_affine();
+        let x_g2 = g2.mul(x.into_repr()).into_affine();
+
+        let k = E::Fr::rand(rng);
+        let r_g1 = g1.mul(k.into_repr()).into_affine();
+
+        let c = challenge::<E>(digest, &x_g1, &x_g2, &r_g1);
+        let z = k + &(c * x);
+
+        Self { x_g1, x_g2, r_g1, z }
+    }
+
+    /// Checks the proof of knowledge against `digest`, and that `x_g1` and
+    /// `x_g2` share the same (non-zero) discrete logarithm.
+    pub fn verify(&self, digest: &[u8]) -> bool {
+        if self.x_g1.is_zero() || self.x_g2.is_zero() {
+            return false;
+        }
+
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+
+        let c = challenge::<E>(digest, &self.x_g1, &self.x_g2, &self.r_g1);
+        let mut rhs = self.x_g1.mul(c.into_repr());
+        rhs.add_assign_mixed(&self.r_g1);
+
+        g1.mul(self.z.into_repr()) == rhs && same_ratio::<E>((g1, self.x_g1), (g2, self.x_g2))
+    }
+
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let x_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let x_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+        let r_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let z = E::Fr::read(&mut reader)?;
+        Ok(Self { x_g1, x_g2, r_g1, z })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for PublicKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x_g1 == other.x_g1
+            && self.x_g2 == other.x_g2
+            && self.r_g1 == other.r_g1
+            && self.z == other.z
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PublicKey<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x_g1.write(&mut writer)?;
+        self.x_g2.write(&mut writer)?;
+        self.r_g1.write(&mut writer)?;
+        self.z.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PublicKey<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for PublicKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+/// Checks that `g1.1 = x * g1.0` and `g2.1 = x * g2.0` for the same `x`.
+pub(crate) fn same_ratio<E: PairingEngine>(
+    g1: (E::G1Affine, E::G1Affine),
+    g2: (E::G2Affine, E::G2Affine),
+) -> bool {
+    E::pairing(g1.0, g2.1) == E::pairing(g1.1, g2.0)
+}
+
+/// Returns random linear combinations `(sum_i rho_i * v1[i], sum_i rho_i * v2[i])`.
+/// If `v2[i] = x * v1[i]` for all `i`, the same holds for the two combinations;
+/// otherwise it does not, except with negligible probability.
+pub(crate) fn merge_pairs<G: AffineCurve, R: Rng>(v1: &[G], v2: &[G], rng: &mut R) -> (G, G) {
+    assert_eq!(v1.len(), v2.len());
+
+    let rho = (0..v1.len())
+        .map(|_| G::ScalarField::rand(rng).into_repr())
+        .collect::<Vec<_>>();
+
+    (
+        VariableBaseMSM::multi_scalar_mul(v1, &rho).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(v2, &rho).into_affine(),
+    )
+}
+
+/// Samples a uniformly random non-zero field element.
+pub(crate) fn nonzero_rand<F: Field, R: Rng>(rng: &mut R) -> F {
+    loop {
+        let x = F::rand(rng);
+        if !x.is_zero() {
+            return x;
+        }
+    }
+}
+
+/// Adapter feeding everything written to it into a Blake2b hasher, so that
+/// large objects can be hashed without serializing them into memory first.
+pub(crate) struct HashWriter {
+    hasher: Blake2b,
+}
+
+impl HashWriter {
+    pub(crate) fn new() -> Self {
+        Self { hasher: Blake2b::default() }
+    }
+
+    pub(crate) fn into_digest(self) -> [u8; DIGEST_LENGTH] {
+        let mut digest = [0u8; DIGEST_LENGTH];
+        digest.copy_from_slice(self.hasher.result().as_slice());
+        digest
+    }
+}
+
+impl Write for HashWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hasher.input(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the Blake2b digest of the serialization of `obj`.
+pub(crate) fn hash_to_bytes<T: ToBytes>(obj: &T) -> [u8; DIGEST_LENGTH] {
+    let mut writer = HashWriter::new();
+    obj.write(&mut writer)
+        .expect("writing to a hasher cannot fail");
+    writer.into_digest()
+}
+
+/// Deterministically maps `seed` to a field element, by rejection sampling
+/// on the Blake2b output.
+fn hash_to_field<F: PrimeField>(seed: &[u8]) -> F {
+    let mut counter = 0u32;
+    loop {
+        // Two blocks are enough to fill the representation of any of our fields.
+        let mut bytes = Vec::with_capacity(2 * DIGEST_LENGTH);
+        for block in 0u8..2 {
+            let mut h = Blake2b::default();
+            h.input(seed);
+            h.input(&counter.to_be_bytes());
+            h.input(&[block]);
+            bytes.extend_from_slice(h.result().as_slice());
+        }
+        if let Some(f) = F::from_random_bytes(&bytes) {
+            return f;
+        }
+        counter += 1;
+    }
+}
+
+fn challenge<E: PairingEngine>(
+    digest: &[u8],
+    x_g1: &E::G1Affine,
+    x_g2: &E::G2Affine,
+    r_g1: &E::G1Affine,
+) -> E::Fr {
+    let mut seed = digest.to_vec();
+    seed.extend_from_slice(to_bytes!(x_g1, x_g2, r_g1).unwrap().as_slice());
+    hash_to_field(&seed)
+}
@@ -0,0 +1,266 @@
+use algebra::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes},
+    fft::EvaluationDomain,
+    AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve,
+};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use r1cs_core::SynthesisError;
+use rand::Rng;
+use rayon::prelude::*;
+
+use std::io::{self, Read, Result as IoResult, Write};
+
+use super::{hash_to_bytes, merge_pairs, nonzero_rand, same_ratio, PublicKey};
+use crate::groth16::{read_affine, read_affine_vec};
+
+/// The state of the powers of tau ceremony for a domain of size `n`:
+/// `tau^i * G1` for `i < 2n - 1`, `tau^i * G2`, `alpha * tau^i * G1` and
+/// `beta * tau^i * G1` for `i < n`, and `beta * G2`.
+#[derive(Clone, Debug)]
+pub struct Accumulator<E: PairingEngine> {
+    pub tau_powers_g1:       Vec<E::G1Affine>,
+    pub tau_powers_g2:       Vec<E::G2Affine>,
+    pub alpha_tau_powers_g1: Vec<E::G1Affine>,
+    pub beta_tau_powers_g1:  Vec<E::G1Affine>,
+    pub beta_g2:             E::G2Affine,
+}
+
+/// The public keys of the secrets introduced by a phase 1 contribution.
+#[derive(Clone, Debug)]
+pub struct Phase1PublicKey<E: PairingEngine> {
+    pub tau:   PublicKey<E>,
+    pub alpha: PublicKey<E>,
+    pub beta:  PublicKey<E>,
+}
+
+impl<E: PairingEngine> Accumulator<E> {
+    /// Creates the initial accumulator, with all the secrets set to one, for
+    /// circuits whose evaluation domain fits `size`.
+    pub fn new(size: usize) -> Result<Self, SynthesisError> {
+        let size = EvaluationDomain::<E::Fr>::compute_size_of_domain(size)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?
+            .max(2);
+
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+
+        Ok(Self {
+            tau_powers_g1:       vec![g1; 2 * size - 1],
+            tau_powers_g2:       vec![g2; size],
+            alpha_tau_powers_g1: vec![g1; size],
+            beta_tau_powers_g1:  vec![g1; size],
+            beta_g2:             g2,
+        })
+    }
+
+    /// The size of the largest evaluation domain supported by the accumulator.
+    pub fn size(&self) -> usize {
+        self.tau_powers_g2.len()
+    }
+
+    /// Multiplies the accumulator by fresh random secrets and returns the
+    /// public keys proving knowledge of them. The secrets are dropped on return.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Phase1PublicKey<E> {
+        let contribution_time = start_timer!(|| "Phase 1 contribution");
+
+        let digest = hash_to_bytes(self);
+
+        let tau = nonzero_rand::<E::Fr, _>(rng);
+        let alpha = nonzero_rand::<E::Fr, _>(rng);
+        let beta = nonzero_rand::<E::Fr, _>(rng);
+
+        let key = Phase1PublicKey {
+            tau:   PublicKey::new(&tau, &digest, rng),
+            alpha: PublicKey::new(&alpha, &digest, rng),
+            beta:  PublicKey::new(&beta, &digest, rng),
+        };
+
+        let mut tau_powers = Vec::with_capacity(self.tau_powers_g1.len());
+        let mut power = E::Fr::one();
+        for _ in 0..self.tau_powers_g1.len() {
+            tau_powers.push(power);
+            power *= &tau;
+        }
+
+        let size = self.size();
+        batch_mul(&mut self.tau_powers_g1, &tau_powers);
+        batch_mul(&mut self.tau_powers_g2, &tau_powers[..size]);
+        batch_mul(
+            &mut self.alpha_tau_powers_g1,
+            &tau_powers[..size].iter().map(|t| alpha * t).collect::<Vec<_>>(),
+        );
+        batch_mul(
+            &mut self.beta_tau_powers_g1,
+            &tau_powers[..size].iter().map(|t| beta * t).collect::<Vec<_>>(),
+        );
+        self.beta_g2 = self.beta_g2.mul(beta.into_repr()).into_affine();
+
+        end_timer!(contribution_time);
+        key
+    }
+
+    /// Checks that `after` results from a well-formed contribution to `before`,
+    /// by the participant holding the secrets behind `key`.
+    pub fn verify_contribution<R: Rng>(
+        before: &Self,
+        after: &Self,
+        key: &Phase1PublicKey<E>,
+        rng: &mut R,
+    ) -> bool {
+        let verification_time = start_timer!(|| "Phase 1 contribution verification");
+
+        let size = before.size();
+        if size < 2 || !before.has_consistent_lengths() || !after.has_consistent_lengths() || after.size() != size {
+            return false;
+        }
+
+        let digest = hash_to_bytes(before);
+        if !(key.tau.verify(&digest) && key.alpha.verify(&digest) && key.beta.verify(&digest)) {
+            return false;
+        }
+
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+        if after.tau_powers_g1[0] != g1 || after.tau_powers_g2[0] != g2 {
+            return false;
+        }
+
+        // The new secrets are the old ones multiplied by those behind `key`
+        let tau_g2 = after.tau_powers_g2[1];
+        let result = same_ratio::<E>(
+                (before.tau_powers_g1[1], after.tau_powers_g1[1]),
+                (g2, key.tau.x_g2),
+            )
+            && same_ratio::<E>(
+                (before.alpha_tau_powers_g1[0], after.alpha_tau_powers_g1[0]),
+                (g2, key.alpha.x_g2),
+            )
+            && same_ratio::<E>(
+                (before.beta_tau_powers_g1[0], after.beta_tau_powers_g1[0]),
+                (g2, key.beta.x_g2),
+            )
+            && same_ratio::<E>(
+                (before.beta_tau_powers_g1[0], after.beta_tau_powers_g1[0]),
+                (before.beta_g2, after.beta_g2),
+            )
+            // ... and the vectors are made of consecutive powers of tau
+            && same_ratio::<E>(
+                merge_pairs(&after.tau_powers_g1[..2 * size - 2], &after.tau_powers_g1[1..], rng),
+                (g2, tau_g2),
+            )
+            && same_ratio::<E>(
+                (g1, after.tau_powers_g1[1]),
+                merge_pairs(&after.tau_powers_g2[..size - 1], &after.tau_powers_g2[1..], rng),
+            )
+            && same_ratio::<E>(
+                merge_pairs(&after.alpha_tau_powers_g1[..size - 1], &after.alpha_tau_powers_g1[1..], rng),
+                (g2, tau_g2),
+            )
+            && same_ratio::<E>(
+                merge_pairs(&after.beta_tau_powers_g1[..size - 1], &after.beta_tau_powers_g1[1..], rng),
+                (g2, tau_g2),
+            );
+
+        end_timer!(verification_time);
+        result
+    }
+
+    fn has_consistent_lengths(&self) -> bool {
+        let size = self.size();
+        self.tau_powers_g1.len() == 2 * size - 1
+            && self.alpha_tau_powers_g1.len() == size
+            && self.beta_tau_powers_g1.len() == size
+    }
+
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let size = reader.read_u32::<BigEndian>()? as usize;
+        if size < 2 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "accumulator too small"));
+        }
+        let tau_powers_g1 = read_affine_vec::<E::G1Affine, _>(2 * size - 1, true, checked, false, &mut reader)?;
+        let tau_powers_g2 = read_affine_vec::<E::G2Affine, _>(size, true, checked, false, &mut reader)?;
+        let alpha_tau_powers_g1 = read_affine_vec::<E::G1Affine, _>(size, true, checked, false, &mut reader)?;
+        let beta_tau_powers_g1 = read_affine_vec::<E::G1Affine, _>(size, true, checked, false, &mut reader)?;
+        let beta_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+        Ok(Self { tau_powers_g1, tau_powers_g2, alpha_tau_powers_g1, beta_tau_powers_g1, beta_g2 })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for Accumulator<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tau_powers_g1 == other.tau_powers_g1
+            && self.tau_powers_g2 == other.tau_powers_g2
+            && self.alpha_tau_powers_g1 == other.alpha_tau_powers_g1
+            && self.beta_tau_powers_g1 == other.beta_tau_powers_g1
+            && self.beta_g2 == other.beta_g2
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Accumulator<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        writer.write_u32::<BigEndian>(self.size() as u32)?;
+        for g in self.tau_powers_g1.iter() { g.write(&mut writer)?; }
+        for g in self.tau_powers_g2.iter() { g.write(&mut writer)?; }
+        for g in self.alpha_tau_powers_g1.iter() { g.write(&mut writer)?; }
+        for g in self.beta_tau_powers_g1.iter() { g.write(&mut writer)?; }
+        self.beta_g2.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Accumulator<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Accumulator<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+impl<E: PairingEngine> PartialEq for Phase1PublicKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tau == other.tau && self.alpha == other.alpha && self.beta == other.beta
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Phase1PublicKey<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.tau.write(&mut writer)?;
+        self.alpha.write(&mut writer)?;
+        self.beta.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Phase1PublicKey<E> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let tau = PublicKey::read(&mut reader)?;
+        let alpha = PublicKey::read(&mut reader)?;
+        let beta = PublicKey::read(&mut reader)?;
+        Ok(Self { tau, alpha, beta })
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Phase1PublicKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let tau = PublicKey::read_checked(&mut reader)?;
+        let alpha = PublicKey::read_checked(&mut reader)?;
+        let beta = PublicKey::read_checked(&mut reader)?;
+        Ok(Self { tau, alpha, beta })
+    }
+}
+
+/// Sets `bases[i] := scalars[i] * bases[i]`.
+fn batch_mul<G: AffineCurve>(bases: &mut [G], scalars: &[G::ScalarField]) {
+    bases
+        .par_iter_mut()
+        .zip(scalars.par_iter())
+        .for_each(|(base, scalar)| *base = base.mul(scalar.into_repr()).into_affine());
+}
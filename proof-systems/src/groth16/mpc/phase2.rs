@@ -0,0 +1,432 @@
+use algebra::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes},
+    fft::EvaluationDomain,
+    AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve,
+};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, Index, SynthesisError};
+use rand::Rng;
+use rayon::prelude::*;
+
+use std::io::{Read, Result as IoResult, Write};
+
+use super::{
+    hash_to_bytes, merge_pairs, nonzero_rand, same_ratio, Accumulator, HashWriter, PublicKey,
+    DIGEST_LENGTH,
+};
+use crate::groth16::{generator::KeypairAssembly, read_affine, Parameters, VerifyingKey};
+
+/// A phase 2 contribution: the public key of the secret `delta` introduced by
+/// the participant, and the value of `delta * G1` after the contribution.
+#[derive(Clone, Debug)]
+pub struct Contribution<E: PairingEngine> {
+    pub delta_after: E::G1Affine,
+    pub key:         PublicKey<E>,
+}
+
+/// Circuit-specific Groth16 parameters under construction, together with the
+/// transcript of the contributions made to them so far.
+#[derive(Clone, Debug)]
+pub struct MPCParameters<E: PairingEngine> {
+    pub(crate) params:        Parameters<E>,
+    pub(crate) cs_hash:       [u8; DIGEST_LENGTH],
+    pub(crate) contributions: Vec<Contribution<E>>,
+}
+
+impl<E: PairingEngine> MPCParameters<E> {
+    /// Specializes the phase 1 `accumulator` to `circuit`. The resulting
+    /// parameters have `gamma = delta = 1` and are not yet secure: they need
+    /// at least one honest contribution.
+    pub fn new<C: ConstraintSynthesizer<E::Fr>>(
+        circuit: C,
+        accumulator: &Accumulator<E>,
+    ) -> Result<Self, SynthesisError> {
+        let setup_time = start_timer!(|| "Phase 2 initialization");
+
+        let mut assembly = KeypairAssembly::<E> {
+            num_inputs:      0,
+            num_aux:         0,
+            num_constraints: 0,
+            at:              vec![],
+            bt:              vec![],
+            ct:              vec![],
+        };
+
+        // Allocate the "one" input variable
+        assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+        let synthesis_time = start_timer!(|| "Constraint synthesis");
+        circuit.generate_constraints(&mut assembly)?;
+        end_timer!(synthesis_time);
+
+        let domain_size = assembly.num_constraints + (assembly.num_inputs - 1) + 1;
+        let domain = EvaluationDomain::<E::Fr>::new(domain_size)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let n = domain.size();
+        if n > accumulator.size() {
+            return Err(SynthesisError::PolynomialDegreeTooLarge);
+        }
+
+        // Evaluate the Lagrange polynomials of the domain at tau, in the
+        // exponent, by an inverse FFT of the powers of tau.
+        let lagrange_time = start_timer!(|| "Evaluate Lagrange coefficients in the exponent");
+        let lagrange_g1 = group_ifft(&accumulator.tau_powers_g1[..n], &domain);
+        let lagrange_g2 = group_ifft(&accumulator.tau_powers_g2[..n], &domain);
+        let alpha_lagrange_g1 = group_ifft(&accumulator.alpha_tau_powers_g1[..n], &domain);
+        let beta_lagrange_g1 = group_ifft(&accumulator.beta_tau_powers_g1[..n], &domain);
+        end_timer!(lagrange_time);
+
+        // Evaluate the QAP polynomials at tau, in the exponent, in the same
+        // way as `R1CStoQAP::instance_map_with_evaluation`.
+        let qap_time = start_timer!(|| "Evaluate QAP polynomials in the exponent");
+        let num_variables = assembly.num_inputs + assembly.num_aux;
+        let mut a_g1 = vec![E::G1Projective::zero(); num_variables];
+        let mut b_g1 = vec![E::G1Projective::zero(); num_variables];
+        let mut b_g2 = vec![E::G2Projective::zero(); num_variables];
+        // beta * a_i(tau) + alpha * b_i(tau) + c_i(tau)
+        let mut ext = vec![E::G1Projective::zero(); num_variables];
+
+        for i in 0..assembly.num_inputs {
+            a_g1[i].add_assign_mixed(&lagrange_g1[assembly.num_constraints + i]);
+            ext[i].add_assign_mixed(&beta_lagrange_g1[assembly.num_constraints + i]);
+        }
+
+        let var_index = |index: Index| match index {
+            Index::Input(i) => i,
+            Index::Aux(i) => assembly.num_inputs + i,
+        };
+
+        for j in 0..assembly.num_constraints {
+            for &(coeff, index) in assembly.at[j].iter() {
+                let index = var_index(index);
+                a_g1[index] += &lagrange_g1[j].mul(coeff.into_repr());
+                ext[index] += &beta_lagrange_g1[j].mul(coeff.into_repr());
+            }
+            for &(coeff, index) in assembly.bt[j].iter() {
+                let index = var_index(index);
+                b_g1[index] += &lagrange_g1[j].mul(coeff.into_repr());
+                b_g2[index] += &lagrange_g2[j].mul(coeff.into_repr());
+                ext[index] += &alpha_lagrange_g1[j].mul(coeff.into_repr());
+            }
+            for &(coeff, index) in assembly.ct[j].iter() {
+                let index = var_index(index);
+                ext[index] += &lagrange_g1[j].mul(coeff.into_repr());
+            }
+        }
+        end_timer!(qap_time);
+
+        // h_query[i] = tau^i * (tau^n - 1) * G1
+        let h_query = (0..n - 1)
+            .into_par_iter()
+            .map(|i| {
+                let mut h = accumulator.tau_powers_g1[i + n].into_projective();
+                h -= &accumulator.tau_powers_g1[i].into_projective();
+                h.into_affine()
+            })
+            .collect::<Vec<_>>();
+
+        let to_affine = |v: Vec<E::G1Projective>| v.par_iter().map(|p| p.into_affine()).collect::<Vec<_>>();
+        let l_query = to_affine(ext.split_off(assembly.num_inputs));
+        let gamma_abc_g1 = to_affine(ext);
+
+        let alpha_g1 = accumulator.alpha_tau_powers_g1[0];
+        let beta_g1 = accumulator.beta_tau_powers_g1[0];
+        let beta_g2 = accumulator.beta_g2;
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+
+        let vk = VerifyingKey::<E> {
+            alpha_g1_beta_g2: E::pairing(alpha_g1, beta_g2),
+            gamma_g2:         g2,
+            delta_g2:         g2,
+            gamma_abc_g1,
+        };
+
+        let params = Parameters {
+            vk,
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            delta_g1:   g1,
+            delta_g2:   g2,
+            a_query:    to_affine(a_g1),
+            b_g1_query: to_affine(b_g1),
+            b_g2_query: b_g2.par_iter().map(|p| p.into_affine()).collect(),
+            h_query,
+            l_query,
+        };
+        let cs_hash = hash_to_bytes(&params);
+
+        end_timer!(setup_time);
+
+        Ok(Self { params, cs_hash, contributions: vec![] })
+    }
+
+    /// The parameters in their current state.
+    pub fn params(&self) -> &Parameters<E> {
+        &self.params
+    }
+
+    /// Consumes the ceremony state, returning the final parameters.
+    pub fn into_params(self) -> Parameters<E> {
+        self.params
+    }
+
+    /// The digest of the initial parameters, which identifies the circuit and
+    /// the phase 1 accumulator they were derived from.
+    pub fn cs_hash(&self) -> &[u8; DIGEST_LENGTH] {
+        &self.cs_hash
+    }
+
+    /// The contributions made so far, in order.
+    pub fn contributions(&self) -> &[Contribution<E>] {
+        &self.contributions
+    }
+
+    /// Multiplies `delta` by a fresh random secret and records the proof of
+    /// knowledge of it. The secret is dropped on return.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) {
+        let contribution_time = start_timer!(|| "Phase 2 contribution");
+
+        let delta = nonzero_rand::<E::Fr, _>(rng);
+        let delta_inv = delta.inverse().unwrap();
+
+        let key = PublicKey::new(&delta, &transcript_digest(&self.cs_hash, &self.contributions), rng);
+
+        self.params.delta_g1 = self.params.delta_g1.mul(delta.into_repr()).into_affine();
+        self.params.delta_g2 = self.params.delta_g2.mul(delta.into_repr()).into_affine();
+        self.params.vk.delta_g2 = self.params.delta_g2;
+
+        let delta_inv = delta_inv.into_repr();
+        self.params.h_query
+            .par_iter_mut()
+            .for_each(|h| *h = h.mul(delta_inv).into_affine());
+        self.params.l_query
+            .par_iter_mut()
+            .for_each(|l| *l = l.mul(delta_inv).into_affine());
+
+        self.contributions.push(Contribution { delta_after: self.params.delta_g1, key });
+
+        end_timer!(contribution_time);
+    }
+
+    /// Checks that the parameters derive from `circuit` and `accumulator`
+    /// through the recorded contributions, and that every contribution proves
+    /// knowledge of its secret.
+    pub fn verify<C: ConstraintSynthesizer<E::Fr>, R: Rng>(
+        &self,
+        circuit: C,
+        accumulator: &Accumulator<E>,
+        rng: &mut R,
+    ) -> Result<bool, SynthesisError> {
+        let verification_time = start_timer!(|| "Phase 2 verification");
+
+        let initial = Self::new(circuit, accumulator)?;
+        let initial = &initial.params;
+        let params = &self.params;
+
+        if hash_to_bytes(initial) != self.cs_hash {
+            return Ok(false);
+        }
+
+        // Only delta and the queries divided by it may change.
+        if params.vk.alpha_g1_beta_g2 != initial.vk.alpha_g1_beta_g2
+            || params.vk.gamma_g2 != initial.vk.gamma_g2
+            || params.vk.gamma_abc_g1 != initial.vk.gamma_abc_g1
+            || params.vk.delta_g2 != params.delta_g2
+            || params.alpha_g1 != initial.alpha_g1
+            || params.beta_g1 != initial.beta_g1
+            || params.beta_g2 != initial.beta_g2
+            || params.a_query != initial.a_query
+            || params.b_g1_query != initial.b_g1_query
+            || params.b_g2_query != initial.b_g2_query
+            || params.h_query.len() != initial.h_query.len()
+            || params.l_query.len() != initial.l_query.len()
+        {
+            return Ok(false);
+        }
+
+        let g2 = E::G2Affine::prime_subgroup_generator();
+
+        let mut delta_before = initial.delta_g1;
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            let digest = transcript_digest(&self.cs_hash, &self.contributions[..i]);
+            if !contribution.key.verify(&digest)
+                || !same_ratio::<E>(
+                    (delta_before, contribution.delta_after),
+                    (g2, contribution.key.x_g2),
+                )
+            {
+                return Ok(false);
+            }
+            delta_before = contribution.delta_after;
+        }
+
+        let result = delta_before == params.delta_g1
+            && same_ratio::<E>((initial.delta_g1, params.delta_g1), (g2, params.delta_g2))
+            && same_ratio::<E>(merge_pairs(&params.h_query, &initial.h_query, rng), (g2, params.delta_g2))
+            && same_ratio::<E>(merge_pairs(&params.l_query, &initial.l_query, rng), (g2, params.delta_g2));
+
+        end_timer!(verification_time);
+        Ok(result)
+    }
+
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let params = if checked {
+            Parameters::<E>::read_checked(&mut reader)?
+        } else {
+            Parameters::<E>::read(&mut reader)?
+        };
+        let mut cs_hash = [0u8; DIGEST_LENGTH];
+        reader.read_exact(&mut cs_hash)?;
+        let num_contributions = reader.read_u32::<BigEndian>()? as usize;
+        let mut contributions = Vec::with_capacity(num_contributions);
+        for _ in 0..num_contributions {
+            contributions.push(Contribution::read_maybe_checked(&mut reader, checked)?);
+        }
+        Ok(Self { params, cs_hash, contributions })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for MPCParameters<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.params == other.params
+            && self.cs_hash[..] == other.cs_hash[..]
+            && self.contributions == other.contributions
+    }
+}
+
+impl<E: PairingEngine> ToBytes for MPCParameters<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.params.write(&mut writer)?;
+        writer.write_all(&self.cs_hash)?;
+        writer.write_u32::<BigEndian>(self.contributions.len() as u32)?;
+        for contribution in self.contributions.iter() {
+            contribution.write(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for MPCParameters<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for MPCParameters<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+impl<E: PairingEngine> Contribution<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let delta_after = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+        let key = if checked {
+            PublicKey::read_checked(&mut reader)?
+        } else {
+            PublicKey::read(&mut reader)?
+        };
+        Ok(Self { delta_after, key })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for Contribution<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.delta_after == other.delta_after && self.key == other.key
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Contribution<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.delta_after.write(&mut writer)?;
+        self.key.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Contribution<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Contribution<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+/// The digest the next contribution's proof of knowledge is bound to.
+fn transcript_digest<E: PairingEngine>(
+    cs_hash: &[u8; DIGEST_LENGTH],
+    contributions: &[Contribution<E>],
+) -> [u8; DIGEST_LENGTH] {
+    let mut writer = HashWriter::new();
+    writer.write_all(cs_hash).expect("writing to a hasher cannot fail");
+    for contribution in contributions.iter() {
+        contribution.write(&mut writer).expect("writing to a hasher cannot fail");
+    }
+    writer.into_digest()
+}
+
+/// Computes the inverse FFT of `evals` over `domain` in the exponent, i.e.
+/// returns `v` with `v[j] = (1/n) * sum_k omega^(-jk) * evals[k]`. Applied to
+/// `tau^k * G`, this yields `L_j(tau) * G` for the Lagrange polynomials `L_j`.
+fn group_ifft<G: AffineCurve>(evals: &[G], domain: &EvaluationDomain<G::ScalarField>) -> Vec<G> {
+    #[inline]
+    fn bitreverse(mut n: u32, l: u32) -> u32 {
+        let mut r = 0;
+        for _ in 0..l {
+            r = (r << 1) | (n & 1);
+            n >>= 1;
+        }
+        r
+    }
+
+    let n = domain.size() as u32;
+    let log_n = domain.log_size_of_group;
+    assert_eq!(evals.len() as u32, n);
+
+    let mut a = evals.iter().map(|g| g.into_projective()).collect::<Vec<_>>();
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n);
+        if k < rk {
+            a.swap(rk as usize, k as usize);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_n {
+        let w_m = domain.group_gen_inv.pow([(n / (2 * m)) as u64]);
+
+        let mut k = 0;
+        while k < n {
+            let mut w = G::ScalarField::one();
+            for j in 0..m {
+                let mut t = a[(k + j + m) as usize];
+                t.mul_assign(w);
+                let mut tmp = a[(k + j) as usize];
+                tmp -= &t;
+                a[(k + j + m) as usize] = tmp;
+                a[(k + j) as usize] += &t;
+                w *= &w_m;
+            }
+
+            k += 2 * m;
+        }
+
+        m *= 2;
+    }
+
+    a.par_iter_mut().for_each(|g| g.mul_assign(domain.size_inv));
+    G::Projective::batch_normalization(a.as_mut_slice());
+    a.into_iter().map(|g| g.into_affine()).collect()
+}
@@ -0,0 +1,95 @@
+use algebra::{
+    curves::{mnt4753::MNT4, mnt6753::MNT6},
+    to_bytes, AffineCurve, FromBytes, FromBytesChecked, PairingEngine, ProjectiveCurve,
+    ToBytes, UniformRand,
+};
+use rand::thread_rng;
+
+use crate::groth16::{
+    create_random_proof, prepare_verifying_key, verify_proof,
+    mpc::{Accumulator, MPCParameters, Phase1PublicKey},
+    test::MySillyCircuit,
+};
+
+fn mpc_ceremony<E: PairingEngine>() {
+    let rng = &mut thread_rng();
+    let empty_circuit = MySillyCircuit::<E::Fr> { a: None, b: None };
+
+    // Phase 1
+    let mut accumulator = Accumulator::<E>::new(8).unwrap();
+    for _ in 0..2 {
+        let before = accumulator.clone();
+        let key = accumulator.contribute(rng);
+        assert!(Accumulator::verify_contribution(&before, &accumulator, &key, rng));
+
+        let key_serialized = to_bytes!(key).unwrap();
+        let key_deserialized = Phase1PublicKey::<E>::read_checked(key_serialized.as_slice()).unwrap();
+        assert_eq!(key, key_deserialized);
+
+        // The key does not verify a contribution made on top of a different state
+        let mut other = before.clone();
+        other.contribute(rng);
+        assert!(!Accumulator::verify_contribution(&other, &accumulator, &key, rng));
+
+        // Tampering with a single power is detected
+        let mut tampered = accumulator.clone();
+        tampered.tau_powers_g1[3] = tampered.tau_powers_g1[2];
+        assert!(!Accumulator::verify_contribution(&before, &tampered, &key, rng));
+    }
+
+    let accumulator_serialized = to_bytes!(accumulator).unwrap();
+    let accumulator_deserialized = Accumulator::<E>::read_checked(accumulator_serialized.as_slice()).unwrap();
+    assert_eq!(accumulator, accumulator_deserialized);
+
+    // Phase 2
+    let mut mpc = MPCParameters::new(empty_circuit, &accumulator).unwrap();
+    assert!(mpc.verify(empty_circuit, &accumulator, rng).unwrap());
+    for _ in 0..2 {
+        mpc.contribute(rng);
+        assert!(mpc.verify(empty_circuit, &accumulator, rng).unwrap());
+    }
+    assert_eq!(mpc.contributions().len(), 2);
+
+    let mpc_serialized = to_bytes!(mpc).unwrap();
+    let mpc_deserialized = MPCParameters::<E>::read(mpc_serialized.as_slice()).unwrap();
+    assert_eq!(mpc, mpc_deserialized);
+
+    // Changing delta without a matching contribution is detected
+    let mut tampered = mpc_deserialized.clone();
+    tampered.params.delta_g1 = tampered.params.delta_g1.mul(E::Fr::rand(rng)).into_affine();
+    assert!(!tampered.verify(empty_circuit, &accumulator, rng).unwrap());
+
+    // So is dropping a contribution from the transcript
+    let mut tampered = mpc_deserialized.clone();
+    tampered.contributions.remove(0);
+    assert!(!tampered.verify(empty_circuit, &accumulator, rng).unwrap());
+
+    // The resulting parameters are usable to prove and verify
+    let params = mpc.into_params();
+    let pvk = prepare_verifying_key(&params.vk);
+    for _ in 0..5 {
+        let a = E::Fr::rand(rng);
+        let b = E::Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(MySillyCircuit { a: Some(a), b: Some(b) }, &params, rng).unwrap();
+        assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+        assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+    }
+}
+
+#[test]
+fn mnt4753_mpc_ceremony() {
+    mpc_ceremony::<MNT4>();
+}
+
+#[test]
+fn mnt6753_mpc_ceremony() {
+    mpc_ceremony::<MNT6>();
+}
+
+#[test]
+fn circuit_too_large_for_accumulator() {
+    let accumulator = Accumulator::<MNT4>::new(4).unwrap();
+    assert!(MPCParameters::new(MySillyCircuit { a: None, b: None }, &accumulator).is_err());
+}
@@ -74,48 +74,27 @@ impl R1CStoQAP {
         d2: &E::Fr,
         d3: &E::Fr,
     ) -> Result<(Vec<E::Fr>, Vec<E::Fr>, usize), SynthesisError> {
-        #[inline]
-        fn evaluate_constraint<E: PairingEngine>(
-            terms: &[(E::Fr, Index)],
-            assignment: &[E::Fr],
-            num_input: usize,
-        ) -> E::Fr {
-            let mut acc = E::Fr::zero();
-            for &(coeff, index) in terms {
-                let val = match index {
-                    Index::Input(i) => assignment[i],
-                    Index::Aux(i) => assignment[num_input + i],
-                };
-                acc += &(val * &coeff);
-            }
-            acc
-        }
-
         let zero = E::Fr::zero();
         let one = E::Fr::one();
 
         let mut full_input_assignment = prover.input_assignment.clone();
-        full_input_assignment.extend(prover.aux_assignment.clone());
+        full_input_assignment.extend_from_slice(&prover.aux_assignment);
+
+        let num_inputs = prover.num_inputs();
+        let num_constraints = prover.a.len();
 
         let domain =
-            EvaluationDomain::<E::Fr>::new(prover.num_constraints + (prover.num_inputs - 1) + 1)
+            EvaluationDomain::<E::Fr>::new(num_constraints + (num_inputs - 1) + 1)
                 .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let domain_size = domain.size();
 
         let mut a = vec![zero; domain_size];
         let mut b = vec![zero; domain_size];
-        a[..prover.num_constraints]
-            .par_iter_mut()
-            .zip(b[..prover.num_constraints].par_iter_mut())
-            .zip((&prover.at).par_iter())
-            .zip((&prover.bt).par_iter())
-            .for_each(|(((a, b), at_i), bt_i)| {
-                *a = evaluate_constraint::<E>(&at_i, &full_input_assignment, prover.num_inputs);
-                *b = evaluate_constraint::<E>(&bt_i, &full_input_assignment, prover.num_inputs);
-            });
-
-        for i in 0..prover.num_inputs {
-            a[prover.num_constraints + i] = if i > 0 { full_input_assignment[i] } else { one };
+        a[..num_constraints].copy_from_slice(&prover.a);
+        b[..num_constraints].copy_from_slice(&prover.b);
+
+        for i in 0..num_inputs {
+            a[num_constraints + i] = if i > 0 { full_input_assignment[i] } else { one };
         }
 
         domain.ifft_in_place(&mut a);
@@ -139,16 +118,7 @@ impl R1CStoQAP {
         drop(b);
 
         let mut c = vec![zero; domain_size];
-        c[..prover.num_constraints]
-            .par_iter_mut()
-            .enumerate()
-            .for_each(|(i, c)| {
-                *c = evaluate_constraint::<E>(
-                    &prover.ct[i],
-                    &full_input_assignment,
-                    prover.num_inputs,
-                );
-            });
+        c[..num_constraints].copy_from_slice(&prover.c);
 
         domain.ifft_in_place(&mut c);
         domain.coset_fft_in_place(&mut c);
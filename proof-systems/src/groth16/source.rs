@@ -0,0 +1,331 @@
+use algebra::{AffineCurve, PairingEngine};
+use r1cs_core::SynthesisError;
+
+use std::{
+    borrow::Cow,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    ops::Range,
+};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::groth16::{read_affine, read_affine_vec, Parameters, VerifyingKey};
+
+/// Default number of query elements a `LazyParameters` loads at once.
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+/// A source of proving parameters, from which the prover reads the queries
+/// in ranges, on demand, rather than requiring them all to be in memory.
+pub trait ParameterSource<E: PairingEngine> {
+    fn get_alpha_g1(&mut self) -> Result<E::G1Affine, SynthesisError>;
+
+    fn get_beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError>;
+
+    fn get_beta_g2(&mut self) -> Result<E::G2Affine, SynthesisError>;
+
+    fn get_delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError>;
+
+    fn get_delta_g2(&mut self) -> Result<E::G2Affine, SynthesisError>;
+
+    fn get_a_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError>;
+
+    fn get_b_g1_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError>;
+
+    fn get_b_g2_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G2Affine]>, SynthesisError>;
+
+    fn get_h_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError>;
+
+    fn get_l_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError>;
+
+    /// The maximum number of query elements the prover should request at once.
+    fn chunk_size(&self) -> usize {
+        usize::MAX
+    }
+}
+
+impl<'a, E: PairingEngine, P: ParameterSource<E>> ParameterSource<E> for &'a mut P {
+    fn get_alpha_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        (**self).get_alpha_g1()
+    }
+
+    fn get_beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        (**self).get_beta_g1()
+    }
+
+    fn get_beta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        (**self).get_beta_g2()
+    }
+
+    fn get_delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        (**self).get_delta_g1()
+    }
+
+    fn get_delta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        (**self).get_delta_g2()
+    }
+
+    fn get_a_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        (**self).get_a_query(range)
+    }
+
+    fn get_b_g1_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        (**self).get_b_g1_query(range)
+    }
+
+    fn get_b_g2_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G2Affine]>, SynthesisError> {
+        (**self).get_b_g2_query(range)
+    }
+
+    fn get_h_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        (**self).get_h_query(range)
+    }
+
+    fn get_l_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        (**self).get_l_query(range)
+    }
+
+    fn chunk_size(&self) -> usize {
+        (**self).chunk_size()
+    }
+}
+
+impl<'a, E: PairingEngine> ParameterSource<E> for &'a Parameters<E> {
+    fn get_alpha_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.alpha_g1)
+    }
+
+    fn get_beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.beta_g1)
+    }
+
+    fn get_beta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        Ok(self.beta_g2)
+    }
+
+    fn get_delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.delta_g1)
+    }
+
+    fn get_delta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        Ok(self.delta_g2)
+    }
+
+    fn get_a_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        Ok(Cow::Borrowed(&self.a_query[range]))
+    }
+
+    fn get_b_g1_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        Ok(Cow::Borrowed(&self.b_g1_query[range]))
+    }
+
+    fn get_b_g2_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G2Affine]>, SynthesisError> {
+        Ok(Cow::Borrowed(&self.b_g2_query[range]))
+    }
+
+    fn get_h_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        Ok(Cow::Borrowed(&self.h_query[range]))
+    }
+
+    fn get_l_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        Ok(Cow::Borrowed(&self.l_query[range]))
+    }
+}
+
+/// Position of a query inside a serialized `Parameters`.
+#[derive(Clone, Copy, Debug)]
+struct QueryLocation {
+    offset: u64,
+    len:    usize,
+}
+
+/// Groth16 proving parameters serialized as by `Parameters::write`, of which
+/// only the fixed-size part is held in memory: the queries are read from
+/// `reader` in chunks of at most `chunk_size` elements, when the prover needs
+/// them.
+pub struct LazyParameters<E: PairingEngine, R: Read + Seek> {
+    reader:     R,
+    checked:    bool,
+    chunk_size: usize,
+
+    pub vk:       VerifyingKey<E>,
+    pub alpha_g1: E::G1Affine,
+    pub beta_g1:  E::G1Affine,
+    pub beta_g2:  E::G2Affine,
+    pub delta_g1: E::G1Affine,
+    pub delta_g2: E::G2Affine,
+
+    a_query:    QueryLocation,
+    b_g1_query: QueryLocation,
+    b_g2_query: QueryLocation,
+    h_query:    QueryLocation,
+    l_query:    QueryLocation,
+
+    g1_size: u64,
+    g2_size: u64,
+}
+
+impl<E: PairingEngine, R: Read + Seek> LazyParameters<E, R> {
+    /// Reads the fixed-size part of the parameters from `reader` and indexes
+    /// the position of the queries. If `checked` is set, every point read,
+    /// now or later, is checked to be on the curve and in the prime order
+    /// subgroup.
+    pub fn new(mut reader: R, checked: bool) -> io::Result<Self> {
+        let g1_size = serialized_size(&E::G1Affine::prime_subgroup_generator())?;
+        let g2_size = serialized_size(&E::G2Affine::prime_subgroup_generator())?;
+
+        let (vk, alpha_g1, beta_g1, beta_g2, delta_g1, delta_g2) = {
+            let mut reader = BufReader::new(&mut reader);
+            let vk = VerifyingKey::<E>::read_maybe_checked(&mut reader, checked, false)?;
+            let alpha_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+            let beta_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+            let beta_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+            let delta_g1 = read_affine::<E::G1Affine, _>(checked, false, &mut reader)?;
+            let delta_g2 = read_affine::<E::G2Affine, _>(checked, false, &mut reader)?;
+
+            // Seeking through the buffered reader discards what it read ahead,
+            // moving the underlying one back to the end of the fixed-size part.
+            reader.seek(SeekFrom::Current(0))?;
+            (vk, alpha_g1, beta_g1, beta_g2, delta_g1, delta_g2)
+        };
+
+        let a_query = Self::skip_query(&mut reader, g1_size)?;
+        let b_g1_query = Self::skip_query(&mut reader, g1_size)?;
+        let b_g2_query = Self::skip_query(&mut reader, g2_size)?;
+        let h_query = Self::skip_query(&mut reader, g1_size)?;
+        let l_query = Self::skip_query(&mut reader, g1_size)?;
+
+        let expected_len = l_query.offset + l_query.len as u64 * g1_size;
+        if reader.seek(SeekFrom::End(0))? < expected_len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated parameters"));
+        }
+
+        Ok(Self {
+            reader,
+            checked,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            vk,
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            delta_g1,
+            delta_g2,
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            h_query,
+            l_query,
+            g1_size,
+            g2_size,
+        })
+    }
+
+    /// Sets the maximum number of query elements loaded in memory at once.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Reads a whole `Parameters` out of the lazy ones.
+    pub fn into_parameters(mut self) -> Result<Parameters<E>, SynthesisError> {
+        let a_query = self.get_a_query(0..self.a_query.len)?.into_owned();
+        let b_g1_query = self.get_b_g1_query(0..self.b_g1_query.len)?.into_owned();
+        let b_g2_query = self.get_b_g2_query(0..self.b_g2_query.len)?.into_owned();
+        let h_query = self.get_h_query(0..self.h_query.len)?.into_owned();
+        let l_query = self.get_l_query(0..self.l_query.len)?.into_owned();
+        Ok(Parameters {
+            vk: self.vk,
+            alpha_g1: self.alpha_g1,
+            beta_g1: self.beta_g1,
+            beta_g2: self.beta_g2,
+            delta_g1: self.delta_g1,
+            delta_g2: self.delta_g2,
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            h_query,
+            l_query,
+        })
+    }
+
+    /// Records the position of the length-prefixed query at the current
+    /// position of `reader` and moves past it.
+    fn skip_query(reader: &mut R, element_size: u64) -> io::Result<QueryLocation> {
+        let len = reader.read_u32::<BigEndian>()? as usize;
+        let offset = reader.seek(SeekFrom::Current(0))?;
+        reader.seek(SeekFrom::Current((len as u64 * element_size) as i64))?;
+        Ok(QueryLocation { offset, len })
+    }
+
+    fn read_query<G: AffineCurve>(
+        &mut self,
+        location: QueryLocation,
+        element_size: u64,
+        range: Range<usize>,
+    ) -> Result<Vec<G>, SynthesisError> {
+        if range.start > range.end || range.end > location.len {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "query range out of bounds").into());
+        }
+        self.reader.seek(SeekFrom::Start(location.offset + range.start as u64 * element_size))?;
+        let reader = BufReader::new(&mut self.reader);
+        Ok(read_affine_vec::<G, _>(range.len(), false, self.checked, false, reader)?)
+    }
+}
+
+impl<E: PairingEngine, R: Read + Seek> ParameterSource<E> for LazyParameters<E, R> {
+    fn get_alpha_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.alpha_g1)
+    }
+
+    fn get_beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.beta_g1)
+    }
+
+    fn get_beta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        Ok(self.beta_g2)
+    }
+
+    fn get_delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.delta_g1)
+    }
+
+    fn get_delta_g2(&mut self) -> Result<E::G2Affine, SynthesisError> {
+        Ok(self.delta_g2)
+    }
+
+    fn get_a_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        let (location, size) = (self.a_query, self.g1_size);
+        self.read_query(location, size, range).map(Cow::Owned)
+    }
+
+    fn get_b_g1_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        let (location, size) = (self.b_g1_query, self.g1_size);
+        self.read_query(location, size, range).map(Cow::Owned)
+    }
+
+    fn get_b_g2_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G2Affine]>, SynthesisError> {
+        let (location, size) = (self.b_g2_query, self.g2_size);
+        self.read_query(location, size, range).map(Cow::Owned)
+    }
+
+    fn get_h_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        let (location, size) = (self.h_query, self.g1_size);
+        self.read_query(location, size, range).map(Cow::Owned)
+    }
+
+    fn get_l_query(&mut self, range: Range<usize>) -> Result<Cow<'_, [E::G1Affine]>, SynthesisError> {
+        let (location, size) = (self.l_query, self.g1_size);
+        self.read_query(location, size, range).map(Cow::Owned)
+    }
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+}
+
+/// Size in bytes of the uncompressed serialization of a point, which is the
+/// same for all the points of a given curve.
+fn serialized_size<G: AffineCurve>(g: &G) -> io::Result<u64> {
+    let mut bytes = vec![];
+    g.write(&mut bytes)?;
+    Ok(bytes.len() as u64)
+}
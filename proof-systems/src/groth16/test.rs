@@ -1,8 +1,10 @@
 use algebra::Field;
 use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
-struct MySillyCircuit<F: Field> {
-    a: Option<F>,
-    b: Option<F>,
+
+#[derive(Clone, Copy)]
+pub(crate) struct MySillyCircuit<F: Field> {
+    pub(crate) a: Option<F>,
+    pub(crate) b: Option<F>,
 }
 
 impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<ConstraintF> {
@@ -34,16 +36,49 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
     }
 }
 
+// Same as MySillyCircuit, through the variable d = a + b fixed by a linear constraint
+struct MyLinearCircuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MyLinearCircuit<ConstraintF> {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(
+        self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let d_val = self.a.and_then(|a| self.b.map(|b| a + &b));
+        let d = cs.alloc(|| "d", || d_val.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || {
+                let d = d_val.ok_or(SynthesisError::AssignmentMissing)?;
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                Ok(d * &b)
+            },
+        )?;
+
+        cs.enforce(|| "a+b=d", |lc| lc + a + b, |lc| lc + CS::one(), |lc| lc + d);
+        cs.enforce(|| "d*b=c", |lc| lc + d, |lc| lc + b, |lc| lc + c);
+
+        Ok(())
+    }
+}
+
 mod bls12_377 {
     use super::*;
     use crate::groth16::{
         Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        create_random_proof, create_random_proof_from_witness, generate_random_parameters, prepare_verifying_key, verify_proof,
+        batch_verify_proofs, rerandomize_proof, LazyParameters,
     };
 
     use algebra::{curves::bls12_377::Bls12_377, fields::bls12_377::Fr, UniformRand,
-            ToBytes, FromBytes, to_bytes,
+            ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes,
     };
+    use r1cs_core::{OptimizingConstraintSystem, WitnessConstraintSystem};
     use rand::thread_rng;
     use std::ops::MulAssign;
 
@@ -78,6 +113,180 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn prove_and_verify_optimized() {
+        let rng = &mut thread_rng();
+
+        let blank = OptimizingConstraintSystem::from_synthesizer(MyLinearCircuit { a: None, b: None }).unwrap();
+        let params = generate_random_parameters::<Bls12_377, _, _>(blank, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = (a + &b) * &b;
+
+            let circuit = OptimizingConstraintSystem::from_synthesizer(MyLinearCircuit { a: Some(a), b: Some(b) }).unwrap();
+            let proof = create_random_proof(circuit, &params, rng).unwrap();
+
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_from_witness() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * &b;
+
+        // The witness is computed once for several proofs
+        let witness = WitnessConstraintSystem::from_synthesizer(MySillyCircuit { a: Some(a), b: Some(b) }).unwrap();
+        assert_eq!((witness.num_inputs(), witness.num_aux(), witness.num_constraints()), (2, 2, 6));
+        assert!(witness.which_is_unsatisfied().is_none());
+
+        for _ in 0..2 {
+            let proof = create_random_proof_from_witness(&witness, &params, rng).unwrap();
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn batch_verify() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let mut proofs_and_inputs = vec![];
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * &b;
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+            proofs_and_inputs.push((proof, vec![c]));
+        }
+
+        assert!(batch_verify_proofs(&pvk, &[], rng).unwrap());
+        assert!(batch_verify_proofs(&pvk, &proofs_and_inputs, rng).unwrap());
+
+        // A single wrong public input invalidates the whole batch
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[5].1[0] = Fr::rand(rng);
+        assert!(!batch_verify_proofs(&pvk, &bad_batch, rng).unwrap());
+
+        // So does a swapped proof
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[3].0 = proofs_and_inputs[4].0.clone();
+        assert!(!batch_verify_proofs(&pvk, &bad_batch, rng).unwrap());
+
+        // Wrong number of public inputs
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[0].1.push(Fr::rand(rng));
+        assert!(batch_verify_proofs(&pvk, &bad_batch, rng).is_err());
+    }
+
+    #[test]
+    fn rerandomize() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+
+        let rerandomized_proof = rerandomize_proof(&params.vk, &proof, rng);
+        assert_ne!(proof, rerandomized_proof);
+        assert!(verify_proof(&pvk, &rerandomized_proof, &[c]).unwrap());
+        assert!(!verify_proof(&pvk, &rerandomized_proof, &[a]).unwrap());
+
+        // Rerandomizing twice still yields a valid proof
+        let rerandomized_proof = rerandomize_proof(&params.vk, &rerandomized_proof, rng);
+        assert!(verify_proof(&pvk, &rerandomized_proof, &[c]).unwrap());
+    }
+
+    #[test]
+    fn prove_with_lazy_parameters() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let params_serialized = to_bytes!(params).unwrap();
+
+        for &chunk_size in [1, 3, 1000].iter() {
+            let mut lazy_params = LazyParameters::<Bls12_377, _>::new(
+                std::io::Cursor::new(params_serialized.as_slice()),
+                true
+            ).unwrap().with_chunk_size(chunk_size);
+            assert_eq!(lazy_params.vk, params.vk);
+
+            for _ in 0..5 {
+                let a = Fr::rand(rng);
+                let b = Fr::rand(rng);
+                let mut c = a;
+                c.mul_assign(&b);
+
+                let proof = create_random_proof(
+                    MySillyCircuit {
+                        a: Some(a),
+                        b: Some(b),
+                    },
+                    &mut lazy_params,
+                    rng,
+                )
+                .unwrap();
+
+                assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+                assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+            }
+
+            assert_eq!(lazy_params.into_parameters().unwrap(), params);
+        }
+
+        // Truncated parameters are rejected upfront
+        let truncated = &params_serialized[..params_serialized.len() - 1];
+        assert!(LazyParameters::<Bls12_377, _>::new(std::io::Cursor::new(truncated), false).is_err());
+    }
+
     #[test]
     fn serialize_deserialize() {
 
@@ -92,10 +301,20 @@ mod bls12_377 {
         let params_serialized = to_bytes!(params).unwrap();
         let params_deserialized = Parameters::<Bls12_377>::read(params_serialized.as_slice()).unwrap();
         assert_eq!(params, params_deserialized);
+        let params_deserialized = Parameters::<Bls12_377>::read_checked(params_serialized.as_slice()).unwrap();
+        assert_eq!(params, params_deserialized);
 
         let vk_serialized = to_bytes!(vk).unwrap();
         let vk_deserialized = VerifyingKey::<Bls12_377>::read(vk_serialized.as_slice()).unwrap();
         assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<Bls12_377>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<Bls12_377>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
 
 
         let a = Fr::rand(rng);
@@ -115,6 +334,14 @@ mod bls12_377 {
         let proof_serialized = to_bytes!(proof).unwrap();
         let proof_deserialized = Proof::<Bls12_377>::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<Bls12_377>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<Bls12_377>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
 
         let pvk = prepare_verifying_key(&vk_deserialized);
         let pvk_serialized = to_bytes!(pvk).unwrap();
@@ -135,7 +362,7 @@ mod sw6 {
     use rand::thread_rng;
 
     use algebra::{curves::sw6::SW6, fields::sw6::Fr as SW6Fr, Field, UniformRand,
-                  ToBytes, FromBytes, to_bytes,
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes,
     };
 
     #[test]
@@ -180,10 +407,20 @@ mod sw6 {
         let params_serialized = to_bytes!(params).unwrap();
         let params_deserialized = Parameters::<SW6>::read(params_serialized.as_slice()).unwrap();
         assert_eq!(params, params_deserialized);
+        let params_deserialized = Parameters::<SW6>::read_checked(params_serialized.as_slice()).unwrap();
+        assert_eq!(params, params_deserialized);
 
         let vk_serialized = to_bytes!(vk).unwrap();
         let vk_deserialized = VerifyingKey::<SW6>::read(vk_serialized.as_slice()).unwrap();
         assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<SW6>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<SW6>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
 
 
         let a = SW6Fr::rand(rng);
@@ -203,6 +440,14 @@ mod sw6 {
         let proof_serialized = to_bytes!(proof).unwrap();
         let proof_deserialized = Proof::<SW6>::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<SW6>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<SW6>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
 
         let pvk = prepare_verifying_key(&vk_deserialized);
         let pvk_serialized = to_bytes!(pvk).unwrap();
@@ -218,12 +463,13 @@ mod mnt4753 {
     use crate::groth16::{
         Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        batch_verify_proofs, rerandomize_proof,
     };
 
     use rand::thread_rng;
 
     use algebra::{curves::mnt4753::MNT4, fields::mnt4753::Fr as MNT4Fr, Field, UniformRand,
-                  ToBytes, FromBytes, to_bytes};
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes};
 
     #[test]
     fn prove_and_verify() {
@@ -253,6 +499,87 @@ mod mnt4753 {
         assert!(!verify_proof(&pvk, &proof, &[MNT4Fr::zero()]).unwrap());
     }
 
+    #[test]
+    fn batch_verify() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<MNT4, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<MNT4>(&params.vk);
+
+        let mut proofs_and_inputs = vec![];
+        for _ in 0..10 {
+            let a = MNT4Fr::rand(rng);
+            let b = MNT4Fr::rand(rng);
+            let c = a * &b;
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+            proofs_and_inputs.push((proof, vec![c]));
+        }
+
+        assert!(batch_verify_proofs(&pvk, &[], rng).unwrap());
+        assert!(batch_verify_proofs(&pvk, &proofs_and_inputs, rng).unwrap());
+
+        // A single wrong public input invalidates the whole batch
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[5].1[0] = MNT4Fr::rand(rng);
+        assert!(!batch_verify_proofs(&pvk, &bad_batch, rng).unwrap());
+
+        // So does a swapped proof
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[3].0 = proofs_and_inputs[4].0.clone();
+        assert!(!batch_verify_proofs(&pvk, &bad_batch, rng).unwrap());
+
+        // Wrong number of public inputs
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[0].1.push(MNT4Fr::rand(rng));
+        assert!(batch_verify_proofs(&pvk, &bad_batch, rng).is_err());
+    }
+
+    #[test]
+    fn rerandomize() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<MNT4, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<MNT4>(&params.vk);
+
+        let a = MNT4Fr::rand(rng);
+        let b = MNT4Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+
+        let rerandomized_proof = rerandomize_proof(&params.vk, &proof, rng);
+        assert_ne!(proof, rerandomized_proof);
+        assert!(verify_proof(&pvk, &rerandomized_proof, &[c]).unwrap());
+        assert!(!verify_proof(&pvk, &rerandomized_proof, &[a]).unwrap());
+
+        // Rerandomizing twice still yields a valid proof
+        let rerandomized_proof = rerandomize_proof(&params.vk, &rerandomized_proof, rng);
+        assert!(verify_proof(&pvk, &rerandomized_proof, &[c]).unwrap());
+    }
+
     #[test]
     fn serialize_deserialize() {
 
@@ -267,10 +594,20 @@ mod mnt4753 {
         let params_serialized = to_bytes!(params).unwrap();
         let params_deserialized = Parameters::<MNT4>::read(params_serialized.as_slice()).unwrap();
         assert_eq!(params, params_deserialized);
+        let params_deserialized = Parameters::<MNT4>::read_checked(params_serialized.as_slice()).unwrap();
+        assert_eq!(params, params_deserialized);
 
         let vk_serialized = to_bytes!(vk).unwrap();
         let vk_deserialized = VerifyingKey::<MNT4>::read(vk_serialized.as_slice()).unwrap();
         assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<MNT4>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<MNT4>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
 
 
         let a = MNT4Fr::rand(rng);
@@ -290,6 +627,14 @@ mod mnt4753 {
         let proof_serialized = to_bytes!(proof).unwrap();
         let proof_deserialized = Proof::<MNT4>::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<MNT4>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<MNT4>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
 
         let pvk = prepare_verifying_key(&vk_deserialized);
         let pvk_serialized = to_bytes!(pvk).unwrap();
@@ -310,7 +655,7 @@ mod mnt6753 {
     use rand::thread_rng;
 
     use algebra::{curves::mnt6753::MNT6, fields::mnt6753::Fr as MNT6Fr, Field, UniformRand,
-                  ToBytes, FromBytes, to_bytes,};
+                  ToBytes, FromBytes, FromBytesChecked, ToCompressedBytes, FromCompressedBytes, to_bytes,};
 
     #[test]
     fn prove_and_verify() {
@@ -354,10 +699,20 @@ mod mnt6753 {
         let params_serialized = to_bytes!(params).unwrap();
         let params_deserialized = Parameters::<MNT6>::read(params_serialized.as_slice()).unwrap();
         assert_eq!(params, params_deserialized);
+        let params_deserialized = Parameters::<MNT6>::read_checked(params_serialized.as_slice()).unwrap();
+        assert_eq!(params, params_deserialized);
 
         let vk_serialized = to_bytes!(vk).unwrap();
         let vk_deserialized = VerifyingKey::<MNT6>::read(vk_serialized.as_slice()).unwrap();
         assert_eq!(vk, vk_deserialized);
+        let vk_deserialized = VerifyingKey::<MNT6>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(vk, vk_deserialized);
+
+        let mut vk_compressed = vec![];
+        vk.write_compressed(&mut vk_compressed).unwrap();
+        assert!(vk_compressed.len() < vk_serialized.len());
+        let vk_decompressed = VerifyingKey::<MNT6>::read_compressed(vk_compressed.as_slice()).unwrap();
+        assert_eq!(vk, vk_decompressed);
 
 
         let a = MNT6Fr::rand(rng);
@@ -377,6 +732,14 @@ mod mnt6753 {
         let proof_serialized = to_bytes!(proof).unwrap();
         let proof_deserialized = Proof::<MNT6>::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
+        let proof_deserialized = Proof::<MNT6>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        let mut proof_compressed = vec![];
+        proof.write_compressed(&mut proof_compressed).unwrap();
+        assert!(proof_compressed.len() < proof_serialized.len());
+        let proof_decompressed = Proof::<MNT6>::read_compressed(proof_compressed.as_slice()).unwrap();
+        assert_eq!(proof, proof_decompressed);
 
         let pvk = prepare_verifying_key(&vk_deserialized);
         let pvk_serialized = to_bytes!(pvk).unwrap();
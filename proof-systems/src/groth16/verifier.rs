@@ -1,9 +1,13 @@
-use algebra::{AffineCurve, PairingCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use algebra::{
+    msm::VariableBaseMSM, AffineCurve, Field, PairingCurve, PairingEngine, PrimeField,
+    ProjectiveCurve, UniformRand,
+};
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
 
 use crate::groth16::SynthesisError;
 
+use rand::Rng;
 use std::ops::{AddAssign, Neg};
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: &VerifyingKey<E>) -> PreparedVerifyingKey<E> {
@@ -41,4 +45,70 @@ pub fn verify_proof<E: PairingEngine>(
     let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
 
     Ok(test == pvk.alpha_g1_beta_g2)
-}
\ No newline at end of file
+}
+
+/// Verifies a batch of proofs for the same verifying key at once.
+///
+/// Each proof is weighted by a random scalar `r_i` and the individual checks
+/// are combined into
+///
+///   prod_i e(r_i * A_i, B_i) * e(sum_i r_i * IC_i, -gamma) * e(sum_i r_i * C_i, -delta)
+///     == e(alpha, beta)^(sum_i r_i),
+///
+/// which costs N + 2 Miller loops, a single final exponentiation and one MSM
+/// over the public inputs. If any proof in the batch is invalid, the check
+/// fails except with negligible probability; it does not tell which one.
+pub fn batch_verify_proofs<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(Proof<E>, Vec<E::Fr>)],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if proofs_and_inputs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut r_sum = E::Fr::zero();
+    let mut input_scalars = vec![E::Fr::zero(); pvk.gamma_abc_g1.len()];
+    let mut c_bases = Vec::with_capacity(proofs_and_inputs.len());
+    let mut c_scalars = Vec::with_capacity(proofs_and_inputs.len());
+    let mut a_b_prepared = Vec::with_capacity(proofs_and_inputs.len());
+
+    for (proof, public_inputs) in proofs_and_inputs.iter() {
+        if (public_inputs.len() + 1) != pvk.gamma_abc_g1.len() {
+            return Err(SynthesisError::MalformedVerifyingKey);
+        }
+
+        let r = E::Fr::rand(rng);
+        r_sum += &r;
+
+        // Accumulate the coefficients of the combined input commitment
+        // sum_i r_i * IC_i = (sum_i r_i) * gamma_abc[0] + sum_j (sum_i r_i * x_ij) * gamma_abc[j]
+        for (acc, input) in input_scalars.iter_mut().skip(1).zip(public_inputs.iter()) {
+            *acc += &(r * input);
+        }
+
+        c_bases.push(proof.c);
+        c_scalars.push(r.into_repr());
+
+        let r_a = proof.a.mul(r.into_repr()).into_affine();
+        a_b_prepared.push((r_a.prepare(), proof.b.prepare()));
+    }
+    input_scalars[0] = r_sum;
+
+    let input_scalars = input_scalars.into_iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    let g_ic = VariableBaseMSM::multi_scalar_mul(pvk.gamma_abc_g1.as_slice(), input_scalars.as_slice());
+    let g_c = VariableBaseMSM::multi_scalar_mul(c_bases.as_slice(), c_scalars.as_slice());
+
+    let g_ic_prepared = g_ic.into_affine().prepare();
+    let g_c_prepared = g_c.into_affine().prepare();
+
+    let mut pairs = a_b_prepared.iter().map(|(a, b)| (a, b)).collect::<Vec<_>>();
+    pairs.push((&g_ic_prepared, &pvk.gamma_g2_neg_pc));
+    pairs.push((&g_c_prepared, &pvk.delta_g2_neg_pc));
+
+    let qap = E::miller_loop(pairs.iter());
+
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(r_sum.into_repr()))
+}
@@ -6,7 +6,7 @@
 #![deny(unused_comparisons, bare_trait_objects, unused_must_use, const_err)]
 #![forbid(unsafe_code)]
 
-#[cfg(any(feature = "groth16", feature = "gm17"))]
+#[cfg(any(feature = "groth16", feature = "gm17", feature = "marlin"))]
 #[macro_use]
 extern crate bench_utils;
 
@@ -15,3 +15,6 @@ pub mod groth16;
 
 #[cfg(feature = "gm17")]
 pub mod gm17;
+
+#[cfg(feature = "marlin")]
+pub mod marlin;
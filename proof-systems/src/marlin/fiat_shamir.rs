@@ -0,0 +1,112 @@
+//! A Blake2b-based Fiat-Shamir transform, turning the interactive Marlin
+//! protocol into a non-interactive one.
+use algebra::{bytes::ToBytes, fft::EvaluationDomain, PrimeField};
+use blake2::Blake2b;
+use digest::Digest;
+use rand::{Error, RngCore};
+
+/// The state of the transcript: a digest of everything absorbed so far, from
+/// which the verifier challenges are derived.
+pub(crate) struct FiatShamirRng {
+    seed: Vec<u8>,
+}
+
+impl FiatShamirRng {
+    pub(crate) fn new(domain_separator: &[u8]) -> Self {
+        let mut h = Blake2b::default();
+        h.input(domain_separator);
+        Self { seed: h.result().as_slice().to_vec() }
+    }
+
+    /// Binds the transcript to the serialization of `obj`.
+    pub(crate) fn absorb<T: ToBytes>(&mut self, obj: &T) {
+        let mut h = Blake2b::default();
+        h.input(&self.seed);
+        obj.write(&mut BlakeWriter(&mut h))
+            .expect("writing to a hasher cannot fail");
+        self.seed = h.result().as_slice().to_vec();
+    }
+
+    /// Derives a challenge from the transcript, and updates the transcript so
+    /// that the next challenge is independent from this one.
+    pub(crate) fn squeeze<F: PrimeField>(&mut self) -> F {
+        loop {
+            // Two blocks are enough to fill the representation of any of our fields.
+            let mut bytes = Vec::with_capacity(128);
+            for block in 0u8..2 {
+                let mut h = Blake2b::default();
+                h.input(&self.seed);
+                h.input(&[block]);
+                bytes.extend_from_slice(h.result().as_slice());
+            }
+            self.ratchet();
+
+            if let Some(f) = F::from_random_bytes(&bytes) {
+                return f;
+            }
+        }
+    }
+
+    /// Derives a challenge which is not an element of `domain`.
+    pub(crate) fn squeeze_outside_domain<F: PrimeField>(&mut self, domain: &EvaluationDomain<F>) -> F {
+        loop {
+            let f = self.squeeze::<F>();
+            if !domain.evaluate_vanishing_polynomial(f).is_zero() {
+                return f;
+            }
+        }
+    }
+
+    /// Moves the transcript to a new state, independent from the output
+    /// derived from the current one.
+    fn ratchet(&mut self) {
+        let mut h = Blake2b::default();
+        h.input(&self.seed);
+        h.input(&[2u8]);
+        self.seed = h.result().as_slice().to_vec();
+    }
+}
+
+/// Exposes the transcript as a source of randomness, for the components
+/// sampling their own challenges.
+impl RngCore for FiatShamirRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(64) {
+            let mut h = Blake2b::default();
+            h.input(&self.seed);
+            h.input(&[3u8]);
+            chunk.copy_from_slice(&h.result().as_slice()[..chunk.len()]);
+            self.ratchet();
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+struct BlakeWriter<'a>(&'a mut Blake2b);
+
+impl<'a> std::io::Write for BlakeWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.input(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use algebra::{
+    fft::{DensePolynomial, EvaluationDomain},
+    Field, PairingEngine, PrimeField,
+};
+use r1cs_core::{
+    ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+use rand::Rng;
+
+use std::collections::BTreeMap;
+
+use primitives::poly_commit::{kzg10::KZG10, PolynomialCommitment};
+
+use crate::marlin::{
+    commit, max_degree, Matrix, MatrixArithmetization, ProvingKey, UniversalSRS, VerifyingKey,
+};
+
+/// Generates a universal SRS supporting every circuit with at most
+/// `num_constraints` constraints, `num_variables` variables (the public inputs,
+/// including the constant `1`, counting as a power of two) and `num_non_zero`
+/// non-zero entries in each of its constraint matrices.
+pub fn generate_universal_srs<E, R>(
+    num_constraints: usize,
+    num_variables: usize,
+    num_non_zero: usize,
+    rng: &mut R,
+) -> Result<UniversalSRS<E>, SynthesisError>
+    where
+        E: PairingEngine,
+        R: Rng,
+{
+    let domain_h_size = EvaluationDomain::<E::Fr>::compute_size_of_domain(
+        num_constraints.max(num_variables).max(2),
+    )
+    .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let domain_k_size = EvaluationDomain::<E::Fr>::compute_size_of_domain(num_non_zero.max(2))
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+    KZG10::<E>::setup(max_degree(domain_h_size, domain_k_size), rng)
+        .map_err(|_| SynthesisError::PolynomialDegreeTooLarge)
+}
+
+/// This is our assembly structure that we'll use to extract the constraint
+/// matrices of the circuit.
+pub(crate) struct IndexAssembly<F: Field> {
+    pub(crate) num_inputs:      usize,
+    pub(crate) num_aux:         usize,
+    pub(crate) num_constraints: usize,
+    pub(crate) at:              Vec<Vec<(F, Index)>>,
+    pub(crate) bt:              Vec<Vec<(F, Index)>>,
+    pub(crate) ct:              Vec<Vec<(F, Index)>>,
+}
+
+impl<F: Field> ConstraintSystem<F> for IndexAssembly<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+        where
+            FN: FnOnce() -> Result<F, SynthesisError>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+    {
+        // There is no assignment, so we don't invoke the
+        // function for obtaining one.
+
+        let index = self.num_aux;
+        self.num_aux += 1;
+
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+        where
+            FN: FnOnce() -> Result<F, SynthesisError>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+    {
+        // There is no assignment, so we don't invoke the
+        // function for obtaining one.
+
+        let index = self.num_inputs;
+        self.num_inputs += 1;
+
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+        where
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+            LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+            LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+            LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        fn eval<F: Field>(l: LinearCombination<F>) -> Vec<(F, Index)> {
+            l.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
+        }
+
+        self.at.push(eval(a(LinearCombination::zero())));
+        self.bt.push(eval(b(LinearCombination::zero())));
+        self.ct.push(eval(c(LinearCombination::zero())));
+
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+        where
+            NR: Into<String>,
+            N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
+
+/// Specializes the universal SRS to `circuit`, producing its proving key.
+/// The verification key is its `vk` field.
+pub fn index<E, C>(
+    srs: &UniversalSRS<E>,
+    circuit: C,
+) -> Result<ProvingKey<E>, SynthesisError>
+    where
+        E: PairingEngine,
+        C: ConstraintSynthesizer<E::Fr>,
+{
+    let index_time = start_timer!(|| "Marlin::Index");
+
+    let mut assembly = IndexAssembly::<E::Fr> {
+        num_inputs:      0,
+        num_aux:         0,
+        num_constraints: 0,
+        at:              vec![],
+        bt:              vec![],
+        ct:              vec![],
+    };
+
+    // Allocate the "one" input variable
+    assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+    // Synthesize the circuit.
+    let synthesis_time = start_timer!(|| "Constraint synthesis");
+    circuit.generate_constraints(&mut assembly)?;
+    end_timer!(synthesis_time);
+
+    // The public inputs occupy the subgroup X of H, padded with zeros,
+    // and are followed by the witnesses.
+    let domain_x = EvaluationDomain::<E::Fr>::new(assembly.num_inputs)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let num_variables = domain_x.size() + assembly.num_aux;
+    let domain_h = EvaluationDomain::<E::Fr>::new(
+        assembly.num_constraints.max(num_variables).max(2),
+    )
+    .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+    let column = |index: Index| -> usize {
+        let variable = match index {
+            Index::Input(i) => i,
+            Index::Aux(i) => domain_x.size() + i,
+        };
+        domain_h.reindex_by_subdomain(domain_x, variable)
+    };
+    let matrices = [
+        to_matrix(&assembly.at, column),
+        to_matrix(&assembly.bt, column),
+        to_matrix(&assembly.ct, column),
+    ];
+
+    let num_non_zero = matrices
+        .iter()
+        .map(|m| m.iter().map(|row| row.len()).sum::<usize>())
+        .max()
+        .unwrap();
+    let domain_k = EvaluationDomain::<E::Fr>::new(num_non_zero.max(2))
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+    if srs.max_degree() < max_degree(domain_h.size(), domain_k.size()) {
+        return Err(SynthesisError::PolynomialDegreeTooLarge);
+    }
+
+    let arithmetization_time = start_timer!(|| "Arithmetize matrices");
+    let arithmetizations = [
+        arithmetize(&matrices[0], domain_h, domain_k),
+        arithmetize(&matrices[1], domain_h, domain_k),
+        arithmetize(&matrices[2], domain_h, domain_k),
+    ];
+    end_timer!(arithmetization_time);
+
+    // The degree bounds are enforced against the full degree of the SRS
+    let (ck, pc_vk) = KZG10::<E>::trim(srs, srs.max_degree())
+        .map_err(|_| SynthesisError::PolynomialDegreeTooLarge)?;
+
+    let commit_time = start_timer!(|| "Commit to index polynomials");
+    let mut index_comms = vec![];
+    for arith in arithmetizations.iter() {
+        for p in [&arith.row, &arith.col, &arith.val].iter() {
+            index_comms.push(commit(&ck, p)?);
+        }
+    }
+    end_timer!(commit_time);
+
+    let vk = VerifyingKey {
+        num_inputs: assembly.num_inputs,
+        domain_h_size: domain_h.size(),
+        domain_k_size: domain_k.size(),
+        max_degree: srs.max_degree(),
+        index_comms,
+        pc_vk,
+    };
+
+    end_timer!(index_time);
+    Ok(ProvingKey {
+        vk,
+        ck,
+        num_aux: assembly.num_aux,
+        matrices,
+        arithmetizations,
+    })
+}
+
+/// Converts the constraints into a matrix, merging repeated variables and
+/// mapping them to their column in `H`.
+fn to_matrix<F: Field>(constraints: &[Vec<(F, Index)>], column: impl Fn(Index) -> usize) -> Matrix<F> {
+    constraints
+        .iter()
+        .map(|lc| {
+            let mut row = BTreeMap::new();
+            for &(coeff, index) in lc {
+                *row.entry(column(index)).or_insert_with(F::zero) += &coeff;
+            }
+            row.into_iter()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(col, coeff)| (coeff, col))
+                .collect()
+        })
+        .collect()
+}
+
+/// Encodes the non-zero entries `M[r][c]` of the matrix as the evaluations
+/// over `K` of `row(k) = w^r`, `col(k) = w^c` and `val(k) = M[r][c] * w^c / |H|`,
+/// `w` being the generator of `H`. The padding entries have value zero.
+fn arithmetize<F: PrimeField>(
+    matrix: &Matrix<F>,
+    domain_h: EvaluationDomain<F>,
+    domain_k: EvaluationDomain<F>,
+) -> MatrixArithmetization<F> {
+    let elements_h = domain_h.elements().collect::<Vec<_>>();
+
+    let mut row_evals = Vec::with_capacity(domain_k.size());
+    let mut col_evals = Vec::with_capacity(domain_k.size());
+    let mut val_evals = Vec::with_capacity(domain_k.size());
+    for (r, entries) in matrix.iter().enumerate() {
+        for &(coeff, c) in entries {
+            row_evals.push(elements_h[r]);
+            col_evals.push(elements_h[c]);
+            val_evals.push(coeff * &elements_h[c] * &domain_h.size_inv);
+        }
+    }
+    row_evals.resize(domain_k.size(), F::one());
+    col_evals.resize(domain_k.size(), F::one());
+    val_evals.resize(domain_k.size(), F::zero());
+
+    MatrixArithmetization {
+        row: DensePolynomial::from_coefficients_vec(domain_k.ifft(&row_evals)),
+        col: DensePolynomial::from_coefficients_vec(domain_k.ifft(&col_evals)),
+        val: DensePolynomial::from_coefficients_vec(domain_k.ifft(&val_evals)),
+        row_evals,
+        col_evals,
+        val_evals,
+    }
+}
@@ -0,0 +1,301 @@
+//! An implementation of the [Marlin][marlin] preprocessing SNARK, instantiated
+//! with the KZG10 polynomial commitment scheme.
+//!
+//! Unlike Groth16, the setup is universal: a single `UniversalSRS` supports
+//! any circuit up to the size it was generated for, and specializing it to a
+//! circuit (`index`) is a deterministic and public operation.
+//!
+//! The proofs are zero-knowledge: the prover masks the witness polynomials
+//! with random multiples of the vanishing polynomial of the constraint
+//! domain, masks the outer sumcheck with a random polynomial summing to zero
+//! over it, and commits to all the polynomials depending on the witness with
+//! hiding KZG10 commitments.
+//!
+//! [marlin]: https://eprint.iacr.org/2019/1047
+use algebra::{
+    bytes::{FromBytes, FromBytesChecked, ToBytes},
+    fft::DensePolynomial,
+    AffineCurve, PairingEngine, PrimeField,
+};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use primitives::poly_commit::{
+    kzg10::{CommitterKey, UniversalParams, VerifierKey, KZG10},
+    PolynomialCommitment,
+};
+use r1cs_core::SynthesisError;
+use rand::Rng;
+use std::io::{Read, Result as IoResult, Write};
+
+mod fiat_shamir;
+
+/// Generate the universal SRS and index circuits for the Marlin construction.
+pub mod generator;
+
+/// Create proofs for the Marlin construction.
+pub mod prover;
+
+/// Verify proofs for the Marlin construction.
+pub mod verifier;
+
+#[cfg(test)]
+mod test;
+
+pub use self::{generator::*, prover::*, verifier::*};
+
+/// Number of evaluations at the first query point `beta`, namely those of
+/// `w, z_A, z_B, s, t, g_1, h_1`.
+pub(crate) const NUM_BETA_EVALS: usize = 7;
+
+/// Number of evaluations at the second query point `gamma`, namely those of
+/// `g_2, h_2` and of `row, col, val` for each of the matrices `A, B, C`.
+pub(crate) const NUM_GAMMA_EVALS: usize = 11;
+
+/// Number of polynomials describing an indexed circuit.
+pub(crate) const NUM_INDEX_POLYS: usize = 9;
+
+/// Number of evaluations revealed of each of the polynomials depending on the
+/// witness, which determines the degree of their masks and blinding
+/// polynomials.
+pub(crate) const ZK_BOUND: usize = 1;
+
+/// The domain separator of the Fiat-Shamir transcript.
+pub(crate) const PROTOCOL_NAME: &[u8] = b"MARLIN-KZG10";
+
+/// Returns the maximum degree of the polynomials committed when proving a
+/// circuit with constraint domain of size `domain_h_size` and non-zero
+/// entries domain of size `domain_k_size`.
+pub(crate) fn max_degree(domain_h_size: usize, domain_k_size: usize) -> usize {
+    (2 * domain_h_size + ZK_BOUND - 1).max(6 * domain_k_size)
+}
+
+/// Commits to `polynomial`, which must fit the committer key.
+pub(crate) fn commit<E: PairingEngine>(
+    ck: &CommitterKey<E>,
+    polynomial: &DensePolynomial<E::Fr>,
+) -> Result<E::G1Affine, SynthesisError> {
+    KZG10::<E>::commit(ck, polynomial).map_err(|_| SynthesisError::PolynomialDegreeTooLarge)
+}
+
+/// Commits to `polynomial`, which must fit the committer key, hiding it up to
+/// `ZK_BOUND` evaluations. Returns the blinding polynomial along with the
+/// commitment.
+pub(crate) fn commit_hiding<E: PairingEngine, R: Rng>(
+    ck: &CommitterKey<E>,
+    polynomial: &DensePolynomial<E::Fr>,
+    rng: &mut R,
+) -> Result<(E::G1Affine, DensePolynomial<E::Fr>), SynthesisError> {
+    KZG10::<E>::commit_hiding(ck, polynomial, ZK_BOUND, rng)
+        .map_err(|_| SynthesisError::PolynomialDegreeTooLarge)
+}
+
+/// The universal structured reference string: `tau^i * G` and
+/// `tau^i * gamma * G` for all `i` up to the maximum degree supported, `H`
+/// and `tau * H`.
+pub type UniversalSRS<E> = UniversalParams<E>;
+
+/// The verification key of an indexed circuit: the sizes of its domains and
+/// the commitments to the polynomials describing its constraint matrices.
+#[derive(Clone, Debug)]
+pub struct VerifyingKey<E: PairingEngine> {
+    /// Number of public inputs, including the constant `1`.
+    pub num_inputs:    usize,
+    pub domain_h_size: usize,
+    pub domain_k_size: usize,
+    /// The maximum degree supported by the SRS the circuit was indexed with.
+    pub max_degree:    usize,
+    /// Commitments to `row, col, val` for each of the matrices `A, B, C`.
+    pub index_comms:   Vec<E::G1Affine>,
+    pub pc_vk:         VerifierKey<E>,
+}
+
+impl<E: PairingEngine> VerifyingKey<E> {
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let num_inputs = reader.read_u32::<BigEndian>()? as usize;
+        let domain_h_size = reader.read_u32::<BigEndian>()? as usize;
+        let domain_k_size = reader.read_u32::<BigEndian>()? as usize;
+        let max_degree = reader.read_u32::<BigEndian>()? as usize;
+        let index_comms = read_affine_vec::<E::G1Affine, _>(NUM_INDEX_POLYS, checked, &mut reader)?;
+        let pc_vk = if checked {
+            VerifierKey::read_checked(&mut reader)?
+        } else {
+            VerifierKey::read(&mut reader)?
+        };
+        Ok(Self { num_inputs, domain_h_size, domain_k_size, max_degree, index_comms, pc_vk })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for VerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_inputs == other.num_inputs
+            && self.domain_h_size == other.domain_h_size
+            && self.domain_k_size == other.domain_k_size
+            && self.max_degree == other.max_degree
+            && self.index_comms == other.index_comms
+            && self.pc_vk == other.pc_vk
+    }
+}
+
+impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        writer.write_u32::<BigEndian>(self.num_inputs as u32)?;
+        writer.write_u32::<BigEndian>(self.domain_h_size as u32)?;
+        writer.write_u32::<BigEndian>(self.domain_k_size as u32)?;
+        writer.write_u32::<BigEndian>(self.max_degree as u32)?;
+        for c in self.index_comms.iter() {
+            c.write(&mut writer)?;
+        }
+        self.pc_vk.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for VerifyingKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+/// A constraint matrix in sparse row-major form. Columns are positions in
+/// the constraint domain `H` rather than variable indices.
+pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
+
+/// The polynomials `row, col, val` describing a constraint matrix over the
+/// domain `K`, in coefficient form and as evaluations over `K`.
+#[derive(Clone, Debug)]
+pub(crate) struct MatrixArithmetization<F: PrimeField> {
+    pub(crate) row:       DensePolynomial<F>,
+    pub(crate) col:       DensePolynomial<F>,
+    pub(crate) val:       DensePolynomial<F>,
+    pub(crate) row_evals: Vec<F>,
+    pub(crate) col_evals: Vec<F>,
+    pub(crate) val_evals: Vec<F>,
+}
+
+/// The proving key of an indexed circuit.
+#[derive(Clone, Debug)]
+pub struct ProvingKey<E: PairingEngine> {
+    pub vk:                     VerifyingKey<E>,
+    pub(crate) ck:              CommitterKey<E>,
+    pub(crate) num_aux:         usize,
+    pub(crate) matrices:        [Matrix<E::Fr>; 3],
+    pub(crate) arithmetizations: [MatrixArithmetization<E::Fr>; 3],
+}
+
+/// A proof in the Marlin SNARK.
+#[derive(Clone, Debug)]
+pub struct Proof<E: PairingEngine> {
+    pub w_comm:            E::G1Affine,
+    pub z_a_comm:          E::G1Affine,
+    pub z_b_comm:          E::G1Affine,
+    /// Commitment to the mask `s` of the outer sumcheck.
+    pub s_comm:            E::G1Affine,
+    pub t_comm:            E::G1Affine,
+    pub g_1_comm:          E::G1Affine,
+    pub g_1_shifted_comm:  E::G1Affine,
+    pub h_1_comm:          E::G1Affine,
+    pub g_2_comm:          E::G1Affine,
+    pub g_2_shifted_comm:  E::G1Affine,
+    pub h_2_comm:          E::G1Affine,
+    /// Evaluations of `w, z_A, z_B, s, t, g_1, h_1` at `beta`.
+    pub beta_evals:        Vec<E::Fr>,
+    /// Evaluations of `g_2, h_2` and of the index polynomials at `gamma`.
+    pub gamma_evals:       Vec<E::Fr>,
+    pub beta_opening:      E::G1Affine,
+    /// The combined evaluation at `beta` of the blinding polynomials of the
+    /// hiding commitments.
+    pub beta_random_value: E::Fr,
+    pub gamma_opening:     E::G1Affine,
+}
+
+impl<E: PairingEngine> Proof<E> {
+    fn commitments(&self) -> [&E::G1Affine; 11] {
+        [
+            &self.w_comm, &self.z_a_comm, &self.z_b_comm, &self.s_comm, &self.t_comm, &self.g_1_comm,
+            &self.g_1_shifted_comm, &self.h_1_comm, &self.g_2_comm, &self.g_2_shifted_comm,
+            &self.h_2_comm,
+        ]
+    }
+
+    fn read_maybe_checked<R: Read>(mut reader: R, checked: bool) -> IoResult<Self> {
+        let mut comms = read_affine_vec::<E::G1Affine, _>(11, checked, &mut reader)?.into_iter();
+        let mut next = || comms.next().unwrap();
+        let (w_comm, z_a_comm, z_b_comm, s_comm) = (next(), next(), next(), next());
+        let (t_comm, g_1_comm, g_1_shifted_comm, h_1_comm) = (next(), next(), next(), next());
+        let (g_2_comm, g_2_shifted_comm, h_2_comm) = (next(), next(), next());
+
+        let beta_evals = (0..NUM_BETA_EVALS)
+            .map(|_| E::Fr::read(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        let gamma_evals = (0..NUM_GAMMA_EVALS)
+            .map(|_| E::Fr::read(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+
+        let beta_opening = read_affine::<E::G1Affine, _>(checked, &mut reader)?;
+        let beta_random_value = E::Fr::read(&mut reader)?;
+        let gamma_opening = read_affine::<E::G1Affine, _>(checked, &mut reader)?;
+
+        Ok(Self {
+            w_comm, z_a_comm, z_b_comm, s_comm, t_comm, g_1_comm, g_1_shifted_comm, h_1_comm,
+            g_2_comm, g_2_shifted_comm, h_2_comm, beta_evals, gamma_evals, beta_opening,
+            beta_random_value, gamma_opening,
+        })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for Proof<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.commitments() == other.commitments()
+            && self.beta_evals == other.beta_evals
+            && self.gamma_evals == other.gamma_evals
+            && self.beta_opening == other.beta_opening
+            && self.beta_random_value == other.beta_random_value
+            && self.gamma_opening == other.gamma_opening
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Proof<E> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for c in self.commitments().iter() {
+            c.write(&mut writer)?;
+        }
+        for e in self.beta_evals.iter().chain(self.gamma_evals.iter()) {
+            e.write(&mut writer)?;
+        }
+        self.beta_opening.write(&mut writer)?;
+        self.beta_random_value.write(&mut writer)?;
+        self.gamma_opening.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, false)
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Proof<E> {
+    #[inline]
+    fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_maybe_checked(reader, true)
+    }
+}
+
+fn read_affine<G: AffineCurve, R: Read>(checked: bool, reader: R) -> IoResult<G> {
+    if checked {
+        G::read_checked(reader)
+    } else {
+        G::read(reader)
+    }
+}
+
+fn read_affine_vec<G: AffineCurve, R: Read>(len: usize, checked: bool, mut reader: R) -> IoResult<Vec<G>> {
+    (0..len).map(|_| read_affine::<G, _>(checked, &mut reader)).collect()
+}
@@ -0,0 +1,390 @@
+use algebra::{
+    fft::{DensePolynomial, EvaluationDomain},
+    fields::batch_inversion,
+    Field, PairingEngine, PrimeField,
+};
+use r1cs_core::{
+    ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+use rand::Rng;
+
+use primitives::poly_commit::{kzg10::KZG10, PolynomialCommitment};
+
+use crate::marlin::{
+    commit, commit_hiding, fiat_shamir::FiatShamirRng, Matrix, Proof, ProvingKey, PROTOCOL_NAME, ZK_BOUND,
+};
+
+/// Collects the assignment of the variables, ignoring the constraints which
+/// are known from the proving key.
+pub(crate) struct ProvingAssignment<F: Field> {
+    pub(crate) input_assignment: Vec<F>,
+    pub(crate) aux_assignment:   Vec<F>,
+}
+
+impl<F: Field> ConstraintSystem<F> for ProvingAssignment<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+        where
+            FN: FnOnce() -> Result<F, SynthesisError>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+    {
+        let index = self.aux_assignment.len();
+        self.aux_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+        where
+            FN: FnOnce() -> Result<F, SynthesisError>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+    {
+        let index = self.input_assignment.len();
+        self.input_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+        where
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+            LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+            LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+            LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        // Do nothing; the constraints are part of the proving key.
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+        where
+            NR: Into<String>,
+            N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        0
+    }
+}
+
+/// Creates a zero-knowledge proof that the assignment of `circuit` satisfies
+/// the circuit indexed by `pk`, drawing the masks and the blinding
+/// polynomials from `rng`.
+pub fn create_random_proof<E, C, R>(
+    circuit: C,
+    pk: &ProvingKey<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+    where
+        E: PairingEngine,
+        C: ConstraintSynthesizer<E::Fr>,
+        R: Rng,
+{
+    let prover_time = start_timer!(|| "Marlin::Prover");
+    let vk = &pk.vk;
+
+    let mut prover = ProvingAssignment::<E::Fr> {
+        input_assignment: vec![],
+        aux_assignment:   vec![],
+    };
+
+    // Allocate the "one" input variable
+    prover.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+    // Synthesize the circuit.
+    let synthesis_time = start_timer!(|| "Constraint synthesis");
+    circuit.generate_constraints(&mut prover)?;
+    end_timer!(synthesis_time);
+
+    if prover.input_assignment.len() != vk.num_inputs || prover.aux_assignment.len() != pk.num_aux {
+        return Err(SynthesisError::MalformedVerifyingKey);
+    }
+
+    let domain_x = EvaluationDomain::<E::Fr>::new(vk.num_inputs)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let domain_h = EvaluationDomain::<E::Fr>::new(vk.domain_h_size)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let domain_k = EvaluationDomain::<E::Fr>::new(vk.domain_k_size)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+    let mut x = prover.input_assignment.clone();
+    x.resize(domain_x.size(), E::Fr::zero());
+
+    let mut fs_rng = FiatShamirRng::new(PROTOCOL_NAME);
+    fs_rng.absorb(vk);
+    fs_rng.absorb(&x);
+
+    // First round: commit to the witness, to the products z_A = Az, z_B = Bz
+    // and to the mask of the outer sumcheck. The polynomials interpolating
+    // z, z_A and z_B over H are masked with random multiples of v_H.
+    let first_round_time = start_timer!(|| "First round");
+    let mut z = vec![E::Fr::zero(); domain_h.size()];
+    for (i, v) in x.iter().chain(prover.aux_assignment.iter()).enumerate() {
+        z[domain_h.reindex_by_subdomain(domain_x, i)] = *v;
+    }
+    let z_a = matrix_vector_product(&pk.matrices[0], &z, domain_h.size());
+    let z_b = matrix_vector_product(&pk.matrices[1], &z, domain_h.size());
+    let z_c = matrix_vector_product(&pk.matrices[2], &z, domain_h.size());
+    if z_a.iter().zip(&z_b).zip(&z_c).any(|((a, b), c)| *a * b != *c) {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let z_poly = mask(&DensePolynomial::from_coefficients_vec(domain_h.ifft(&z)), domain_h, rng);
+    let x_poly = DensePolynomial::from_coefficients_vec(domain_x.ifft(&x));
+    let (w_poly, _) = (&z_poly - &x_poly)
+        .divide_by_vanishing_poly(domain_x)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let z_a_poly = mask(&DensePolynomial::from_coefficients_vec(domain_h.ifft(&z_a)), domain_h, rng);
+    let z_b_poly = mask(&DensePolynomial::from_coefficients_vec(domain_h.ifft(&z_b)), domain_h, rng);
+
+    // The sum of s over H is |H| times the sum of its coefficients of degree
+    // a multiple of |H|, so it is made zero through the constant coefficient.
+    let mut s_poly = DensePolynomial::rand(2 * domain_h.size() + ZK_BOUND - 1, rng);
+    let s_sum = s_poly.coeffs.iter()
+        .step_by(domain_h.size())
+        .skip(1)
+        .fold(E::Fr::zero(), |acc, c| acc + c);
+    s_poly.coeffs[0] = -s_sum;
+
+    let (w_comm, w_blinding) = commit_hiding(&pk.ck, &w_poly, rng)?;
+    let (z_a_comm, z_a_blinding) = commit_hiding(&pk.ck, &z_a_poly, rng)?;
+    let (z_b_comm, z_b_blinding) = commit_hiding(&pk.ck, &z_b_poly, rng)?;
+    let (s_comm, s_blinding) = commit_hiding(&pk.ck, &s_poly, rng)?;
+    end_timer!(first_round_time);
+
+    fs_rng.absorb(&vec![w_comm, z_a_comm, z_b_comm, s_comm]);
+    let alpha = fs_rng.squeeze_outside_domain(&domain_h);
+    let eta: [E::Fr; 3] = [fs_rng.squeeze(), fs_rng.squeeze(), fs_rng.squeeze()];
+
+    // Second round: the outer sumcheck, proving that
+    //   sum_{k in H} s(k) + r(alpha, k) (eta_A z_A(k) + eta_B z_B(k) + eta_C z_A(k) z_B(k)) - t(k) z(k) = 0,
+    // where t(X) = sum_M eta_M sum_{k in H} r(alpha, k) M(k, X) and r(X, Y) = (v_H(X) - v_H(Y)) / (X - Y).
+    let second_round_time = start_timer!(|| "Second round");
+    let v_h_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
+    let mut r_alpha_evals = domain_h.elements().map(|k| alpha - &k).collect::<Vec<_>>();
+    batch_inversion(&mut r_alpha_evals);
+    r_alpha_evals.iter_mut().for_each(|r| *r *= &v_h_alpha);
+
+    let mut t_evals = vec![E::Fr::zero(); domain_h.size()];
+    for (matrix, eta) in pk.matrices.iter().zip(eta.iter()) {
+        for (row, entries) in matrix.iter().enumerate() {
+            let factor = *eta * &r_alpha_evals[row];
+            for &(coeff, col) in entries {
+                t_evals[col] += &(factor * &coeff);
+            }
+        }
+    }
+    let t_poly = DensePolynomial::from_coefficients_vec(domain_h.ifft(&t_evals));
+
+    // r(alpha, X) = sum_{i < |H|} alpha^(|H| - 1 - i) X^i
+    let mut r_alpha_coeffs = Vec::with_capacity(domain_h.size());
+    let mut alpha_power = E::Fr::one();
+    for _ in 0..domain_h.size() {
+        r_alpha_coeffs.push(alpha_power);
+        alpha_power *= &alpha;
+    }
+    r_alpha_coeffs.reverse();
+
+    let domain_b = EvaluationDomain::<E::Fr>::new(3 * domain_h.size())
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let r_alpha_b = domain_b.fft(&r_alpha_coeffs);
+    let z_a_b = domain_b.fft(&z_a_poly);
+    let z_b_b = domain_b.fft(&z_b_poly);
+    let t_b = domain_b.fft(&t_poly);
+    let z_poly_b = domain_b.fft(&z_poly);
+    let s_b = domain_b.fft(&s_poly);
+    let q_1_evals = (0..domain_b.size())
+        .map(|i| {
+            let sum = eta[0] * &z_a_b[i] + &(eta[1] * &z_b_b[i]) + &(eta[2] * &z_a_b[i] * &z_b_b[i]);
+            s_b[i] + &(r_alpha_b[i] * &sum) - &(t_b[i] * &z_poly_b[i])
+        })
+        .collect::<Vec<_>>();
+    let q_1 = DensePolynomial::from_coefficients_vec(domain_b.ifft(&q_1_evals));
+    let (h_1_poly, remainder) = q_1
+        .divide_by_vanishing_poly(domain_h)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let g_1_poly = divide_by_x(&remainder);
+
+    let g_1_bound = domain_h.size() - 2;
+    let t_comm = commit(&pk.ck, &t_poly)?;
+    let (g_1_comm, g_1_blinding) = commit_hiding(&pk.ck, &g_1_poly, rng)?;
+    let g_1_shifted = shift(&g_1_poly, vk.max_degree - g_1_bound);
+    let (g_1_shifted_comm, g_1_shifted_blinding) = commit_hiding(&pk.ck, &g_1_shifted, rng)?;
+    let (h_1_comm, h_1_blinding) = commit_hiding(&pk.ck, &h_1_poly, rng)?;
+    end_timer!(second_round_time);
+
+    fs_rng.absorb(&vec![t_comm, g_1_comm, g_1_shifted_comm, h_1_comm]);
+    let beta = fs_rng.squeeze_outside_domain(&domain_h);
+
+    // Third round: the inner sumcheck, proving that t(beta) = sum_{k in K} f_2(k), where
+    //   f_2(k) = sum_M eta_M v_H(alpha) v_H(beta) val_M(k) / ((alpha - row_M(k)) (beta - col_M(k)))
+    // is the rational function a(X) / b(X) over K.
+    let third_round_time = start_timer!(|| "Third round");
+    let v_h_beta = domain_h.evaluate_vanishing_polynomial(beta);
+    let v_h_alpha_beta = v_h_alpha * &v_h_beta;
+
+    let mut f_2_evals = vec![E::Fr::zero(); domain_k.size()];
+    for (arith, eta) in pk.arithmetizations.iter().zip(eta.iter()) {
+        let mut denominators = arith
+            .row_evals
+            .iter()
+            .zip(&arith.col_evals)
+            .map(|(row, col)| (alpha - row) * &(beta - col))
+            .collect::<Vec<_>>();
+        batch_inversion(&mut denominators);
+        let factor = *eta * &v_h_alpha_beta;
+        for ((f, val), den) in f_2_evals.iter_mut().zip(&arith.val_evals).zip(denominators) {
+            *f += &(factor * val * &den);
+        }
+    }
+    let f_2_poly = DensePolynomial::from_coefficients_vec(domain_k.ifft(&f_2_evals));
+    let g_2_poly = divide_by_x(&f_2_poly);
+
+    let domain_b = EvaluationDomain::<E::Fr>::new(7 * domain_k.size())
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let mut a_evals = vec![E::Fr::zero(); domain_b.size()];
+    let mut b_evals = vec![E::Fr::one(); domain_b.size()];
+    for (arith, eta) in pk.arithmetizations.iter().zip(eta.iter()) {
+        let row = domain_b.fft(&arith.row);
+        let col = domain_b.fft(&arith.col);
+        let val = domain_b.fft(&arith.val);
+        let factor = *eta * &v_h_alpha_beta;
+        for i in 0..domain_b.size() {
+            // a / b + factor * val / den = (a * den + factor * val * b) / (b * den)
+            let den = (alpha - &row[i]) * &(beta - &col[i]);
+            a_evals[i] = a_evals[i] * &den + &(factor * &val[i] * &b_evals[i]);
+            b_evals[i] *= &den;
+        }
+    }
+    let f_2_b = domain_b.fft(&f_2_poly);
+    let q_2_evals = (0..domain_b.size())
+        .map(|i| a_evals[i] - &(b_evals[i] * &f_2_b[i]))
+        .collect::<Vec<_>>();
+    let q_2 = DensePolynomial::from_coefficients_vec(domain_b.ifft(&q_2_evals));
+    let (h_2_poly, _) = q_2
+        .divide_by_vanishing_poly(domain_k)
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+    let g_2_bound = domain_k.size() - 2;
+    let g_2_comm = commit(&pk.ck, &g_2_poly)?;
+    let g_2_shifted = shift(&g_2_poly, vk.max_degree - g_2_bound);
+    let g_2_shifted_comm = commit(&pk.ck, &g_2_shifted)?;
+    let h_2_comm = commit(&pk.ck, &h_2_poly)?;
+    end_timer!(third_round_time);
+
+    fs_rng.absorb(&vec![g_2_comm, g_2_shifted_comm, h_2_comm]);
+    let gamma = fs_rng.squeeze::<E::Fr>();
+
+    // Evaluations and opening proofs
+    let opening_time = start_timer!(|| "Opening proofs");
+    let beta_polys = [&w_poly, &z_a_poly, &z_b_poly, &s_poly, &t_poly, &g_1_poly, &h_1_poly];
+    let beta_evals = beta_polys.iter().map(|p| p.evaluate(beta)).collect::<Vec<_>>();
+
+    let mut gamma_polys = vec![&g_2_poly, &h_2_poly];
+    for arith in pk.arithmetizations.iter() {
+        gamma_polys.extend_from_slice(&[&arith.row, &arith.col, &arith.val]);
+    }
+    let gamma_evals = gamma_polys.iter().map(|p| p.evaluate(gamma)).collect::<Vec<_>>();
+
+    fs_rng.absorb(&beta_evals);
+    fs_rng.absorb(&gamma_evals);
+    let xi_beta = fs_rng.squeeze::<E::Fr>();
+    let xi_gamma = fs_rng.squeeze::<E::Fr>();
+
+    // t depends only on the index and the challenges, so it is not hidden
+    let t_blinding = DensePolynomial::zero();
+    let (beta_opening, beta_random_value) = KZG10::<E>::open_hiding(
+        &pk.ck,
+        &[&w_poly, &z_a_poly, &z_b_poly, &s_poly, &t_poly, &g_1_poly, &g_1_shifted, &h_1_poly],
+        &[
+            &w_blinding, &z_a_blinding, &z_b_blinding, &s_blinding, &t_blinding, &g_1_blinding,
+            &g_1_shifted_blinding, &h_1_blinding,
+        ],
+        beta,
+        xi_beta,
+    )
+    .map_err(|_| SynthesisError::PolynomialDegreeTooLarge)?;
+    let mut gamma_polys = vec![&g_2_poly, &g_2_shifted, &h_2_poly];
+    for arith in pk.arithmetizations.iter() {
+        gamma_polys.extend_from_slice(&[&arith.row, &arith.col, &arith.val]);
+    }
+    let gamma_opening = KZG10::<E>::open(&pk.ck, &gamma_polys, gamma, xi_gamma)
+        .map_err(|_| SynthesisError::PolynomialDegreeTooLarge)?;
+    end_timer!(opening_time);
+
+    end_timer!(prover_time);
+    Ok(Proof {
+        w_comm,
+        z_a_comm,
+        z_b_comm,
+        s_comm,
+        t_comm,
+        g_1_comm,
+        g_1_shifted_comm,
+        h_1_comm,
+        g_2_comm,
+        g_2_shifted_comm,
+        h_2_comm,
+        beta_evals,
+        gamma_evals,
+        beta_opening,
+        beta_random_value,
+        gamma_opening,
+    })
+}
+
+/// Returns the evaluations over `H` of `M * z`.
+fn matrix_vector_product<F: Field>(matrix: &Matrix<F>, z: &[F], size: usize) -> Vec<F> {
+    let mut result = vec![F::zero(); size];
+    for (r, entries) in matrix.iter().enumerate() {
+        for &(coeff, col) in entries {
+            result[r] += &(coeff * &z[col]);
+        }
+    }
+    result
+}
+
+/// Returns `p(X) + rho(X) v_H(X)` for a random `rho` of degree `ZK_BOUND - 1`,
+/// which agrees with `p` over `H`.
+fn mask<F: PrimeField, R: Rng>(
+    p: &DensePolynomial<F>,
+    domain_h: EvaluationDomain<F>,
+    rng: &mut R,
+) -> DensePolynomial<F> {
+    let rho = DensePolynomial::rand(ZK_BOUND - 1, rng);
+    // rho(X) v_H(X) = X^|H| rho(X) - rho(X)
+    &(p + &shift(&rho, domain_h.size())) - &rho
+}
+
+/// Returns `(p(X) - p(0)) / X`.
+fn divide_by_x<F: Field>(p: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if p.coeffs.len() < 2 {
+        DensePolynomial::zero()
+    } else {
+        DensePolynomial::from_coefficients_slice(&p.coeffs[1..])
+    }
+}
+
+/// Returns `X^power * p(X)`.
+fn shift<F: Field>(p: &DensePolynomial<F>, power: usize) -> DensePolynomial<F> {
+    if p.is_zero() {
+        return DensePolynomial::zero();
+    }
+    let mut coeffs = vec![F::zero(); power];
+    coeffs.extend_from_slice(&p.coeffs);
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
@@ -0,0 +1,252 @@
+use algebra::{
+    curves::{bls12_377::Bls12_377, mnt4753::MNT4},
+    to_bytes, Field, FromBytes, FromBytesChecked, PairingEngine, ToBytes, UniformRand,
+};
+use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use rand::thread_rng;
+
+use crate::marlin::{
+    create_random_proof, generate_universal_srs, index, verify_proof, Proof, UniversalSRS, VerifyingKey,
+};
+
+#[derive(Clone, Copy)]
+struct MySillyCircuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<ConstraintF> {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(
+        self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || {
+                let mut a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+
+                a.mul_assign(&b);
+                Ok(a)
+            },
+        )?;
+
+        cs.enforce(|| "a*b=c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        cs.enforce(|| "a*b=c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        cs.enforce(|| "a*b=c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+
+        Ok(())
+    }
+}
+
+/// Proves knowledge of `x` such that `x^3 + x + 5 = y` for the public input
+/// `y`, also exposing `x + y` as a public input.
+#[derive(Clone, Copy)]
+struct CubicCircuit<F: Field> {
+    x: Option<F>,
+}
+
+impl<F: Field> CubicCircuit<F> {
+    fn outputs(x: F) -> (F, F) {
+        let y = x.square() * &x + &x + &F::from(5u64);
+        (y, x + &y)
+    }
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for CubicCircuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let x_val = || self.x.ok_or(SynthesisError::AssignmentMissing);
+        let x = cs.alloc(|| "x", x_val)?;
+        let x_sq = cs.alloc(|| "x^2", || x_val().map(|x| x.square()))?;
+        let x_cu = cs.alloc(|| "x^3", || x_val().map(|x| x.square() * &x))?;
+        let y = cs.alloc_input(|| "y", || x_val().map(|x| Self::outputs(x).0))?;
+        let x_plus_y = cs.alloc_input(|| "x + y", || x_val().map(|x| Self::outputs(x).1))?;
+
+        let five = F::from(5u64);
+        cs.enforce(|| "x^2", |lc| lc + x, |lc| lc + x, |lc| lc + x_sq);
+        cs.enforce(|| "x^3", |lc| lc + x_sq, |lc| lc + x, |lc| lc + x_cu);
+        cs.enforce(
+            || "y",
+            |lc| lc + x_cu + x + (five, CS::one()),
+            |lc| lc + CS::one(),
+            |lc| lc + y,
+        );
+        cs.enforce(|| "x + y", |lc| lc + x + y, |lc| lc + CS::one(), |lc| lc + x_plus_y);
+
+        Ok(())
+    }
+}
+
+fn prove_and_verify<E: PairingEngine>() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<E, _>(16, 16, 16, rng).unwrap();
+
+    // The same SRS serves different circuits
+    let silly_pk = index(&srs, MySillyCircuit::<E::Fr> { a: None, b: None }).unwrap();
+    let cubic_pk = index(&srs, CubicCircuit::<E::Fr> { x: None }).unwrap();
+
+    for _ in 0..5 {
+        let a = E::Fr::rand(rng);
+        let b = E::Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(MySillyCircuit { a: Some(a), b: Some(b) }, &silly_pk, rng).unwrap();
+        assert!(verify_proof(&silly_pk.vk, &[c], &proof).unwrap());
+        assert!(!verify_proof(&silly_pk.vk, &[a], &proof).unwrap());
+        assert!(!verify_proof(&cubic_pk.vk, &[c, c], &proof).unwrap());
+
+        let x = E::Fr::rand(rng);
+        let (y, x_plus_y) = CubicCircuit::outputs(x);
+        let proof = create_random_proof(CubicCircuit { x: Some(x) }, &cubic_pk, rng).unwrap();
+        assert!(verify_proof(&cubic_pk.vk, &[y, x_plus_y], &proof).unwrap());
+        assert!(!verify_proof(&cubic_pk.vk, &[x_plus_y, y], &proof).unwrap());
+    }
+
+    // Wrong number of public inputs
+    let circuit = MySillyCircuit { a: Some(E::Fr::one()), b: Some(E::Fr::one()) };
+    let proof = create_random_proof(circuit, &silly_pk, rng).unwrap();
+    assert!(verify_proof(&silly_pk.vk, &[], &proof).is_err());
+}
+
+fn zero_knowledge<E: PairingEngine>() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<E, _>(8, 8, 8, rng).unwrap();
+    let pk = index(&srs, CubicCircuit::<E::Fr> { x: None }).unwrap();
+
+    // Proofs of the same witness are randomized by the masks and blinding
+    // polynomials, down to the commitments to the witness polynomials
+    let x = E::Fr::rand(rng);
+    let (y, x_plus_y) = CubicCircuit::outputs(x);
+    let proof = create_random_proof(CubicCircuit { x: Some(x) }, &pk, rng).unwrap();
+    let other_proof = create_random_proof(CubicCircuit { x: Some(x) }, &pk, rng).unwrap();
+    assert!(verify_proof(&pk.vk, &[y, x_plus_y], &proof).unwrap());
+    assert!(verify_proof(&pk.vk, &[y, x_plus_y], &other_proof).unwrap());
+
+    assert_ne!(proof, other_proof);
+    assert_ne!(proof.w_comm, other_proof.w_comm);
+    assert_ne!(proof.z_a_comm, other_proof.z_a_comm);
+    assert_ne!(proof.z_b_comm, other_proof.z_b_comm);
+    assert_ne!(proof.s_comm, other_proof.s_comm);
+    assert_ne!(proof.beta_evals, other_proof.beta_evals);
+}
+
+fn serialization<E: PairingEngine>() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<E, _>(8, 8, 8, rng).unwrap();
+    let srs_serialized = to_bytes!(srs).unwrap();
+    assert_eq!(srs, UniversalSRS::<E>::read_checked(srs_serialized.as_slice()).unwrap());
+
+    let pk = index(&srs, CubicCircuit::<E::Fr> { x: None }).unwrap();
+    let vk_serialized = to_bytes!(pk.vk).unwrap();
+    let vk = VerifyingKey::<E>::read_checked(vk_serialized.as_slice()).unwrap();
+    assert_eq!(pk.vk, vk);
+
+    let x = E::Fr::rand(rng);
+    let (y, x_plus_y) = CubicCircuit::outputs(x);
+    let proof = create_random_proof(CubicCircuit { x: Some(x) }, &pk, rng).unwrap();
+    let proof_serialized = to_bytes!(proof).unwrap();
+    let proof_deserialized = Proof::<E>::read(proof_serialized.as_slice()).unwrap();
+    assert_eq!(proof, proof_deserialized);
+    let proof_deserialized = Proof::<E>::read_checked(proof_serialized.as_slice()).unwrap();
+    assert!(verify_proof(&vk, &[y, x_plus_y], &proof_deserialized).unwrap());
+}
+
+fn tampered_proofs<E: PairingEngine>() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<E, _>(8, 8, 8, rng).unwrap();
+    let pk = index(&srs, CubicCircuit::<E::Fr> { x: None }).unwrap();
+
+    let x = E::Fr::rand(rng);
+    let (y, x_plus_y) = CubicCircuit::outputs(x);
+    let proof = create_random_proof(CubicCircuit { x: Some(x) }, &pk, rng).unwrap();
+    assert!(verify_proof(&pk.vk, &[y, x_plus_y], &proof).unwrap());
+
+    for i in 0..proof.beta_evals.len() {
+        let mut tampered = proof.clone();
+        tampered.beta_evals[i] += &E::Fr::one();
+        assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+    }
+    for i in 0..proof.gamma_evals.len() {
+        let mut tampered = proof.clone();
+        tampered.gamma_evals[i] += &E::Fr::one();
+        assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+    }
+
+    let mut tampered = proof.clone();
+    tampered.w_comm = proof.z_a_comm;
+    assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.g_1_shifted_comm = proof.g_1_comm;
+    assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.beta_random_value += &E::Fr::one();
+    assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.gamma_opening = proof.beta_opening;
+    assert!(!verify_proof(&pk.vk, &[y, x_plus_y], &tampered).unwrap());
+}
+
+#[test]
+fn bls12_377_prove_and_verify() {
+    prove_and_verify::<Bls12_377>();
+}
+
+#[test]
+fn mnt4753_prove_and_verify() {
+    prove_and_verify::<MNT4>();
+}
+
+#[test]
+fn bls12_377_zero_knowledge() {
+    zero_knowledge::<Bls12_377>();
+}
+
+#[test]
+fn bls12_377_serialization() {
+    serialization::<Bls12_377>();
+}
+
+#[test]
+fn bls12_377_tampered_proofs() {
+    tampered_proofs::<Bls12_377>();
+}
+
+#[test]
+fn unsatisfied_witness() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<Bls12_377, _>(8, 8, 8, rng).unwrap();
+    let pk = index(&srs, MySillyCircuit { a: None, b: None }).unwrap();
+
+    // c is computed from a and b, so break the circuit by lying about b
+    struct Lying<F: Field>(MySillyCircuit<F>);
+    impl<F: Field> ConstraintSynthesizer<F> for Lying<F> {
+        fn generate_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let a = cs.alloc(|| "a", || self.0.a.ok_or(SynthesisError::AssignmentMissing))?;
+            let b = cs.alloc(|| "b", || self.0.b.ok_or(SynthesisError::AssignmentMissing))?;
+            let c = cs.alloc_input(|| "c", || Ok(F::one()))?;
+            for _ in 0..3 {
+                cs.enforce(|| "a*b=c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            }
+            Ok(())
+        }
+    }
+
+    let a = algebra::fields::bls12_377::Fr::rand(rng);
+    let circuit = Lying(MySillyCircuit { a: Some(a), b: Some(a) });
+    match create_random_proof(circuit, &pk, rng) {
+        Err(SynthesisError::Unsatisfiable) => (),
+        _ => panic!("an unsatisfied witness should not be provable"),
+    }
+}
+
+#[test]
+fn circuit_too_large_for_srs() {
+    let rng = &mut thread_rng();
+    let srs = generate_universal_srs::<Bls12_377, _>(2, 2, 2, rng).unwrap();
+    assert!(index(&srs, CubicCircuit { x: None }).is_err());
+}
@@ -0,0 +1,157 @@
+use algebra::{fft::EvaluationDomain, Field, PairingEngine};
+use r1cs_core::SynthesisError;
+
+use primitives::poly_commit::kzg10::{OpeningClaim, KZG10};
+
+use crate::marlin::{
+    fiat_shamir::FiatShamirRng, Proof, VerifyingKey, NUM_BETA_EVALS, NUM_GAMMA_EVALS, NUM_INDEX_POLYS, PROTOCOL_NAME,
+};
+
+/// Verifies a proof against the verification key of the circuit and its
+/// public inputs (not including the constant `1`).
+pub fn verify_proof<E: PairingEngine>(
+    vk: &VerifyingKey<E>,
+    public_inputs: &[E::Fr],
+    proof: &Proof<E>,
+) -> Result<bool, SynthesisError> {
+    let verifier_time = start_timer!(|| "Marlin::Verifier");
+
+    if public_inputs.len() + 1 != vk.num_inputs
+        || vk.index_comms.len() != NUM_INDEX_POLYS
+        || vk.domain_h_size < 2
+        || vk.domain_k_size < 2
+        || vk.max_degree < vk.domain_h_size.max(vk.domain_k_size)
+    {
+        return Err(SynthesisError::MalformedVerifyingKey);
+    }
+    if proof.beta_evals.len() != NUM_BETA_EVALS || proof.gamma_evals.len() != NUM_GAMMA_EVALS {
+        return Ok(false);
+    }
+
+    let domain_x = EvaluationDomain::<E::Fr>::new(vk.num_inputs)
+        .ok_or(SynthesisError::MalformedVerifyingKey)?;
+    let domain_h = EvaluationDomain::<E::Fr>::new(vk.domain_h_size)
+        .filter(|d| d.size() == vk.domain_h_size && d.size() >= domain_x.size())
+        .ok_or(SynthesisError::MalformedVerifyingKey)?;
+    let domain_k = EvaluationDomain::<E::Fr>::new(vk.domain_k_size)
+        .filter(|d| d.size() == vk.domain_k_size)
+        .ok_or(SynthesisError::MalformedVerifyingKey)?;
+
+    let mut x = vec![E::Fr::one()];
+    x.extend_from_slice(public_inputs);
+    x.resize(domain_x.size(), E::Fr::zero());
+
+    // Replay the transcript to recover the challenges
+    let mut fs_rng = FiatShamirRng::new(PROTOCOL_NAME);
+    fs_rng.absorb(vk);
+    fs_rng.absorb(&x);
+
+    fs_rng.absorb(&vec![proof.w_comm, proof.z_a_comm, proof.z_b_comm, proof.s_comm]);
+    let alpha = fs_rng.squeeze_outside_domain(&domain_h);
+    let eta: [E::Fr; 3] = [fs_rng.squeeze(), fs_rng.squeeze(), fs_rng.squeeze()];
+
+    fs_rng.absorb(&vec![proof.t_comm, proof.g_1_comm, proof.g_1_shifted_comm, proof.h_1_comm]);
+    let beta = fs_rng.squeeze_outside_domain(&domain_h);
+
+    fs_rng.absorb(&vec![proof.g_2_comm, proof.g_2_shifted_comm, proof.h_2_comm]);
+    let gamma = fs_rng.squeeze::<E::Fr>();
+
+    fs_rng.absorb(&proof.beta_evals);
+    fs_rng.absorb(&proof.gamma_evals);
+    let xi_beta = fs_rng.squeeze::<E::Fr>();
+    let xi_gamma = fs_rng.squeeze::<E::Fr>();
+
+    // The randomness combining the openings is derived from the transcript as well
+    fs_rng.absorb(&vec![proof.beta_opening, proof.gamma_opening]);
+    fs_rng.absorb(&proof.beta_random_value);
+
+    let (w, z_a, z_b, s, t, g_1, h_1) = (
+        proof.beta_evals[0], proof.beta_evals[1], proof.beta_evals[2], proof.beta_evals[3],
+        proof.beta_evals[4], proof.beta_evals[5], proof.beta_evals[6],
+    );
+    let (g_2, h_2) = (proof.gamma_evals[0], proof.gamma_evals[1]);
+
+    // Outer sumcheck:
+    //   s + r(alpha, beta) (eta_A z_A + eta_B z_B + eta_C z_A z_B) - t z = h_1 v_H(beta) + beta g_1
+    let v_h_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
+    let v_h_beta = domain_h.evaluate_vanishing_polynomial(beta);
+    let r_alpha_beta = match (alpha - &beta).inverse() {
+        Some(inv) => (v_h_alpha - &v_h_beta) * &inv,
+        None => return Ok(false),
+    };
+
+    let x_at_beta = domain_x
+        .evaluate_all_lagrange_coefficients(beta)
+        .into_iter()
+        .zip(&x)
+        .map(|(l, x)| l * x)
+        .fold(E::Fr::zero(), |acc, v| acc + &v);
+    let z = w * &domain_x.evaluate_vanishing_polynomial(beta) + &x_at_beta;
+
+    let lhs = s + &(r_alpha_beta * &(eta[0] * &z_a + &(eta[1] * &z_b) + &(eta[2] * &z_a * &z_b))) - &(t * &z);
+    let rhs = h_1 * &v_h_beta + &(beta * &g_1);
+    if lhs != rhs {
+        end_timer!(verifier_time);
+        return Ok(false);
+    }
+
+    // Inner sumcheck:
+    //   a(gamma) - b(gamma) (gamma g_2 + t / |K|) = h_2 v_K(gamma)
+    let factor = v_h_alpha * &v_h_beta;
+    let mut a = E::Fr::zero();
+    let mut b = E::Fr::one();
+    for (evals, eta) in proof.gamma_evals[2..].chunks(3).zip(eta.iter()) {
+        let (row, col, val) = (evals[0], evals[1], evals[2]);
+        let den = (alpha - &row) * &(beta - &col);
+        a = a * &den + &(*eta * &factor * &val * &b);
+        b *= &den;
+    }
+    let lhs = a - &(b * &(gamma * &g_2 + &(t * &domain_k.size_inv)));
+    let rhs = h_2 * &domain_k.evaluate_vanishing_polynomial(gamma);
+    if lhs != rhs {
+        end_timer!(verifier_time);
+        return Ok(false);
+    }
+
+    // Polynomial commitment openings, including the degree bounds on g_1 and g_2
+    let g_1_shift = beta.pow([(vk.max_degree - (vk.domain_h_size - 2)) as u64]);
+    let g_2_shift = gamma.pow([(vk.max_degree - (vk.domain_k_size - 2)) as u64]);
+
+    let beta_comms = [
+        proof.w_comm, proof.z_a_comm, proof.z_b_comm, proof.s_comm, proof.t_comm, proof.g_1_comm,
+        proof.g_1_shifted_comm, proof.h_1_comm,
+    ];
+    let beta_values = [w, z_a, z_b, s, t, g_1, g_1 * &g_1_shift, h_1];
+
+    let mut gamma_comms = vec![proof.g_2_comm, proof.g_2_shifted_comm, proof.h_2_comm];
+    gamma_comms.extend_from_slice(&vk.index_comms);
+    let mut gamma_values = vec![g_2, g_2 * &g_2_shift, h_2];
+    gamma_values.extend_from_slice(&proof.gamma_evals[2..]);
+
+    let result = KZG10::<E>::batch_check(
+        &vk.pc_vk,
+        &[
+            OpeningClaim {
+                commitments:       &beta_comms,
+                point:             beta,
+                values:            &beta_values,
+                proof:             proof.beta_opening,
+                random_value:      Some(proof.beta_random_value),
+                opening_challenge: xi_beta,
+            },
+            OpeningClaim {
+                commitments:       &gamma_comms,
+                point:             gamma,
+                values:            &gamma_values,
+                proof:             proof.gamma_opening,
+                random_value:      None,
+                opening_challenge: xi_gamma,
+            },
+        ],
+        &mut fs_rng,
+    )
+    .map_err(|_| SynthesisError::MalformedVerifyingKey)?;
+
+    end_timer!(verifier_time);
+    Ok(result)
+}
@@ -13,9 +13,15 @@ mod constraint_system;
 mod error;
 mod impl_lc;
 mod impl_constraint_var;
+mod optimizing_cs;
+mod recording_cs;
+mod witness_cs;
 
 pub use constraint_system::{ConstraintSystem, ConstraintSynthesizer, Namespace};
 pub use error::SynthesisError;
+pub use optimizing_cs::OptimizingConstraintSystem;
+pub use recording_cs::RecordingConstraintSystem;
+pub use witness_cs::WitnessConstraintSystem;
 pub use algebra::ToConstraintField;
 
 use algebra::Field;
@@ -0,0 +1,366 @@
+use algebra::{Field, PrimeField};
+use std::collections::BTreeMap;
+
+use crate::{
+    ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination,
+    RecordingConstraintSystem, SynthesisError, Variable,
+};
+
+type SparseLC<F> = BTreeMap<Variable, F>;
+
+/// A constraint system recording a circuit and removing, when synthesized,
+/// the private variables fixed by a linear constraint.
+///
+/// A constraint `lc * 1 = coeff * var`, with `var` a private variable not in
+/// `lc`, is removed, and `var` is replaced by `lc / coeff` in the other
+/// constraints. Which constraints are removed depends only on the constraints,
+/// not on the assignment, so that the parameters generated from the optimized
+/// circuit fit the proofs of the optimized circuit.
+pub struct OptimizingConstraintSystem<F: PrimeField> {
+    cs: RecordingConstraintSystem<F>,
+}
+
+impl<F: PrimeField> Default for OptimizingConstraintSystem<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> OptimizingConstraintSystem<F> {
+    /// An empty constraint system.
+    pub fn new() -> Self {
+        Self { cs: RecordingConstraintSystem::new() }
+    }
+
+    /// Records the constraints of `circuit`, and its assignment if known.
+    pub fn from_synthesizer<C: ConstraintSynthesizer<F>>(circuit: C) -> Result<Self, SynthesisError> {
+        let mut cs = Self::new();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs)
+    }
+
+    /// Returns the recorded constraint system, without the inlined variables
+    /// and the constraints fixing them. The remaining private variables keep
+    /// their order.
+    pub fn optimize(self) -> Result<RecordingConstraintSystem<F>, SynthesisError> {
+        let cs = self.cs;
+        let num_constraints = cs.a.len();
+        let mut a = cs.a.iter().map(to_sparse).collect::<Vec<_>>();
+        let mut b = cs.b.iter().map(to_sparse).collect::<Vec<_>>();
+        let mut c = cs.c.iter().map(to_sparse).collect::<Vec<_>>();
+
+        // Find the definitions `var = lc`. The definitions are always written
+        // in terms of the variables not defined, so a definition is rewritten
+        // each time one of its variables gets defined, the definitions using
+        // a variable being indexed in `users`.
+        let mut definitions: Vec<(Variable, SparseLC<F>)> = Vec::new();
+        let mut defined = BTreeMap::new();
+        let mut users: BTreeMap<Variable, Vec<usize>> = BTreeMap::new();
+        let mut removed = vec![false; num_constraints];
+        for i in 0..num_constraints {
+            let lc = if is_one(&b[i]) {
+                &a[i]
+            } else if is_one(&a[i]) {
+                &b[i]
+            } else {
+                continue;
+            };
+            let (var, coeff) = match single_aux_variable(&c[i]) {
+                Some(var_coeff) => var_coeff,
+                None => continue,
+            };
+            if defined.contains_key(&var) {
+                continue;
+            }
+            let coeff_inv = coeff.inverse().ok_or(SynthesisError::DivisionByZero)?;
+            let mut definition = SparseLC::new();
+            add_substituted(&mut definition, lc, coeff_inv, &definitions, &defined);
+            if definition.contains_key(&var) {
+                continue;
+            }
+
+            for j in users.remove(&var).unwrap_or_default() {
+                if let Some(var_coeff) = definitions[j].1.remove(&var) {
+                    for (&def_var, &def_coeff) in definition.iter() {
+                        add_term(&mut definitions[j].1, def_var, def_coeff * &var_coeff);
+                        users.entry(def_var).or_default().push(j);
+                    }
+                }
+            }
+            for &def_var in definition.keys() {
+                users.entry(def_var).or_default().push(definitions.len());
+            }
+            defined.insert(var, definitions.len());
+            definitions.push((var, definition));
+            removed[i] = true;
+        }
+
+        // Renumber the remaining private variables
+        let mut aux_map = Vec::with_capacity(cs.num_aux);
+        let mut num_aux = 0;
+        for i in 0..cs.num_aux {
+            if defined.contains_key(&Variable::new_unchecked(Index::Aux(i))) {
+                aux_map.push(None);
+            } else {
+                aux_map.push(Some(num_aux));
+                num_aux += 1;
+            }
+        }
+        let to_lc = |lc: &SparseLC<F>| {
+            let mut substituted = SparseLC::new();
+            add_substituted(&mut substituted, lc, F::one(), &definitions, &defined);
+            let mut new_lc = LinearCombination::zero();
+            for (var, coeff) in substituted {
+                let var = match var.get_unchecked() {
+                    Index::Input(_) => var,
+                    // The definitions never use an inlined variable
+                    Index::Aux(i) => Variable::new_unchecked(Index::Aux(
+                        aux_map[i].ok_or(SynthesisError::Unsatisfiable)?
+                    )),
+                };
+                new_lc.0.push((var, coeff));
+            }
+            Ok::<_, SynthesisError>(new_lc)
+        };
+
+        let mut optimized = RecordingConstraintSystem {
+            num_inputs: cs.num_inputs,
+            num_aux,
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+            input_assignment: cs.input_assignment,
+            aux_assignment: cs.aux_assignment.map(|assignment| {
+                assignment
+                    .into_iter()
+                    .zip(aux_map.iter())
+                    .filter_map(|(value, new_index)| new_index.map(|_| value))
+                    .collect()
+            }),
+        };
+        for i in (0..num_constraints).filter(|&i| !removed[i]) {
+            optimized.a.push(to_lc(&std::mem::take(&mut a[i]))?);
+            optimized.b.push(to_lc(&std::mem::take(&mut b[i]))?);
+            optimized.c.push(to_lc(&std::mem::take(&mut c[i]))?);
+        }
+        Ok(optimized)
+    }
+}
+
+impl<F: PrimeField> ConstraintSystem<F> for OptimizingConstraintSystem<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc(annotation, f)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc_input(annotation, f)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+}
+
+/// Synthesizes the optimized constraints.
+impl<F: PrimeField> ConstraintSynthesizer<F> for OptimizingConstraintSystem<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        self.optimize()?.generate_constraints(cs)
+    }
+}
+
+// Merges the repeated variables of `lc`, dropping the zero coefficients.
+fn to_sparse<F: Field>(lc: &LinearCombination<F>) -> SparseLC<F> {
+    let mut sparse = SparseLC::new();
+    for &(var, coeff) in lc.0.iter() {
+        add_term(&mut sparse, var, coeff);
+    }
+    sparse
+}
+
+fn add_term<F: Field>(lc: &mut SparseLC<F>, var: Variable, coeff: F) {
+    let sum = *lc.entry(var).or_insert_with(F::zero) + &coeff;
+    if sum.is_zero() {
+        lc.remove(&var);
+    } else {
+        lc.insert(var, sum);
+    }
+}
+
+// Adds `scalar * lc` to `result`, replacing the defined variables by their
+// definition, which only uses variables not defined.
+fn add_substituted<F: Field>(
+    result: &mut SparseLC<F>,
+    lc: &SparseLC<F>,
+    scalar: F,
+    definitions: &[(Variable, SparseLC<F>)],
+    defined: &BTreeMap<Variable, usize>,
+) {
+    for (&var, &coeff) in lc.iter() {
+        let coeff = coeff * &scalar;
+        match defined.get(&var) {
+            Some(&j) => for (&def_var, &def_coeff) in definitions[j].1.iter() {
+                add_term(result, def_var, def_coeff * &coeff);
+            },
+            None => add_term(result, var, coeff),
+        }
+    }
+}
+
+fn is_one<F: Field>(lc: &SparseLC<F>) -> bool {
+    lc.len() == 1 && lc.get(&Variable::new_unchecked(Index::Input(0))) == Some(&F::one())
+}
+
+fn single_aux_variable<F: Field>(lc: &SparseLC<F>) -> Option<(Variable, F)> {
+    match lc.iter().next() {
+        Some((&var, &coeff)) if lc.len() == 1 => match var.get_unchecked() {
+            Index::Aux(_) => Some((var, coeff)),
+            Index::Input(_) => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::fields::mnt4753::Fr;
+
+    // Computes out = (x + y)^2 + 3 * (x + y), with a variable for x + y and
+    // one for 3 * (x + y) fixed by linear constraints
+    struct LinearCircuit {
+        x: Option<Fr>,
+        y: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for LinearCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let three = Fr::from(3u64);
+            let sum_val = self.x.and_then(|x| self.y.map(|y| x + &y));
+            let out_val = sum_val.map(|s| s.square() + &(three * &s));
+
+            let x = cs.alloc(|| "x", || self.x.ok_or(SynthesisError::AssignmentMissing))?;
+            let y = cs.alloc(|| "y", || self.y.ok_or(SynthesisError::AssignmentMissing))?;
+            let out = cs.alloc_input(|| "out", || out_val.ok_or(SynthesisError::AssignmentMissing))?;
+            let sum = cs.alloc(|| "sum", || sum_val.ok_or(SynthesisError::AssignmentMissing))?;
+            let triple = cs.alloc(|| "triple", || sum_val.map(|s| three * &s).ok_or(SynthesisError::AssignmentMissing))?;
+            let square = cs.alloc(|| "square", || sum_val.map(|s| s.square()).ok_or(SynthesisError::AssignmentMissing))?;
+
+            cs.enforce(|| "sum", |lc| lc + x + y, |lc| lc + CS::one(), |lc| lc + sum);
+            cs.enforce(|| "triple", |lc| lc + CS::one(), |lc| lc + (three, sum), |lc| lc + triple);
+            cs.enforce(|| "square", |lc| lc + sum, |lc| lc + sum, |lc| lc + square);
+            cs.enforce(|| "out", |lc| lc + square + triple, |lc| lc + CS::one(), |lc| lc + out);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn inline_linear_variables() {
+        let circuit = LinearCircuit { x: Some(Fr::from(2u64)), y: Some(Fr::from(5u64)) };
+        let cs = RecordingConstraintSystem::from_synthesizer(LinearCircuit { x: circuit.x, y: circuit.y }).unwrap();
+        assert_eq!((cs.num_aux, cs.num_constraints()), (5, 4));
+        assert_eq!(cs.is_satisfied(), Some(true));
+
+        // sum and triple are inlined, the square and the public output stay
+        let optimized = OptimizingConstraintSystem::from_synthesizer(circuit).unwrap().optimize().unwrap();
+        assert_eq!((optimized.num_inputs, optimized.num_aux, optimized.num_constraints()), (2, 3, 2));
+        assert_eq!(optimized.aux_assignment, Some(vec![Fr::from(2u64), Fr::from(5u64), Fr::from(49u64)]));
+        assert_eq!(optimized.is_satisfied(), Some(true));
+
+        let mut bad_optimized = optimized.clone();
+        bad_optimized.input_assignment.as_mut().unwrap()[1] += &Fr::one();
+        assert_eq!(bad_optimized.is_satisfied(), Some(false));
+
+        // The constraints don't depend on the assignment
+        let blank = OptimizingConstraintSystem::from_synthesizer(LinearCircuit { x: None, y: None }).unwrap().optimize().unwrap();
+        let (mut optimized_r1cs, mut blank_r1cs) = (Vec::new(), Vec::new());
+        optimized.write_r1cs(&mut optimized_r1cs).unwrap();
+        blank.write_r1cs(&mut blank_r1cs).unwrap();
+        assert_eq!(optimized_r1cs, blank_r1cs);
+
+        // And synthesizing the optimizing constraint system gives them
+        let synthesized = RecordingConstraintSystem::from_synthesizer(
+            OptimizingConstraintSystem::from_synthesizer(LinearCircuit { x: None, y: None }).unwrap()
+        ).unwrap();
+        let mut synthesized_r1cs = Vec::new();
+        synthesized.write_r1cs(&mut synthesized_r1cs).unwrap();
+        assert_eq!(synthesized_r1cs, blank_r1cs);
+    }
+
+    #[test]
+    fn cyclic_definitions() {
+        // a * 1 = b and b * 1 = a: only one of them can be inlined
+        let mut cs = OptimizingConstraintSystem::<Fr>::new();
+        let a = cs.alloc(|| "a", || Ok(Fr::from(7u64))).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::from(7u64))).unwrap();
+        cs.enforce(|| "a = b", |lc| lc + a, |lc| lc + OptimizingConstraintSystem::<Fr>::one(), |lc| lc + b);
+        cs.enforce(|| "b = a", |lc| lc + b, |lc| lc + OptimizingConstraintSystem::<Fr>::one(), |lc| lc + a);
+        cs.enforce(|| "a * a = a * b", |lc| lc + a, |lc| lc + a, |lc| lc + (Fr::from(7u64), b));
+
+        let optimized = cs.optimize().unwrap();
+        assert_eq!((optimized.num_aux, optimized.num_constraints()), (1, 2));
+        assert_eq!(optimized.is_satisfied(), Some(true));
+    }
+
+    #[test]
+    fn definition_chain() {
+        // b = a, c = b and x = c are inlined before a = d uses a
+        let mut cs = OptimizingConstraintSystem::<Fr>::new();
+        let one = OptimizingConstraintSystem::<Fr>::one();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(4u64))).unwrap();
+        let a = cs.alloc(|| "a", || Ok(Fr::from(4u64))).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::from(4u64))).unwrap();
+        let c = cs.alloc(|| "c", || Ok(Fr::from(4u64))).unwrap();
+        let d = cs.alloc(|| "d", || Ok(Fr::from(4u64))).unwrap();
+        cs.enforce(|| "b = a", |lc| lc + b, |lc| lc + one, |lc| lc + a);
+        cs.enforce(|| "c = b", |lc| lc + c, |lc| lc + one, |lc| lc + b);
+        cs.enforce(|| "x = c", |lc| lc + x, |lc| lc + one, |lc| lc + c);
+        cs.enforce(|| "a = d", |lc| lc + a, |lc| lc + one, |lc| lc + d);
+        cs.enforce(|| "d * d = 4x", |lc| lc + d, |lc| lc + d, |lc| lc + (Fr::from(4u64), x));
+
+        let optimized = cs.optimize().unwrap();
+        assert_eq!((optimized.num_aux, optimized.num_constraints()), (1, 1));
+        assert_eq!(optimized.is_satisfied(), Some(true));
+    }
+}
@@ -0,0 +1,559 @@
+//! A constraint system recording the constraints and the assignment of a
+//! circuit, to export them to the `.r1cs` and `.wtns` binary formats of iden3
+//! and to load them back.
+//!
+//! Both formats are little endian. A file starts with a 4 bytes magic string
+//! (`r1cs` or `wtns`), a `u32` version and a `u32` number of sections, each
+//! section being a `u32` type, a `u64` size in bytes and its content. Field
+//! elements take `n8` bytes, the size of the representation of the field, and
+//! are stored in standard (not Montgomery) form.
+//!
+//! The `.r1cs` file (version 1) has a header section (type 1):
+//! `n8: u32`, `prime: n8 bytes`, `nWires: u32`, `nPubOut: u32`, `nPubIn: u32`,
+//! `nPrvIn: u32`, `nLabels: u64`, `mConstraints: u32`; a constraints section
+//! (type 2) listing the `A`, `B` and `C` linear combinations of each
+//! constraint, each one as `nFactors: u32` followed by `(wireId: u32,
+//! coeff: n8 bytes)` pairs sorted by wire; and a wire to label section
+//! (type 3) giving a `u64` label per wire.
+//!
+//! The `.wtns` file (version 2) has a header section (type 1): `n8: u32`,
+//! `prime: n8 bytes`, `nWitness: u32`, and a section (type 2) with the value
+//! of each wire.
+//!
+//! Wire 0 is the constant one, followed by the public inputs and then by the
+//! auxiliary variables, in order of allocation. Public inputs are exported as
+//! `nPubIn`, and when loading a file the public outputs and inputs both become
+//! public inputs, and the private inputs auxiliary variables.
+
+use algebra::{FpParameters, FromBytes, PrimeField, ToBytes};
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+};
+
+use crate::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const R1CS_HEADER_SECTION: u32 = 1;
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+const WTNS_HEADER_SECTION: u32 = 1;
+const WTNS_VALUES_SECTION: u32 = 2;
+
+/// A constraint system recording the rows of the `A`, `B` and `C` matrices of
+/// a circuit, and the assignment of its variables when it is known.
+///
+/// Namespaces are ignored. Once recorded, the constraint system can be
+/// written to, and read from, the `.r1cs` and `.wtns` formats, and it is a
+/// `ConstraintSynthesizer` synthesizing the recorded constraints again.
+#[derive(Clone, Debug)]
+pub struct RecordingConstraintSystem<F: PrimeField> {
+    /// The number of public inputs, including the constant one.
+    pub num_inputs: usize,
+    /// The number of auxiliary variables.
+    pub num_aux: usize,
+    /// The `A` linear combination of each constraint.
+    pub a: Vec<LinearCombination<F>>,
+    /// The `B` linear combination of each constraint.
+    pub b: Vec<LinearCombination<F>>,
+    /// The `C` linear combination of each constraint.
+    pub c: Vec<LinearCombination<F>>,
+    /// The values of the public inputs, `None` if some of them are missing.
+    pub input_assignment: Option<Vec<F>>,
+    /// The values of the auxiliary variables, `None` if some of them are missing.
+    pub aux_assignment: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> Default for RecordingConstraintSystem<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> RecordingConstraintSystem<F> {
+    /// An empty constraint system, with the constant one as only variable.
+    pub fn new() -> Self {
+        Self {
+            num_inputs: 1,
+            num_aux: 0,
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+            input_assignment: Some(vec![F::one()]),
+            aux_assignment: Some(Vec::new()),
+        }
+    }
+
+    /// Records the constraints of `circuit`, and its assignment if the values
+    /// of all the variables are known.
+    pub fn from_synthesizer<C: ConstraintSynthesizer<F>>(circuit: C) -> Result<Self, SynthesisError> {
+        let mut cs = Self::new();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs)
+    }
+
+    /// Whether the assignment satisfies the constraints, or `None` if the
+    /// assignment is not known.
+    pub fn is_satisfied(&self) -> Option<bool> {
+        let inputs = self.input_assignment.as_ref()?;
+        let aux = self.aux_assignment.as_ref()?;
+        let eval = |lc: &LinearCombination<F>| {
+            lc.0.iter().fold(F::zero(), |acc, (var, coeff)| {
+                let value = match var.get_unchecked() {
+                    Index::Input(i) => inputs[i],
+                    Index::Aux(i) => aux[i],
+                };
+                acc + &(value * coeff)
+            })
+        };
+        Some(
+            self.a.iter().zip(self.b.iter()).zip(self.c.iter())
+                .all(|((a, b), c)| eval(a) * &eval(b) == eval(c))
+        )
+    }
+
+    /// Writes the constraints in the `.r1cs` format.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let n8 = field_size_in_bytes::<F>();
+        let num_wires = self.num_inputs + self.num_aux;
+
+        let mut header = Vec::new();
+        write_u32(&mut header, n8 as u32)?;
+        F::Params::MODULUS.write(&mut header)?;
+        write_u32(&mut header, num_wires as u32)?;
+        write_u32(&mut header, 0)?;
+        write_u32(&mut header, (self.num_inputs - 1) as u32)?;
+        write_u32(&mut header, 0)?;
+        write_u64(&mut header, num_wires as u64)?;
+        write_u32(&mut header, self.a.len() as u32)?;
+
+        let mut constraints = Vec::new();
+        for ((a, b), c) in self.a.iter().zip(self.b.iter()).zip(self.c.iter()) {
+            for lc in [a, b, c].iter() {
+                let factors = self.to_factors(lc);
+                write_u32(&mut constraints, factors.len() as u32)?;
+                for (wire, coeff) in factors {
+                    write_u32(&mut constraints, wire as u32)?;
+                    coeff.into_repr().write(&mut constraints)?;
+                }
+            }
+        }
+
+        let mut labels = Vec::new();
+        for wire in 0..num_wires {
+            write_u64(&mut labels, wire as u64)?;
+        }
+
+        writer.write_all(R1CS_MAGIC)?;
+        write_u32(&mut writer, R1CS_VERSION)?;
+        write_u32(&mut writer, 3)?;
+        write_section(&mut writer, R1CS_HEADER_SECTION, &header)?;
+        write_section(&mut writer, R1CS_CONSTRAINTS_SECTION, &constraints)?;
+        write_section(&mut writer, R1CS_WIRE_TO_LABEL_SECTION, &labels)
+    }
+
+    /// Reads constraints in the `.r1cs` format, without any assignment.
+    pub fn read_r1cs<R: Read>(reader: R) -> io::Result<Self> {
+        let mut sections = read_sections(reader, R1CS_MAGIC, R1CS_VERSION)?;
+        let n8 = field_size_in_bytes::<F>();
+
+        let header = sections.remove(&R1CS_HEADER_SECTION).ok_or_else(|| invalid_data("missing r1cs header"))?;
+        let mut header = header.as_slice();
+        read_field_header::<F, _>(&mut header)?;
+        let num_wires = read_u32(&mut header)? as usize;
+        let num_public_outputs = read_u32(&mut header)? as usize;
+        let num_public_inputs = read_u32(&mut header)? as usize;
+        let _num_private_inputs = read_u32(&mut header)?;
+        let _num_labels = read_u64(&mut header)?;
+        let num_constraints = read_u32(&mut header)? as usize;
+
+        let num_inputs = 1 + num_public_outputs + num_public_inputs;
+        if num_wires < num_inputs {
+            return Err(invalid_data("more public inputs than wires"));
+        }
+
+        let constraints = sections.remove(&R1CS_CONSTRAINTS_SECTION).ok_or_else(|| invalid_data("missing r1cs constraints"))?;
+        let mut constraints = constraints.as_slice();
+        let mut cs = Self {
+            num_inputs,
+            num_aux: num_wires - num_inputs,
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+            input_assignment: None,
+            aux_assignment: None,
+        };
+        for _ in 0..num_constraints {
+            let mut lcs = Vec::with_capacity(3);
+            for _ in 0..3 {
+                let num_factors = read_u32(&mut constraints)? as usize;
+                let mut lc = LinearCombination::zero();
+                for _ in 0..num_factors {
+                    let wire = read_u32(&mut constraints)? as usize;
+                    if wire >= num_wires {
+                        return Err(invalid_data("wire out of range"));
+                    }
+                    let coeff = read_field_element::<F, _>(&mut constraints, n8)?;
+                    lc += (coeff, cs.wire_to_variable(wire));
+                }
+                lcs.push(lc);
+            }
+            cs.c.push(lcs.pop().unwrap());
+            cs.b.push(lcs.pop().unwrap());
+            cs.a.push(lcs.pop().unwrap());
+        }
+        if !constraints.is_empty() {
+            return Err(invalid_data("trailing bytes in r1cs constraints"));
+        }
+        Ok(cs)
+    }
+
+    /// Writes the assignment in the `.wtns` format. Fails if it is not known.
+    pub fn write_witness<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let (inputs, aux) = match (&self.input_assignment, &self.aux_assignment) {
+            (Some(inputs), Some(aux)) => (inputs, aux),
+            _ => return Err(io::Error::new(io::ErrorKind::Other, "the assignment is not known")),
+        };
+
+        let mut header = Vec::new();
+        write_u32(&mut header, field_size_in_bytes::<F>() as u32)?;
+        F::Params::MODULUS.write(&mut header)?;
+        write_u32(&mut header, (inputs.len() + aux.len()) as u32)?;
+
+        let mut values = Vec::new();
+        for value in inputs.iter().chain(aux.iter()) {
+            value.into_repr().write(&mut values)?;
+        }
+
+        writer.write_all(WTNS_MAGIC)?;
+        write_u32(&mut writer, WTNS_VERSION)?;
+        write_u32(&mut writer, 2)?;
+        write_section(&mut writer, WTNS_HEADER_SECTION, &header)?;
+        write_section(&mut writer, WTNS_VALUES_SECTION, &values)
+    }
+
+    /// Reads the assignment of the constraint system in the `.wtns` format.
+    pub fn read_witness<R: Read>(&mut self, reader: R) -> io::Result<()> {
+        let mut sections = read_sections(reader, WTNS_MAGIC, WTNS_VERSION)?;
+        let n8 = field_size_in_bytes::<F>();
+
+        let header = sections.remove(&WTNS_HEADER_SECTION).ok_or_else(|| invalid_data("missing wtns header"))?;
+        let mut header = header.as_slice();
+        read_field_header::<F, _>(&mut header)?;
+        let num_wires = read_u32(&mut header)? as usize;
+        if num_wires != self.num_inputs + self.num_aux {
+            return Err(invalid_data("wrong number of wires"));
+        }
+
+        let values = sections.remove(&WTNS_VALUES_SECTION).ok_or_else(|| invalid_data("missing wtns values"))?;
+        let mut values = values.as_slice();
+        let mut assignment = Vec::with_capacity(num_wires);
+        for _ in 0..num_wires {
+            assignment.push(read_field_element::<F, _>(&mut values, n8)?);
+        }
+        if assignment[0] != F::one() {
+            return Err(invalid_data("the first wire is not one"));
+        }
+
+        self.aux_assignment = Some(assignment.split_off(self.num_inputs));
+        self.input_assignment = Some(assignment);
+        Ok(())
+    }
+
+    fn wire_to_variable(&self, wire: usize) -> Variable {
+        if wire < self.num_inputs {
+            Variable::new_unchecked(Index::Input(wire))
+        } else {
+            Variable::new_unchecked(Index::Aux(wire - self.num_inputs))
+        }
+    }
+
+    // The wires of `lc` and their coefficients, sorted by wire and without
+    // repetitions or zero coefficients.
+    fn to_factors(&self, lc: &LinearCombination<F>) -> Vec<(usize, F)> {
+        let mut factors = BTreeMap::new();
+        for (var, coeff) in lc.0.iter() {
+            let wire = match var.get_unchecked() {
+                Index::Input(i) => i,
+                Index::Aux(i) => self.num_inputs + i,
+            };
+            *factors.entry(wire).or_insert_with(F::zero) += coeff;
+        }
+        factors.into_iter().filter(|(_, coeff)| !coeff.is_zero()).collect()
+    }
+}
+
+impl<F: PrimeField> ConstraintSystem<F> for RecordingConstraintSystem<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        match f() {
+            Ok(value) => if let Some(aux) = self.aux_assignment.as_mut() {
+                aux.push(value)
+            },
+            Err(SynthesisError::AssignmentMissing) => self.aux_assignment = None,
+            Err(e) => return Err(e),
+        }
+        self.num_aux += 1;
+        Ok(Variable::new_unchecked(Index::Aux(self.num_aux - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        match f() {
+            Ok(value) => if let Some(inputs) = self.input_assignment.as_mut() {
+                inputs.push(value)
+            },
+            Err(SynthesisError::AssignmentMissing) => self.input_assignment = None,
+            Err(e) => return Err(e),
+        }
+        self.num_inputs += 1;
+        Ok(Variable::new_unchecked(Index::Input(self.num_inputs - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.a.push(a(LinearCombination::zero()));
+        self.b.push(b(LinearCombination::zero()));
+        self.c.push(c(LinearCombination::zero()));
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+}
+
+/// Synthesizes the recorded constraints, allocating the variables with the
+/// recorded assignment, if any.
+impl<F: PrimeField> ConstraintSynthesizer<F> for RecordingConstraintSystem<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let mut inputs = vec![CS::one()];
+        for i in 1..self.num_inputs {
+            inputs.push(cs.alloc_input(
+                || format!("input_{}", i),
+                || self.input_assignment.as_ref().map(|v| v[i]).ok_or(SynthesisError::AssignmentMissing),
+            )?);
+        }
+        let mut aux = Vec::with_capacity(self.num_aux);
+        for i in 0..self.num_aux {
+            aux.push(cs.alloc(
+                || format!("aux_{}", i),
+                || self.aux_assignment.as_ref().map(|v| v[i]).ok_or(SynthesisError::AssignmentMissing),
+            )?);
+        }
+
+        let map = |lc: &LinearCombination<F>, mut new_lc: LinearCombination<F>| {
+            for &(var, coeff) in lc.0.iter() {
+                let var = match var.get_unchecked() {
+                    Index::Input(i) => inputs[i],
+                    Index::Aux(i) => aux[i],
+                };
+                new_lc += (coeff, var);
+            }
+            new_lc
+        };
+        for (i, ((a, b), c)) in self.a.iter().zip(self.b.iter()).zip(self.c.iter()).enumerate() {
+            cs.enforce(
+                || format!("constraint_{}", i),
+                |lc| map(a, lc),
+                |lc| map(b, lc),
+                |lc| map(c, lc),
+            );
+        }
+        Ok(())
+    }
+}
+
+fn field_size_in_bytes<F: PrimeField>() -> usize {
+    F::Params::MODULUS.as_ref().len() * 8
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn write_u32<W: Write>(mut writer: W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_u64<W: Write>(mut writer: W, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u32<R: Read>(mut reader: R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(mut reader: R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn write_section<W: Write>(mut writer: W, section_type: u32, content: &[u8]) -> io::Result<()> {
+    write_u32(&mut writer, section_type)?;
+    write_u64(&mut writer, content.len() as u64)?;
+    writer.write_all(content)
+}
+
+// Checks the magic string and the version of a file, and returns the content
+// of its sections by type.
+fn read_sections<R: Read>(mut reader: R, magic: &[u8; 4], version: u32) -> io::Result<BTreeMap<u32, Vec<u8>>> {
+    let mut file_magic = [0u8; 4];
+    reader.read_exact(&mut file_magic)?;
+    if &file_magic != magic {
+        return Err(invalid_data("wrong magic string"));
+    }
+    if read_u32(&mut reader)? != version {
+        return Err(invalid_data("unsupported version"));
+    }
+
+    let num_sections = read_u32(&mut reader)?;
+    let mut sections = BTreeMap::new();
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut reader)?;
+        let size = read_u64(&mut reader)?;
+        let mut content = Vec::new();
+        (&mut reader).take(size).read_to_end(&mut content)?;
+        if content.len() as u64 != size {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated section"));
+        }
+        if sections.insert(section_type, content).is_some() {
+            return Err(invalid_data("duplicated section"));
+        }
+    }
+    Ok(sections)
+}
+
+// Checks that the field of a header is `F`.
+fn read_field_header<F: PrimeField, R: Read>(mut reader: R) -> io::Result<()> {
+    let n8 = read_u32(&mut reader)? as usize;
+    if n8 != field_size_in_bytes::<F>() || F::BigInt::read(&mut reader)? != F::Params::MODULUS {
+        return Err(invalid_data("wrong field"));
+    }
+    Ok(())
+}
+
+fn read_field_element<F: PrimeField, R: Read>(reader: R, n8: usize) -> io::Result<F> {
+    debug_assert_eq!(n8, field_size_in_bytes::<F>());
+    let repr = F::BigInt::read(reader)?;
+    if repr >= F::Params::MODULUS {
+        return Err(invalid_data("field element out of range"));
+    }
+    Ok(F::from_repr(repr))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{fields::mnt4753::Fr, Field};
+
+    // Proves the knowledge of x such that x^3 + x + 5 = out, with out public
+    struct CubeCircuit {
+        x: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for CubeCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let x_val = self.x;
+            let x_sq_val = x_val.map(|x| x.square());
+            let out_val = x_val.map(|x| x.square() * &x + &x + &Fr::from(5u64));
+
+            let out = cs.alloc_input(|| "out", || out_val.ok_or(SynthesisError::AssignmentMissing))?;
+            let x = cs.alloc(|| "x", || x_val.ok_or(SynthesisError::AssignmentMissing))?;
+            let x_sq = cs.alloc(|| "x_sq", || x_sq_val.ok_or(SynthesisError::AssignmentMissing))?;
+            cs.enforce(|| "x_sq = x * x", |lc| lc + x, |lc| lc + x, |lc| lc + x_sq);
+            cs.enforce(
+                || "out = x_sq * x + x + 5",
+                |lc| lc + x_sq,
+                |lc| lc + x,
+                |lc| lc + out - x - (Fr::from(5u64), CS::one()),
+            );
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn r1cs_file_roundtrip() {
+        let cs = RecordingConstraintSystem::from_synthesizer(CubeCircuit { x: Some(Fr::from(3u64)) }).unwrap();
+        assert_eq!((cs.num_inputs, cs.num_aux, cs.num_constraints()), (2, 2, 2));
+        assert_eq!(cs.is_satisfied(), Some(true));
+
+        let mut r1cs = Vec::new();
+        cs.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = Vec::new();
+        cs.write_witness(&mut wtns).unwrap();
+
+        let mut read_cs = RecordingConstraintSystem::<Fr>::read_r1cs(r1cs.as_slice()).unwrap();
+        assert_eq!((read_cs.num_inputs, read_cs.num_aux, read_cs.num_constraints()), (2, 2, 2));
+        assert_eq!(read_cs.is_satisfied(), None);
+        read_cs.read_witness(wtns.as_slice()).unwrap();
+        assert_eq!(read_cs.input_assignment, cs.input_assignment);
+        assert_eq!(read_cs.aux_assignment, cs.aux_assignment);
+        assert_eq!(read_cs.is_satisfied(), Some(true));
+
+        // The loaded constraint system synthesizes the same constraints
+        let synthesized_cs = RecordingConstraintSystem::from_synthesizer(read_cs).unwrap();
+        let mut synthesized_r1cs = Vec::new();
+        synthesized_cs.write_r1cs(&mut synthesized_r1cs).unwrap();
+        assert_eq!(synthesized_r1cs, r1cs);
+
+        // A wrong witness doesn't satisfy it
+        let mut bad_cs = synthesized_cs.clone();
+        bad_cs.aux_assignment.as_mut().unwrap()[0] = Fr::from(4u64);
+        assert_eq!(bad_cs.is_satisfied(), Some(false));
+
+        // Without the witness, only the constraints are recorded
+        let blank_cs = RecordingConstraintSystem::from_synthesizer(CubeCircuit { x: None }).unwrap();
+        assert!(blank_cs.input_assignment.is_none() && blank_cs.aux_assignment.is_none());
+        assert!(blank_cs.write_witness(Vec::new()).is_err());
+        let mut blank_r1cs = Vec::new();
+        blank_cs.write_r1cs(&mut blank_r1cs).unwrap();
+        assert_eq!(blank_r1cs, r1cs);
+
+        // Malformed files are rejected
+        assert!(RecordingConstraintSystem::<Fr>::read_r1cs(&r1cs[..r1cs.len() - 1]).is_err());
+        assert!(RecordingConstraintSystem::<Fr>::read_r1cs(wtns.as_slice()).is_err());
+        assert!(RecordingConstraintSystem::<algebra::fields::mnt6753::Fr>::read_r1cs(r1cs.as_slice()).is_err());
+        let mut read_cs = RecordingConstraintSystem::<Fr>::read_r1cs(r1cs.as_slice()).unwrap();
+        read_cs.num_aux += 1;
+        assert!(read_cs.read_witness(wtns.as_slice()).is_err());
+    }
+}
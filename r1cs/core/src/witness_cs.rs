@@ -0,0 +1,148 @@
+use algebra::Field;
+
+use crate::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+/// A constraint system computing the assignment of a circuit and the
+/// evaluations of the `A`, `B` and `C` linear combinations of each constraint,
+/// without keeping the constraints themselves. This is all a prover needs, so
+/// the witness of a circuit can be generated once and then used to create
+/// several proofs, with any proving system.
+#[derive(Clone, Debug)]
+pub struct WitnessConstraintSystem<F: Field> {
+    /// The assignment of the public inputs, starting with the variable one
+    pub input_assignment: Vec<F>,
+    /// The assignment of the auxiliary variables
+    pub aux_assignment:   Vec<F>,
+    /// The evaluations of the `A` linear combinations
+    pub a:                Vec<F>,
+    /// The evaluations of the `B` linear combinations
+    pub b:                Vec<F>,
+    /// The evaluations of the `C` linear combinations
+    pub c:                Vec<F>,
+}
+
+impl<F: Field> Default for WitnessConstraintSystem<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> WitnessConstraintSystem<F> {
+    /// A constraint system with only the variable one.
+    pub fn new() -> Self {
+        Self {
+            input_assignment: vec![F::one()],
+            aux_assignment:   vec![],
+            a:                vec![],
+            b:                vec![],
+            c:                vec![],
+        }
+    }
+
+    /// Computes the witness of `circuit`, whose assignment must be known.
+    pub fn from_synthesizer<C: ConstraintSynthesizer<F>>(circuit: C) -> Result<Self, SynthesisError> {
+        let mut cs = Self::new();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs)
+    }
+
+    /// The number of public inputs, including the variable one.
+    pub fn num_inputs(&self) -> usize {
+        self.input_assignment.len()
+    }
+
+    /// The number of auxiliary variables.
+    pub fn num_aux(&self) -> usize {
+        self.aux_assignment.len()
+    }
+
+    /// Returns the index of the first constraint not satisfied, if any.
+    pub fn which_is_unsatisfied(&self) -> Option<usize> {
+        self.a
+            .iter()
+            .zip(self.b.iter())
+            .zip(self.c.iter())
+            .position(|((a_i, b_i), c_i)| *a_i * b_i != *c_i)
+    }
+
+    fn eval(&self, lc: &LinearCombination<F>) -> F {
+        let mut acc = F::zero();
+        for &(var, ref coeff) in lc.as_ref() {
+            let value = match var.get_unchecked() {
+                Index::Input(i) => self.input_assignment[i],
+                Index::Aux(i) => self.aux_assignment[i],
+            };
+            if coeff.is_one() {
+                acc += &value;
+            } else {
+                acc += &(value * coeff);
+            }
+        }
+        acc
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for WitnessConstraintSystem<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.aux_assignment.len();
+        self.aux_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.input_assignment.len();
+        self.input_assignment.push(f()?);
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = self.eval(&a(LinearCombination::zero()));
+        let b = self.eval(&b(LinearCombination::zero()));
+        let c = self.eval(&c(LinearCombination::zero()));
+        self.a.push(a);
+        self.b.push(b);
+        self.c.push(c);
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+}
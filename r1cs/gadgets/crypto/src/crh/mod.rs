@@ -2,7 +2,7 @@ use algebra::Field;
 use std::fmt::Debug;
 
 use primitives::crh::{
-    FieldBasedHash, FixedLengthCRH
+    FieldBasedHash, FieldBasedSponge, FixedLengthCRH
 };
 use r1cs_core::{ConstraintSystem, SynthesisError};
 
@@ -40,4 +40,25 @@ pub trait FieldBasedHashGadget<H: FieldBasedHash<Data = ConstraintF>, Constraint
         cs: CS,
         input: &[Self::DataGadget],
     ) -> Result<Self::DataGadget, SynthesisError>;
-}
\ No newline at end of file
+}
+
+pub trait FieldBasedSpongeGadget<S: FieldBasedSponge<Data = ConstraintF>, ConstraintF: Field>: Sized {
+    type DataGadget: FieldGadget<ConstraintF, ConstraintF>;
+
+    fn new<CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        domain_tag: &ConstraintF,
+    ) -> Result<Self, SynthesisError>;
+
+    fn absorb<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        input: &[Self::DataGadget],
+    ) -> Result<(), SynthesisError>;
+
+    fn squeeze<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<Self::DataGadget>, SynthesisError>;
+}
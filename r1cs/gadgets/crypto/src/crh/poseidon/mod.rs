@@ -2,15 +2,20 @@ use algebra::{
     fields::{
         mnt4753::Fr as MNT4753Fr,
         mnt6753::Fr as MNT6753Fr,
-    }, PrimeField, MulShort,
+        bls12_377::Fr as BLS12377Fr,
+        bls12_381::Fr as BLS12381Fr,
+        edwards_bls12::fr::Fr as EdwardsBls12Fr,
+        edwards_sw6::fr::Fr as EdwardsSW6Fr,
+        jubjub::{fq::Fq as JubJubFq, fr::Fr as JubJubFr},
+        mnt6::Fr as MNT6298Fr,
+        sw6::Fr as SW6Fr,
+    }, PrimeField,
 };
 use primitives::crh::poseidon::PoseidonParameters;
 use crate::crh::FieldBasedHashGadget;
 use primitives::crh::{
     poseidon::PoseidonHash,
-    parameters::{
-        MNT4753PoseidonParameters, MNT6753PoseidonParameters
-    },
+    parameters::*,
 };
 use std::marker::PhantomData;
 use r1cs_core::{ConstraintSystem, SynthesisError};
@@ -21,8 +26,19 @@ use r1cs_std::alloc::{AllocGadget, ConstantGadget};
 use r1cs_std::Assignment;
 use r1cs_std::eq::ConditionalEqGadget;
 
+pub mod sponge;
+pub use self::sponge::*;
+
 pub type MNT4PoseidonHashGadget = PoseidonHashGadget<MNT4753Fr, MNT4753PoseidonParameters>;
 pub type MNT6PoseidonHashGadget = PoseidonHashGadget<MNT6753Fr, MNT6753PoseidonParameters>;
+pub type BLS12381PoseidonHashGadget = PoseidonHashGadget<BLS12381Fr, BLS12381PoseidonParameters>;
+pub type BLS12377PoseidonHashGadget = PoseidonHashGadget<BLS12377Fr, BLS12377PoseidonParameters>;
+pub type JubJubFqPoseidonHashGadget = PoseidonHashGadget<JubJubFq, JubJubFqPoseidonParameters>;
+pub type JubJubFrPoseidonHashGadget = PoseidonHashGadget<JubJubFr, JubJubFrPoseidonParameters>;
+pub type EdwardsBls12FrPoseidonHashGadget = PoseidonHashGadget<EdwardsBls12Fr, EdwardsBls12FrPoseidonParameters>;
+pub type SW6PoseidonHashGadget = PoseidonHashGadget<SW6Fr, SW6PoseidonParameters>;
+pub type EdwardsSW6FrPoseidonHashGadget = PoseidonHashGadget<EdwardsSW6Fr, EdwardsSW6FrPoseidonParameters>;
+pub type MNT6298PoseidonHashGadget = PoseidonHashGadget<MNT6298Fr, MNT6298PoseidonParameters>;
 
 pub struct PoseidonHashGadget
 <
@@ -34,7 +50,7 @@ pub struct PoseidonHashGadget
     _parameters: PhantomData<P>,
 }
 
-impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>> PoseidonHashGadget<ConstraintF, P> {
+impl<ConstraintF: PrimeField, P: PoseidonParameters<Fr = ConstraintF>> PoseidonHashGadget<ConstraintF, P> {
 
     fn mod_inv_sbox<CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
@@ -70,7 +86,38 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
         Ok(())
     }
 
-    fn poseidon_perm<CS: ConstraintSystem<ConstraintF>>(
+    // Computes x^alpha by square and multiply, with one constraint for each
+    // squaring and multiplication
+    fn exp_sbox<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        x: &mut FpGadget<ConstraintF>,
+        alpha: u64,
+    ) -> Result<(), SynthesisError>
+    {
+        let num_bits = 64 - alpha.leading_zeros();
+        let mut result = x.clone();
+        for i in (0..num_bits - 1).rev() {
+            result.square_in_place(cs.ns(|| format!("square_{}", i)))?;
+            if (alpha >> i) & 1 == 1 {
+                result.mul_in_place(cs.ns(|| format!("mul_{}", i)), x)?;
+            }
+        }
+        *x = result;
+        Ok(())
+    }
+
+    fn sbox<CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        x: &mut FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+    {
+        match P::SBOX.alpha() {
+            None => Self::mod_inv_sbox(cs, x),
+            Some(alpha) => Self::exp_sbox(cs, x, alpha),
+        }
+    }
+
+    pub(crate) fn poseidon_perm<CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
         state: &mut [FpGadget<ConstraintF>],
     ) -> Result<(), SynthesisError>
@@ -93,7 +140,7 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
 
             // Apply the S-BOX to each of the elements of the state vector
             for (j, d) in state.iter_mut().enumerate() {
-                Self::mod_inv_sbox(cs.ns(||format!("mod_inv_S-Box_1_{}_{}",i, j)), d)?;
+                Self::sbox(cs.ns(||format!("S-Box_1_{}_{}",i, j)), d)?;
             }
 
             // Perform the matrix mix
@@ -112,8 +159,8 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
         for _i in 0..P::R_P {
 
             // Apply S-Box only to the first element of the state vector
-            Self::mod_inv_sbox(
-                cs.ns(||format!("mod_inv_S-Box_2_{}_{}",_i, 0)),
+            Self::sbox(
+                cs.ns(||format!("S-Box_2_{}_{}",_i, 0)),
                 &mut state[0]
             )?;
 
@@ -135,7 +182,7 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
 
             // Apply the S-BOX to each of the elements of the state vector
             for (j, d) in state.iter_mut().enumerate() {
-                Self::mod_inv_sbox(cs.ns(||format!("mod_inv_S-Box_3_{}_{}",_i, j)), d)?;
+                Self::sbox(cs.ns(||format!("S-Box_3_{}_{}",_i, j)), d)?;
             }
 
             // Perform the matrix mix
@@ -143,7 +190,6 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
 
             // Add the round constants
             for d in state.iter_mut() {
-                //let rc = MNT4753Fr::from_str(ROUND_CST[round_cst_idx]).map_err(|_| ()).unwrap();
                 let rc = P::ROUND_CST[round_cst_idx];
                 (*d).add_constant_in_place(cs.ns(|| format!("add_constant_3_{}", round_cst_idx)), &rc)?;
                 round_cst_idx += 1;
@@ -154,7 +200,7 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
         {
             // Apply the S-BOX to each of the elements of the state vector
             for (j, d) in state.iter_mut().enumerate() {
-                Self::mod_inv_sbox(cs.ns(|| format!("mod_inv_S-Box_4_{}_{}", P::R_F-1, j)), d)?;
+                Self::sbox(cs.ns(|| format!("S-Box_4_{}_{}", P::R_F-1, j)), d)?;
             }
         }
 
@@ -162,7 +208,7 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
     }
 
     // Function that does the mix matrix
-    // Assumption: t = 3
+    // The multiplications by constants are linear, hence don't cost any constraint
     fn matrix_mix<CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
         state: &mut [FpGadget<ConstraintF>],
@@ -172,50 +218,21 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
         // Check that the length of the state vector is t
         assert_eq!(state.len(), P::T);
 
-        let m_11 = P::MDS_CST[0];
-        let m_12 = P::MDS_CST[1];
-        let m_13 = P::MDS_CST[2];
-
-        // scalar multiplication for position 0 of the state vector
-        let mut el_0 = state[0].mul_by_constant(cs.ns(||"partial_product_1_1"), &m_11)?;
-        let elem_1 = state[1].mul_by_constant(cs.ns(||"partial_product_1_2"), &m_12)?;
-        let elem_2 = state[2].mul_by_constant(cs.ns(||"partial_product_1_3"), &m_13)?;
-
-        // sum of partial products
-        el_0.add_in_place(cs.ns(|| "add_partial_product_1_2"), &elem_1)?;
-        el_0.add_in_place(cs.ns(|| "add_partial_product_1_3"), &elem_2)?;
-
-        // scalar multiplication for position 1 of the state vector
-        let m_21 = P::MDS_CST[3];
-        let m_22 = P::MDS_CST[4];
-        let m_23 = P::MDS_CST[5];
+        let mut new_state = Vec::with_capacity(P::T);
+        for (i, row) in P::MDS_CST.chunks(P::T).enumerate() {
 
-        // scalar multiplication for position 1 of the state vector
-        let mut el_1 = state[0].mul_by_constant(cs.ns(||"partial_product_2_1"), &m_21)?;
-        let elem_4 = state[1].mul_by_constant(cs.ns(||"partial_product_2_2"), &m_22)?;
-        let elem_5 = state[2].mul_by_constant(cs.ns(||"partial_product_2_3"), &m_23)?;
+            // scalar multiplication for position i of the state vector
+            let mut el = state[0].mul_by_constant(cs.ns(|| format!("partial_product_{}_0", i)), &row[0])?;
+            for j in 1..P::T {
+                let elem = state[j].mul_by_constant(cs.ns(|| format!("partial_product_{}_{}", i, j)), &row[j])?;
 
-        // sum of partial products
-        el_1.add_in_place(cs.ns(|| "add_partial_product_2_2"), &elem_4)?;
-        el_1.add_in_place(cs.ns(|| "add_partial_product_2_3"), &elem_5)?;
-
-        // scalar multiplication for the position 2 of the state vector
-        let m_31 = P::MDS_CST[6];
-        let m_32 = P::MDS_CST[7];
-        let m_33 = P::MDS_CST[8];
-
-        // scalar multiplication for position 2 of the state vector
-        let mut el_2 = state[0].mul_by_constant(cs.ns(||"partial_product_3_1"), &m_31)?;
-        let elem_7 = state[1].mul_by_constant(cs.ns(||"partial_product_3_2"), &m_32)?;
-        let elem_8 = state[2].mul_by_constant(cs.ns(||"partial_product_3_3"), &m_33)?;
-
-        // sum of partial products
-        el_2.add_in_place(cs.ns(|| "add_partial_product_3_2"), &elem_7)?;
-        el_2.add_in_place(cs.ns(|| "add_partial_product_3_3"), &elem_8)?;
+                // sum of partial products
+                el.add_in_place(cs.ns(|| format!("add_partial_product_{}_{}", i, j)), &elem)?;
+            }
+            new_state.push(el);
+        }
 
-        state[0] = el_0;
-        state[1] = el_1;
-        state[2] = el_2;
+        state.clone_from_slice(&new_state);
 
         Ok(())
     }
@@ -223,7 +240,7 @@ impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>
 
 impl<ConstraintF, P> FieldBasedHashGadget<PoseidonHash<ConstraintF, P>, ConstraintF> for PoseidonHashGadget<ConstraintF, P>
     where
-        ConstraintF: PrimeField + MulShort,
+        ConstraintF: PrimeField,
         P:           PoseidonParameters<Fr = ConstraintF>
 {
     type DataGadget = FpGadget<ConstraintF>;
@@ -232,22 +249,19 @@ impl<ConstraintF, P> FieldBasedHashGadget<PoseidonHash<ConstraintF, P>, Constrai
         mut cs: CS,
         input: &[Self::DataGadget],
     ) -> Result<Self::DataGadget, SynthesisError>
-    // Assumption:
-    //     rate r = 2
-    //     capacity c = 1
-    //     t = 3
     {
-        let state_0 = FpGadget::<ConstraintF>::from_value(cs.ns(|| "hardcode_state_0"), &P::AFTER_ZERO_PERM[0]);
-        let state_1 = FpGadget::<ConstraintF>::from_value(cs.ns(|| "hardcode_state_1"), &P::AFTER_ZERO_PERM[1]);
-        let state_2 = FpGadget::<ConstraintF>::from_value(cs.ns(|| "hardcode_state_2"), &P::AFTER_ZERO_PERM[2]);
-
-        let mut state = [state_0, state_1, state_2];
+        let mut state = Vec::with_capacity(P::T);
+        for i in 0..P::T {
+            let elem = FpGadget::<ConstraintF>::from_value(
+                cs.ns(|| format!("hardcode_state_{}", i)),
+                &P::AFTER_ZERO_PERM[i]
+            );
+            state.push(elem);
+        }
 
         // calculate the number of cycles to process the input dividing in portions of rate elements
         let num_cycles = input.len() / P::R;
         // check if the input is a multiple of the rate by calculating the remainder of the division
-        // the remainder of dividing the input length by the rate can be 1 or 0 because we are assuming
-        // that the rate is 2
         let rem = input.len() % P::R;
 
         // index to process the input
@@ -255,24 +269,26 @@ impl<ConstraintF, P> FieldBasedHashGadget<PoseidonHash<ConstraintF, P>, Constrai
         // iterate of the portions of rate elements
         for i in 0..num_cycles {
             // add the elements to the state vector. Add rate elements
-            for j in 0..P::R {
-                state[j].add_in_place(cs.ns(|| format!("add_input_{}_{}", i, j)), &input[input_idx])?;
-                input_idx += 1;
+            for (j, (d, x)) in state.iter_mut().zip(&input[input_idx..input_idx + P::R]).enumerate() {
+                d.add_in_place(cs.ns(|| format!("add_input_{}_{}", i, j)), x)?;
             }
-            // for application to a 2-1 Merkle tree, add the constant 3 to the third state vector
+            input_idx += P::R;
+            // for application to a Merkle tree, add the constant 3 to the first capacity element of the state vector
             state[P::R].add_constant_in_place(cs.ns(|| format!("add_constant_C2_{}", i)), &P::C2)?;
             // apply permutation after adding the input vector
             Self::poseidon_perm(cs.ns(|| format!("poseidon_perm_{}", i)), &mut state)?;
         }
 
-        // in case the input is not a multiple of the rate process the remainder part padding a zero
-        // in this case add C2 to state[2]
+        // in case the input is not a multiple of the rate process the remainder part padding zeros
+        // and adding C2 to the first capacity element
         //
-        //   rem_input   0       C2
-        // + state[0] state[1] state[2]
+        //   rem_input  ...  0    C2
+        // + state[0]   ... ... state[R]
         //
         if rem != 0 {
-            state[0].add_in_place(cs.ns(|| "poseidon_padding_add"), &input[input_idx])?;
+            for (j, (d, x)) in state.iter_mut().zip(&input[input_idx..]).enumerate() {
+                d.add_in_place(cs.ns(|| format!("poseidon_padding_add_{}", j)), x)?;
+            }
             state[P::R].add_constant_in_place(cs.ns(|| "add_constant_C2_last_chunk"), &P::C2)?;
             // apply permutation after adding the input vector
             Self::poseidon_perm(cs.ns(|| "poseidon_padding_perm"), &mut state)?;
@@ -299,7 +315,10 @@ mod test {
     use algebra::fields::{
         mnt4753::Fr as MNT4753Fr,
         mnt6753::Fr as MNT6753Fr,
+        bls12_381::Fr,
     };
+    use algebra::{field_new, biginteger::BigInteger256};
+    use primitives::crh::{FieldBasedHashParameters, poseidon::PoseidonSBox};
 
     type Mnt4FieldGadget = FpGadget<MNT4753Fr>;
     type Mnt6FieldGadget = FpGadget<MNT6753Fr>;
@@ -367,4 +386,180 @@ mod test {
         assert_eq!(primitive_result, gadget_result.value.unwrap());
         assert!(cs.is_satisfied());
     }
-}
\ No newline at end of file
+
+    // Width 5 and rate 4 test instances over the BLS12-381 scalar field, for
+    // each of the power S-Boxes. The round constants are arbitrary small
+    // values, and the MDS matrix is the Cauchy matrix `1 / (i + j + 5)`
+    macro_rules! test_parameters {
+        ($name:ident, $sbox:expr, $after_zero_perm:expr) => {
+            #[derive(Clone)]
+            struct $name;
+
+            impl FieldBasedHashParameters for $name {
+                type Fr = Fr;
+            }
+
+            impl PoseidonParameters for $name {
+                const T: usize = 5;
+                const R_F: i32 = 4;
+                const R_P: i32 = 8;
+                const R: usize = 4;
+                const SBOX: PoseidonSBox = $sbox;
+                const ZERO: Fr = field_new!(Fr, BigInteger256([0, 0, 0, 0]));
+                const C2: Fr = field_new!(Fr, BigInteger256([3, 0, 0, 0]));
+                const AFTER_ZERO_PERM: &'static [Fr] = &$after_zero_perm;
+                const ROUND_CST: &'static [Fr] = ROUND_CST;
+                const MDS_CST: &'static [Fr] = MDS_CST;
+            }
+        };
+    }
+
+    const ROUND_CST: &[Fr] = &[
+        field_new!(Fr, BigInteger256([5, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([22, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([39, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([56, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([73, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([90, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([107, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([124, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([141, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([158, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([175, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([192, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([209, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([226, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([243, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([260, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([277, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([294, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([311, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([328, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([345, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([362, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([379, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([396, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([413, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([430, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([447, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([464, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([481, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([498, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([515, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([532, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([549, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([566, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([583, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([600, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([617, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([634, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([651, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([668, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([685, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([702, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([719, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([736, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([753, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([770, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([787, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([804, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([821, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([838, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([855, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([872, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([889, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([906, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([923, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([940, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([957, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([974, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([991, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1008, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1025, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1042, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1059, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1076, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1093, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1110, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1127, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1144, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1161, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1178, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1195, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1212, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1229, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1246, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1263, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1280, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1297, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1314, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1331, 0, 0, 0])),
+        field_new!(Fr, BigInteger256([1348, 0, 0, 0])),
+    ];
+
+    const MDS_CST: &[Fr] = &[
+        field_new!(Fr, BigInteger256([1717986918, 1275685051326677402, 5902210070027911985, 347942070956130582])), field_new!(Fr, BigInteger256([18446744072277895851, 2011386469512694100, 1230406299546590551, 5858962965439741720])), field_new!(Fr, BigInteger256([15811494917868205788, 16624336857528583898, 17920762862402342329, 7408687358795335574])), field_new!(Fr, BigInteger256([0, 0, 0, 2305843009213693952])), field_new!(Fr, BigInteger256([12297829380086941469, 15650140164994191245, 2050677165910984251, 6690480930114644264])),
+        field_new!(Fr, BigInteger256([18446744072277895851, 2011386469512694100, 1230406299546590551, 5858962965439741720])), field_new!(Fr, BigInteger256([15811494917868205788, 16624336857528583898, 17920762862402342329, 7408687358795335574])), field_new!(Fr, BigInteger256([0, 0, 0, 2305843009213693952])), field_new!(Fr, BigInteger256([12297829380086941469, 15650140164994191245, 2050677165910984251, 6690480930114644264])), field_new!(Fr, BigInteger256([858993459, 9861214562518114509, 2951105035013955992, 173971035478065291])),
+        field_new!(Fr, BigInteger256([15811494917868205788, 16624336857528583898, 17920762862402342329, 7408687358795335574])), field_new!(Fr, BigInteger256([0, 0, 0, 2305843009213693952])), field_new!(Fr, BigInteger256([12297829380086941469, 15650140164994191245, 2050677165910984251, 6690480930114644264])), field_new!(Fr, BigInteger256([858993459, 9861214562518114509, 2951105035013955992, 173971035478065291])), field_new!(Fr, BigInteger256([16769767338955052870, 9482003562329447516, 2348107442817190447, 3195797981148950029])),
+        field_new!(Fr, BigInteger256([0, 0, 0, 2305843009213693952])), field_new!(Fr, BigInteger256([12297829380086941469, 15650140164994191245, 2050677165910984251, 6690480930114644264])), field_new!(Fr, BigInteger256([858993459, 9861214562518114509, 2951105035013955992, 173971035478065291])), field_new!(Fr, BigInteger256([16769767338955052870, 9482003562329447516, 2348107442817190447, 3195797981148950029])), field_new!(Fr, BigInteger256([18446744070846240086, 4022772939025388201, 2460812599093181102, 7106239912452095536])),
+        field_new!(Fr, BigInteger256([12297829380086941469, 15650140164994191245, 2050677165910984251, 6690480930114644264])), field_new!(Fr, BigInteger256([858993459, 9861214562518114509, 2951105035013955992, 173971035478065291])), field_new!(Fr, BigInteger256([16769767338955052870, 9482003562329447516, 2348107442817190447, 3195797981148950029])), field_new!(Fr, BigInteger256([18446744070846240086, 4022772939025388201, 2460812599093181102, 7106239912452095536])), field_new!(Fr, BigInteger256([14189803131310057315, 4668143071805855979, 9082480973771243050, 5917027853073899775])),
+    ];
+
+    test_parameters!(
+        Alpha3Parameters,
+        PoseidonSBox::Alpha3,
+        [field_new!(Fr, BigInteger256([11167214391597945071, 1539125922309936913, 13106554204928163267, 4016415466293777162])), field_new!(Fr, BigInteger256([3141225138885881961, 12028667016784258388, 8202584948908479799, 3308688231339634057])), field_new!(Fr, BigInteger256([10573586346045747977, 7676854110831760137, 1126522618235365181, 7645330884129136090])), field_new!(Fr, BigInteger256([778849599029644056, 10788040078760001178, 8615003452091232727, 6135371801700267646])), field_new!(Fr, BigInteger256([17841825100201312010, 5626452312840031247, 7943166134556425721, 5280836667791874141]))]
+    );
+
+    test_parameters!(
+        Alpha5Parameters,
+        PoseidonSBox::Alpha5,
+        [field_new!(Fr, BigInteger256([10437373533025423687, 5790263647962527566, 1829726956003545271, 7175447394846426621])), field_new!(Fr, BigInteger256([16893930889631872738, 17016816696557011297, 10209912728446838201, 3031524340600636107])), field_new!(Fr, BigInteger256([11685020527084074343, 8490754904934549532, 13270759556275425929, 8235827860750966893])), field_new!(Fr, BigInteger256([17606822841019687759, 367773123498316923, 9737032107633245473, 5005522086315642647])), field_new!(Fr, BigInteger256([8102202484803309260, 2158416087640496111, 5779228372745782055, 8015020034106550774]))]
+    );
+
+    test_parameters!(
+        Alpha17Parameters,
+        PoseidonSBox::Alpha17,
+        [field_new!(Fr, BigInteger256([2157335371451957849, 15122310362075503887, 15999879451565638943, 7691348281811506159])), field_new!(Fr, BigInteger256([13360250855239232526, 13674577862558246162, 12334960136560600111, 1367203238683221090])), field_new!(Fr, BigInteger256([12665354257443871432, 10279977877354290599, 5662768238066540385, 2971315593932827379])), field_new!(Fr, BigInteger256([18283601782280526217, 17794150287808430317, 7404034835236495744, 888495964145125091])), field_new!(Fr, BigInteger256([11087204226622392625, 13148881911907225287, 15257910565192360180, 7142262506115256230]))]
+    );
+
+    fn generic_primitive_gadget_test<P: PoseidonParameters<Fr = Fr>>() {
+        let mut rng = &mut thread_rng();
+
+        // Inputs of length 4, and not multiple of the rate
+        for &input_len in [4, 7].iter() {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input = (0..input_len).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let primitive_result = PoseidonHash::<Fr, P>::evaluate(&input).unwrap();
+
+            let input_gadget = input
+                .iter()
+                .enumerate()
+                .map(|(i, v)| FpGadget::<Fr>::alloc(cs.ns(|| format!("alloc_v{}", i)), || Ok(*v)).unwrap())
+                .collect::<Vec<_>>();
+
+            let gadget_result =
+                PoseidonHashGadget::<Fr, P>::check_evaluation_gadget(
+                    cs.ns(||"check_poseidon_gadget"),
+                    input_gadget.as_slice()).unwrap();
+
+            println!("number of constraints total: {}", cs.num_constraints());
+
+            assert_eq!(primitive_result, gadget_result.value.unwrap());
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn crh_alpha_sbox_primitive_gadget_test() {
+        generic_primitive_gadget_test::<Alpha3Parameters>();
+        generic_primitive_gadget_test::<Alpha5Parameters>();
+        generic_primitive_gadget_test::<Alpha17Parameters>();
+    }
+
+    #[test]
+    fn crh_bls12_381_primitive_gadget_test() {
+        generic_primitive_gadget_test::<BLS12381PoseidonParameters>();
+    }
+}
@@ -0,0 +1,187 @@
+use algebra::{
+    fields::{
+        mnt4753::Fr as MNT4753Fr,
+        mnt6753::Fr as MNT6753Fr,
+    }, PrimeField,
+};
+use primitives::crh::{
+    poseidon::{PoseidonParameters, PoseidonSponge, SpongeMode},
+    parameters::{MNT4753PoseidonParameters, MNT6753PoseidonParameters},
+};
+use crate::crh::{FieldBasedSpongeGadget, poseidon::PoseidonHashGadget};
+use std::marker::PhantomData;
+use r1cs_core::{ConstraintSystem, SynthesisError};
+use r1cs_std::fields::fp::FpGadget;
+use r1cs_std::fields::FieldGadget;
+use r1cs_std::alloc::ConstantGadget;
+
+pub type MNT4PoseidonSpongeGadget = PoseidonSpongeGadget<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6PoseidonSpongeGadget = PoseidonSpongeGadget<MNT6753Fr, MNT6753PoseidonParameters>;
+
+/// The gadget of `PoseidonSponge`: the absorptions are linear, and hence free,
+/// while each permutation costs as much as in `PoseidonHashGadget`.
+pub struct PoseidonSpongeGadget
+<
+    ConstraintF: PrimeField,
+    P:           PoseidonParameters<Fr = ConstraintF>,
+>
+{
+    state:       Vec<FpGadget<ConstraintF>>,
+    mode:        SpongeMode,
+    // The number of permutations so far, to name their namespaces
+    num_perms:   usize,
+    _parameters: PhantomData<P>,
+}
+
+impl<ConstraintF: PrimeField, P: PoseidonParameters<Fr = ConstraintF>> Clone for PoseidonSpongeGadget<ConstraintF, P> {
+    fn clone(&self) -> Self {
+        Self {
+            state:       self.state.clone(),
+            mode:        self.mode,
+            num_perms:   self.num_perms,
+            _parameters: PhantomData,
+        }
+    }
+}
+
+impl<ConstraintF: PrimeField, P: PoseidonParameters<Fr = ConstraintF>> PoseidonSpongeGadget<ConstraintF, P> {
+
+    fn permute<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+    ) -> Result<(), SynthesisError>
+    {
+        PoseidonHashGadget::<ConstraintF, P>::poseidon_perm(
+            cs.ns(|| format!("poseidon_perm_{}", self.num_perms)),
+            &mut self.state
+        )?;
+        self.num_perms += 1;
+        Ok(())
+    }
+}
+
+impl<ConstraintF, P> FieldBasedSpongeGadget<PoseidonSponge<ConstraintF, P>, ConstraintF> for PoseidonSpongeGadget<ConstraintF, P>
+    where
+        ConstraintF: PrimeField,
+        P:           PoseidonParameters<Fr = ConstraintF>
+{
+    type DataGadget = FpGadget<ConstraintF>;
+
+    fn new<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        domain_tag: &ConstraintF,
+    ) -> Result<Self, SynthesisError>
+    {
+        let state = (0..P::T)
+            .map(|i| {
+                let value = if i == P::R { *domain_tag } else { P::ZERO };
+                FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_state_{}", i)), &value)
+            })
+            .collect();
+
+        Ok(Self { state, mode: SpongeMode::Absorbing(0), num_perms: 0, _parameters: PhantomData })
+    }
+
+    fn absorb<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        input: &[Self::DataGadget],
+    ) -> Result<(), SynthesisError>
+    {
+        let mut pos = match self.mode {
+            SpongeMode::Absorbing(pos) => pos,
+            SpongeMode::Squeezing(_) => 0,
+        };
+        for (i, x) in input.iter().enumerate() {
+            self.state[pos].add_in_place(cs.ns(|| format!("add_input_{}", i)), x)?;
+            pos += 1;
+            if pos == P::R {
+                self.permute(cs.ns(|| format!("permute_after_input_{}", i)))?;
+                pos = 0;
+            }
+        }
+        self.mode = SpongeMode::Absorbing(pos);
+        Ok(())
+    }
+
+    fn squeeze<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        num: usize,
+    ) -> Result<Vec<Self::DataGadget>, SynthesisError>
+    {
+        let mut pos = match self.mode {
+            SpongeMode::Absorbing(pos) => {
+                self.state[pos].add_constant_in_place(cs.ns(|| "add_padding"), &ConstraintF::one())?;
+                self.permute(cs.ns(|| "permute_after_padding"))?;
+                0
+            },
+            SpongeMode::Squeezing(pos) => pos,
+        };
+        let mut output = Vec::with_capacity(num);
+        for i in 0..num {
+            if pos == P::R {
+                self.permute(cs.ns(|| format!("permute_before_output_{}", i)))?;
+                pos = 0;
+            }
+            output.push(self.state[pos].clone());
+            pos += 1;
+        }
+        self.mode = SpongeMode::Squeezing(pos);
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+    use r1cs_std::test_constraint_system::TestConstraintSystem;
+    use primitives::crh::{FieldBasedSponge, MNT4PoseidonSponge, MNT6PoseidonSponge};
+    use r1cs_std::alloc::AllocGadget;
+    use super::*;
+
+    fn sponge_primitive_gadget_test<F, S, SG>()
+        where
+            F:  PrimeField,
+            S:  FieldBasedSponge<Data = F>,
+            SG: FieldBasedSpongeGadget<S, F, DataGadget = FpGadget<F>>,
+    {
+        let mut rng = &mut thread_rng();
+        let mut cs = TestConstraintSystem::<F>::new();
+
+        let domain_tag = F::from(7u64);
+        let mut sponge = S::new(domain_tag);
+        let mut sponge_gadget = SG::new(cs.ns(|| "new sponge"), &domain_tag).unwrap();
+
+        // Interleave absorptions and squeezings of various lengths, across the
+        // boundaries of the rate
+        for (i, &(num_absorbed, num_squeezed)) in [(1, 1), (3, 4), (0, 2), (2, 1)].iter().enumerate() {
+            let input = (0..num_absorbed).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+            let input_gadget = input
+                .iter()
+                .enumerate()
+                .map(|(j, x)| FpGadget::<F>::alloc(cs.ns(|| format!("alloc_input_{}_{}", i, j)), || Ok(*x)).unwrap())
+                .collect::<Vec<_>>();
+
+            sponge.absorb(&input);
+            sponge_gadget.absorb(cs.ns(|| format!("absorb_{}", i)), &input_gadget).unwrap();
+
+            let output = sponge.squeeze(num_squeezed);
+            let output_gadget = sponge_gadget.squeeze(cs.ns(|| format!("squeeze_{}", i)), num_squeezed).unwrap();
+            assert_eq!(output, output_gadget.iter().map(|x| x.get_value().unwrap()).collect::<Vec<_>>());
+        }
+
+        println!("number of constraints total: {}", cs.num_constraints());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4_753_sponge_primitive_gadget_test() {
+        sponge_primitive_gadget_test::<_, MNT4PoseidonSponge, MNT4PoseidonSpongeGadget>();
+    }
+
+    #[test]
+    fn mnt6_753_sponge_primitive_gadget_test() {
+        sponge_primitive_gadget_test::<_, MNT6PoseidonSponge, MNT6PoseidonSpongeGadget>();
+    }
+}
@@ -24,6 +24,11 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use self::signature::*;
 
+#[cfg(feature = "transcript")]
+pub mod transcript;
+#[cfg(feature = "transcript")]
+pub use self::transcript::*;
+
 #[cfg(feature = "vrf")]
 pub mod vrf;
 #[cfg(feature = "vrf")]
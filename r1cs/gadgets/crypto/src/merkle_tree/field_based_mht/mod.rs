@@ -11,6 +11,14 @@ use crate::crh::FieldBasedHashGadget;
 use std::borrow::Borrow;
 use std::marker::PhantomData;
 
+pub mod sparse;
+pub use self::sparse::*;
+
+pub mod multi_path;
+pub use self::multi_path::*;
+
+/// The gadget of `FieldBasedMerkleTreePath`: the position of a node among its
+/// siblings is given by its `log2(P::ARITY)` little endian bits.
 pub struct FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
     where
         P: FieldBasedMerkleTreeConfig,
@@ -18,7 +26,7 @@ pub struct FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
         HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
         ConstraintF: Field,
 {
-    path: Vec<(HGadget::DataGadget, Boolean)>,
+    path: Vec<(Vec<HGadget::DataGadget>, Vec<Boolean>)>,
 }
 
 impl<P, HGadget, ConstraintF> FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
@@ -55,26 +63,20 @@ impl<P, HGadget, ConstraintF> FieldBasedMerkleTreePathGadget<P, HGadget, Constra
 
         let mut previous_hash = (*leaf).clone();
 
-        for (i, &(ref sibling_hash, ref direction)) in self.path.iter().enumerate() {
-
-            //Select left hash based on direction
-            let lhs = HGadget::DataGadget::conditionally_select(cs.ns(|| format!("Choose left hash {}", i)),
-                                                                  direction,
-                                                                  &sibling_hash,
-                                                                  &previous_hash
-            )?;
+        for (i, (siblings, position)) in self.path.iter().enumerate() {
+            debug_assert!(siblings.len() == P::ARITY - 1);
 
-            //Select right hash based on direction
-            let rhs = HGadget::DataGadget::conditionally_select(cs.ns(|| format!("Choose right hash {}", i)),
-                                                                  direction,
-                                                                  &previous_hash,
-                                                                  &sibling_hash
+            //Place the previous hash among its siblings based on position
+            let children = select_children_gadget::<P::H, HGadget, ConstraintF, _>(
+                cs.ns(|| format!("Choose children {}", i)),
+                &previous_hash,
+                siblings,
+                position,
             )?;
 
-            previous_hash = hash_inner_node_gadget::<P::H, HGadget, ConstraintF, _>(
-                &mut cs.ns(|| format!("hash_inner_node_{}", i)),
-                lhs,
-                rhs,
+            previous_hash = HGadget::check_evaluation_gadget(
+                cs.ns(|| format!("hash_inner_node_{}", i)),
+                children.as_slice(),
             )?;
         }
 
@@ -86,6 +88,64 @@ impl<P, HGadget, ConstraintF> FieldBasedMerkleTreePathGadget<P, HGadget, Constra
     }
 }
 
+/// Returns the children of a node, given one of them, the little endian bits
+/// of its position among them, and the other ones in order.
+fn select_children_gadget<H, HG, ConstraintF, CS>(
+    mut cs: CS,
+    node: &HG::DataGadget,
+    siblings: &[HG::DataGadget],
+    position: &[Boolean],
+) -> Result<Vec<HG::DataGadget>, SynthesisError>
+    where
+        ConstraintF: Field,
+        CS: ConstraintSystem<ConstraintF>,
+        H: FieldBasedHash<Data = ConstraintF>,
+        HG: FieldBasedHashGadget<H, ConstraintF>,
+{
+    let arity = siblings.len() + 1;
+    debug_assert!(arity == 1 << position.len());
+
+    // is_position[j] iff the node is the j-th child
+    let mut is_position = Vec::with_capacity(arity);
+    for j in 0..arity {
+        let bits = position
+            .iter()
+            .enumerate()
+            .map(|(k, bit)| if (j >> k) & 1 == 1 { *bit } else { bit.not() })
+            .collect::<Vec<_>>();
+        is_position.push(Boolean::kary_and(cs.ns(|| format!("is_position_{}", j)), &bits)?);
+    }
+
+    // The j-th child is the node, or the j-th sibling if the node comes after
+    // it, or the (j-1)-th otherwise
+    let mut children = vec![node.clone(); arity];
+    let mut is_after = Boolean::Constant(false);
+    for j in (0..arity).rev() {
+        let sibling = if j == arity - 1 {
+            siblings[j - 1].clone()
+        } else if j == 0 {
+            siblings[0].clone()
+        } else {
+            HG::DataGadget::conditionally_select(
+                cs.ns(|| format!("Choose sibling {}", j)),
+                &is_after,
+                &siblings[j],
+                &siblings[j - 1],
+            )?
+        };
+        children[j] = HG::DataGadget::conditionally_select(
+            cs.ns(|| format!("Choose child {}", j)),
+            &is_position[j],
+            node,
+            &sibling,
+        )?;
+        if j > 1 {
+            is_after = Boolean::or(cs.ns(|| format!("is_after_{}", j - 1)), &is_after, &is_position[j])?;
+        }
+    }
+    Ok(children)
+}
+
 pub struct FieldBasedMerkleTreeGadget<P, HGadget, ConstraintF>
     where
         P: FieldBasedMerkleTreeConfig,
@@ -124,23 +184,20 @@ impl<P, HGadget, ConstraintF> FieldBasedMerkleTreeGadget<P, HGadget, ConstraintF
         root: &HGadget::DataGadget,
         should_enforce: &Boolean,
     ) -> Result<(), SynthesisError> {
-        debug_assert!(leaves.len() == 2_usize.pow((P::HEIGHT - 1) as u32));
+        debug_assert!(leaves.len() == P::ARITY.pow((P::HEIGHT - 1) as u32));
 
         let mut prev_level_nodes = leaves.to_vec();
         //Iterate over all levels except the root
         for level in 0..P::HEIGHT-1 {
             let mut curr_level_nodes = vec![];
 
-            //Iterate over all nodes in a level. We assume their number to be even (e.g a power of two)
+            //Iterate over all nodes in a level. We assume their number to be a power of P::ARITY
 
-            for (i, nodes) in prev_level_nodes.chunks(2).enumerate() {
+            for (i, children) in prev_level_nodes.chunks(P::ARITY).enumerate() {
                 //Compute parent hash
-                let mut children = vec![];
-                children.push(nodes[0].clone());
-                children.push(nodes[1].clone());
                 let parent_hash = HGadget::check_evaluation_gadget(
                     cs.ns(|| format!("hash_children_pair_{}_of_level_{}", i, level)),
-                    children.as_slice(),
+                    children,
                 )?;
                 curr_level_nodes.push(parent_hash);
             }
@@ -193,16 +250,25 @@ for FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
             T: Borrow<FieldBasedMerkleTreePath<P>>,
     {
         let mut path = Vec::new();
-        for (i, &(ref sibling, ref d)) in value_gen()?.borrow().path.iter().enumerate() {
-            let sibling_hash =
-                HGadget::DataGadget::alloc(&mut cs.ns(|| format!("sibling_hash_{}", i)), || {
-                    Ok(sibling)
-                })?;
-            let direction =
-                Boolean::alloc(&mut cs.ns(|| format!("direction_bit_{}", i)), || {
-                    Ok(d)
-                })?;
-            path.push((sibling_hash, direction));
+        let position_bits = P::ARITY.trailing_zeros() as usize;
+        for (i, (siblings, position)) in value_gen()?.borrow().path.iter().enumerate() {
+            let mut sibling_hashes = Vec::with_capacity(siblings.len());
+            for (j, sibling) in siblings.iter().enumerate() {
+                sibling_hashes.push(
+                    HGadget::DataGadget::alloc(&mut cs.ns(|| format!("sibling_hash_{}_{}", i, j)), || {
+                        Ok(sibling)
+                    })?
+                );
+            }
+            let mut position_gadget = Vec::with_capacity(position_bits);
+            for k in 0..position_bits {
+                position_gadget.push(
+                    Boolean::alloc(&mut cs.ns(|| format!("position_bit_{}_{}", i, k)), || {
+                        Ok((position >> k) & 1 == 1)
+                    })?
+                );
+            }
+            path.push((sibling_hashes, position_gadget));
         }
         Ok(FieldBasedMerkleTreePathGadget { path })
     }
@@ -216,16 +282,25 @@ for FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
             T: Borrow<FieldBasedMerkleTreePath<P>>,
     {
         let mut path = Vec::new();
-        for (i, &(ref sibling, ref d)) in value_gen()?.borrow().path.iter().enumerate() {
-            let sibling_hash =
-                HGadget::DataGadget::alloc_input(&mut cs.ns(|| format!("sibling_hash_{}", i)), || {
-                    Ok(sibling)
-                })?;
-            let direction =
-                Boolean::alloc_input(&mut cs.ns(|| format!("direction_bit_{}", i)), || {
-                    Ok(d)
-                })?;
-            path.push((sibling_hash, direction));
+        let position_bits = P::ARITY.trailing_zeros() as usize;
+        for (i, (siblings, position)) in value_gen()?.borrow().path.iter().enumerate() {
+            let mut sibling_hashes = Vec::with_capacity(siblings.len());
+            for (j, sibling) in siblings.iter().enumerate() {
+                sibling_hashes.push(
+                    HGadget::DataGadget::alloc_input(&mut cs.ns(|| format!("sibling_hash_{}_{}", i, j)), || {
+                        Ok(sibling)
+                    })?
+                );
+            }
+            let mut position_gadget = Vec::with_capacity(position_bits);
+            for k in 0..position_bits {
+                position_gadget.push(
+                    Boolean::alloc_input(&mut cs.ns(|| format!("position_bit_{}_{}", i, k)), || {
+                        Ok((position >> k) & 1 == 1)
+                    })?
+                );
+            }
+            path.push((sibling_hashes, position_gadget));
         }
         Ok(FieldBasedMerkleTreePathGadget { path })
     }
@@ -416,4 +491,68 @@ mod test {
         assert!(!check_merkle_paths(&leaves, true));
         assert!(!check_leaves(&leaves, true));
     }
-}
\ No newline at end of file
+
+    struct MNT4753QuaternaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternaryMerkleTreeParams {
+        const HEIGHT: usize = 4;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    struct MNT4753OctaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753OctaryMerkleTreeParams {
+        const HEIGHT: usize = 3;
+        const ARITY: usize = 8;
+        type H = MNT4PoseidonHash;
+    }
+
+    fn check_arity_merkle_paths<P: FieldBasedMerkleTreeConfig<H = MNT4PoseidonHash>>(leaves: &[Fr]) {
+        let tree = FieldBasedMerkleHashTree::<P>::new(leaves).unwrap();
+        let root = tree.root();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(i, leaf).unwrap();
+            let check_path = |root: Fr, leaf: Fr| {
+                let mut cs = TestConstraintSystem::<Fr>::new();
+                let root_g = FqGadget::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+                let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+                let path_g = FieldBasedMerkleTreePathGadget::<P, HG, _>::alloc(
+                    cs.ns(|| "alloc path"),
+                    || Ok(proof.clone()),
+                ).unwrap();
+                path_g.check_membership(cs.ns(|| "check membership"), &root_g, &leaf_g).unwrap();
+                cs.is_satisfied()
+            };
+            assert!(check_path(root, *leaf));
+            assert!(!check_path(Fr::zero(), *leaf));
+            assert!(!check_path(root, leaves[(i + 1) % leaves.len()]) || leaves.len() == 1);
+        }
+
+        // The tree gadget hashes the children of a node at once
+        let all_leaves = tree.leaves();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let root_g = FqGadget::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+        let leaves_g = all_leaves
+            .iter()
+            .enumerate()
+            .map(|(i, leaf)| FqGadget::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf)).unwrap())
+            .collect::<Vec<_>>();
+        if all_leaves.len() == P::ARITY.pow((P::HEIGHT - 1) as u32) {
+            FieldBasedMerkleTreeGadget::<P, HG, Fr>::check_leaves(cs.ns(|| "check leaves"), &leaves_g, &root_g).unwrap();
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn arity_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [1, 11, 64].iter() {
+            let leaves = (0..num_leaves).map(|_| rng.gen()).collect::<Vec<Fr>>();
+            check_arity_merkle_paths::<MNT4753QuaternaryMerkleTreeParams>(&leaves);
+            check_arity_merkle_paths::<MNT4753OctaryMerkleTreeParams>(&leaves);
+        }
+    }
+}
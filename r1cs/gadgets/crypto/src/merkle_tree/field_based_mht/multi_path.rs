@@ -0,0 +1,325 @@
+use algebra::PrimeField;
+use r1cs_core::{ConstraintSystem, SynthesisError};
+use r1cs_std::prelude::*;
+
+use primitives::{
+    crh::FieldBasedHash,
+    merkle_tree::field_based_mht::*,
+};
+use crate::{
+    crh::FieldBasedHashGadget,
+    merkle_tree::field_based_mht::FieldBasedMerkleTreeGadget,
+};
+
+use std::borrow::Borrow;
+
+/// The gadget of `FieldBasedMerkleTreeMultiPath`: the indices of the leaves are
+/// part of the circuit, while the auxiliary nodes are witnesses.
+pub struct FieldBasedMerkleTreeMultiPathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+        ConstraintF: PrimeField,
+{
+    indices:         Vec<usize>,
+    auxiliary_nodes: Vec<HGadget::DataGadget>,
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedMerkleTreeMultiPathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+        ConstraintF: PrimeField,
+{
+    #[inline]
+    pub fn indices(&self) -> &[usize] {
+        self.indices.as_slice()
+    }
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedMerkleTreeGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+        ConstraintF: PrimeField,
+{
+    pub fn check_multi_path<CS: ConstraintSystem<ConstraintF>>
+    (
+        cs: CS,
+        leaves: &[HGadget::DataGadget],
+        multi_path: &FieldBasedMerkleTreeMultiPathGadget<P, HGadget, ConstraintF>,
+        root: &HGadget::DataGadget,
+    ) -> Result<(), SynthesisError> {
+        Self::conditionally_check_multi_path(cs, leaves, multi_path, root, &Boolean::Constant(true))
+    }
+
+    /// Enforces that `leaves` are the leaves at `multi_path.indices()` of the
+    /// tree of root `root`, hashing each node of their paths only once.
+    /// Returns `SynthesisError::Unsatisfiable` if there is not one leaf per
+    /// index, or no leaf at all.
+    pub fn conditionally_check_multi_path<CS: ConstraintSystem<ConstraintF>>
+    (
+        mut cs: CS,
+        leaves: &[HGadget::DataGadget],
+        multi_path: &FieldBasedMerkleTreeMultiPathGadget<P, HGadget, ConstraintF>,
+        root: &HGadget::DataGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        if leaves.len() != multi_path.indices.len() || leaves.is_empty() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut auxiliary_nodes = multi_path.auxiliary_nodes.iter();
+        let mut level_nodes = leaves.to_vec();
+        let mut next_level_nodes = Vec::with_capacity(level_nodes.len());
+        let mut current_level = 0;
+        let mut pos = 0;
+
+        walk_multi_path::<SynthesisError, _>(P::ARITY, P::HEIGHT, &multi_path.indices, |level, first_child, known| {
+            if level != current_level {
+                level_nodes = std::mem::take(&mut next_level_nodes);
+                current_level = level;
+                pos = 0;
+            }
+            let mut children = Vec::with_capacity(P::ARITY);
+            for &known in known {
+                if known {
+                    children.push(level_nodes[pos].clone());
+                    pos += 1;
+                } else {
+                    children.push(auxiliary_nodes.next().ok_or(SynthesisError::Unsatisfiable)?.clone());
+                }
+            }
+            next_level_nodes.push(HGadget::check_evaluation_gadget(
+                cs.ns(|| format!("hash_inner_node_{}_of_level_{}", first_child / P::ARITY, level + 1)),
+                children.as_slice(),
+            )?);
+            Ok(())
+        })?;
+        if auxiliary_nodes.next().is_some() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        root.conditional_enforce_equal(
+            &mut cs.ns(|| "root_is_last"),
+            &next_level_nodes[0],
+            should_enforce,
+        )
+    }
+}
+
+// Checks, as `FieldBasedMerkleTreeMultiPath::verify` does, that the indices
+// are sorted, without duplicates and leaves of the tree, and that there are
+// as many auxiliary nodes as their paths need.
+fn check_shape<P: FieldBasedMerkleTreeConfig>(multi_path: &FieldBasedMerkleTreeMultiPath<P>) -> Result<(), SynthesisError> {
+    match num_auxiliary_nodes::<P>(&multi_path.indices) {
+        Ok(n) if n == multi_path.auxiliary_nodes.len() => Ok(()),
+        _ => Err(SynthesisError::Unsatisfiable),
+    }
+}
+
+impl<P, HGadget, ConstraintF> AllocGadget<FieldBasedMerkleTreeMultiPath<P>, ConstraintF>
+for FieldBasedMerkleTreeMultiPathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+        ConstraintF: PrimeField,
+{
+    fn alloc<F, T, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<FieldBasedMerkleTreeMultiPath<P>>,
+    {
+        let value = value_gen()?;
+        let multi_path = value.borrow();
+        check_shape::<P>(multi_path)?;
+        let mut auxiliary_nodes = Vec::with_capacity(multi_path.auxiliary_nodes.len());
+        for (i, node) in multi_path.auxiliary_nodes.iter().enumerate() {
+            auxiliary_nodes.push(HGadget::DataGadget::alloc(
+                &mut cs.ns(|| format!("auxiliary_node_{}", i)),
+                || Ok(node),
+            )?);
+        }
+        Ok(Self { indices: multi_path.indices.clone(), auxiliary_nodes })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<FieldBasedMerkleTreeMultiPath<P>>,
+    {
+        let value = value_gen()?;
+        let multi_path = value.borrow();
+        check_shape::<P>(multi_path)?;
+        let mut auxiliary_nodes = Vec::with_capacity(multi_path.auxiliary_nodes.len());
+        for (i, node) in multi_path.auxiliary_nodes.iter().enumerate() {
+            auxiliary_nodes.push(HGadget::DataGadget::alloc_input(
+                &mut cs.ns(|| format!("auxiliary_node_{}", i)),
+                || Ok(node),
+            )?);
+        }
+        Ok(Self { indices: multi_path.indices.clone(), auxiliary_nodes })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use primitives::crh::MNT4PoseidonHash;
+    use crate::crh::MNT4PoseidonHashGadget;
+    use algebra::{fields::mnt4753::Fr, Field, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use super::*;
+    use crate::merkle_tree::field_based_mht::FieldBasedMerkleTreePathGadget;
+    use r1cs_std::{
+        fields::fp::FpGadget,
+        test_constraint_system::TestConstraintSystem,
+    };
+
+    struct MNT4753FieldBasedMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753FieldBasedMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        type H = MNT4PoseidonHash;
+    }
+
+    type MNT4753FieldBasedMerkleTree = FieldBasedMerkleHashTree<MNT4753FieldBasedMerkleTreeParams>;
+    type TreeGadget = FieldBasedMerkleTreeGadget<MNT4753FieldBasedMerkleTreeParams, MNT4PoseidonHashGadget, Fr>;
+    type MultiPathGadget = FieldBasedMerkleTreeMultiPathGadget<MNT4753FieldBasedMerkleTreeParams, MNT4PoseidonHashGadget, Fr>;
+    type PathGadget = FieldBasedMerkleTreePathGadget<MNT4753FieldBasedMerkleTreeParams, MNT4PoseidonHashGadget, Fr>;
+
+    // Returns the number of constraints of the multi path, if satisfied
+    fn check_multi_path(tree: &MNT4753FieldBasedMerkleTree, indices: &[usize], leaves: &[Fr], root: Fr) -> Option<usize> {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let multi_path = tree.generate_multi_proof(indices).unwrap();
+
+        let root_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+        let leaves_g = multi_path.indices
+            .iter()
+            .map(|&i| FpGadget::<Fr>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaves[i])).unwrap())
+            .collect::<Vec<_>>();
+        let multi_path_g = MultiPathGadget::alloc(cs.ns(|| "alloc multi path"), || Ok(multi_path)).unwrap();
+        let num_constraints = cs.num_constraints();
+        TreeGadget::check_multi_path(cs.ns(|| "check multi path"), &leaves_g, &multi_path_g, &root_g).unwrap();
+
+        if cs.is_satisfied() { Some(cs.num_constraints() - num_constraints) } else { None }
+    }
+
+    fn num_constraints_of_paths(tree: &MNT4753FieldBasedMerkleTree, indices: &[usize], leaves: &[Fr]) -> usize {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let root_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc root"), || Ok(tree.root())).unwrap();
+        let mut num_constraints = 0;
+        for &i in indices.iter() {
+            let leaf_g = FpGadget::<Fr>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaves[i])).unwrap();
+            let path = tree.generate_proof(i, &leaves[i]).unwrap();
+            let path_g = PathGadget::alloc(cs.ns(|| format!("alloc path_{}", i)), || Ok(path)).unwrap();
+            let before = cs.num_constraints();
+            path_g.check_membership(cs.ns(|| format!("check path_{}", i)), &root_g, &leaf_g).unwrap();
+            num_constraints += cs.num_constraints() - before;
+        }
+        assert!(cs.is_satisfied());
+        num_constraints
+    }
+
+    #[test]
+    fn multi_path_gadget_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        for &num_leaves in [32, 13].iter() {
+            let leaves = (0..num_leaves).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+            let root = tree.root();
+
+            for indices in [vec![5], vec![0, 1, 2, 3], vec![1, 6, 7, 12]].iter() {
+                let num_constraints = check_multi_path(&tree, indices, &leaves, root).unwrap();
+                if indices.len() > 1 {
+                    assert!(num_constraints < num_constraints_of_paths(&tree, indices, &leaves));
+                }
+
+                assert!(check_multi_path(&tree, indices, &leaves, Fr::zero()).is_none());
+                let mut bad_leaves = leaves.clone();
+                bad_leaves[indices[0]] += &Fr::one();
+                assert!(check_multi_path(&tree, indices, &bad_leaves, root).is_none());
+            }
+        }
+    }
+
+    struct MNT4753QuaternaryMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753QuaternaryMerkleTreeParams {
+        const HEIGHT: usize = 4;
+        const ARITY: usize = 4;
+        type H = MNT4PoseidonHash;
+    }
+
+    #[test]
+    fn quaternary_multi_path_gadget_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let leaves = (0..29).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = FieldBasedMerkleHashTree::<MNT4753QuaternaryMerkleTreeParams>::new(&leaves).unwrap();
+
+        for indices in [vec![5], vec![0, 1, 3], vec![2, 13, 14, 28]].iter() {
+            for &(root, satisfied) in [(tree.root(), true), (Fr::zero(), false)].iter() {
+                let mut cs = TestConstraintSystem::<Fr>::new();
+                let multi_path = tree.generate_multi_proof(indices).unwrap();
+                let root_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+                let leaves_g = indices
+                    .iter()
+                    .map(|&i| FpGadget::<Fr>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaves[i])).unwrap())
+                    .collect::<Vec<_>>();
+                let multi_path_g = FieldBasedMerkleTreeMultiPathGadget::<_, MNT4PoseidonHashGadget, _>::alloc(
+                    cs.ns(|| "alloc multi path"),
+                    || Ok(multi_path),
+                ).unwrap();
+                FieldBasedMerkleTreeGadget::<MNT4753QuaternaryMerkleTreeParams, MNT4PoseidonHashGadget, Fr>::check_multi_path(
+                    cs.ns(|| "check multi path"),
+                    &leaves_g,
+                    &multi_path_g,
+                    &root_g,
+                ).unwrap();
+                assert_eq!(cs.is_satisfied(), satisfied);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_multi_path_gadget_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let leaves = (0..32).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+        let multi_path = tree.generate_multi_proof(&[1, 6, 7]).unwrap();
+
+        // The indices must be sorted, distinct and leaves of the tree, with
+        // as many auxiliary nodes as their paths need
+        let mut bad_multi_paths = vec![multi_path.clone(); 4];
+        bad_multi_paths[0].indices = vec![1, 7, 6];
+        bad_multi_paths[1].indices = vec![1, 6, 6];
+        bad_multi_paths[2].indices = vec![1, 6, 32];
+        bad_multi_paths[3].auxiliary_nodes.pop();
+        for bad_multi_path in bad_multi_paths.into_iter() {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            assert!(MultiPathGadget::alloc(cs.ns(|| "alloc multi path"), || Ok(bad_multi_path)).is_err());
+        }
+
+        // There must be one leaf per index
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let root_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc root"), || Ok(tree.root())).unwrap();
+        let leaves_g = multi_path.indices
+            .iter()
+            .map(|&i| FpGadget::<Fr>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaves[i])).unwrap())
+            .collect::<Vec<_>>();
+        let multi_path_g = MultiPathGadget::alloc(cs.ns(|| "alloc multi path"), || Ok(multi_path)).unwrap();
+        assert!(TreeGadget::check_multi_path(cs.ns(|| "check with a leaf missing"), &leaves_g[1..], &multi_path_g, &root_g).is_err());
+        assert!(TreeGadget::check_multi_path(cs.ns(|| "check without leaves"), &[], &multi_path_g, &root_g).is_err());
+    }
+}
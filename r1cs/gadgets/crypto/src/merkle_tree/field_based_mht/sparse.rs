@@ -0,0 +1,257 @@
+use algebra::{FpParameters, PrimeField};
+use r1cs_core::{ConstraintSystem, SynthesisError};
+use r1cs_std::{fields::fp::FpGadget, prelude::*};
+
+use primitives::{
+    crh::FieldBasedHash,
+    merkle_tree::field_based_mht::*,
+};
+use crate::{
+    crh::FieldBasedHashGadget,
+    merkle_tree::field_based_mht::hash_inner_node_gadget,
+};
+
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+/// The gadget of `FieldBasedSparseMerkleTreePath`: the directions of the path
+/// are the bits of the key, so that a path proves the membership, or the
+/// non-membership, of the key itself.
+pub struct FieldBasedSparseMerkleTreePathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+        ConstraintF: PrimeField,
+{
+    siblings:     Vec<FpGadget<ConstraintF>>,
+    _params:      PhantomData<P>,
+    _hash_gadget: PhantomData<HGadget>,
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedSparseMerkleTreePathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+        ConstraintF: PrimeField,
+{
+    /// Enforces that `key` is in the tree of root `root`, with value `leaf`.
+    pub fn check_membership<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        root: &FpGadget<ConstraintF>,
+        key: &FpGadget<ConstraintF>,
+        leaf: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+    {
+        let empty_leaf = Self::empty_leaf(cs.ns(|| "hardcode empty leaf"))?;
+        leaf.enforce_not_equal(cs.ns(|| "leaf is not empty"), &empty_leaf)?;
+        self.check_leaf(cs.ns(|| "check leaf"), root, key, leaf)
+    }
+
+    /// Enforces that `key` is not in the tree of root `root`.
+    pub fn check_non_membership<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        root: &FpGadget<ConstraintF>,
+        key: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+    {
+        let empty_leaf = Self::empty_leaf(cs.ns(|| "hardcode empty leaf"))?;
+        self.check_leaf(cs.ns(|| "check empty leaf"), root, key, &empty_leaf)
+    }
+
+    /// Enforces that the leaf of `key` in the tree of root `root` is `leaf`,
+    /// proving the membership of `key` if `leaf` is not `hash_empty`, and its
+    /// non-membership otherwise.
+    pub fn check_leaf<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+        root: &FpGadget<ConstraintF>,
+        key: &FpGadget<ConstraintF>,
+        leaf: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+    {
+        self.conditionally_check_leaf(cs, root, key, leaf, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_leaf<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        root: &FpGadget<ConstraintF>,
+        key: &FpGadget<ConstraintF>,
+        leaf: &FpGadget<ConstraintF>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError>
+    {
+        debug_assert!(self.siblings.len() == P::HEIGHT - 1);
+
+        // The directions, from the leaf to the root. The key must be smaller
+        // than 2^(HEIGHT - 1), otherwise its leaf is not in the tree.
+        let modulus_bits = ConstraintF::Params::MODULUS_BITS as usize;
+        let mut position = if P::HEIGHT - 1 == modulus_bits {
+            key.to_bits_strict(cs.ns(|| "key to bits"))?
+        } else {
+            key.to_bits_with_length_restriction(cs.ns(|| "key to bits"), modulus_bits - (P::HEIGHT - 1))?
+        };
+        position.reverse();
+
+        let mut previous_hash = leaf.clone();
+        for (i, (sibling_hash, direction)) in self.siblings.iter().zip(position.iter()).enumerate() {
+
+            //Select left hash based on direction
+            let lhs = FpGadget::<ConstraintF>::conditionally_select(
+                cs.ns(|| format!("Choose left hash {}", i)),
+                direction,
+                sibling_hash,
+                &previous_hash,
+            )?;
+
+            //Select right hash based on direction
+            let rhs = FpGadget::<ConstraintF>::conditionally_select(
+                cs.ns(|| format!("Choose right hash {}", i)),
+                direction,
+                &previous_hash,
+                sibling_hash,
+            )?;
+
+            previous_hash = hash_inner_node_gadget::<P::H, HGadget, ConstraintF, _>(
+                &mut cs.ns(|| format!("hash_inner_node_{}", i)),
+                lhs,
+                rhs,
+            )?;
+        }
+
+        root.conditional_enforce_equal(
+            &mut cs.ns(|| "root_is_last"),
+            &previous_hash,
+            should_enforce,
+        )
+    }
+
+    fn empty_leaf<CS: ConstraintSystem<ConstraintF>>(cs: CS) -> Result<FpGadget<ConstraintF>, SynthesisError> {
+        let empty_leaf = hash_empty::<P::H>()
+            .map_err(|e| SynthesisError::IoError(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())))?;
+        Ok(FpGadget::<ConstraintF>::from_value(cs, &empty_leaf))
+    }
+}
+
+impl<P, HGadget, ConstraintF> AllocGadget<FieldBasedSparseMerkleTreePath<P>, ConstraintF>
+for FieldBasedSparseMerkleTreePathGadget<P, HGadget, ConstraintF>
+    where
+        P: FieldBasedMerkleTreeConfig,
+        P::H: FieldBasedHash<Data = ConstraintF>,
+        HGadget: FieldBasedHashGadget<P::H, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+        ConstraintF: PrimeField,
+{
+    fn alloc<F, T, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<FieldBasedSparseMerkleTreePath<P>>,
+    {
+        let mut siblings = Vec::new();
+        for (i, sibling) in value_gen()?.borrow().siblings.iter().enumerate() {
+            siblings.push(FpGadget::<ConstraintF>::alloc(
+                cs.ns(|| format!("sibling_hash_{}", i)),
+                || Ok(sibling),
+            )?);
+        }
+        Ok(Self { siblings, _params: PhantomData, _hash_gadget: PhantomData })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<FieldBasedSparseMerkleTreePath<P>>,
+    {
+        let mut siblings = Vec::new();
+        for (i, sibling) in value_gen()?.borrow().siblings.iter().enumerate() {
+            siblings.push(FpGadget::<ConstraintF>::alloc_input(
+                cs.ns(|| format!("sibling_hash_{}", i)),
+                || Ok(sibling),
+            )?);
+        }
+        Ok(Self { siblings, _params: PhantomData, _hash_gadget: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use primitives::crh::MNT4PoseidonHash;
+    use crate::crh::MNT4PoseidonHashGadget;
+    use algebra::{fields::mnt4753::Fr, Field, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use super::*;
+    use r1cs_std::test_constraint_system::TestConstraintSystem;
+
+    struct MNT4753SparseMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeConfig for MNT4753SparseMerkleTreeParams {
+        const HEIGHT: usize = 6;
+        type H = MNT4PoseidonHash;
+    }
+
+    type MNT4753SparseMerkleTree = FieldBasedSparseMerkleTree<MNT4753SparseMerkleTreeParams>;
+    type PathGadget = FieldBasedSparseMerkleTreePathGadget<MNT4753SparseMerkleTreeParams, MNT4PoseidonHashGadget, Fr>;
+
+    // Checks the path of `key` in the gadget, claiming its leaf is `leaf`, or
+    // that it isn't in the tree if `leaf` is None
+    fn check_path(tree: &MNT4753SparseMerkleTree, key: Fr, leaf: Option<Fr>, proof_key: Fr) -> bool {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let proof = tree.generate_proof(&proof_key).unwrap();
+
+        let root_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc root"), || Ok(tree.root())).unwrap();
+        let key_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc key"), || Ok(key)).unwrap();
+        let path_g = PathGadget::alloc(cs.ns(|| "alloc path"), || Ok(proof)).unwrap();
+        match leaf {
+            Some(leaf) => {
+                let leaf_g = FpGadget::<Fr>::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+                path_g.check_membership(cs.ns(|| "check membership"), &root_g, &key_g, &leaf_g).unwrap();
+            },
+            None => path_g.check_non_membership(cs.ns(|| "check non membership"), &root_g, &key_g).unwrap(),
+        }
+
+        if !cs.is_satisfied() {
+            println!("Unsatisfied constraint: {}", cs.which_is_unsatisfied().unwrap());
+        }
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn sparse_merkle_tree_gadget_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+        let mut tree = MNT4753SparseMerkleTree::new().unwrap();
+
+        let keys = [3u64, 4, 17, 31].iter().map(|&k| Fr::from(k)).collect::<Vec<_>>();
+        let leaves = (0..keys.len()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            tree.insert(key, *leaf).unwrap();
+        }
+
+        for (key, leaf) in keys.iter().zip(leaves.iter()) {
+            assert!(check_path(&tree, *key, Some(*leaf), *key));
+            assert!(!check_path(&tree, *key, None, *key));
+            assert!(!check_path(&tree, *key, Some(Fr::rand(&mut rng)), *key));
+        }
+
+        for key in [0u64, 5, 16, 30].iter().map(|&k| Fr::from(k)) {
+            assert!(check_path(&tree, key, None, key));
+            assert!(!check_path(&tree, key, Some(Fr::rand(&mut rng)), key));
+
+            // The path of a key doesn't prove the non-membership of another
+            assert!(!check_path(&tree, keys[0], None, key));
+        }
+
+        // Keys out of the tree can't be proven not to be in it
+        assert!(!check_path(&tree, Fr::from(32u64), None, Fr::zero()));
+        assert!(!check_path(&tree, -Fr::one(), None, Fr::zero()));
+    }
+}
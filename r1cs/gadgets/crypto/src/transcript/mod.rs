@@ -0,0 +1,291 @@
+use algebra::PrimeField;
+use primitives::{
+    bytes_to_bits, compute_truncation_size,
+    crh::FieldBasedSponge,
+    transcript::{
+        bytes_to_field_elements, FiatShamirTranscript, SpongeTranscript, Blake2sTranscript,
+        BLAKE2S_OUTPUT_BITS,
+    },
+};
+use crate::{
+    crh::{FieldBasedSpongeGadget, MNT4PoseidonSpongeGadget, MNT6PoseidonSpongeGadget},
+    prf::blake2s::blake2s_gadget,
+};
+use r1cs_core::{ConstraintSystem, SynthesisError};
+use r1cs_std::{
+    alloc::ConstantGadget,
+    bits::{boolean::Boolean, ToBitsGadget, ToBytesGadget},
+    fields::fp::FpGadget,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+};
+use std::marker::PhantomData;
+
+/// The gadget of a `FiatShamirTranscript`, deriving the same challenges as `T`
+/// out of the same messages.
+pub trait FiatShamirTranscriptGadget<T, ConstraintF>: Sized + Clone
+    where
+        T:           FiatShamirTranscript<Field = ConstraintF>,
+        ConstraintF: PrimeField,
+{
+    fn new<CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        protocol_name: &[u8],
+    ) -> Result<Self, SynthesisError>;
+
+    fn append_field<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        elems: &[FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError>;
+
+    /// Appends the coordinates of `point`.
+    fn append_point<CS, G>(
+        &mut self,
+        cs: CS,
+        point: &G,
+    ) -> Result<(), SynthesisError>
+        where
+            CS: ConstraintSystem<ConstraintF>,
+            G:  ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    {
+        self.append_field(cs, point.to_field_gadget_elements()?.as_slice())
+    }
+
+    /// Derives a challenge in `S`, as its big endian bits.
+    fn challenge_bits<S: PrimeField, CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError>;
+}
+
+pub struct SpongeTranscriptGadget<ConstraintF, S, SG>
+    where
+        ConstraintF: PrimeField,
+        S:           FieldBasedSponge<Data = ConstraintF>,
+        SG:          FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    sponge:  SG,
+    _sponge: PhantomData<S>,
+    _field:  PhantomData<ConstraintF>,
+}
+
+impl<ConstraintF, S, SG> Clone for SpongeTranscriptGadget<ConstraintF, S, SG>
+    where
+        ConstraintF: PrimeField,
+        S:           FieldBasedSponge<Data = ConstraintF>,
+        SG:          FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self { sponge: self.sponge.clone(), _sponge: PhantomData, _field: PhantomData }
+    }
+}
+
+impl<ConstraintF, S, SG> FiatShamirTranscriptGadget<SpongeTranscript<S>, ConstraintF> for SpongeTranscriptGadget<ConstraintF, S, SG>
+    where
+        ConstraintF: PrimeField,
+        S:           FieldBasedSponge<Data = ConstraintF>,
+        SG:          FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>> + Clone,
+{
+    fn new<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        protocol_name: &[u8],
+    ) -> Result<Self, SynthesisError>
+    {
+        let mut sponge = SG::new(cs.ns(|| "new sponge"), &ConstraintF::zero())?;
+        let protocol_name = bytes_to_field_elements::<ConstraintF>(protocol_name)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_protocol_name_{}", i)), x))
+            .collect::<Vec<_>>();
+        sponge.absorb(cs.ns(|| "absorb protocol name"), protocol_name.as_slice())?;
+
+        Ok(Self { sponge, _sponge: PhantomData, _field: PhantomData })
+    }
+
+    fn append_field<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        elems: &[FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError>
+    {
+        self.sponge.absorb(cs, elems)
+    }
+
+    fn challenge_bits<F: PrimeField, CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError>
+    {
+        let to_skip = compute_truncation_size(
+            ConstraintF::size_in_bits() as i32,
+            F::size_in_bits() as i32,
+        );
+        // The decomposition must be the canonical one, for the challenge to be
+        // the same as the native one
+        let output = self.sponge.squeeze(cs.ns(|| "squeeze"), 1)?;
+        let mut bits = output[0].to_bits_strict(cs.ns(|| "output to bits"))?;
+        Ok(bits.split_off(to_skip))
+    }
+}
+
+pub type MNT4PoseidonTranscriptGadget = SpongeTranscriptGadget<
+    algebra::fields::mnt4753::Fr, primitives::crh::MNT4PoseidonSponge, MNT4PoseidonSpongeGadget
+>;
+pub type MNT6PoseidonTranscriptGadget = SpongeTranscriptGadget<
+    algebra::fields::mnt6753::Fr, primitives::crh::MNT6PoseidonSponge, MNT6PoseidonSpongeGadget
+>;
+
+/// The gadget of `Blake2sTranscript`: the messages are serialized through their
+/// canonical byte decomposition.
+pub struct Blake2sTranscriptGadget<ConstraintF: PrimeField> {
+    // The little endian bits of the bytes of the transcript since the
+    // previous challenge
+    state:  Vec<Boolean>,
+    _field: PhantomData<ConstraintF>,
+}
+
+impl<ConstraintF: PrimeField> Clone for Blake2sTranscriptGadget<ConstraintF> {
+    fn clone(&self) -> Self {
+        Self { state: self.state.clone(), _field: PhantomData }
+    }
+}
+
+impl<ConstraintF: PrimeField> FiatShamirTranscriptGadget<Blake2sTranscript<ConstraintF>, ConstraintF> for Blake2sTranscriptGadget<ConstraintF> {
+    fn new<CS: ConstraintSystem<ConstraintF>>(
+        _cs: CS,
+        protocol_name: &[u8],
+    ) -> Result<Self, SynthesisError>
+    {
+        let mut bytes = (protocol_name.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(protocol_name);
+        let state = bytes_to_bits(bytes.as_slice()).into_iter().map(Boolean::constant).collect();
+        Ok(Self { state, _field: PhantomData })
+    }
+
+    fn append_field<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError>
+    {
+        for (i, elem) in elems.iter().enumerate() {
+            let bytes = elem.to_bytes_strict(cs.ns(|| format!("elem_{}_to_bytes", i)))?;
+            self.state.extend(bytes.iter().flat_map(|byte| byte.into_bits_le()));
+        }
+        Ok(())
+    }
+
+    fn challenge_bits<S: PrimeField, CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError>
+    {
+        let digest = blake2s_gadget(cs.ns(|| "hash state"), self.state.as_slice())?;
+        self.state = digest.iter().flat_map(|word| word.to_bits_le()).collect();
+
+        let mut bits = self.state.clone();
+        bits.reverse();
+
+        let to_skip = compute_truncation_size(BLAKE2S_OUTPUT_BITS as i32, S::size_in_bits() as i32);
+        Ok(bits.split_off(to_skip))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{
+        curves::mnt6753::G1Projective as MNT6G1Projective,
+        fields::{mnt4753::Fr as MNT4753Fr, mnt6753::Fr as MNT6753Fr, bls12_381::Fr as BLS12381Fr},
+        ToBits, UniformRand,
+    };
+    use primitives::transcript::{MNT4PoseidonTranscript, MNT6PoseidonTranscript};
+    use r1cs_std::{
+        alloc::AllocGadget,
+        groups::curves::short_weierstrass::mnt::mnt6::mnt6753::MNT6G1Gadget,
+        test_constraint_system::TestConstraintSystem,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn transcript_primitive_gadget_test<F, T, TG>()
+        where
+            F:  PrimeField,
+            T:  FiatShamirTranscript<Field = F>,
+            TG: FiatShamirTranscriptGadget<T, F>,
+    {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<F>::new();
+
+        let mut transcript = T::new(b"TEST");
+        let mut transcript_gadget = TG::new(cs.ns(|| "new transcript"), b"TEST").unwrap();
+
+        // Challenges in fields smaller, of the same size, and bigger than the
+        // field of the messages
+        for i in 0..3 {
+            let elems = (0..i + 1).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+            let elems_gadget = elems
+                .iter()
+                .enumerate()
+                .map(|(j, x)| FpGadget::<F>::alloc(cs.ns(|| format!("alloc_elem_{}_{}", i, j)), || Ok(*x)).unwrap())
+                .collect::<Vec<_>>();
+
+            transcript.append_field(&elems);
+            transcript_gadget.append_field(cs.ns(|| format!("append_{}", i)), &elems_gadget).unwrap();
+
+            let check_bits = |bits: Vec<bool>, bits_gadget: Vec<Boolean>| {
+                assert_eq!(bits, bits_gadget.iter().map(|b| b.get_value().unwrap()).collect::<Vec<_>>());
+            };
+            check_bits(
+                transcript.challenge_bits::<BLS12381Fr>(),
+                transcript_gadget.challenge_bits::<BLS12381Fr, _>(cs.ns(|| format!("challenge_bls12_{}", i))).unwrap(),
+            );
+            check_bits(
+                transcript.challenge_bits::<MNT4753Fr>(),
+                transcript_gadget.challenge_bits::<MNT4753Fr, _>(cs.ns(|| format!("challenge_mnt4_{}", i))).unwrap(),
+            );
+            check_bits(
+                transcript.challenge_bits::<MNT6753Fr>(),
+                transcript_gadget.challenge_bits::<MNT6753Fr, _>(cs.ns(|| format!("challenge_mnt6_{}", i))).unwrap(),
+            );
+        }
+
+        println!("number of constraints total: {}", cs.num_constraints());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4_poseidon_transcript_primitive_gadget_test() {
+        transcript_primitive_gadget_test::<_, MNT4PoseidonTranscript, MNT4PoseidonTranscriptGadget>();
+    }
+
+    #[test]
+    fn mnt6_poseidon_transcript_primitive_gadget_test() {
+        transcript_primitive_gadget_test::<_, MNT6PoseidonTranscript, MNT6PoseidonTranscriptGadget>();
+    }
+
+    #[test]
+    fn blake2s_transcript_primitive_gadget_test() {
+        transcript_primitive_gadget_test::<_, Blake2sTranscript<MNT4753Fr>, Blake2sTranscriptGadget<MNT4753Fr>>();
+    }
+
+    #[test]
+    fn append_point_primitive_gadget_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<MNT4753Fr>::new();
+        let point = MNT6G1Projective::rand(&mut rng);
+        let point_gadget = MNT6G1Gadget::alloc(cs.ns(|| "alloc point"), || Ok(point)).unwrap();
+
+        let mut transcript = MNT4PoseidonTranscript::new(b"TEST");
+        transcript.append_point(&point).unwrap();
+        let mut transcript_gadget = MNT4PoseidonTranscriptGadget::new(cs.ns(|| "new transcript"), b"TEST").unwrap();
+        transcript_gadget.append_point(cs.ns(|| "append point"), &point_gadget).unwrap();
+
+        let challenge = transcript.challenge_scalar::<MNT6753Fr>().write_bits();
+        let challenge_gadget = transcript_gadget.challenge_bits::<MNT6753Fr, _>(cs.ns(|| "challenge")).unwrap();
+        let challenge_gadget = challenge_gadget.iter().map(|b| b.get_value().unwrap()).collect::<Vec<_>>();
+        // The scalar has the leading zeros skipped by the challenge bits
+        assert_eq!(challenge[challenge.len() - challenge_gadget.len()..], challenge_gadget[..]);
+        assert!(cs.is_satisfied());
+    }
+}
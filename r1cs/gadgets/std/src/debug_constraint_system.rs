@@ -0,0 +1,359 @@
+use algebra::Field;
+use r1cs_core::{ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+use crate::test_constraint_system::compute_path;
+#[cfg(feature = "debug-soundness")]
+use rand::Rng;
+
+/// A way in which a variable may be under-constrained.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariableIssue {
+    /// The variable appears in no constraint: it can take any value.
+    Unconstrained,
+    /// The variable appears only in the C column, i.e. it is computed by some
+    /// constraints but never used by any other one.
+    OnlyInC,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Occurrences {
+    a: bool,
+    b: bool,
+    c: bool,
+}
+
+type Constraint<F> = (LinearCombination<F>, LinearCombination<F>, LinearCombination<F>, String);
+
+/// Constraint system for finding under-constrained variables in gadgets.
+/// Unlike `TestConstraintSystem`, it accepts variables without assignment,
+/// so that blank circuits can be checked too.
+pub struct DebugConstraintSystem<ConstraintF: Field> {
+    current_namespace: Vec<String>,
+    pub constraints:   Vec<Constraint<ConstraintF>>,
+    inputs:            Vec<(Option<ConstraintF>, String)>,
+    aux:               Vec<(Option<ConstraintF>, String)>,
+}
+
+impl<ConstraintF: Field> Default for DebugConstraintSystem<ConstraintF> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ConstraintF: Field> DebugConstraintSystem<ConstraintF> {
+    pub fn new() -> Self {
+        DebugConstraintSystem {
+            current_namespace: vec![],
+            constraints:       vec![],
+            inputs:            vec![(Some(ConstraintF::one()), "ONE".into())],
+            aux:               vec![],
+        }
+    }
+
+    // The columns in which each input and each aux variable appear
+    fn occurrences(&self) -> (Vec<Occurrences>, Vec<Occurrences>) {
+        let mut inputs = vec![Occurrences::default(); self.inputs.len()];
+        let mut aux = vec![Occurrences::default(); self.aux.len()];
+        for (a, b, c, _) in self.constraints.iter() {
+            for (lc, column) in [(a, 0), (b, 1), (c, 2)].iter() {
+                for &(var, ref coeff) in lc.as_ref() {
+                    if coeff.is_zero() {
+                        continue;
+                    }
+                    let occurrences = match var.get_unchecked() {
+                        Index::Input(index) => &mut inputs[index],
+                        Index::Aux(index) => &mut aux[index],
+                    };
+                    match column {
+                        0 => occurrences.a = true,
+                        1 => occurrences.b = true,
+                        _ => occurrences.c = true,
+                    }
+                }
+            }
+        }
+        (inputs, aux)
+    }
+
+    /// Returns the path of every allocated variable, but ONE, which appears
+    /// in no constraint or only in the C column of constraints.
+    pub fn under_constrained_variables(&self) -> Vec<(&str, VariableIssue)> {
+        let (input_occurrences, aux_occurrences) = self.occurrences();
+        let inputs = self.inputs.iter().zip(input_occurrences).skip(1);
+        let aux = self.aux.iter().zip(aux_occurrences);
+        inputs
+            .chain(aux)
+            .filter_map(|((_, path), occurrences)| match occurrences {
+                Occurrences { a: false, b: false, c: false } => Some((path.as_str(), VariableIssue::Unconstrained)),
+                Occurrences { a: false, b: false, c: true } => Some((path.as_str(), VariableIssue::OnlyInC)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "debug-soundness")]
+impl<ConstraintF: Field> DebugConstraintSystem<ConstraintF> {
+    /// Looks, for each aux variable, for another satisfying assignment with
+    /// the same inputs in which this variable has a random different value.
+    /// The other aux variables are re-solved one at a time, from constraints
+    /// in which they appear linearly, so an aux variable not reported is not
+    /// proven to be constrained. Returns the paths of the reported variables,
+    /// or an error if the assignment is missing or doesn't satisfy the
+    /// constraints. Available with the `debug-soundness` feature.
+    pub fn check_soundness<R: Rng>(&self, rng: &mut R) -> Result<Vec<&str>, SynthesisError> {
+        let values = |vars: &[(Option<ConstraintF>, String)]| {
+            vars.iter()
+                .map(|(value, _)| value.ok_or(SynthesisError::AssignmentMissing))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let (inputs, aux) = (values(&self.inputs)?, values(&self.aux)?);
+        if (0..self.constraints.len()).any(|k| !self.is_constraint_satisfied(k, &inputs, &aux)) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // The constraints in which each aux variable appears
+        let mut aux_constraints = vec![vec![]; self.aux.len()];
+        for (k, (a, b, c, _)) in self.constraints.iter().enumerate() {
+            for &(var, _) in a.as_ref().iter().chain(b.as_ref()).chain(c.as_ref()) {
+                if let Index::Aux(index) = var.get_unchecked() {
+                    if aux_constraints[index].last() != Some(&k) {
+                        aux_constraints[index].push(k);
+                    }
+                }
+            }
+        }
+
+        let mut reported = vec![];
+        for index in 0..self.aux.len() {
+            let mut perturbed = aux.clone();
+            perturbed[index] += &loop {
+                let delta = ConstraintF::rand(rng);
+                if !delta.is_zero() {
+                    break delta;
+                }
+            };
+            if self.resolve(index, &inputs, &mut perturbed, &aux_constraints) {
+                reported.push(self.aux[index].1.as_str());
+            }
+        }
+        Ok(reported)
+    }
+
+    // Re-solves the constraints, the aux variable at `index` being fixed,
+    // and returns true if they are all satisfied in the end
+    fn resolve(
+        &self,
+        index: usize,
+        inputs: &[ConstraintF],
+        aux: &mut [ConstraintF],
+        aux_constraints: &[Vec<usize>],
+    ) -> bool {
+        let mut fixed = vec![false; aux.len()];
+        fixed[index] = true;
+        let mut pending = aux_constraints[index].clone();
+        while let Some(k) = pending.pop() {
+            if self.is_constraint_satisfied(k, inputs, aux) {
+                continue;
+            }
+            match self.solve_constraint(k, inputs, aux, &fixed) {
+                Some((solved, value)) => {
+                    aux[solved] = value;
+                    fixed[solved] = true;
+                    pending.extend(aux_constraints[solved].iter().filter(|&&other| other != k));
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+
+    // Finds a variable not yet fixed and appearing linearly in the k-th
+    // constraint, and the value satisfying it
+    fn solve_constraint(
+        &self,
+        k: usize,
+        inputs: &[ConstraintF],
+        aux: &[ConstraintF],
+        fixed: &[bool],
+    ) -> Option<(usize, ConstraintF)> {
+        let (a, b, c, _) = &self.constraints[k];
+        let (a, b, c) = (a.as_ref(), b.as_ref(), c.as_ref());
+        let eval = |lc: &[(Variable, ConstraintF)]| Self::eval_lc(lc, inputs, aux);
+        for &(var, _) in a.iter().chain(b).chain(c) {
+            let index = match var.get_unchecked() {
+                Index::Aux(index) if !fixed[index] => index,
+                _ => continue,
+            };
+            let (coeff_a, coeff_b, coeff_c) = (coeff(a, var), coeff(b, var), coeff(c, var));
+            let value = aux[index];
+            // The LC without the variable
+            let rest = |lc, coeff: ConstraintF| eval(lc) - &(coeff * &value);
+            let solution = match (coeff_a.is_zero(), coeff_b.is_zero(), coeff_c.is_zero()) {
+                (true, true, false) => Some((eval(a) * &eval(b) - &rest(c, coeff_c)) / &coeff_c),
+                (false, true, true) => eval(b).inverse().map(|b_inv| (eval(c) * &b_inv - &rest(a, coeff_a)) / &coeff_a),
+                (true, false, true) => eval(a).inverse().map(|a_inv| (eval(c) * &a_inv - &rest(b, coeff_b)) / &coeff_b),
+                _ => None,
+            };
+            if let Some(solution) = solution {
+                return Some((index, solution));
+            }
+        }
+        None
+    }
+
+    fn is_constraint_satisfied(&self, k: usize, inputs: &[ConstraintF], aux: &[ConstraintF]) -> bool {
+        let (a, b, c, _) = &self.constraints[k];
+        Self::eval_lc(a.as_ref(), inputs, aux) * &Self::eval_lc(b.as_ref(), inputs, aux)
+            == Self::eval_lc(c.as_ref(), inputs, aux)
+    }
+
+    fn eval_lc(terms: &[(Variable, ConstraintF)], inputs: &[ConstraintF], aux: &[ConstraintF]) -> ConstraintF {
+        let mut acc = ConstraintF::zero();
+        for &(var, ref coeff) in terms {
+            acc += &(match var.get_unchecked() {
+                Index::Input(index) => inputs[index],
+                Index::Aux(index) => aux[index],
+            } * coeff);
+        }
+        acc
+    }
+}
+
+// The coefficient of `var` in `terms`, which may contain it several times
+#[cfg(feature = "debug-soundness")]
+fn coeff<F: Field>(terms: &[(Variable, F)], var: Variable) -> F {
+    terms.iter().filter(|(other, _)| *other == var).fold(F::zero(), |acc, (_, coeff)| acc + coeff)
+}
+
+impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for DebugConstraintSystem<ConstraintF> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<ConstraintF, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.aux.len();
+        let path = compute_path(&self.current_namespace, annotation().into());
+        self.aux.push((f().ok(), path));
+
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<ConstraintF, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.inputs.len();
+        let path = compute_path(&self.current_namespace, annotation().into());
+        self.inputs.push((f().ok(), path));
+
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LB: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LC: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+    {
+        let path = compute_path(&self.current_namespace, annotation().into());
+        let a = a(LinearCombination::zero());
+        let b = b(LinearCombination::zero());
+        let c = c(LinearCombination::zero());
+
+        self.constraints.push((a, b, c, path));
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.current_namespace.push(name_fn().into());
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.current_namespace.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        fields::{fp::FpGadget, FieldGadget},
+        prelude::*,
+    };
+    use algebra::fields::mnt6753::Fq;
+    #[cfg(feature = "debug-soundness")]
+    use rand::SeedableRng;
+    #[cfg(feature = "debug-soundness")]
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn under_constrained_variables() {
+        let mut cs = DebugConstraintSystem::<Fq>::new();
+        let x = FpGadget::alloc(cs.ns(|| "x"), || Ok(Fq::from(3u64))).unwrap();
+        let y = FpGadget::alloc_input(cs.ns(|| "y"), || Ok(Fq::from(9u64))).unwrap();
+        FpGadget::<Fq>::alloc(cs.ns(|| "unused"), || Ok(Fq::from(5u64))).unwrap();
+        let square = x.square(cs.ns(|| "square")).unwrap();
+        square.enforce_equal(cs.ns(|| "square = y"), &y).unwrap();
+        x.mul(cs.ns(|| "x * y"), &y).unwrap();
+
+        assert_eq!(
+            cs.under_constrained_variables(),
+            vec![("unused/alloc", VariableIssue::Unconstrained), ("x * y/mul/alloc", VariableIssue::OnlyInC)]
+        );
+
+        // An assignment is needed
+        let mut cs = DebugConstraintSystem::<Fq>::new();
+        FpGadget::<Fq>::alloc(cs.ns(|| "x"), || Err::<Fq, _>(SynthesisError::AssignmentMissing)).unwrap();
+        assert!(cs.under_constrained_variables().len() == 1);
+    }
+
+    #[cfg(feature = "debug-soundness")]
+    #[test]
+    fn soundness() {
+        let mut cs = DebugConstraintSystem::<Fq>::new();
+        let x = FpGadget::alloc(cs.ns(|| "x"), || Ok(Fq::from(3u64))).unwrap();
+        let y = FpGadget::alloc_input(cs.ns(|| "y"), || Ok(Fq::from(9u64))).unwrap();
+        FpGadget::<Fq>::alloc(cs.ns(|| "unused"), || Ok(Fq::from(5u64))).unwrap();
+        let square = x.square(cs.ns(|| "square")).unwrap();
+        square.enforce_equal(cs.ns(|| "square = y"), &y).unwrap();
+        x.mul(cs.ns(|| "x * y"), &y).unwrap();
+
+        // The product is fixed by x, itself fixed by y
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        assert_eq!(cs.check_soundness(rng).unwrap(), vec!["unused/alloc"]);
+
+        // Without the square, x is free too, and so are the variables
+        // computed from it
+        let mut cs = DebugConstraintSystem::<Fq>::new();
+        let x = FpGadget::alloc(cs.ns(|| "x"), || Ok(Fq::from(3u64))).unwrap();
+        let y = FpGadget::alloc_input(cs.ns(|| "y"), || Ok(Fq::from(9u64))).unwrap();
+        let xy = x.mul(cs.ns(|| "x * y"), &y).unwrap();
+        let z = xy.add_constant(cs.ns(|| "xy + 1"), &Fq::one()).unwrap();
+        z.inverse(cs.ns(|| "inverse")).unwrap();
+        assert_eq!(cs.check_soundness(rng).unwrap(), vec!["x/alloc", "x * y/mul/alloc", "inverse/inverse/alloc"]);
+
+        // An assignment is needed
+        let mut cs = DebugConstraintSystem::<Fq>::new();
+        FpGadget::<Fq>::alloc(cs.ns(|| "x"), || Err::<Fq, _>(SynthesisError::AssignmentMissing)).unwrap();
+        assert!(cs.check_soundness(rng).is_err());
+    }
+}
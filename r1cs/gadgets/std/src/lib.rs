@@ -39,6 +39,8 @@ extern crate algebra;
 extern crate derivative;
 
 pub mod test_constraint_system;
+pub mod profiling_constraint_system;
+pub mod debug_constraint_system;
 
 pub mod bits;
 pub use self::bits::*;
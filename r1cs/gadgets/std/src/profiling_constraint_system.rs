@@ -0,0 +1,282 @@
+use algebra::Field;
+use r1cs_core::{ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+use std::{collections::HashMap, fmt, io, marker::PhantomData};
+
+/// The number of constraints and variables created in a namespace, and in
+/// its sub-namespaces, in the order in which they were first entered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileNode {
+    pub name:            String,
+    /// The constraints enforced directly in this namespace
+    pub num_constraints: usize,
+    /// The variables, public or private, allocated directly in this namespace
+    pub num_variables:   usize,
+    pub children:        Vec<ProfileNode>,
+    // The index of each child in `children`, by name
+    child_indices:       HashMap<String, usize>,
+}
+
+/// The quantity reported by `ProfilingConstraintSystem::write_folded_stacks`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileMetric {
+    Constraints,
+    Variables,
+}
+
+impl ProfileNode {
+    fn new(name: String) -> Self {
+        Self { name, ..Default::default() }
+    }
+
+    /// The number of constraints enforced in this namespace and its sub-namespaces.
+    pub fn total_constraints(&self) -> usize {
+        self.num_constraints + self.children.iter().map(|child| child.total_constraints()).sum::<usize>()
+    }
+
+    /// The number of variables allocated in this namespace and its sub-namespaces.
+    pub fn total_variables(&self) -> usize {
+        self.num_variables + self.children.iter().map(|child| child.total_variables()).sum::<usize>()
+    }
+
+    /// Returns the sub-namespace at `path`, given relatively to this namespace.
+    pub fn get(&self, path: &[&str]) -> Option<&ProfileNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((name, rest)) => self.children[*self.child_indices.get(*name)?].get(rest),
+        }
+    }
+
+    // Returns the index of the child named `name`, adding it if needed.
+    fn child_index(&mut self, name: String) -> usize {
+        match self.child_indices.get(&name) {
+            Some(&i) => i,
+            None => {
+                let i = self.children.len();
+                self.child_indices.insert(name.clone(), i);
+                self.children.push(ProfileNode::new(name));
+                i
+            },
+        }
+    }
+
+    fn write_folded_stacks<W: io::Write>(
+        &self,
+        stack: &mut String,
+        metric: ProfileMetric,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let stack_len = stack.len();
+        if stack_len > 0 {
+            stack.push(';');
+        }
+        // ';' separates the frames and the last space the count
+        stack.extend(self.name.chars().map(|c| if c == ';' || c.is_whitespace() { '_' } else { c }));
+
+        let count = match metric {
+            ProfileMetric::Constraints => self.num_constraints,
+            ProfileMetric::Variables => self.num_variables,
+        };
+        if count > 0 {
+            writeln!(writer, "{} {}", stack, count)?;
+        }
+        for child in self.children.iter() {
+            child.write_folded_stacks(stack, metric, writer)?;
+        }
+
+        stack.truncate(stack_len);
+        Ok(())
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}{}: {} constraints, {} variables",
+            "",
+            self.name,
+            self.total_constraints(),
+            self.total_variables(),
+            indent = 2 * depth
+        )?;
+        for child in self.children.iter() {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the tree of namespaces with their total number of constraints and
+/// variables.
+impl fmt::Display for ProfileNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// Constraint system counting the constraints and variables created in each
+/// namespace, to find out which parts of a circuit are the most expensive.
+/// The assignment is never computed, so that blank circuits can be profiled.
+pub struct ProfilingConstraintSystem<ConstraintF: Field> {
+    root:         ProfileNode,
+    // The index of each namespace of the current path among its siblings
+    current_path: Vec<usize>,
+    num_inputs:   usize,
+    num_aux:      usize,
+    _field:       PhantomData<ConstraintF>,
+}
+
+impl<ConstraintF: Field> Default for ProfilingConstraintSystem<ConstraintF> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ConstraintF: Field> ProfilingConstraintSystem<ConstraintF> {
+    pub fn new() -> Self {
+        ProfilingConstraintSystem {
+            root:         ProfileNode::new("root".into()),
+            current_path: vec![],
+            num_inputs:   1,
+            num_aux:      0,
+            _field:       PhantomData,
+        }
+    }
+
+    /// The profile of the whole circuit, its root being named "root".
+    pub fn profile(&self) -> &ProfileNode {
+        &self.root
+    }
+
+    /// Writes the profile in the folded stacks format read by flamegraph
+    /// tools: one line per namespace, with its path separated by ';' and
+    /// the constraints or variables created directly in it.
+    pub fn write_folded_stacks<W: io::Write>(&self, metric: ProfileMetric, mut writer: W) -> io::Result<()> {
+        self.root.write_folded_stacks(&mut String::new(), metric, &mut writer)
+    }
+
+    fn current_node(&mut self) -> &mut ProfileNode {
+        let mut node = &mut self.root;
+        for &i in self.current_path.iter() {
+            node = &mut node.children[i];
+        }
+        node
+    }
+}
+
+impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ProfilingConstraintSystem<ConstraintF> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<ConstraintF, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.num_aux;
+        self.num_aux += 1;
+        self.current_node().num_variables += 1;
+
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<ConstraintF, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.num_inputs;
+        self.num_inputs += 1;
+        self.current_node().num_variables += 1;
+
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LB: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LC: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+    {
+        self.current_node().num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        let i = self.current_node().child_index(name_fn().into());
+        self.current_path.push(i);
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.current_path.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.root.total_constraints()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        fields::{fp::FpGadget, FieldGadget},
+        prelude::*,
+        test_constraint_system::TestConstraintSystem,
+    };
+    use algebra::fields::mnt6753::Fq;
+
+    fn square_and_invert<CS: ConstraintSystem<Fq>>(mut cs: CS) {
+        let x = FpGadget::alloc(cs.ns(|| "alloc x"), || Ok(Fq::from(3u64))).unwrap();
+        let y = x.square(cs.ns(|| "square")).unwrap();
+        y.inverse(cs.ns(|| "invert")).unwrap();
+        let z = y.square(cs.ns(|| "square again")).unwrap();
+        let expected = FpGadget::from(cs.ns(|| "81"), &Fq::from(81u64));
+        z.enforce_equal(cs.ns(|| "check"), &expected).unwrap();
+    }
+
+    #[test]
+    fn profile_namespaces() {
+        let mut test_cs = TestConstraintSystem::<Fq>::new();
+        square_and_invert(test_cs.ns(|| "first"));
+        assert!(test_cs.is_satisfied());
+
+        let mut cs = ProfilingConstraintSystem::<Fq>::new();
+        square_and_invert(cs.ns(|| "first"));
+        square_and_invert(cs.ns(|| "second"));
+        cs.enforce(|| "root constraint", |lc| lc, |lc| lc, |lc| lc);
+
+        let profile = cs.profile();
+        assert_eq!(cs.num_constraints(), 2 * test_cs.num_constraints() + 1);
+        assert_eq!(profile.num_constraints, 1);
+        assert_eq!(profile.children.iter().map(|child| child.name.as_str()).collect::<Vec<_>>(), vec!["first", "second"]);
+        assert_eq!(profile.get(&["first"]), profile.get(&["second"]).map(|second| ProfileNode { name: "first".into(), ..second.clone() }).as_ref());
+        assert_eq!(profile.get(&["first", "square"]).unwrap().total_constraints(), 1);
+        assert_eq!(profile.get(&["first", "alloc x"]).unwrap().total_variables(), 1);
+        assert!(profile.get(&["first", "cube"]).is_none());
+
+        // Entering a namespace again adds to it
+        square_and_invert(cs.ns(|| "first"));
+        assert_eq!(cs.profile().get(&["first"]).unwrap().total_constraints(), 2 * test_cs.num_constraints());
+
+        let mut folded = Vec::new();
+        cs.write_folded_stacks(ProfileMetric::Constraints, &mut folded).unwrap();
+        let folded = String::from_utf8(folded).unwrap();
+        assert!(folded.starts_with("root 1\n"));
+        assert!(folded.contains("\nroot;first;square_again 2\n"));
+        assert!(folded.contains("\nroot;second;square_again 1\n"));
+        assert_eq!(
+            folded.lines().map(|line| line.rsplit(' ').next().unwrap().parse::<usize>().unwrap()).sum::<usize>(),
+            cs.num_constraints()
+        );
+    }
+}
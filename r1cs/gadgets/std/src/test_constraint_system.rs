@@ -130,7 +130,7 @@ impl<ConstraintF: Field> TestConstraintSystem<ConstraintF> {
     }
 }
 
-fn compute_path(ns: &[String], this: String) -> String {
+pub(crate) fn compute_path(ns: &[String], this: String) -> String {
     if this.chars().any(|a| a == '/') {
         panic!("'/' is not allowed in names");
     }